@@ -0,0 +1,42 @@
+//! 生成 `src` 下所有源文件的内容表，供 `example!` 展示示例源码
+//!
+//! `file!()` 在作为依赖、或者在 workspace 中构建时不一定相对于本 crate 的根目录，
+//! 所以不能在宏里直接拼出路径交给 `include_str!`，改为在这里用 `CARGO_MANIFEST_DIR` 一次性收集。
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn main() -> io::Result<()> {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").expect("cargo 会设置 CARGO_MANIFEST_DIR"));
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("cargo 会设置 OUT_DIR"));
+
+    let mut files = Vec::new();
+    collect(&manifest_dir.join("src"), &mut files)?;
+    files.sort();
+
+    let mut table = String::from("&[\n");
+    for file in &files {
+        let relative = file.strip_prefix(&manifest_dir).expect("src 在 crate 根目录下");
+        let key = relative.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/");
+        table += &format!("    ({:?}, include_str!({:?})),\n", key, file.display().to_string());
+    }
+    table += "]\n";
+    fs::write(out_dir.join("sources.rs"), table)?;
+
+    println!("cargo:rerun-if-changed=src");
+    Ok(())
+}
+
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,16 @@
+// 结构体中的引用字段需要生命周期标识符
+pub struct User {
+    pub username: &str,
+    pub email: &str,
+    pub sign_in_count: u64,
+    pub active: bool,
+}
+
+pub fn build_user() -> User {
+    User {
+        email: "someone@example.com",
+        username: "someusername123",
+        active: true,
+        sign_in_count: 1,
+    }
+}
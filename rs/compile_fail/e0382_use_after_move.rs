@@ -0,0 +1,8 @@
+// `let s2 = s1` 把所有权从 s1 移动到了 s2，s1 不再有效
+pub fn use_after_move() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+
+    println!("{}, world!", s1);
+    println!("{}", s2);
+}
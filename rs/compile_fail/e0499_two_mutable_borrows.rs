@@ -0,0 +1,9 @@
+// 同一作用域，特定数据只能有一个可变引用
+pub fn two_mutable_borrows() {
+    let mut s = String::from("hello");
+
+    let r1 = &mut s;
+    let r2 = &mut s;
+
+    println!("{}, {}", r1, r2);
+}
@@ -0,0 +1,10 @@
+// 可变引用与不可变引用不能同时存在
+pub fn shared_and_mutable_borrow() {
+    let mut s = String::from("hello");
+
+    let r1 = &s; // 没问题
+    let r2 = &s; // 没问题
+    let r3 = &mut s; // 大问题
+
+    println!("{}, {}, and {}", r1, r2, r3);
+}
@@ -0,0 +1,17 @@
+// 练习：String 与字符串切片
+
+// 返回第一个单词（以空格分隔），没有空格时返回整个字符串
+// 返回值是输入的切片，不要分配新的 String
+pub fn first_word(s: &str) -> &str {
+    todo!()
+}
+
+// 返回字符串中的字符个数，注意 "中国人".len() 是 9 而不是 3
+pub fn char_count(s: &str) -> usize {
+    todo!()
+}
+
+// 把两个字符串用 "-" 连接起来，例如 ("hello", "world") -> "hello-world"
+pub fn join(a: &str, b: &str) -> String {
+    todo!()
+}
@@ -0,0 +1,33 @@
+// 练习：结构体、方法与枚举
+
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rectangle {
+    // 关联函数：创建一个正方形
+    pub fn square(size: u32) -> Rectangle {
+        todo!()
+    }
+
+    pub fn area(&self) -> u32 {
+        todo!()
+    }
+
+    // self 能否完全容纳 other（不旋转）
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        todo!()
+    }
+}
+
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+}
+
+// 用 match 描述消息：Quit -> "quit"，Move -> "move to (x, y)"，Write -> 消息内容本身
+pub fn describe(message: &Message) -> String {
+    todo!()
+}
@@ -0,0 +1,28 @@
+// 练习：泛型与特征
+
+pub trait Summary {
+    fn summarize_author(&self) -> String;
+
+    // 默认实现：可以调用同一特征中没有默认实现的方法
+    fn summarize(&self) -> String {
+        // 返回 "(Read more from <作者>...)"
+        todo!()
+    }
+}
+
+pub struct Weibo {
+    pub username: String,
+    pub content: String,
+}
+
+// 为 Weibo 实现 Summary，作者为 "@" 加上用户名
+impl Summary for Weibo {
+    fn summarize_author(&self) -> String {
+        todo!()
+    }
+}
+
+// 返回切片中最大的元素，需要给 T 加上合适的特征约束
+pub fn largest<T>(list: &[T]) -> T {
+    todo!()
+}
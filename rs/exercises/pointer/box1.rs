@@ -0,0 +1,24 @@
+// 练习：Box 与递归类型
+// List 的大小在编译期无法确定，需要用 Box 把下一个节点放到堆上
+
+#[derive(Debug, PartialEq)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+impl List {
+    // 从切片构建链表，例如 [1, 2] -> Cons(1, Cons(2, Nil))
+    pub fn from_slice(values: &[i32]) -> List {
+        todo!()
+    }
+
+    pub fn sum(&self) -> i32 {
+        todo!()
+    }
+}
+
+// 把一个运行期生成的 String 变成 &'static str，提示：Box::leak
+pub fn leak_string(s: String) -> &'static str {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// 练习：Vec 与 HashMap
+use std::collections::HashMap;
+
+// 返回所有偶数，保持原有顺序
+pub fn evens(numbers: &[i32]) -> Vec<i32> {
+    todo!()
+}
+
+// 统计每个单词出现的次数（以空白分隔），提示：entry(...).or_insert(0)
+pub fn word_count(text: &str) -> HashMap<String, usize> {
+    todo!()
+}
+
+// 按年龄从大到小排序，年龄相同的按名字字母序
+pub fn sort_people(people: &mut Vec<(String, u32)>) {
+    todo!()
+}
@@ -0,0 +1,17 @@
+// 练习：所有权与借用
+// 调用这两个函数之后，调用者手里的 String 仍然要可以继续使用
+
+// 给字符串追加 ", world"，只借用，不获取所有权
+pub fn append_world(s: &mut String) {
+    todo!("通过可变引用修改字符串")
+}
+
+// 返回字符串的字节长度，只借用，不获取所有权
+pub fn calculate_length(s: &str) -> usize {
+    todo!()
+}
+
+// 返回一个和输入内容相同、但互相独立的 String（深拷贝）
+pub fn duplicate(s: &String) -> String {
+    todo!()
+}
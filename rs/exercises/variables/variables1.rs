@@ -0,0 +1,15 @@
+// 练习：变量绑定、可变性与变量遮蔽
+// 把下面的 todo!() 替换成实现，然后运行 `rust_code_examples check variables1`
+
+// 返回 1 到 n 的和
+// 需要一个可以修改的累加变量，想一想 let 和 let mut 的区别
+pub fn count_up(n: u32) -> u32 {
+    todo!("声明一个可变的累加变量，在 for 循环中累加")
+}
+
+// 把字符串形式的数字（可能带有首尾空格）解析出来并乘以 2
+// 不要引入新的变量名，用变量遮蔽让 value 从 &str 变成 i32
+pub fn parse_and_double(input: &str) -> i32 {
+    let value = input.trim();
+    todo!("用 let value = ... 遮蔽上面的 value")
+}
@@ -0,0 +1,86 @@
+//! 状态压缩：用一个整数的各个二进制位表示集合，旅行商问题（Held-Karp）
+
+use super::Table;
+
+/// 一条回路：从 0 号城市出发，经过每个城市一次再回到 0 号
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour {
+    pub length: u64,
+    /// 依次经过的城市，以 0 开头，不重复写回到的 0
+    pub order: Vec<usize>,
+}
+
+/// 最短的回路，`distance[i][j]` 是从 i 到 j 的距离（可以不对称），O(2^n · n²)
+///
+/// `best[visited][last]`：从 0 出发，恰好走过集合 visited 中的城市并停在 last 的最短路程。
+/// 去掉 last 之后的集合比 visited 小，按集合的整数值从小到大填表时已经算好。
+///
+/// # Panics
+///
+/// 没有城市、距离矩阵不是方阵或者超过 16 个城市时 panic。
+pub fn shortest_tour(distance: &[Vec<u64>]) -> Tour {
+    let n = distance.len();
+    assert!((1..=16).contains(&n), "需要 1 到 16 个城市");
+    assert!(distance.iter().all(|row| row.len() == n), "距离矩阵需要是 n x n 的");
+
+    // 值是 (最短路程, 上一个城市)，None 表示这个状态不可能出现
+    type Cell = Option<(u64, usize)>;
+    let best = Table::build(1 << n, n, |best: &Table<Cell>, visited, last| {
+        if visited & 1 == 0 || visited >> last & 1 == 0 {
+            return None;
+        }
+        if visited == 1 {
+            return Some((0, 0));
+        }
+        let before = visited & !(1 << last);
+        // 不可能的状态是 None，直接枚举所有的上一个城市
+        (0..n)
+            .filter_map(|previous| best[(before, previous)].map(|(length, _)| (length + distance[previous][last], previous)))
+            .min()
+    });
+
+    let all = (1 << n) - 1;
+    let (length, mut last) = (0..n)
+        .filter_map(|last| best[(all, last)].map(|(length, _)| (length + distance[last][0], last)))
+        .min()
+        .expect("至少有一个城市");
+    let mut order = Vec::with_capacity(n);
+    let mut visited = all;
+    while visited != 1 {
+        order.push(last);
+        let (_, previous) = best[(visited, last)].expect("回溯经过的状态都可以到达");
+        visited &= !(1 << last);
+        last = previous;
+    }
+    order.push(0);
+    order.reverse();
+    Tour { length, order }
+}
+
+/// 回路的长度
+pub fn tour_length(distance: &[Vec<u64>], order: &[usize]) -> u64 {
+    order.iter().zip(order.iter().skip(1).chain(&[0])).map(|(&from, &to)| distance[from][to]).sum()
+}
+
+/// 暴力枚举 0 号城市之后其余城市的所有排列，返回最短回路的长度
+#[cfg(test)]
+pub(super) fn brute_force(distance: &[Vec<u64>]) -> u64 {
+    fn go(distance: &[Vec<u64>], order: &mut Vec<usize>, rest: &mut Vec<usize>) -> u64 {
+        if rest.is_empty() {
+            return tour_length(distance, order);
+        }
+        let mut best = u64::MAX;
+        for i in 0..rest.len() {
+            let city = rest.swap_remove(i);
+            order.push(city);
+            best = best.min(go(distance, order, rest));
+            order.pop();
+            rest.push(city);
+            let last = rest.len() - 1;
+            rest.swap(i, last);
+        }
+        best
+    }
+    let mut rest: Vec<usize> = (1..distance.len()).collect();
+    go(distance, &mut vec![0], &mut rest)
+}
@@ -0,0 +1,65 @@
+//! 找零：最少的硬币个数和凑出金额的方法数
+
+use super::{Memo, Table};
+
+/// 凑出 amount 最少要几枚硬币，返回用到的硬币（从大到小），凑不出时返回 None，O(amount · 硬币种类)
+///
+/// 每种硬币的数量不限。`fewest[x]` 是凑出 x 的最少枚数和最后一枚的面值。
+pub fn min_coins(coins: &[u64], amount: u64) -> Option<Vec<u64>> {
+    let fewest = Table::line(amount as usize + 1, |fewest: &Table<Option<(usize, u64)>>, x| match x {
+        0 => Some((0, 0)),
+        _ => coins
+            .iter()
+            .filter(|&&c| c > 0 && c as usize <= x)
+            .filter_map(|&c| fewest[x - c as usize].map(|(n, _)| (n + 1, c)))
+            .min_by_key(|&(n, _)| n),
+    });
+    fewest[amount as usize]?;
+
+    let mut used = Vec::new();
+    let mut x = amount as usize;
+    while x > 0 {
+        let (_, coin) = fewest[x].expect("凑得出的金额去掉最后一枚硬币也凑得出");
+        used.push(coin);
+        x -= coin as usize;
+    }
+    used.sort_unstable_by(|a, b| b.cmp(a));
+    Some(used)
+}
+
+/// 凑出 amount 有多少种方法，只看每种硬币用了几枚，不看顺序
+///
+/// `ways(i, x)`：只用第 i 种及以后的硬币凑出 x 的方法数，等于不再用第 i 种的方法数加上再用一枚的方法数。
+/// 自顶向下计算，只会访问到真正需要的子问题；递归深度可以达到 amount / 最小面值。
+pub fn count_ways(coins: &[u64], amount: u64) -> u64 {
+    let mut ways = Memo::new(|ways: &mut dyn FnMut((usize, u64)) -> u64, (i, x): (usize, u64)| match coins.get(i) {
+        _ if x == 0 => 1,
+        None => 0,
+        // 面值为 0 的硬币凑不出任何金额，跳过它
+        Some(0) => ways((i + 1, x)),
+        Some(&coin) => ways((i + 1, x)) + if coin <= x { ways((i, x - coin)) } else { 0 },
+    });
+    ways.get((0, amount))
+}
+
+/// 暴力枚举每种硬币的枚数，返回 (最少枚数, 方法数)
+#[cfg(test)]
+pub(super) fn brute_force(coins: &[u64], amount: u64) -> (Option<usize>, u64) {
+    fn go(coins: &[u64], amount: u64, used: usize, best: &mut Option<usize>, ways: &mut u64) {
+        let Some((&coin, rest)) = coins.split_first() else {
+            if amount == 0 {
+                *best = Some(best.map_or(used, |b| b.min(used)));
+                *ways += 1;
+            }
+            return;
+        };
+        // 面值为 0 的硬币没有用
+        let max = amount.checked_div(coin).unwrap_or(0);
+        for count in 0..=max {
+            go(rest, amount - count * coin, used + count as usize, best, ways);
+        }
+    }
+    let (mut best, mut ways) = (None, 0);
+    go(coins, amount, 0, &mut best, &mut ways);
+    (best, ways)
+}
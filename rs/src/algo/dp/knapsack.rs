@@ -0,0 +1,59 @@
+//! 0-1 背包
+
+use super::Table;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub weight: usize,
+    pub value: u64,
+}
+
+/// 选中的物品和总价值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packing {
+    pub value: u64,
+    pub weight: usize,
+    /// 选中物品的下标，从小到大
+    pub chosen: Vec<usize>,
+}
+
+/// 每个物品最多选一次，总重量不超过 capacity 时的最大价值，O(n · capacity)
+///
+/// `best[i][w]` 是只用前 i 个物品、容量为 w 时的最大价值；
+/// 第 i 个物品要么不选，要么选上并从 `best[i - 1][w - weight]` 转移过来。
+pub fn knapsack(items: &[Item], capacity: usize) -> Packing {
+    let best = Table::build(items.len() + 1, capacity + 1, |best: &Table<u64>, i, w| match i {
+        0 => 0,
+        _ => {
+            let Item { weight, value } = items[i - 1];
+            let skip = best[(i - 1, w)];
+            if weight <= w { skip.max(best[(i - 1, w - weight)] + value) } else { skip }
+        }
+    });
+
+    // 从最后一行往回走，价值变化了说明选了这个物品
+    let mut chosen = Vec::new();
+    let mut w = capacity;
+    for i in (1..=items.len()).rev() {
+        if best[(i, w)] != best[(i - 1, w)] {
+            chosen.push(i - 1);
+            w -= items[i - 1].weight;
+        }
+    }
+    chosen.reverse();
+    Packing { value: best[(items.len(), capacity)], weight: capacity - w, chosen }
+}
+
+/// 暴力枚举所有 2^n 个子集，返回最大价值
+#[cfg(test)]
+pub(super) fn brute_force(items: &[Item], capacity: usize) -> u64 {
+    assert!(items.len() < 24, "物品太多，暴力枚举不现实");
+    (0..1usize << items.len())
+        .filter_map(|mask| {
+            let chosen = items.iter().enumerate().filter(|(i, _)| mask >> i & 1 == 1).map(|(_, item)| item);
+            let (weight, value) = chosen.fold((0, 0), |(w, v), item| (w + item.weight, v + item.value));
+            (weight <= capacity).then_some(value)
+        })
+        .max()
+        .unwrap_or(0)
+}
@@ -0,0 +1,51 @@
+//! 矩阵连乘的最优加括号方式
+
+use super::Memo;
+
+/// 最少的标量乘法次数和对应的加括号方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parenthesization {
+    pub cost: u64,
+    /// 例如 `((AB)C)`，矩阵依次记为 A、B、C……
+    pub order: String,
+}
+
+/// 第 i 个矩阵的大小是 `dims[i] x dims[i + 1]`，求连乘 `dims.len() - 1` 个矩阵的最少乘法次数，O(n³)
+///
+/// `cost(i, j)` 是第 i 到第 j 个矩阵连乘的最少次数，枚举最后一次乘法的分割点 k：
+/// `cost(i, k) + cost(k + 1, j) + dims[i] · dims[k + 1] · dims[j + 1]`。
+/// 区间问题的子问题不是按行优先顺序依赖的，用 [`Memo`] 自顶向下计算最自然。
+///
+/// # Panics
+///
+/// 没有矩阵或者超过 26 个矩阵时 panic。
+pub fn matrix_chain(dims: &[u64]) -> Parenthesization {
+    let n = dims.len().saturating_sub(1);
+    assert!((1..=26).contains(&n), "需要 1 到 26 个矩阵");
+    // 值是 (最少次数, 最优的分割点)
+    let mut cost = Memo::new(|cost: &mut dyn FnMut((usize, usize)) -> (u64, usize), (i, j): (usize, usize)| {
+        (i..j)
+            .map(|k| (cost((i, k)).0 + cost((k + 1, j)).0 + dims[i] * dims[k + 1] * dims[j + 1], k))
+            .min()
+            .unwrap_or((0, i))
+    });
+
+    fn order(cost: &mut dyn FnMut((usize, usize)) -> (u64, usize), i: usize, j: usize) -> String {
+        if i == j {
+            return char::from(b'A' + i as u8).to_string();
+        }
+        let (_, k) = cost((i, j));
+        format!("({}{})", order(cost, i, k), order(cost, k + 1, j))
+    }
+    let order = order(&mut |key| cost.get(key), 0, n - 1);
+    Parenthesization { cost: cost.get((0, n - 1)).0, order }
+}
+
+/// 暴力枚举所有加括号方式（卡塔兰数个），返回最少次数
+#[cfg(test)]
+pub(super) fn brute_force(dims: &[u64]) -> u64 {
+    fn go(dims: &[u64], i: usize, j: usize) -> u64 {
+        (i..j).map(|k| go(dims, i, k) + go(dims, k + 1, j) + dims[i] * dims[k + 1] * dims[j + 1]).min().unwrap_or(0)
+    }
+    go(dims, 0, dims.len() - 2)
+}
@@ -0,0 +1,74 @@
+//! 自顶向下：记住递归函数每个参数的结果
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 带缓存的递归函数
+///
+/// 函数写成 `|recurse, key| ...` 的形式，需要子问题的结果时调用 `recurse(子问题)` 而不是直接递归，
+/// 同一个参数只计算一次。多个参数用元组作为 key。递归深度与子问题链的长度相同，链很长时改用 [`Table`](super::Table)。
+pub struct Memo<K, V, F> {
+    f: F,
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V, F> Memo<K, V, F>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    pub fn new(f: F) -> Memo<K, V, F> {
+        Memo { f, cache: HashMap::new(), hits: 0, misses: 0 }
+    }
+
+    /// key 的结果，没有算过时计算并记住
+    pub fn get(&mut self, key: K) -> V {
+        // 递归时 f 只读、缓存和计数可变，分开借用
+        let Memo { f, cache, hits, misses } = self;
+        lookup(f, cache, hits, misses, key)
+    }
+
+    /// 已经记住的子问题个数
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// 直接从缓存中取到结果的次数
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// 实际调用函数计算的次数
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+fn lookup<K, V, F>(f: &F, cache: &mut HashMap<K, V>, hits: &mut usize, misses: &mut usize, key: K) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    if let Some(value) = cache.get(&key) {
+        *hits += 1;
+        return value.clone();
+    }
+    *misses += 1;
+    let value = f(&mut |sub| lookup(f, cache, hits, misses, sub), key.clone());
+    cache.insert(key, value.clone());
+    value
+}
@@ -0,0 +1,377 @@
+/*!
+动态规划
+
+两种写法各有一个辅助类型：
+
+- [`Memo`]：自顶向下，把递归函数的每个参数的结果记下来，只计算真正用到的子问题
+- [`Table`]：自底向上，按行优先顺序填表，每个格子只依赖已经填好的格子
+
+| 问题 | 函数 | 写法 | 复杂度 |
+|------|------|------|--------|
+| 0-1 背包 | [`knapsack`] | 表 | O(n · 容量) |
+| 最长上升子序列 | [`longest_increasing_subsequence`] / [`lis_length`] | 表 / 二分 | O(n²) / O(n log n) |
+| 最长公共子序列 | [`longest_common_subsequence`] | 表 | O(nm) |
+| 找零：最少硬币 | [`min_coins`] | 表 | O(金额 · 面值数) |
+| 找零：方法数 | [`count_ways`] | 记忆化 | O(金额 · 面值数) |
+| 矩阵连乘 | [`matrix_chain`] | 记忆化 | O(n³) |
+| 旅行商（状态压缩） | [`shortest_tour`] | 表 | O(2^n · n²) |
+
+每个问题都有一个暴力解法，单元测试在随机的小输入上对比两者。
+
+```
+use rust_code_examples::algo::dp::{self, Memo, Table};
+
+let mut fib = Memo::new(|fib: &mut dyn FnMut(u64) -> u64, n: u64| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+assert_eq!(fib.get(90), 2880067194370816120);
+assert_eq!(fib.misses(), 91);
+
+// 从左上角只向右、向下走到每个格子的路径数
+let paths = Table::build(3, 4, |t: &Table<u64>, i, j| if i == 0 || j == 0 { 1 } else { t[(i - 1, j)] + t[(i, j - 1)] });
+assert_eq!(paths[(2, 3)], 10);
+assert_eq!(paths.row(1), [1, 2, 3, 4]);
+
+assert_eq!(dp::min_coins(&[1, 3, 4], 6), Some(vec![3, 3]));
+assert_eq!(dp::lcs_str("ABCBDAB", "BDCABA").len(), 4);
+assert_eq!(dp::matrix_chain(&[10, 30, 5, 60]).order, "((AB)C)");
+```
+*/
+
+mod bitmask;
+mod coin_change;
+mod knapsack;
+mod matrix_chain;
+mod memo;
+mod subsequence;
+mod table;
+
+pub use bitmask::{shortest_tour, tour_length, Tour};
+pub use coin_change::{count_ways, min_coins};
+pub use knapsack::{knapsack, Item, Packing};
+pub use matrix_chain::{matrix_chain, Parenthesization};
+pub use memo::Memo;
+pub use subsequence::{is_subsequence, lcs_str, lis_length, longest_common_subsequence, longest_increasing_subsequence};
+pub use table::Table;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_memo, "记忆化递归", "Memo 记住每个参数的结果，斐波那契数从指数次调用降到线性"),
+    example!(ex2_table, "自底向上填表", "Table 按行优先顺序填表，格子只能读取已经填好的部分"),
+    example!(ex3_knapsack, "0-1 背包", "容量有限时选出价值最大的物品，并回溯出选了哪些"),
+    example!(ex4_subsequence, "子序列", "最长上升子序列和最长公共子序列，中文按字符比较"),
+    example!(ex5_coin_change, "找零", "贪心不一定最少，动态规划求最少硬币数和凑出金额的方法数"),
+    example!(ex6_matrix_chain, "矩阵连乘", "区间上的动态规划，找出乘法次数最少的加括号方式"),
+    example!(ex7_bitmask, "状态压缩", "用二进制位表示走过的城市，求解小规模的旅行商问题"),
+];
+
+pub fn ex1_memo() {
+    println!("记忆化递归 示例代码\n\n");
+
+    // 直接递归计算 fib(n) 要调用 2 * fib(n + 1) - 1 次
+    fn naive(n: u64, calls: &mut u64) -> u64 {
+        *calls += 1;
+        if n < 2 { n } else { naive(n - 1, calls) + naive(n - 2, calls) }
+    }
+    let mut calls = 0;
+    assert_eq!(naive(25, &mut calls), 75025);
+    println!("直接递归计算 fib(25): 调用 {} 次", calls);
+    assert_eq!(calls, 242785);
+
+    let mut fib = Memo::new(|fib: &mut dyn FnMut(u64) -> u64, n: u64| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+    assert_eq!(fib.get(25), 75025);
+    println!("记忆化计算 fib(25): 计算 {} 次，命中缓存 {} 次", fib.misses(), fib.hits());
+    assert_eq!((fib.misses(), fib.hits()), (26, 23));
+    // 之后更大的参数只需要计算新的部分
+    assert_eq!(fib.get(90), 2880067194370816120);
+    println!("再计算 fib(90): 一共计算 {} 次", fib.misses());
+    assert_eq!(fib.misses(), 91);
+
+    // 多个参数用元组作为 key：从 n 个中选 k 个的组合数
+    let mut choose = Memo::new(|choose: &mut dyn FnMut((u64, u64)) -> u64, (n, k): (u64, u64)| match k {
+        0 => 1,
+        _ if k == n => 1,
+        _ => choose((n - 1, k - 1)) + choose((n - 1, k)),
+    });
+    println!("\nC(30, 15) = {}，记住了 {} 个子问题", choose.get((30, 15)), choose.len());
+    assert_eq!(choose.get((30, 15)), 155117520);
+    choose.clear();
+    assert!(choose.is_empty());
+}
+
+pub fn ex2_table() {
+    println!("自底向上填表 示例代码\n\n");
+
+    // 网格中只能向右、向下走，(1, 1) 和 (2, 3) 是障碍
+    let blocked = [(1, 1), (2, 3)];
+    let paths = Table::build(4, 5, |t: &Table<u64>, i, j| match (i, j) {
+        _ if blocked.contains(&(i, j)) => 0,
+        (0, 0) => 1,
+        (0, _) => t[(0, j - 1)],
+        (_, 0) => t[(i - 1, 0)],
+        _ => t[(i - 1, j)] + t[(i, j - 1)],
+    });
+    println!("到达每个格子的路径数:");
+    for i in 0..paths.rows() {
+        let row: Vec<String> = paths.row(i).iter().map(|n| format!("{:>3}", n)).collect();
+        println!("{}", row.join(""));
+    }
+    assert_eq!(paths.last(), Some(&7));
+
+    // 只有一行的表：第 i 个卡特兰数由前面所有的卡特兰数得到
+    let catalan = Table::line(10, |c: &Table<u64>, n| match n {
+        0 => 1,
+        _ => (0..n).map(|i| c[i] * c[n - 1 - i]).sum(),
+    });
+    println!("\n卡特兰数: {:?}", catalan.row(0));
+    assert_eq!(catalan[9], 4862);
+
+    // 还没有填的格子读不到
+    let partial = Table::build(2, 2, |t: &Table<usize>, i, j| {
+        assert_eq!(t.get(i, j), None);
+        assert_eq!(t.get(1, 1), None);
+        i * 2 + j
+    });
+    assert_eq!(partial.get(1, 1), Some(&3));
+    assert_eq!(partial.get(2, 0), None);
+}
+
+pub fn ex3_knapsack() {
+    println!("0-1 背包 示例代码\n\n");
+
+    let names = ["帐篷", "睡袋", "炉具", "相机", "食物", "水", "书"];
+    let items = [(11, 20), (4, 10), (3, 6), (2, 9), (5, 15), (6, 12), (1, 2)].map(|(weight, value)| Item { weight, value });
+    for capacity in [10, 20] {
+        let packing = knapsack(&items, capacity);
+        let chosen: Vec<&str> = packing.chosen.iter().map(|&i| names[i]).collect();
+        println!("容量 {:>2}: 价值 {}，重量 {}，{}", capacity, packing.value, packing.weight, chosen.join("、"));
+    }
+    assert_eq!(knapsack(&items, 10).value, 30);
+    assert_eq!(knapsack(&items, 20).value, 52);
+    assert_eq!(knapsack(&items, 0), Packing { value: 0, weight: 0, chosen: vec![] });
+}
+
+pub fn ex4_subsequence() {
+    println!("子序列 示例代码\n\n");
+
+    let v = [10, 9, 2, 5, 3, 7, 101, 18, 4, 20];
+    let lis: Vec<i32> = longest_increasing_subsequence(&v).into_iter().map(|i| v[i]).collect();
+    println!("{:?} 的最长上升子序列: {:?}", v, lis);
+    assert_eq!(lis, [2, 3, 7, 18, 20]);
+    assert_eq!(lis_length(&v), 5);
+
+    for (a, b) in [("ABCBDAB", "BDCABA"), ("动态规划的子问题", "规划子问题的动态")] {
+        let common = lcs_str(a, b);
+        println!("{} 与 {} 的最长公共子序列: {}", a, b, common);
+        assert!(is_subsequence(&common.chars().collect::<Vec<_>>(), &a.chars().collect::<Vec<_>>()));
+    }
+    assert_eq!(lcs_str("ABCBDAB", "BDCABA"), "BCBA");
+    assert_eq!(lcs_str("动态规划的子问题", "规划子问题的动态").chars().count(), 5);
+    assert_eq!(longest_common_subsequence(&[1, 2, 3], &[4, 5]), []);
+}
+
+pub fn ex5_coin_change() {
+    println!("找零 示例代码\n\n");
+
+    // 每次都拿不超过剩余金额的最大面值
+    fn greedy(coins: &[u64], mut amount: u64) -> Vec<u64> {
+        let mut used = Vec::new();
+        while let Some(&coin) = coins.iter().filter(|&&c| c <= amount).max() {
+            used.push(coin);
+            amount -= coin;
+        }
+        used
+    }
+    let coins = [1, 3, 4];
+    println!("面值 {:?} 凑 6: 贪心 {:?}，动态规划 {:?}", coins, greedy(&coins, 6), min_coins(&coins, 6).unwrap());
+    assert_eq!(greedy(&coins, 6), [4, 1, 1]);
+    assert_eq!(min_coins(&coins, 6), Some(vec![3, 3]));
+    assert_eq!(min_coins(&[5, 10], 3), None);
+
+    let cents = [1, 5, 10, 25, 50];
+    println!("用 {:?} 凑 100 分: {:?}，一共有 {} 种凑法", cents, min_coins(&cents, 100).unwrap(), count_ways(&cents, 100));
+    assert_eq!(count_ways(&cents, 100), 292);
+    assert_eq!(count_ways(&[2, 5, 3, 6], 10), 5);
+}
+
+pub fn ex6_matrix_chain() {
+    println!("矩阵连乘 示例代码\n\n");
+
+    for dims in [&[10, 30, 5, 60][..], &[30, 35, 15, 5, 10, 20, 25]] {
+        let chain = matrix_chain(dims);
+        let shapes: Vec<String> = dims.windows(2).map(|w| format!("{}x{}", w[0], w[1])).collect();
+        println!("{}: {}，{} 次乘法", shapes.join(" "), chain.order, chain.cost);
+    }
+    // 另一种加括号方式 A(BC) 要 27000 次
+    assert_eq!(matrix_chain(&[10, 30, 5, 60]).cost, 4500);
+    assert_eq!(matrix_chain(&[30, 35, 15, 5, 10, 20, 25]), Parenthesization { cost: 15125, order: "((A(BC))((DE)F))".to_string() });
+}
+
+pub fn ex7_bitmask() {
+    println!("状态压缩 示例代码\n\n");
+
+    let cities = ["北京", "上海", "广州", "成都"];
+    let distance = vec![vec![0, 10, 15, 20], vec![10, 0, 35, 25], vec![15, 35, 0, 30], vec![20, 25, 30, 0]];
+    let tour = shortest_tour(&distance);
+    let route: Vec<&str> = tour.order.iter().chain(&[0]).map(|&i| cities[i]).collect();
+    println!("最短回路: {}，长度 {}", route.join(" -> "), tour.length);
+    assert_eq!(tour.length, 80);
+    assert_eq!(tour_length(&distance, &tour.order), 80);
+
+    // visited 的第 i 位表示是否到过第 i 个城市
+    let visited = 0b1011;
+    let names: Vec<&str> = (0..cities.len()).filter(|i| visited >> i & 1 == 1).map(|i| cities[i]).collect();
+    println!("状态 {:04b} 表示到过 {}", visited, names.join("、"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 除了最优值相同，还检查回溯出的方案本身是合法的，并且正好取到最优值
+    #[test]
+    fn matches_brute_force_on_small_inputs() {
+        let mut rng = XorShift64::new(24);
+        for round in 0..500 {
+            let items: Vec<Item> =
+                (0..rng.below(9)).map(|_| Item { weight: 1 + rng.below(10) as usize, value: rng.below(20) }).collect();
+            let capacity = rng.below(30) as usize;
+            let packing = knapsack(&items, capacity);
+            let context = format!("第 {} 轮 物品 {:?} 容量 {}", round, items, capacity);
+            assert_eq!(packing.value, knapsack::brute_force(&items, capacity), "背包 {}", context);
+            assert!(packing.chosen.windows(2).all(|w| w[0] < w[1]), "背包 {}", context);
+            assert_eq!(packing.chosen.iter().map(|&i| items[i].value).sum::<u64>(), packing.value, "背包 {}", context);
+            assert_eq!(packing.chosen.iter().map(|&i| items[i].weight).sum::<usize>(), packing.weight, "背包 {}", context);
+            assert!(packing.weight <= capacity, "背包 {}", context);
+
+            let v: Vec<u64> = (0..rng.below(13)).map(|_| rng.below(8)).collect();
+            let lis = longest_increasing_subsequence(&v);
+            assert_eq!(lis.len(), subsequence::lis_brute_force(&v), "LIS {:?}", v);
+            assert_eq!(lis_length(&v), lis.len(), "LIS {:?}", v);
+            assert!(lis.windows(2).all(|w| w[0] < w[1] && v[w[0]] < v[w[1]]), "LIS {:?} {:?}", v, lis);
+
+            let mut random_string = |len: u64| -> Vec<u8> { (0..rng.below(len)).map(|_| b'a' + rng.below(3) as u8).collect() };
+            let (a, b) = (random_string(11), random_string(11));
+            let lcs = longest_common_subsequence(&a, &b);
+            assert_eq!(lcs.len(), subsequence::lcs_brute_force(&a, &b), "LCS {:?} {:?}", a, b);
+            assert!(is_subsequence(&lcs, &a) && is_subsequence(&lcs, &b), "LCS {:?} {:?}", a, b);
+
+            let mut coins: Vec<u64> = (0..1 + rng.below(3)).map(|_| 1 + rng.below(8)).collect();
+            coins.sort_unstable();
+            coins.dedup();
+            let amount = rng.below(30);
+            let (fewest, ways) = coin_change::brute_force(&coins, amount);
+            let used = min_coins(&coins, amount);
+            assert_eq!(used.as_ref().map(Vec::len), fewest, "找零 {:?} {}", coins, amount);
+            if let Some(used) = used {
+                assert_eq!(used.iter().sum::<u64>(), amount, "找零 {:?} {}", coins, amount);
+                assert!(used.iter().all(|c| coins.contains(c)), "找零 {:?} {}", coins, amount);
+            }
+            assert_eq!(count_ways(&coins, amount), ways, "找零的方法数 {:?} {}", coins, amount);
+
+            let dims: Vec<u64> = (0..2 + rng.below(6)).map(|_| 1 + rng.below(20)).collect();
+            let chain = matrix_chain(&dims);
+            assert_eq!(chain.cost, matrix_chain::brute_force(&dims), "矩阵连乘 {:?}", dims);
+            assert_eq!(chain_cost(&dims, &chain.order), chain.cost, "矩阵连乘 {:?} {}", dims, chain.order);
+
+            let n = 1 + rng.below(6) as usize;
+            let distance: Vec<Vec<u64>> = (0..n).map(|i| (0..n).map(|j| if i == j { 0 } else { 1 + rng.below(50) }).collect()).collect();
+            let tour = shortest_tour(&distance);
+            assert_eq!(tour.length, bitmask::brute_force(&distance), "旅行商 {:?}", distance);
+            assert_eq!(tour_length(&distance, &tour.order), tour.length, "旅行商 {:?}", distance);
+            let mut cities = tour.order.clone();
+            cities.sort_unstable();
+            assert!(tour.order[0] == 0 && cities == (0..n).collect::<Vec<_>>(), "旅行商 {:?} {:?}", distance, tour.order);
+        }
+    }
+
+    #[test]
+    fn memo_computes_each_key_once() {
+        let mut fib = Memo::new(|fib: &mut dyn FnMut(u64) -> u64, n: u64| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+        assert_eq!(fib.get(90), 2_880_067_194_370_816_120);
+        assert_eq!((fib.misses(), fib.len()), (91, 91));
+        // fib(n - 2) 在算 fib(n - 1) 时已经记住了，第二次取 fib(90) 也命中缓存
+        assert_eq!(fib.get(90), 2_880_067_194_370_816_120);
+        assert_eq!((fib.misses(), fib.hits()), (91, 89));
+        fib.clear();
+        assert!(fib.is_empty() && fib.hits() == 0);
+    }
+
+    #[test]
+    fn empty_inputs() {
+        let packing = knapsack(&[], 10);
+        assert_eq!((packing.value, packing.weight, packing.chosen), (0, 0, vec![]));
+        let packing = knapsack(&[Item { weight: 1, value: 5 }], 0);
+        assert_eq!((packing.value, packing.chosen), (0, vec![]));
+
+        let empty: [u64; 0] = [];
+        assert_eq!((longest_increasing_subsequence(&empty), lis_length(&empty)), (vec![], 0));
+        assert_eq!(longest_common_subsequence(&empty, &[1, 2, 3]), []);
+        assert_eq!(lcs_str("abc", ""), "");
+
+        assert_eq!((min_coins(&[], 0), count_ways(&[], 0)), (Some(vec![]), 1));
+        assert_eq!((min_coins(&[], 5), count_ways(&[], 5)), (None, 0));
+    }
+
+    #[test]
+    fn zero_weights_and_zero_coins() {
+        // 重量为 0 的物品不占容量，价值为正就一定选上
+        let items = [Item { weight: 0, value: 3 }, Item { weight: 2, value: 4 }, Item { weight: 0, value: 0 }];
+        let packing = knapsack(&items, 1);
+        assert_eq!((packing.value, packing.weight, packing.chosen), (3, 0, vec![0]));
+        // 面值为 0 的硬币不能用来凑任何金额
+        assert_eq!((min_coins(&[0, 2], 4), count_ways(&[0, 2], 4)), (Some(vec![2, 2]), 1));
+        assert_eq!((min_coins(&[0], 3), count_ways(&[0], 3)), (None, 0));
+        assert_eq!((min_coins(&[2], 3), count_ways(&[2], 3)), (None, 0));
+    }
+
+    #[test]
+    fn repeated_values_are_not_increasing() {
+        assert_eq!(lis_length(&[2, 2, 2]), 1);
+        assert_eq!(lis_length(&[5, 4, 3, 2, 1]), 1);
+        assert_eq!(longest_increasing_subsequence(&[1, 3, 3, 2, 4]).len(), 3);
+    }
+
+    #[test]
+    fn single_matrix_and_single_city() {
+        let chain = matrix_chain(&[3, 4]);
+        assert_eq!((chain.cost, chain.order.as_str()), (0, "A"));
+        let tour = shortest_tour(&[vec![0]]);
+        assert_eq!((tour.length, tour.order), (0, vec![0]));
+        // 两个城市之间来回各走一次，距离不对称时两段都要算上
+        assert_eq!(shortest_tour(&[vec![0, 1], vec![5, 0]]).length, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "需要 1 到 26 个矩阵")]
+    fn matrix_chain_needs_a_matrix() {
+        matrix_chain(&[3]);
+    }
+
+    // 按加括号方式实际计算乘法次数，顺便检查每个矩阵恰好出现一次
+    fn chain_cost(dims: &[u64], order: &str) -> u64 {
+        // 返回 (行数, 列数, 乘法次数, 用到的矩阵个数)
+        fn eval(dims: &[u64], order: &mut std::str::Chars) -> (u64, u64, u64, usize) {
+            match order.next() {
+                Some('(') => {
+                    let (rows, inner, left, m) = eval(dims, order);
+                    let (inner2, cols, right, k) = eval(dims, order);
+                    assert_eq!(inner, inner2, "相乘的矩阵大小不匹配");
+                    assert_eq!(order.next(), Some(')'));
+                    (rows, cols, left + right + rows * inner * cols, m + k)
+                }
+                Some(c @ 'A'..='Z') => {
+                    let i = (c as u8 - b'A') as usize;
+                    (dims[i], dims[i + 1], 0, 1)
+                }
+                other => panic!("无法解析加括号方式: {:?}", other),
+            }
+        }
+        let mut chars = order.chars();
+        let (_, _, cost, count) = eval(dims, &mut chars);
+        assert_eq!((count, chars.next()), (dims.len() - 1, None), "加括号方式 {}", order);
+        cost
+    }
+
+}
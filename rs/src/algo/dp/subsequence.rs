@@ -0,0 +1,100 @@
+//! 子序列问题：最长上升子序列（LIS）和最长公共子序列（LCS）
+
+use super::Table;
+
+/// 最长的严格上升子序列，返回元素的下标，O(n²)
+///
+/// `ending[j]` 是以 v[j] 结尾的最长上升子序列的长度和前一个元素的下标。
+pub fn longest_increasing_subsequence<T: Ord>(v: &[T]) -> Vec<usize> {
+    let ending = Table::line(v.len(), |ending: &Table<(usize, Option<usize>)>, j| {
+        (0..j).filter(|&i| v[i] < v[j]).map(|i| (ending[i].0 + 1, Some(i))).max_by_key(|&(len, _)| len).unwrap_or((1, None))
+    });
+    let Some(mut last) = (0..v.len()).max_by_key(|&j| ending[j].0) else {
+        return Vec::new();
+    };
+    let mut indices = vec![last];
+    while let (_, Some(previous)) = ending[last] {
+        indices.push(previous);
+        last = previous;
+    }
+    indices.reverse();
+    indices
+}
+
+/// 最长严格上升子序列的长度，O(n log n)
+///
+/// `tails[k]` 是所有长度为 k + 1 的上升子序列中最小的结尾元素，它是严格递增的，可以二分查找。
+pub fn lis_length<T: Ord>(v: &[T]) -> usize {
+    let mut tails: Vec<&T> = Vec::new();
+    for x in v {
+        let k = tails.partition_point(|&t| t < x);
+        if k == tails.len() {
+            tails.push(x);
+        } else {
+            tails[k] = x;
+        }
+    }
+    tails.len()
+}
+
+/// a 和 b 的一个最长公共子序列，O(nm)
+///
+/// `common[i][j]` 是 a[..i] 和 b[..j] 的最长公共子序列的长度。
+pub fn longest_common_subsequence<T: PartialEq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let common = Table::build(a.len() + 1, b.len() + 1, |common: &Table<usize>, i, j| match (i, j) {
+        (0, _) | (_, 0) => 0,
+        _ if a[i - 1] == b[j - 1] => common[(i - 1, j - 1)] + 1,
+        _ => common[(i - 1, j)].max(common[(i, j - 1)]),
+    });
+
+    let mut result = Vec::with_capacity(common[(a.len(), b.len())]);
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            result.push(a[i - 1].clone());
+            (i, j) = (i - 1, j - 1);
+        } else if common[(i - 1, j)] >= common[(i, j - 1)] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    result.reverse();
+    result
+}
+
+/// 按字符计算的最长公共子序列
+pub fn lcs_str(a: &str, b: &str) -> String {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    longest_common_subsequence(&a, &b).into_iter().collect()
+}
+
+/// sub 是否是 v 的子序列
+pub fn is_subsequence<T: PartialEq>(sub: &[T], v: &[T]) -> bool {
+    let mut rest = v.iter();
+    sub.iter().all(|x| rest.any(|y| y == x))
+}
+
+/// 暴力枚举 v 的所有子序列，返回最长的严格上升子序列的长度
+#[cfg(test)]
+pub(super) fn lis_brute_force<T: Ord>(v: &[T]) -> usize {
+    assert!(v.len() < 24, "序列太长，暴力枚举不现实");
+    (0..1usize << v.len())
+        .map(|mask| (0..v.len()).filter(|&i| mask >> i & 1 == 1).collect::<Vec<_>>())
+        .filter(|indices| indices.windows(2).all(|w| v[w[0]] < v[w[1]]))
+        .map(|indices| indices.len())
+        .max()
+        .unwrap_or(0)
+}
+
+/// 暴力枚举 a 的所有子序列，返回同时也是 b 的子序列的最长长度
+#[cfg(test)]
+pub(super) fn lcs_brute_force<T: PartialEq + Clone>(a: &[T], b: &[T]) -> usize {
+    assert!(a.len() < 20, "序列太长，暴力枚举不现实");
+    (0..1usize << a.len())
+        .map(|mask| a.iter().enumerate().filter(|(i, _)| mask >> i & 1 == 1).map(|(_, x)| x.clone()).collect::<Vec<T>>())
+        .filter(|sub| is_subsequence(sub, b))
+        .map(|sub| sub.len())
+        .max()
+        .unwrap_or(0)
+}
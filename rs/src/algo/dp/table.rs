@@ -0,0 +1,90 @@
+//! 自底向上：按顺序填一张表
+
+use std::fmt;
+use std::ops::Index;
+
+/// 按行优先顺序填好的二维表
+///
+/// 填格子 `(i, j)` 时可以读取它前面已经填好的格子：上面的行，以及同一行左边的格子。
+/// 读取还没有填的格子会 panic，依赖顺序写错时能立刻发现。只有一行的表也可以用 `table[j]` 下标。
+#[derive(Clone, PartialEq, Eq)]
+pub struct Table<V> {
+    rows: usize,
+    cols: usize,
+    // 已经填好的格子，按行优先顺序
+    cells: Vec<V>,
+}
+
+impl<V> Table<V> {
+    /// rows 行 cols 列，按行优先顺序调用 fill(已填的部分, i, j)
+    pub fn build(rows: usize, cols: usize, mut fill: impl FnMut(&Table<V>, usize, usize) -> V) -> Table<V> {
+        let mut table = Table { rows, cols, cells: Vec::with_capacity(rows * cols) };
+        for i in 0..rows {
+            for j in 0..cols {
+                let value = fill(&table, i, j);
+                table.cells.push(value);
+            }
+        }
+        table
+    }
+
+    /// 只有一行的表，fill(已填的部分, j)
+    pub fn line(len: usize, mut fill: impl FnMut(&Table<V>, usize) -> V) -> Table<V> {
+        Table::build(1, len, |table, _, j| fill(table, j))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// 越界或者还没有填时返回 None
+    pub fn get(&self, i: usize, j: usize) -> Option<&V> {
+        if i < self.rows && j < self.cols {
+            self.cells.get(i * self.cols + j)
+        } else {
+            None
+        }
+    }
+
+    /// 第 i 行，需要已经填完
+    pub fn row(&self, i: usize) -> &[V] {
+        &self.cells[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// 最后填好的格子，通常就是整个问题的答案
+    pub fn last(&self) -> Option<&V> {
+        self.cells.last()
+    }
+}
+
+impl<V> Index<(usize, usize)> for Table<V> {
+    type Output = V;
+
+    fn index(&self, (i, j): (usize, usize)) -> &V {
+        match self.get(i, j) {
+            Some(value) => value,
+            None if i < self.rows && j < self.cols => panic!("格子 ({}, {}) 还没有填，填表顺序是逐行从左到右", i, j),
+            None => panic!("格子 ({}, {}) 越界，表的大小是 {} x {}", i, j, self.rows, self.cols),
+        }
+    }
+}
+
+impl<V> Index<usize> for Table<V> {
+    type Output = V;
+
+    /// 只有一行的表中的第 j 个格子
+    fn index(&self, j: usize) -> &V {
+        assert_eq!(self.rows, 1, "只有一行的表才能用一个下标");
+        &self[(0, j)]
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Table<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.cells.chunks(self.cols.max(1))).finish()
+    }
+}
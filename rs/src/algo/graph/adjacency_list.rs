@@ -0,0 +1,49 @@
+//! 邻接表：每个节点保存自己的出边，空间 O(V + E)，适合稀疏图
+
+use std::iter::Copied;
+use std::slice;
+
+use super::{Graph, Weight};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjacencyList {
+    directed: bool,
+    // adjacency[u] 是从 u 出发的边 (终点, 权重)，按添加的顺序排列
+    adjacency: Vec<Vec<(usize, Weight)>>,
+}
+
+impl Graph for AdjacencyList {
+    type Neighbors<'a> = Copied<slice::Iter<'a, (usize, Weight)>>;
+
+    fn with_nodes(nodes: usize, directed: bool) -> AdjacencyList {
+        AdjacencyList { directed, adjacency: vec![Vec::new(); nodes] }
+    }
+
+    fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// 检查重复的边需要遍历 from 的出边，O(出度)
+    fn add_edge(&mut self, from: usize, to: usize, weight: Weight) -> bool {
+        if self.weight(from, to).is_some() {
+            return false;
+        }
+        self.adjacency[from].push((to, weight));
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, weight));
+        }
+        true
+    }
+
+    fn neighbors(&self, node: usize) -> Self::Neighbors<'_> {
+        self.adjacency[node].iter().copied()
+    }
+
+    fn weight(&self, from: usize, to: usize) -> Option<Weight> {
+        self.neighbors(from).find(|&(next, _)| next == to).map(|(_, weight)| weight)
+    }
+}
@@ -0,0 +1,57 @@
+//! 邻接矩阵：n × n 个格子保存任意两点之间的边，空间 O(V²)，查询两点之间的边只需 O(1)
+
+use std::iter::{Enumerate, FilterMap};
+use std::slice;
+
+use super::{Graph, Weight};
+
+type Cells<'a> = Enumerate<slice::Iter<'a, Option<Weight>>>;
+type Present = fn((usize, &Option<Weight>)) -> Option<(usize, Weight)>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjacencyMatrix {
+    directed: bool,
+    nodes: usize,
+    // 第 from 行第 to 列是从 from 到 to 的边的权重
+    cells: Vec<Option<Weight>>,
+}
+
+fn present((to, weight): (usize, &Option<Weight>)) -> Option<(usize, Weight)> {
+    weight.map(|weight| (to, weight))
+}
+
+impl Graph for AdjacencyMatrix {
+    type Neighbors<'a> = FilterMap<Cells<'a>, Present>;
+
+    fn with_nodes(nodes: usize, directed: bool) -> AdjacencyMatrix {
+        AdjacencyMatrix { directed, nodes, cells: vec![None; nodes * nodes] }
+    }
+
+    fn node_count(&self) -> usize {
+        self.nodes
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, weight: Weight) -> bool {
+        if self.cells[from * self.nodes + to].is_some() {
+            return false;
+        }
+        self.cells[from * self.nodes + to] = Some(weight);
+        if !self.directed {
+            self.cells[to * self.nodes + from] = Some(weight);
+        }
+        true
+    }
+
+    fn neighbors(&self, node: usize) -> Self::Neighbors<'_> {
+        let row = &self.cells[node * self.nodes..(node + 1) * self.nodes];
+        row.iter().enumerate().filter_map(present as Present)
+    }
+
+    fn weight(&self, from: usize, to: usize) -> Option<Weight> {
+        self.cells[from * self.nodes + to]
+    }
+}
@@ -0,0 +1,157 @@
+/*!
+图的文本格式
+
+```text
+# 注释和空行会被忽略
+directed        第一行有效内容是 directed 或 undirected
+A B 7           一条边：起点 终点 [权重]，省略权重时为 1
+C               只有一个名字时声明一个孤立的节点
+```
+
+节点名是任意不含空白的字符串，按第一次出现的顺序编号为 0, 1, 2, ...
+两点之间最多只能有一条边，无向图中 `A B` 与 `B A` 是同一条边。[`Graph::add_edge`] 会拒绝重复的边，
+解析时就报告出来，可以给出所在的行号。
+*/
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::{fixtures_dir, Edge, Graph};
+
+/// 从文本中读出的图：节点名和边
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EdgeList {
+    pub directed: bool,
+    /// 下标是节点编号
+    pub names: Vec<String>,
+    pub edges: Vec<Edge>,
+}
+
+/// 文本格式错误，行号从 1 开始
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// 第一行有效内容不是 directed 或 undirected
+    MissingHeader { line: usize },
+    BadWeight { line: usize, weight: String },
+    TooManyFields { line: usize },
+    /// 与第 first 行的边连接相同的两个节点
+    DuplicateEdge { line: usize, first: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::MissingHeader { line } => write!(f, "第 {} 行: 需要以 directed 或 undirected 开头", line),
+            ParseError::BadWeight { line, weight } => write!(f, "第 {} 行: 权重 {} 不是整数", line, weight),
+            ParseError::TooManyFields { line } => write!(f, "第 {} 行: 最多只能有起点、终点、权重三项", line),
+            ParseError::DuplicateEdge { line, first } => write!(f, "第 {} 行: 与第 {} 行是重复的边", line, first),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl EdgeList {
+    pub fn parse(text: &str) -> Result<EdgeList, ParseError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.split('#').next().unwrap_or_default().trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        let directed = match lines.next() {
+            Some((_, "directed")) => true,
+            Some((_, "undirected")) => false,
+            Some((line, _)) => return Err(ParseError::MissingHeader { line }),
+            None => return Err(ParseError::MissingHeader { line: text.lines().count().max(1) }),
+        };
+
+        let mut list = EdgeList { directed, names: Vec::new(), edges: Vec::new() };
+        let mut ids = HashMap::new();
+        // (起点, 终点) -> 所在的行，无向图的两个端点按编号排序
+        let mut seen = HashMap::new();
+        let mut id = |name: &str, names: &mut Vec<String>| {
+            *ids.entry(name.to_string()).or_insert_with(|| {
+                names.push(name.to_string());
+                names.len() - 1
+            })
+        };
+        for (line, content) in lines {
+            let fields: Vec<&str> = content.split_whitespace().collect();
+            match fields[..] {
+                [name] => {
+                    id(name, &mut list.names);
+                }
+                [from, to] | [from, to, _] => {
+                    let weight = match fields.get(2) {
+                        None => 1,
+                        Some(weight) => weight
+                            .parse()
+                            .map_err(|_| ParseError::BadWeight { line, weight: weight.to_string() })?,
+                    };
+                    let (from, to) = (id(from, &mut list.names), id(to, &mut list.names));
+                    let key = if directed { (from, to) } else { (from.min(to), from.max(to)) };
+                    if let Some(&first) = seen.get(&key) {
+                        return Err(ParseError::DuplicateEdge { line, first });
+                    }
+                    seen.insert(key, line);
+                    list.edges.push(Edge { from, to, weight });
+                }
+                _ => return Err(ParseError::TooManyFields { line }),
+            }
+        }
+        Ok(list)
+    }
+
+    /// 读取并解析文件，格式错误以 `InvalidData` 返回
+    pub fn load(path: impl AsRef<Path>) -> io::Result<EdgeList> {
+        let text = fs::read_to_string(path)?;
+        EdgeList::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// 读取示例图 `fixtures/graph/<name>.txt`
+    pub fn fixture(name: &str) -> io::Result<EdgeList> {
+        EdgeList::load(fixtures_dir().join(format!("{}.txt", name)))
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.names.len()
+    }
+
+    /// 节点名对应的编号
+    pub fn node(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    pub fn name(&self, node: usize) -> &str {
+        &self.names[node]
+    }
+
+    /// 把一串节点编号换成节点名
+    pub fn names_of(&self, nodes: &[usize]) -> Vec<&str> {
+        nodes.iter().map(|&node| self.name(node)).collect()
+    }
+
+    /// 用指定的表示方式建图，`edges` 中有重复的边时 panic（`parse` 得到的边不会重复）
+    pub fn to_graph<G: Graph>(&self) -> G {
+        let mut graph = G::with_nodes(self.node_count(), self.directed);
+        for edge in &self.edges {
+            let added = graph.add_edge(edge.from, edge.to, edge.weight);
+            assert!(added, "{} 与 {} 之间有重复的边", self.name(edge.from), self.name(edge.to));
+        }
+        graph
+    }
+}
+
+impl FromStr for EdgeList {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<EdgeList, ParseError> {
+        EdgeList::parse(text)
+    }
+}
@@ -0,0 +1,106 @@
+//! 最大流（Edmonds-Karp 算法）
+
+use std::collections::VecDeque;
+
+use super::{Edge, Graph, Weight};
+
+/// 最大流的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxFlow {
+    pub value: Weight,
+    /// 有流量经过的边，`weight` 是流量；无向边按实际流动的方向给出
+    pub flows: Vec<Edge>,
+    /// 最小割中源点一侧的节点，从这一侧指向另一侧的边的容量之和等于最大流
+    pub source_side: Vec<usize>,
+}
+
+// 残量网络，边 2i 和 2i + 1 互为反向边
+struct Residual {
+    to: Vec<usize>,
+    capacity: Vec<Weight>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl Residual {
+    fn add(&mut self, from: usize, to: usize, forward: Weight, backward: Weight) {
+        for (from, to, capacity) in [(from, to, forward), (to, from, backward)] {
+            self.adjacency[from].push(self.to.len());
+            self.to.push(to);
+            self.capacity.push(capacity);
+        }
+    }
+
+    // 广度优先找一条残量为正的路径，返回每个节点是经过哪条边到达的
+    fn bfs(&self, source: usize) -> Vec<Option<usize>> {
+        let mut via = vec![None; self.adjacency.len()];
+        let mut reached = vec![false; self.adjacency.len()];
+        reached[source] = true;
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for &e in &self.adjacency[node] {
+                let next = self.to[e];
+                if self.capacity[e] > 0 && !reached[next] {
+                    reached[next] = true;
+                    via[next] = Some(e);
+                    queue.push_back(next);
+                }
+            }
+        }
+        via
+    }
+}
+
+/// 从 source 到 sink 的最大流，边的权重是容量，O(VE²)
+///
+/// 每次沿残量网络中边数最少的增广路径推送流量。没有增广路径时，
+/// 源点在残量网络中能到达的节点就是最小割的一侧。
+///
+/// # Panics
+///
+/// source 与 sink 相同或者有负的容量时 panic。
+pub fn max_flow<G: Graph>(graph: &G, source: usize, sink: usize) -> MaxFlow {
+    assert_ne!(source, sink, "源点和汇点不能相同");
+    let n = graph.node_count();
+    let original = graph.edges();
+    let mut residual = Residual { to: Vec::new(), capacity: Vec::new(), adjacency: vec![Vec::new(); n] };
+    for e in &original {
+        assert!(e.weight >= 0, "容量不能为负: {} -> {} ({})", e.from, e.to, e.weight);
+        // 无向边两个方向都可以流，反向边的初始残量也是容量
+        residual.add(e.from, e.to, e.weight, if graph.is_directed() { 0 } else { e.weight });
+    }
+
+    let mut value = 0;
+    let via = loop {
+        let via = residual.bfs(source);
+        if via[sink].is_none() {
+            break via;
+        }
+        let mut path = Vec::new();
+        let mut node = sink;
+        while let Some(e) = via[node] {
+            path.push(e);
+            node = residual.to[e ^ 1];
+        }
+        let bottleneck = path.iter().map(|&e| residual.capacity[e]).min().unwrap();
+        for e in path {
+            residual.capacity[e] -= bottleneck;
+            residual.capacity[e ^ 1] += bottleneck;
+        }
+        value += bottleneck;
+    };
+
+    let flows = original
+        .iter()
+        .enumerate()
+        .filter_map(|(i, e)| {
+            let flow = e.weight - residual.capacity[2 * i];
+            match flow {
+                0 => None,
+                f if f > 0 => Some(Edge { weight: f, ..*e }),
+                f => Some(Edge { from: e.to, to: e.from, weight: -f }),
+            }
+        })
+        .collect();
+    let source_side = (0..n).filter(|&node| node == source || via[node].is_some()).collect();
+    MaxFlow { value, flows, source_side }
+}
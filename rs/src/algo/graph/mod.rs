@@ -0,0 +1,635 @@
+/*!
+图算法
+
+图由 [`Graph`] trait 描述，有邻接表 [`AdjacencyList`] 和邻接矩阵 [`AdjacencyMatrix`] 两种实现，
+下面的算法对两者都适用。节点编号为 `0..n`，边带有整数权重。
+
+| 算法 | 函数 | 复杂度 |
+|------|------|--------|
+| 广度 / 深度优先遍历 | [`bfs`] / [`dfs`] | O(V + E) |
+| 单源最短路径，非负权重 | [`dijkstra`] | O((V + E) log V) |
+| 单源最短路径，允许负权重，检测负权环 | [`bellman_ford`] | O(VE) |
+| 带启发函数的点对点最短路径 | [`a_star`] | 取决于启发函数 |
+| 拓扑排序 | [`topological_sort`] | O(V + E) |
+| 强连通分量（Tarjan） | [`strongly_connected_components`] | O(V + E) |
+| 最小生成树（Kruskal，用到 [`DisjointSet`](crate::data_structure::disjoint_set::DisjointSet)） | [`kruskal`] | O(E log E) |
+| 最大流（Edmonds-Karp） | [`max_flow`] | O(VE²) |
+
+图可以从 [`edge_list`] 描述的文本格式读入，示例用到的图保存在 crate 根目录的 `fixtures/graph/` 中，运行时读取。
+
+```
+use rust_code_examples::algo::graph::{self, AdjacencyList, EdgeList, Graph, ParseError};
+
+let list: EdgeList = "directed\na b 2\nb c 3\na c 9\n".parse().unwrap();
+let g: AdjacencyList = list.to_graph();
+let paths = graph::dijkstra(&g, 0);
+assert_eq!(paths.distance(2), Some(5));
+assert_eq!(list.names_of(&paths.path(2).unwrap()), ["a", "b", "c"]);
+assert_eq!(graph::topological_sort(&g), Ok(vec![0, 1, 2]));
+
+assert_eq!("a b".parse::<EdgeList>(), Err(ParseError::MissingHeader { line: 1 }));
+```
+*/
+
+mod adjacency_list;
+mod adjacency_matrix;
+pub mod edge_list;
+mod flow;
+mod mst;
+mod scc;
+mod shortest_path;
+mod topological;
+mod traversal;
+
+pub use adjacency_list::AdjacencyList;
+pub use adjacency_matrix::AdjacencyMatrix;
+pub use edge_list::{EdgeList, ParseError};
+pub use flow::{max_flow, MaxFlow};
+pub use mst::{kruskal, SpanningForest};
+pub use scc::strongly_connected_components;
+pub use shortest_path::{a_star, bellman_ford, dijkstra, NegativeCycle, Route, ShortestPaths};
+pub use topological::{topological_sort, CycleError};
+pub use traversal::{bfs, dfs, Bfs, Dfs};
+
+use std::path::{Path, PathBuf};
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_representations, "邻接表与邻接矩阵", "从文件读入同一张图，分别用两种方式存储，并做广度、深度优先遍历"),
+    example!(ex2_shortest_paths, "最短路径", "Dijkstra 计算城市间的最短里程，Bellman-Ford 处理负权边并发现负权环"),
+    example!(ex3_a_star, "A* 搜索", "在迷宫中用曼哈顿距离作为启发函数，比 Dijkstra 展开更少的节点"),
+    example!(ex4_topological_sort, "拓扑排序", "按先修关系排出课程的学习顺序，有环时报告无法排序的课程"),
+    example!(ex5_strongly_connected, "强连通分量", "用 Tarjan 算法找出函数调用图中相互递归的函数"),
+    example!(ex6_minimum_spanning_tree, "最小生成树", "Kruskal 算法借助并查集选出连通所有城市的最短公路"),
+    example!(ex7_max_flow, "最大流", "输水管网的最大流量和对应的最小割"),
+];
+
+/// 边的权重：距离、代价或者容量
+pub type Weight = i64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub weight: Weight,
+}
+
+/// 图的存储方式，节点编号为 `0..node_count()`
+///
+/// 无向图的一条边在两个端点的邻居中各出现一次。
+pub trait Graph {
+    /// 某个节点的出边 (终点, 权重)
+    type Neighbors<'a>: Iterator<Item = (usize, Weight)>
+    where
+        Self: 'a;
+
+    /// 有 nodes 个节点、没有边的图
+    fn with_nodes(nodes: usize, directed: bool) -> Self
+    where
+        Self: Sized;
+
+    fn node_count(&self) -> usize;
+
+    fn is_directed(&self) -> bool;
+
+    /// 添加一条边，无向图同时可以从 to 走到 from
+    ///
+    /// 两点之间最多只能有一条边：已经有边时不修改图，返回 false。
+    fn add_edge(&mut self, from: usize, to: usize, weight: Weight) -> bool;
+
+    fn neighbors(&self, node: usize) -> Self::Neighbors<'_>;
+
+    /// 从 from 到 to 的边的权重
+    fn weight(&self, from: usize, to: usize) -> Option<Weight>;
+
+    /// 所有的边，无向图的每条边只给出一次
+    fn edges(&self) -> Vec<Edge> {
+        let mut edges = Vec::new();
+        for from in 0..self.node_count() {
+            for (to, weight) in self.neighbors(from) {
+                if self.is_directed() || from <= to {
+                    edges.push(Edge { from, to, weight });
+                }
+            }
+        }
+        edges
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges().len()
+    }
+}
+
+/// 示例图的目录，与 `exercises/` 一样按 crate 根目录定位，不依赖当前工作目录
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join("graph")
+}
+
+pub fn ex1_representations() {
+    println!("邻接表与邻接矩阵 示例代码\n\n");
+
+    let cities = EdgeList::fixture("cities").unwrap();
+    let list: AdjacencyList = cities.to_graph();
+    let matrix: AdjacencyMatrix = cities.to_graph();
+    println!("{} 个城市，{} 条公路", list.node_count(), list.edge_count());
+    assert_eq!((list.node_count(), list.edge_count()), (matrix.node_count(), matrix.edge_count()));
+
+    // 邻接表按添加的顺序给出邻居，邻接矩阵按编号的顺序
+    let zhengzhou = cities.node("郑州").unwrap();
+    let from_list: Vec<usize> = list.neighbors(zhengzhou).map(|(city, _)| city).collect();
+    let from_matrix: Vec<usize> = matrix.neighbors(zhengzhou).map(|(city, _)| city).collect();
+    println!("郑州的邻居（邻接表）: {:?}", cities.names_of(&from_list));
+    println!("郑州的邻居（邻接矩阵）: {:?}", cities.names_of(&from_matrix));
+    let mut sorted = from_list.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, from_matrix);
+
+    let (beijing, tianjin) = (cities.node("北京").unwrap(), cities.node("天津").unwrap());
+    assert_eq!(list.weight(beijing, tianjin), Some(137));
+    assert_eq!(matrix.weight(tianjin, beijing), Some(137));
+
+    let by_bfs: Vec<usize> = bfs(&list, beijing).collect();
+    let by_dfs: Vec<usize> = dfs(&list, beijing).collect();
+    println!("\n从北京广度优先: {}", cities.names_of(&by_bfs).join(" "));
+    println!("从北京深度优先: {}", cities.names_of(&by_dfs).join(" "));
+    // 拉萨没有公路连接，从北京出发到不了
+    assert_eq!(by_bfs.len(), cities.node_count() - 1);
+    assert!(!by_dfs.contains(&cities.node("拉萨").unwrap()));
+
+    // 格式错误时给出行号
+    let error = EdgeList::parse("undirected\nA B 3\nB C 很远\n").unwrap_err();
+    assert_eq!(error, ParseError::BadWeight { line: 3, weight: "很远".to_string() });
+    println!("\n解析错误: {}", error);
+}
+
+pub fn ex2_shortest_paths() {
+    println!("最短路径 示例代码\n\n");
+
+    let cities = EdgeList::fixture("cities").unwrap();
+    let graph: AdjacencyList = cities.to_graph();
+    let paths = dijkstra(&graph, cities.node("北京").unwrap());
+    for target in ["上海", "广州", "成都", "拉萨"] {
+        let city = cities.node(target).unwrap();
+        match paths.path(city) {
+            Some(path) => println!("北京 -> {}: {} 公里，{}", target, paths.distance(city).unwrap(), cities.names_of(&path).join(" -> ")),
+            None => println!("北京 -> {}: 无法到达", target),
+        }
+    }
+    assert_eq!(paths.distance(cities.node("广州").unwrap()), Some(2230));
+    assert_eq!(paths.distance(cities.node("拉萨").unwrap()), None);
+
+    // 有负权边时 Dijkstra 会 panic，改用 Bellman-Ford
+    let trails = EdgeList::fixture("trails").unwrap();
+    let mut graph: AdjacencyList = trails.to_graph();
+    let (camp, summit) = (trails.node("营地").unwrap(), trails.node("山顶").unwrap());
+    let paths = bellman_ford(&graph, camp).unwrap();
+    let path = paths.path(summit).unwrap();
+    println!("\n营地 -> 山顶: 消耗 {} 点体力，{}", paths.distance(summit).unwrap(), trails.names_of(&path).join(" -> "));
+    assert_eq!(paths.distance(summit), Some(4));
+
+    // 从山顶坐缆车回营地还能补给 5 点，绕一圈体力反而增加，最短路径不存在
+    assert!(graph.add_edge(summit, camp, -5));
+    let cycle = bellman_ford(&graph, camp).unwrap_err();
+    println!("加上缆车之后出现负权环: {}", trails.names_of(&cycle.nodes).join(" -> "));
+    assert_eq!(cycle.nodes.len(), 6);
+}
+
+pub fn ex3_a_star() {
+    println!("A* 搜索 示例代码\n\n");
+
+    const MAZE: [&str; 8] = [
+        "S.......#.......",
+        "........#.......",
+        "..####..#..###..",
+        ".....#..#....#..",
+        ".....#.......#..",
+        "..####.......#..",
+        "........######..",
+        "...............G",
+    ];
+    let (height, width) = (MAZE.len(), MAZE[0].len());
+    let cell = |r: usize, c: usize| MAZE[r].as_bytes()[c];
+    // 每个格子是一个节点，相邻的两个空地之间有一条长度为 1 的边
+    let mut grid = AdjacencyList::with_nodes(height * width, false);
+    for r in 0..height {
+        for c in 0..width {
+            if cell(r, c) == b'#' {
+                continue;
+            }
+            if c + 1 < width && cell(r, c + 1) != b'#' {
+                assert!(grid.add_edge(r * width + c, r * width + c + 1, 1));
+            }
+            if r + 1 < height && cell(r + 1, c) != b'#' {
+                assert!(grid.add_edge(r * width + c, (r + 1) * width + c, 1));
+            }
+        }
+    }
+
+    let (start, goal) = (0, height * width - 1);
+    // 曼哈顿距离不会超过真实的步数
+    let manhattan = |node: usize| ((node / width).abs_diff(goal / width) + (node % width).abs_diff(goal % width)) as Weight;
+    let guided = a_star(&grid, start, goal, manhattan).unwrap();
+    let blind = a_star(&grid, start, goal, |_| 0).unwrap();
+    println!("最短路径 {} 步", guided.cost);
+    println!("A* 展开了 {} 个格子，Dijkstra 展开了 {} 个", guided.expanded, blind.expanded);
+    assert_eq!(guided.cost, blind.cost);
+    assert!(guided.expanded < blind.expanded);
+
+    for (r, row) in MAZE.iter().enumerate() {
+        let line: String = row
+            .chars()
+            .enumerate()
+            .map(|(c, ch)| if ch == '.' && guided.nodes.contains(&(r * width + c)) { '*' } else { ch })
+            .collect();
+        println!("{}", line);
+    }
+}
+
+pub fn ex4_topological_sort() {
+    println!("拓扑排序 示例代码\n\n");
+
+    let courses = EdgeList::fixture("courses").unwrap();
+    let mut graph: AdjacencyList = courses.to_graph();
+    let order = topological_sort(&graph).unwrap();
+    println!("学习顺序: {}", courses.names_of(&order).join(" -> "));
+    let position = |name: &str| order.iter().position(|&c| c == courses.node(name).unwrap()).unwrap();
+    assert!(position("离散数学") < position("算法") && position("算法") < position("机器学习"));
+
+    // 如果机器学习又成了程序设计的先修课，就没有合法的学习顺序了
+    assert!(graph.add_edge(courses.node("机器学习").unwrap(), courses.node("程序设计").unwrap(), 1));
+    let error = topological_sort(&graph).unwrap_err();
+    println!("出现环之后无法安排: {}", courses.names_of(&error.blocked).join(" "));
+    assert_eq!(error.blocked.len(), 6);
+}
+
+pub fn ex5_strongly_connected() {
+    println!("强连通分量 示例代码\n\n");
+
+    let calls = EdgeList::fixture("calls").unwrap();
+    let graph: AdjacencyMatrix = calls.to_graph();
+    let components = strongly_connected_components(&graph);
+    for component in &components {
+        let names = calls.names_of(component);
+        if component.len() > 1 {
+            println!("相互递归: {}", names.join(" "));
+        } else {
+            println!("单独一个: {}", names[0]);
+        }
+    }
+    let recursive: Vec<Vec<&str>> = components.iter().filter(|c| c.len() > 1).map(|c| calls.names_of(c)).collect();
+    assert_eq!(recursive.len(), 3);
+    // 分量按逆拓扑序排列，被调用的排在调用者之前，main 最后完成
+    assert_eq!(calls.names_of(components.last().unwrap()), ["main"]);
+}
+
+pub fn ex6_minimum_spanning_tree() {
+    println!("最小生成树 示例代码\n\n");
+
+    let cities = EdgeList::fixture("cities").unwrap();
+    let graph: AdjacencyList = cities.to_graph();
+    let forest = kruskal(&graph);
+    for e in &forest.edges {
+        println!("{} - {} {}", cities.name(e.from), cities.name(e.to), e.weight);
+    }
+    println!("共 {} 公里，{} 棵树", forest.weight, forest.trees);
+    let total: Weight = graph.edges().iter().map(|e| e.weight).sum();
+    println!("全部公路共 {} 公里", total);
+    // 拉萨单独成为一棵树，其余 15 个城市连成一棵树需要 14 条边
+    assert_eq!((forest.edges.len(), forest.trees), (cities.node_count() - 2, 2));
+    assert_eq!(forest.weight, 5771);
+}
+
+pub fn ex7_max_flow() {
+    println!("最大流 示例代码\n\n");
+
+    let pipes = EdgeList::fixture("pipes").unwrap();
+    let graph: AdjacencyList = pipes.to_graph();
+    let (plant, city) = (pipes.node("水厂").unwrap(), pipes.node("城区").unwrap());
+    let flow = max_flow(&graph, plant, city);
+    println!("最大流量: 每秒 {} 吨", flow.value);
+    for e in &flow.flows {
+        println!("  {} -> {}: {} / {}", pipes.name(e.from), pipes.name(e.to), e.weight, graph.weight(e.from, e.to).unwrap());
+    }
+    assert_eq!(flow.value, 23);
+
+    // 最小割：切断这些管道就完全断水，它们的容量之和正好是最大流
+    let source_side = &flow.source_side;
+    let cut: Vec<_> = graph
+        .edges()
+        .into_iter()
+        .filter(|e| source_side.contains(&e.from) && !source_side.contains(&e.to))
+        .collect();
+    let names: Vec<String> = cut.iter().map(|e| format!("{}->{}", pipes.name(e.from), pipes.name(e.to))).collect();
+    println!("最小割: {}", names.join(" "));
+    assert_eq!(cut.iter().map(|e| e.weight).sum::<Weight>(), flow.value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{TempDir, XorShift64};
+
+    // 从 rng 生成一张小图的文本：先逐个声明节点，保证编号与名字 n0, n1, ... 一致
+    fn random_graph_text(rng: &mut XorShift64, n: usize, directed: bool, negative: bool) -> String {
+        let mut text = String::from(if directed { "directed\n" } else { "undirected\n" });
+        for node in 0..n {
+            text += &format!("n{}\n", node);
+        }
+        for from in 0..n {
+            for to in 0..n {
+                if from == to || (!directed && from > to) || rng.below(3) != 0 {
+                    continue;
+                }
+                let weight = if negative { rng.range(-3..10) } else { rng.range(0..10) };
+                text += &format!("n{} n{} {}\n", from, to, weight);
+            }
+        }
+        text
+    }
+
+    // 沿 nodes 走一遍的总权重，相邻两点之间没有边时返回 None
+    fn path_weight(graph: &impl Graph, nodes: &[usize]) -> Option<Weight> {
+        nodes.windows(2).map(|w| graph.weight(w[0], w[1])).sum()
+    }
+
+    // 最短路径对比 Floyd-Warshall，可达性对比传递闭包，最小生成树对比 Prim 算法，
+    // 最大流对比枚举所有 s-t 割得到的最小割；同时检查邻接表和邻接矩阵给出相同的边
+    #[test]
+    fn matches_brute_force_on_random_graphs() {
+        let mut rng = XorShift64::new(22);
+        for round in 0..500 {
+            let n = 1 + rng.below(7) as usize;
+            let directed = rng.bool();
+            let negative = rng.below(3) == 0;
+            let text = random_graph_text(&mut rng, n, directed, negative);
+            let context = format!("第 {} 轮的图:\n{}", round, text);
+            let list = EdgeList::parse(&text).unwrap();
+            let graph: AdjacencyList = list.to_graph();
+            let matrix: AdjacencyMatrix = list.to_graph();
+            let mut edges = graph.edges();
+            let mut matrix_edges = matrix.edges();
+            edges.sort_by_key(|e| (e.from, e.to));
+            matrix_edges.sort_by_key(|e| (e.from, e.to));
+            assert_eq!(edges, matrix_edges, "{}", context);
+
+            // reach[u][v]：从 u 出发经过至少一条边可以到达 v
+            let mut reach = vec![vec![false; n]; n];
+            // Floyd-Warshall，dist[v][v] < 0 当且仅当 v 在负权环上
+            let mut dist: Vec<Vec<Option<Weight>>> = (0..n).map(|i| (0..n).map(|j| (i == j).then_some(0)).collect()).collect();
+            for e in &edges {
+                for (from, to) in [(e.from, e.to), (e.to, e.from)].into_iter().take(if directed { 1 } else { 2 }) {
+                    reach[from][to] = true;
+                    dist[from][to] = Some(dist[from][to].map_or(e.weight, |d| d.min(e.weight)));
+                }
+            }
+            for k in 0..n {
+                for i in 0..n {
+                    for j in 0..n {
+                        reach[i][j] |= reach[i][k] && reach[k][j];
+                        if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
+                            if dist[i][j].is_none_or(|d| a + b < d) {
+                                dist[i][j] = Some(a + b);
+                            }
+                        }
+                    }
+                }
+            }
+
+            for s in 0..n {
+                let reachable: Vec<usize> = (0..n).filter(|&v| v == s || reach[s][v]).collect();
+                for order in [bfs(&graph, s).collect::<Vec<_>>(), dfs(&matrix, s).collect()] {
+                    assert_eq!(order[0], s, "{}", context);
+                    // 除起点外，每个节点都有一个更早访问的邻居
+                    for (i, &v) in order.iter().enumerate().skip(1) {
+                        assert!(order[..i].iter().any(|&u| graph.weight(u, v).is_some()), "{}", context);
+                    }
+                    let mut sorted = order;
+                    sorted.sort_unstable();
+                    assert_eq!(sorted, reachable, "{}", context);
+                }
+
+                let cycle_reachable = reachable.iter().any(|&v| dist[v][v].is_some_and(|d| d < 0));
+                match bellman_ford(&graph, s) {
+                    Err(cycle) => {
+                        assert!(cycle_reachable, "{}", context);
+                        let mut closed = cycle.nodes.clone();
+                        closed.push(cycle.nodes[0]);
+                        assert!(path_weight(&graph, &closed).is_some_and(|w| w < 0), "{:?}\n{}", cycle, context);
+                    }
+                    Ok(paths) => {
+                        assert!(!cycle_reachable, "{}", context);
+                        assert_eq!(paths.distances, dist[s], "{}", context);
+                    }
+                }
+                if negative {
+                    continue;
+                }
+                let paths = dijkstra(&matrix, s);
+                assert_eq!(paths.distances, dist[s], "{}", context);
+                for (t, &distance) in dist[s].iter().enumerate() {
+                    if let Some(path) = paths.path(t) {
+                        assert_eq!((path[0], path[path.len() - 1]), (s, t), "{}", context);
+                        assert_eq!(path_weight(&graph, &path), distance, "{}", context);
+                    }
+                    let route = a_star(&graph, s, t, |_| 0);
+                    assert_eq!(route.map(|r| r.cost), distance, "{}", context);
+                }
+            }
+
+            if directed {
+                let has_cycle = (0..n).any(|v| reach[v][v]);
+                match topological_sort(&graph) {
+                    Ok(order) => {
+                        assert!(!has_cycle, "{}", context);
+                        let mut position = vec![0; n];
+                        for (i, &v) in order.iter().enumerate() {
+                            position[v] = i;
+                        }
+                        assert!(edges.iter().all(|e| position[e.from] < position[e.to]), "{}", context);
+                    }
+                    Err(error) => {
+                        assert!(has_cycle, "{}", context);
+                        assert!((0..n).filter(|&v| reach[v][v]).all(|v| error.blocked.contains(&v)), "{}", context);
+                    }
+                }
+
+                let components = strongly_connected_components(&matrix);
+                let mut component = vec![usize::MAX; n];
+                for (i, nodes) in components.iter().enumerate() {
+                    for &v in nodes {
+                        assert_eq!(component[v], usize::MAX, "{}", context);
+                        component[v] = i;
+                    }
+                }
+                for u in 0..n {
+                    for v in 0..n {
+                        let strongly = u == v || (reach[u][v] && reach[v][u]);
+                        assert_eq!(component[u] == component[v], strongly, "{}", context);
+                    }
+                }
+                // 逆拓扑序：边只会指向排在前面（或同一个）的分量
+                assert!(edges.iter().all(|e| component[e.from] >= component[e.to]), "{}", context);
+            } else {
+                // Prim 算法，每次选连接树内外的最短边，一棵树长不动了就从下一个未选的节点开始
+                let mut in_tree = vec![false; n];
+                let (mut prim, mut trees) = (0, 0);
+                for root in 0..n {
+                    if in_tree[root] {
+                        continue;
+                    }
+                    in_tree[root] = true;
+                    trees += 1;
+                    while let Some((weight, v)) = edges
+                        .iter()
+                        .filter(|e| in_tree[e.from] != in_tree[e.to])
+                        .map(|e| (e.weight, if in_tree[e.from] { e.to } else { e.from }))
+                        .min()
+                    {
+                        in_tree[v] = true;
+                        prim += weight;
+                    }
+                }
+                let forest = kruskal(&matrix);
+                assert_eq!((forest.weight, forest.trees, forest.edges.len()), (prim, trees, n - trees), "{}", context);
+            }
+
+            if negative || n < 2 {
+                continue;
+            }
+            let (source, sink) = (0, n - 1);
+            let cut_capacity = |in_source: &dyn Fn(usize) -> bool| -> Weight {
+                edges
+                    .iter()
+                    .filter(|e| (in_source(e.from) && !in_source(e.to)) || (!directed && in_source(e.to) && !in_source(e.from)))
+                    .map(|e| e.weight)
+                    .sum()
+            };
+            let min_cut = (0..1usize << n)
+                .filter(|mask| mask & 1 << source != 0 && mask & 1 << sink == 0)
+                .map(|mask| cut_capacity(&|v| mask & 1 << v != 0))
+                .min()
+                .unwrap();
+            let flow = max_flow(&graph, source, sink);
+            assert_eq!(flow.value, min_cut, "{}", context);
+            assert_eq!(cut_capacity(&|v| flow.source_side.contains(&v)), min_cut, "{}", context);
+            // 每条边的流量不超过容量，除源点和汇点外流入等于流出
+            let mut net = vec![0; n];
+            for e in &flow.flows {
+                assert!(e.weight <= graph.weight(e.from, e.to).unwrap(), "{}", context);
+                net[e.from] -= e.weight;
+                net[e.to] += e.weight;
+            }
+            assert!((0..n).all(|v| v == source || v == sink || net[v] == 0), "{}", context);
+            assert_eq!(net[sink], flow.value, "{}", context);
+        }
+    }
+
+    fn parse<G: Graph>(text: &str) -> G {
+        EdgeList::parse(text).unwrap().to_graph()
+    }
+
+    #[test]
+    fn single_node() {
+        let graph: AdjacencyList = parse("directed\na\n");
+        assert_eq!((bfs(&graph, 0).collect::<Vec<_>>(), dfs(&graph, 0).collect::<Vec<_>>()), (vec![0], vec![0]));
+        assert_eq!(dijkstra(&graph, 0).path(0), Some(vec![0]));
+        assert_eq!(a_star(&graph, 0, 0, |_| 0).map(|r| (r.cost, r.nodes)), Some((0, vec![0])));
+        assert_eq!(topological_sort(&graph).unwrap(), [0]);
+        assert_eq!(strongly_connected_components(&graph), [vec![0]]);
+        let forest = kruskal(&parse::<AdjacencyMatrix>("undirected\na\n"));
+        assert_eq!((forest.weight, forest.trees, forest.edges.len()), (0, 1, 0));
+    }
+
+    #[test]
+    fn self_loops() {
+        // 自环让节点依赖自己，拓扑排序卡在它和它之后的节点上
+        let graph: AdjacencyMatrix = parse("directed\na a 1\na b 2\nc\n");
+        assert_eq!(topological_sort(&graph).unwrap_err().blocked, [0, 1]);
+        assert_eq!(strongly_connected_components(&graph).len(), 3);
+        assert_eq!(dijkstra(&graph, 0).distances, [Some(0), Some(2), None]);
+
+        let negative: AdjacencyList = parse("directed\na b 1\nb b -1\n");
+        assert_eq!(bellman_ford(&negative, 0).unwrap_err().nodes, [1]);
+
+        let undirected: AdjacencyList = parse("undirected\na a 1\na b 2\n");
+        let forest = kruskal(&undirected);
+        assert_eq!((forest.weight, forest.trees), (2, 1));
+    }
+
+    #[test]
+    fn unreachable_nodes() {
+        let graph: AdjacencyList = parse("directed\na b 4\nc b 1\n");
+        let paths = dijkstra(&graph, 0);
+        assert_eq!((paths.path(2), paths.distances), (None, vec![Some(0), Some(4), None]));
+        assert_eq!(bellman_ford(&graph, 0).unwrap().distances, [Some(0), Some(4), None]);
+        assert!(a_star(&graph, 0, 2, |_| 0).is_none());
+        let flow = max_flow(&graph, 0, 2);
+        assert_eq!((flow.value, flow.flows, flow.source_side), (0, vec![], vec![0, 1]));
+
+        let forest = kruskal(&parse::<AdjacencyList>("undirected\na b 1\nc d 2\ne\n"));
+        assert_eq!((forest.weight, forest.trees, forest.edges.len()), (3, 3, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "源点和汇点不能相同")]
+    fn max_flow_needs_distinct_endpoints() {
+        let graph: AdjacencyList = parse("directed\na b 1\n");
+        max_flow(&graph, 0, 0);
+    }
+
+    #[test]
+    fn parallel_edges_are_rejected() {
+        let error = EdgeList::parse("directed\na b 3\na b 4\n").unwrap_err();
+        assert_eq!(error, ParseError::DuplicateEdge { line: 3, first: 2 });
+        let error = EdgeList::parse("undirected\na b 3\nc\nb a 4\n").unwrap_err();
+        assert_eq!(error, ParseError::DuplicateEdge { line: 4, first: 2 });
+
+        // 有向图中方向相反的两条边不算重复，两种存储方式的结果相同
+        let list = EdgeList::parse("directed\na b 3\nb a 4\nb c 5\n").unwrap();
+        let (graph, matrix): (AdjacencyList, AdjacencyMatrix) = (list.to_graph(), list.to_graph());
+        assert_eq!((graph.weight(0, 1), graph.weight(1, 0)), (matrix.weight(0, 1), matrix.weight(1, 0)));
+        assert_eq!(max_flow(&graph, 0, 2).value, 3);
+        assert_eq!(max_flow(&matrix, 0, 2).value, 3);
+    }
+
+    // 两种存储方式对重复的边给出相同的结果，而且不修改图
+    fn check_add_edge_rejects_duplicates<G: Graph>() {
+        let mut undirected = G::with_nodes(3, false);
+        assert!(undirected.add_edge(0, 1, 3));
+        assert!(!undirected.add_edge(1, 0, 4));
+        assert!(!undirected.add_edge(0, 1, 5));
+        assert!(undirected.add_edge(2, 2, 1));
+        assert!(!undirected.add_edge(2, 2, 1));
+        assert_eq!((undirected.weight(0, 1), undirected.weight(1, 0)), (Some(3), Some(3)));
+        assert_eq!(undirected.edge_count(), 2);
+
+        let mut directed = G::with_nodes(2, true);
+        assert!(directed.add_edge(0, 1, 3));
+        assert!(directed.add_edge(1, 0, 4));
+        assert!(!directed.add_edge(0, 1, 5));
+        assert_eq!((directed.weight(0, 1), directed.weight(1, 0)), (Some(3), Some(4)));
+    }
+
+    #[test]
+    fn add_edge_rejects_duplicates() {
+        check_add_edge_rejects_duplicates::<AdjacencyList>();
+        check_add_edge_rejects_duplicates::<AdjacencyMatrix>();
+    }
+
+    #[test]
+    fn fixtures_load_from_disk() {
+        for name in ["calls", "cities", "courses", "pipes", "trails"] {
+            assert!(EdgeList::fixture(name).is_ok(), "{}", name);
+        }
+        assert_eq!(EdgeList::fixture("missing").unwrap_err().kind(), std::io::ErrorKind::NotFound);
+
+        let dir = TempDir::new("graph_fixture").unwrap();
+        let path = dir.path().join("duplicate.txt");
+        std::fs::write(&path, "directed\na b 1\na b 2\n").unwrap();
+        let error = EdgeList::load(&path).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "第 3 行: 与第 2 行是重复的边");
+    }
+}
@@ -0,0 +1,32 @@
+//! 最小生成树（Kruskal 算法）
+
+use super::{Edge, Graph, Weight};
+use crate::algo::sort;
+use crate::data_structure::disjoint_set::DisjointSet;
+
+/// 最小生成森林：图不连通时每个连通分量各有一棵最小生成树
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningForest {
+    /// 按选中的顺序排列，也就是权重从小到大
+    pub edges: Vec<Edge>,
+    pub weight: Weight,
+    /// 树的棵数，等于连通分量的个数
+    pub trees: usize,
+}
+
+/// Kruskal 算法，O(E log E)
+///
+/// 按权重从小到大考察每条边，两端不在同一棵树中时选中它并合并两棵树，用并查集判断和合并。
+/// 排序是稳定的，权重相同的边按它们在图中的顺序选择，结果是确定的。
+///
+/// # Panics
+///
+/// 传入有向图时 panic，有向图的最小树形图要用别的算法。
+pub fn kruskal<G: Graph>(graph: &G) -> SpanningForest {
+    assert!(!graph.is_directed(), "Kruskal 算法只适用于无向图");
+    let mut edges = graph.edges();
+    sort::merge_sort_by(&mut edges, |a, b| a.weight.cmp(&b.weight));
+    let mut trees = DisjointSet::new(graph.node_count());
+    let edges: Vec<Edge> = edges.into_iter().filter(|e| trees.union(e.from, e.to)).collect();
+    SpanningForest { weight: edges.iter().map(|e| e.weight).sum(), edges, trees: trees.set_count() }
+}
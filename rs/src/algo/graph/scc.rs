@@ -0,0 +1,72 @@
+//! 强连通分量（Tarjan 算法）
+
+use super::Graph;
+
+/// 有向图的强连通分量，同一个分量中的节点两两可达
+///
+/// 一次深度优先遍历，O(V + E)。分量内部按编号排序；分量之间按 Tarjan 算法完成的顺序，
+/// 也就是缩点之后的逆拓扑序：如果有边从分量 A 指向分量 B，B 排在 A 之前。
+pub fn strongly_connected_components<G: Graph>(graph: &G) -> Vec<Vec<usize>> {
+    let n = graph.node_count();
+    let mut tarjan = Tarjan {
+        graph,
+        order: vec![None; n],
+        low: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        visited: 0,
+        components: Vec::new(),
+    };
+    for node in 0..n {
+        if tarjan.order[node].is_none() {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+struct Tarjan<'a, G> {
+    graph: &'a G,
+    // 深度优先遍历中第一次访问的次序
+    order: Vec<Option<usize>>,
+    // 经过子树中的边能回到的、仍在栈中的最小次序
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    visited: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl<G: Graph> Tarjan<'_, G> {
+    fn visit(&mut self, node: usize) {
+        self.order[node] = Some(self.visited);
+        self.low[node] = self.visited;
+        self.visited += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+
+        let graph = self.graph;
+        for (next, _) in graph.neighbors(node) {
+            match self.order[next] {
+                None => {
+                    self.visit(next);
+                    self.low[node] = self.low[node].min(self.low[next]);
+                }
+                Some(order) if self.on_stack[next] => self.low[node] = self.low[node].min(order),
+                // 已经属于一个完成的分量，不会和当前节点在同一个分量中
+                Some(_) => {}
+            }
+        }
+
+        // node 是分量中最先访问的节点，栈中它以上的节点构成一个分量
+        if Some(self.low[node]) == self.order[node] {
+            let start = self.stack.iter().rposition(|&x| x == node).unwrap();
+            let mut component = self.stack.split_off(start);
+            for &x in &component {
+                self.on_stack[x] = false;
+            }
+            component.sort_unstable();
+            self.components.push(component);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! 单源最短路径：Dijkstra、Bellman-Ford 和 A*
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::{Graph, Weight};
+
+/// 从 source 出发到每个节点的最短距离和最短路径树
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPaths {
+    pub source: usize,
+    /// 不可达时为 None
+    pub distances: Vec<Option<Weight>>,
+    /// 最短路径上的前一个节点
+    pub parents: Vec<Option<usize>>,
+}
+
+impl ShortestPaths {
+    fn new(nodes: usize, source: usize) -> ShortestPaths {
+        let mut distances = vec![None; nodes];
+        distances[source] = Some(0);
+        ShortestPaths { source, distances, parents: vec![None; nodes] }
+    }
+
+    pub fn distance(&self, to: usize) -> Option<Weight> {
+        self.distances[to]
+    }
+
+    /// 从 source 到 to 经过的节点，不可达时返回 None
+    pub fn path(&self, to: usize) -> Option<Vec<usize>> {
+        self.distances[to]?;
+        let mut path = vec![to];
+        while let Some(parent) = self.parents[*path.last().unwrap()] {
+            path.push(parent);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Dijkstra 算法，O((V + E) log V)
+///
+/// # Panics
+///
+/// 有负权边时 panic：已经确定的最短距离可能被之后的负权边改小，要用 [`bellman_ford`]。
+pub fn dijkstra<G: Graph>(graph: &G, source: usize) -> ShortestPaths {
+    let mut paths = ShortestPaths::new(graph.node_count(), source);
+    let mut done = vec![false; graph.node_count()];
+    let mut heap = BinaryHeap::from([Reverse((0, source))]);
+    while let Some(Reverse((distance, node))) = heap.pop() {
+        // 同一个节点可能多次入堆，只处理第一次弹出的，也就是距离最小的那次
+        if std::mem::replace(&mut done[node], true) {
+            continue;
+        }
+        for (next, weight) in graph.neighbors(node) {
+            assert!(weight >= 0, "Dijkstra 不支持负权边 {} -> {} ({})", node, next, weight);
+            let candidate = distance + weight;
+            if paths.distances[next].is_none_or(|d| candidate < d) {
+                paths.distances[next] = Some(candidate);
+                paths.parents[next] = Some(node);
+                heap.push(Reverse((candidate, next)));
+            }
+        }
+    }
+    paths
+}
+
+/// 从 source 可以到达的负权环，按环上的顺序排列
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle {
+    pub nodes: Vec<usize>,
+}
+
+/// Bellman-Ford 算法，O(VE)，允许负权边
+///
+/// 对所有边做 V - 1 轮松弛，之后还能被松弛说明存在从 source 可达的负权环，这时最短路径没有意义。
+pub fn bellman_ford<G: Graph>(graph: &G, source: usize) -> Result<ShortestPaths, NegativeCycle> {
+    let n = graph.node_count();
+    let mut paths = ShortestPaths::new(n, source);
+    // 扫描所有的边做一轮松弛，返回最后一个距离被改小的节点
+    let relax = |paths: &mut ShortestPaths| {
+        let mut relaxed = None;
+        for node in 0..n {
+            let Some(distance) = paths.distances[node] else { continue };
+            for (next, weight) in graph.neighbors(node) {
+                if paths.distances[next].is_none_or(|d| distance + weight < d) {
+                    paths.distances[next] = Some(distance + weight);
+                    paths.parents[next] = Some(node);
+                    relaxed = Some(next);
+                }
+            }
+        }
+        relaxed
+    };
+    for _ in 1..n {
+        if relax(&mut paths).is_none() {
+            return Ok(paths);
+        }
+    }
+    let Some(relaxed) = relax(&mut paths) else { return Ok(paths) };
+
+    // 沿前驱走 n 步一定落在环上，再绕一圈收集环上的节点
+    let mut node = relaxed;
+    for _ in 0..n {
+        node = paths.parents[node].unwrap();
+    }
+    let mut nodes = vec![node];
+    let mut current = paths.parents[node].unwrap();
+    while current != node {
+        nodes.push(current);
+        current = paths.parents[current].unwrap();
+    }
+    nodes.reverse();
+    Err(NegativeCycle { nodes })
+}
+
+/// A* 找到的路径
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub cost: Weight,
+    pub nodes: Vec<usize>,
+    /// 搜索过程中展开（确定了最短距离）的节点个数
+    pub expanded: usize,
+}
+
+/// A* 搜索，按“已走的距离 + 估计的剩余距离”从小到大展开节点
+///
+/// heuristic 估计从节点到 goal 的距离，不能超过真实距离，并且满足三角不等式（一致性），
+/// 这样第一次展开 goal 时得到的就是最短路径。heuristic 恒为 0 时就是 Dijkstra。
+pub fn a_star<G: Graph>(graph: &G, start: usize, goal: usize, heuristic: impl Fn(usize) -> Weight) -> Option<Route> {
+    let mut paths = ShortestPaths::new(graph.node_count(), start);
+    let mut done = vec![false; graph.node_count()];
+    let mut expanded = 0;
+    let mut heap = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+    while let Some(Reverse((_, distance, node))) = heap.pop() {
+        if std::mem::replace(&mut done[node], true) {
+            continue;
+        }
+        expanded += 1;
+        if node == goal {
+            return Some(Route { cost: distance, nodes: paths.path(goal).unwrap(), expanded });
+        }
+        for (next, weight) in graph.neighbors(node) {
+            assert!(weight >= 0, "A* 不支持负权边 {} -> {} ({})", node, next, weight);
+            let candidate = distance + weight;
+            if paths.distances[next].is_none_or(|d| candidate < d) {
+                paths.distances[next] = Some(candidate);
+                paths.parents[next] = Some(node);
+                heap.push(Reverse((candidate + heuristic(next), candidate, next)));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,47 @@
+//! 拓扑排序（Kahn 算法）
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::Graph;
+
+/// 有向图中有环，这些节点在环上或者依赖环上的节点，无法排序
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    pub blocked: Vec<usize>,
+}
+
+/// 每条边 u -> v 中 u 都排在 v 之前的节点顺序
+///
+/// 反复取出入度为 0 的节点并删掉它的出边。同时有多个入度为 0 的节点时先取编号最小的，
+/// 所以结果是所有拓扑序中字典序最小的那个，不依赖边的存储顺序。
+///
+/// # Panics
+///
+/// 无向图没有拓扑序，传入无向图时 panic。
+pub fn topological_sort<G: Graph>(graph: &G) -> Result<Vec<usize>, CycleError> {
+    assert!(graph.is_directed(), "拓扑排序只适用于有向图");
+    let n = graph.node_count();
+    let mut in_degree = vec![0; n];
+    for node in 0..n {
+        for (next, _) in graph.neighbors(node) {
+            in_degree[next] += 1;
+        }
+    }
+    let mut ready: BinaryHeap<Reverse<usize>> = (0..n).filter(|&node| in_degree[node] == 0).map(Reverse).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(Reverse(node)) = ready.pop() {
+        order.push(node);
+        for (next, _) in graph.neighbors(node) {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                ready.push(Reverse(next));
+            }
+        }
+    }
+    if order.len() == n {
+        Ok(order)
+    } else {
+        Err(CycleError { blocked: (0..n).filter(|&node| in_degree[node] > 0).collect() })
+    }
+}
@@ -0,0 +1,73 @@
+//! 广度优先和深度优先遍历，都以迭代器的形式按访问顺序给出节点
+
+use std::collections::VecDeque;
+
+use super::Graph;
+
+/// 广度优先遍历，离起点的边数越少越先访问
+pub struct Bfs<'a, G: Graph> {
+    graph: &'a G,
+    queue: VecDeque<usize>,
+    visited: Vec<bool>,
+}
+
+pub fn bfs<G: Graph>(graph: &G, start: usize) -> Bfs<'_, G> {
+    let mut visited = vec![false; graph.node_count()];
+    visited[start] = true;
+    Bfs { graph, queue: VecDeque::from([start]), visited }
+}
+
+impl<G: Graph> Iterator for Bfs<'_, G> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let node = self.queue.pop_front()?;
+        for (next, _) in self.graph.neighbors(node) {
+            if !self.visited[next] {
+                self.visited[next] = true;
+                self.queue.push_back(next);
+            }
+        }
+        Some(node)
+    }
+}
+
+/// 深度优先遍历，给出前序（第一次到达节点时）的顺序，与递归写法的顺序相同
+///
+/// 栈中保存每一层尚未看完的邻居迭代器，而不是一次把所有邻居压栈，所以不需要递归也不会重复入栈。
+pub struct Dfs<'a, G: Graph + 'a> {
+    graph: &'a G,
+    start: Option<usize>,
+    stack: Vec<G::Neighbors<'a>>,
+    visited: Vec<bool>,
+}
+
+pub fn dfs<G: Graph>(graph: &G, start: usize) -> Dfs<'_, G> {
+    let mut visited = vec![false; graph.node_count()];
+    visited[start] = true;
+    Dfs { graph, start: Some(start), stack: Vec::new(), visited }
+}
+
+impl<'a, G: Graph> Iterator for Dfs<'a, G> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if let Some(start) = self.start.take() {
+            self.stack.push(self.graph.neighbors(start));
+            return Some(start);
+        }
+        while let Some(neighbors) = self.stack.last_mut() {
+            match neighbors.find(|&(next, _)| !self.visited[next]) {
+                Some((next, _)) => {
+                    self.visited[next] = true;
+                    self.stack.push(self.graph.neighbors(next));
+                    return Some(next);
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,14 @@
+use crate::registry::Registry;
+
+pub mod dp;
+pub mod graph;
+pub mod sort;
+pub mod text;
+
+pub fn register(registry: &mut Registry) {
+    registry.module("algo", "算法");
+    registry.register(sort::EXAMPLES);
+    registry.register(graph::EXAMPLES);
+    registry.register(text::EXAMPLES);
+    registry.register(dp::EXAMPLES);
+}
@@ -0,0 +1,44 @@
+//! 堆排序：先建大顶堆，再反复把堆顶交换到末尾
+
+use std::cmp::Ordering;
+
+use super::{Sorter, Stats};
+
+pub fn heap_sort<T: Ord>(v: &mut [T]) -> Stats {
+    heap_sort_by(v, T::cmp)
+}
+
+pub fn heap_sort_by<T>(v: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) -> Stats {
+    let mut sorter = Sorter::new(&mut compare);
+    sort(v, &mut sorter);
+    sorter.stats
+}
+
+pub(super) fn sort<T>(v: &mut [T], sorter: &mut Sorter<'_, T>) {
+    let n = v.len();
+    for root in (0..n / 2).rev() {
+        sift_down(v, root, n, sorter);
+    }
+    for end in (1..n).rev() {
+        sorter.swap(v, 0, end);
+        sift_down(v, 0, end, sorter);
+    }
+}
+
+// 让 v[root] 在 v[..end] 构成的堆中下沉到合适的位置
+fn sift_down<T>(v: &mut [T], mut root: usize, end: usize, sorter: &mut Sorter<'_, T>) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && sorter.less(&v[child], &v[child + 1]) {
+            child += 1;
+        }
+        if !sorter.less(&v[root], &v[child]) {
+            return;
+        }
+        sorter.swap(v, root, child);
+        root = child;
+    }
+}
@@ -0,0 +1,26 @@
+//! 插入排序：把每个元素向前交换，直到前一个元素不大于它
+
+use std::cmp::Ordering;
+
+use super::{Sorter, Stats};
+
+pub fn insertion_sort<T: Ord>(v: &mut [T]) -> Stats {
+    insertion_sort_by(v, T::cmp)
+}
+
+pub fn insertion_sort_by<T>(v: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) -> Stats {
+    let mut sorter = Sorter::new(&mut compare);
+    sort(v, &mut sorter);
+    sorter.stats
+}
+
+pub(super) fn sort<T>(v: &mut [T], sorter: &mut Sorter<'_, T>) {
+    for i in 1..v.len() {
+        // 只在严格小于时交换，相等的元素不会越过彼此，所以是稳定的
+        let mut j = i;
+        while j > 0 && sorter.less(&v[j], &v[j - 1]) {
+            sorter.swap(v, j - 1, j);
+            j -= 1;
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! 自顶向下的归并排序
+
+use std::cmp::Ordering;
+
+use super::{Sorter, Stats};
+
+pub fn merge_sort<T: Ord + Clone>(v: &mut [T]) -> Stats {
+    merge_sort_by(v, T::cmp)
+}
+
+pub fn merge_sort_by<T: Clone>(v: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) -> Stats {
+    let mut sorter = Sorter::new(&mut compare);
+    sort(v, &mut sorter);
+    sorter.stats
+}
+
+pub(super) fn sort<T: Clone>(v: &mut [T], sorter: &mut Sorter<'_, T>) {
+    sort_range(v, 0, v.len(), sorter);
+}
+
+// 下标都是相对整个切片的，观察者每次都能看到完整的数组
+fn sort_range<T: Clone>(v: &mut [T], lo: usize, hi: usize, sorter: &mut Sorter<'_, T>) {
+    if hi - lo < 2 {
+        return;
+    }
+    let mid = lo + (hi - lo) / 2;
+    sort_range(v, lo, mid, sorter);
+    sort_range(v, mid, hi, sorter);
+    merge(v, lo, mid, hi, sorter);
+}
+
+/// 合并相邻的有序区间 `v[lo..mid]` 和 `v[mid..hi]`
+///
+/// 只把左半部分复制出来，右半部分原地读取：写入位置永远不会超过右半部分的读取位置。
+pub(super) fn merge<T: Clone>(v: &mut [T], lo: usize, mid: usize, hi: usize, sorter: &mut Sorter<'_, T>) {
+    // 两段已经首尾相接时不需要合并
+    if lo == mid || mid == hi || !sorter.less(&v[mid], &v[mid - 1]) {
+        return;
+    }
+    let left = v[lo..mid].to_vec();
+    let (mut i, mut j, mut k) = (0, mid, lo);
+    while i < left.len() && j < hi {
+        // 右边严格小于左边时才取右边，相等时取左边，保证稳定
+        if sorter.less(&v[j], &left[i]) {
+            let value = v[j].clone();
+            sorter.write(v, k, value);
+            j += 1;
+        } else {
+            sorter.write(v, k, left[i].clone());
+            i += 1;
+        }
+        k += 1;
+    }
+    // 右边剩下的元素已经在正确的位置上
+    for value in &left[i..] {
+        sorter.write(v, k, value.clone());
+        k += 1;
+    }
+}
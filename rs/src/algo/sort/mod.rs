@@ -0,0 +1,465 @@
+/*!
+排序算法
+
+`basics::set_types::vec_test` 中用过标准库的 `sort_unstable_by`，这里自己实现几种经典的排序算法。
+每种算法都有 `T: Ord` 和比较闭包两个版本，闭包的签名与 `sort_unstable_by` 相同，
+返回这次排序做了多少次比较、交换和写入，用来对比不同算法在不同输入上的代价。
+
+| 算法 | 时间复杂度 | 稳定 | 说明 |
+|------|-----------|------|------|
+| 插入排序 | O(n²) | 是 | 交换次数等于逆序对数，几乎有序时很快 |
+| 归并排序 | O(n log n) | 是 | 需要 O(n) 的额外空间，`T: Clone` |
+| 快速排序 | 平均 O(n log n) | 否 | 三路划分，大量重复元素时也不会退化 |
+| 堆排序 | O(n log n) | 否 | 原地排序，最坏情况也是 O(n log n) |
+| 基数排序 | O(n · 键的字节数) | 是 | 不做比较，按键的每个字节分配，`T: Clone` |
+| Tim 排序 | O(n log n) | 是 | 利用输入中已有的有序片段，有序输入只需 n - 1 次比较 |
+
+`visual` 把排序的每一步画成终端中的柱状图，`rust_code_examples animate <算法>` 可以观看动画。
+
+```
+use rust_code_examples::algo::sort::{self, Algorithm};
+
+let mut v = [5, 2, 4, 6, 1, 3];
+let stats = sort::insertion_sort(&mut v);
+assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+assert_eq!(stats.swaps, 9);
+
+let mut words = ["pear", "fig", "apple"];
+sort::quick_sort_by(&mut words, |a, b| a.len().cmp(&b.len()));
+assert_eq!(words, ["fig", "pear", "apple"]);
+
+assert!(Algorithm::Merge.is_stable() && !Algorithm::Heap.is_stable());
+assert!(sort::is_stable_on(Algorithm::Tim, &[3, 1, 3, 1, 2]));
+```
+*/
+
+mod heap;
+mod insertion;
+mod merge;
+mod quick;
+mod radix;
+mod tim;
+pub mod visual;
+
+pub use heap::{heap_sort, heap_sort_by};
+pub use insertion::{insertion_sort, insertion_sort_by};
+pub use merge::{merge_sort, merge_sort_by};
+pub use quick::{quick_sort, quick_sort_by};
+pub use radix::{radix_sort, radix_sort_by_key};
+pub use tim::{tim_sort, tim_sort_by};
+
+use std::cmp::Ordering;
+
+use crate::example;
+use crate::registry::Example;
+use crate::utils::XorShift64;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_generic_sorts, "泛型排序", "T: Ord 与比较闭包两个版本，闭包的写法与 sort_unstable_by 相同"),
+    example!(ex2_operation_counts, "比较与交换次数", "同一组输入上各算法的比较、交换、写入次数"),
+    example!(ex3_stability, "稳定性", "验证稳定的算法保持相等元素的原有顺序，并为不稳定的算法找出反例"),
+    example!(ex4_steps, "逐步观察", "记录每一次交换和写入，画出排序过程"),
+];
+
+/// 一次排序的操作计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// 调用比较函数的次数
+    pub comparisons: u64,
+    /// 交换两个元素的次数
+    pub swaps: u64,
+    /// 把一个元素写入某个位置的次数，归并和基数排序从辅助空间写回时计入
+    pub writes: u64,
+}
+
+/// 排序过程中改变了切片的一步
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Swap(usize, usize),
+    Write(usize),
+}
+
+impl Step {
+    /// 这一步改变的位置
+    pub fn positions(self) -> Vec<usize> {
+        match self {
+            Step::Swap(i, j) => vec![i, j],
+            Step::Write(i) => vec![i],
+        }
+    }
+}
+
+// 每次交换或写入之后的回调，参数是当前的切片、这一步和到目前为止的计数
+type Observer<'a, T> = &'a mut dyn FnMut(&[T], Step, Stats);
+
+// 各算法通过它比较、交换和写入，顺便计数并通知观察者
+struct Sorter<'a, T> {
+    compare: &'a mut dyn FnMut(&T, &T) -> Ordering,
+    observer: Option<Observer<'a, T>>,
+    stats: Stats,
+}
+
+impl<'a, T> Sorter<'a, T> {
+    fn new(compare: &'a mut dyn FnMut(&T, &T) -> Ordering) -> Sorter<'a, T> {
+        Sorter { compare, observer: None, stats: Stats::default() }
+    }
+
+    fn compare(&mut self, a: &T, b: &T) -> Ordering {
+        self.stats.comparisons += 1;
+        (self.compare)(a, b)
+    }
+
+    fn less(&mut self, a: &T, b: &T) -> bool {
+        self.compare(a, b) == Ordering::Less
+    }
+
+    fn swap(&mut self, v: &mut [T], i: usize, j: usize) {
+        // 与自身交换什么也不改变，不计数也不通知
+        if i == j {
+            return;
+        }
+        v.swap(i, j);
+        self.stats.swaps += 1;
+        self.notify(v, Step::Swap(i, j));
+    }
+
+    fn write(&mut self, v: &mut [T], i: usize, value: T) {
+        v[i] = value;
+        self.stats.writes += 1;
+        self.notify(v, Step::Write(i));
+    }
+
+    fn notify(&mut self, v: &[T], step: Step) {
+        let stats = self.stats;
+        if let Some(observer) = self.observer.as_mut() {
+            observer(v, step, stats);
+        }
+    }
+}
+
+/// 所有实现的排序算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Insertion,
+    Merge,
+    Quick,
+    Heap,
+    Radix,
+    Tim,
+}
+
+impl Algorithm {
+    pub const ALL: [Algorithm; 6] =
+        [Algorithm::Insertion, Algorithm::Merge, Algorithm::Quick, Algorithm::Heap, Algorithm::Radix, Algorithm::Tim];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Algorithm::Insertion => "insertion",
+            Algorithm::Merge => "merge",
+            Algorithm::Quick => "quick",
+            Algorithm::Heap => "heap",
+            Algorithm::Radix => "radix",
+            Algorithm::Tim => "tim",
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Algorithm::Insertion => "插入排序",
+            Algorithm::Merge => "归并排序",
+            Algorithm::Quick => "快速排序",
+            Algorithm::Heap => "堆排序",
+            Algorithm::Radix => "基数排序",
+            Algorithm::Tim => "Tim 排序",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Algorithm> {
+        Algorithm::ALL.into_iter().find(|a| a.name().eq_ignore_ascii_case(name))
+    }
+
+    /// 相等的元素排序后是否保持原来的相对顺序
+    pub fn is_stable(self) -> bool {
+        !matches!(self, Algorithm::Quick | Algorithm::Heap)
+    }
+
+    /// 按 key 从小到大排序，比较排序比较 key 的大小，基数排序直接使用 key 的各个字节
+    pub fn sort_by_key<T: Clone>(self, v: &mut [T], key: impl Fn(&T) -> u64) -> Stats {
+        self.run(v, &key, None)
+    }
+
+    /// 与 `sort_by_key` 相同，每次交换或写入之后调用 observer，参数是当前的切片、这一步和到目前为止的计数
+    pub fn trace_by_key<T: Clone>(
+        self,
+        v: &mut [T],
+        key: impl Fn(&T) -> u64,
+        mut observer: impl FnMut(&[T], Step, Stats),
+    ) -> Stats {
+        self.run(v, &key, Some(&mut observer))
+    }
+
+    fn run<T: Clone>(self, v: &mut [T], key: &dyn Fn(&T) -> u64, observer: Option<Observer<'_, T>>) -> Stats {
+        let mut compare = |a: &T, b: &T| key(a).cmp(&key(b));
+        let mut sorter = Sorter::new(&mut compare);
+        // Option 中的 trait 对象不会自动缩短生命周期，拆开之后再放回去
+        sorter.observer = observer.map(|observer| observer as _);
+        match self {
+            Algorithm::Insertion => insertion::sort(v, &mut sorter),
+            Algorithm::Merge => merge::sort(v, &mut sorter),
+            Algorithm::Quick => quick::sort(v, &mut sorter),
+            Algorithm::Heap => heap::sort(v, &mut sorter),
+            Algorithm::Radix => radix::sort(v, key, &mut sorter),
+            Algorithm::Tim => tim::sort(v, &mut sorter),
+        }
+        sorter.stats
+    }
+}
+
+/// 给每个 key 附上原来的下标后排序，检查相等的 key 是否仍按下标递增排列
+pub fn is_stable_on(algorithm: Algorithm, keys: &[u32]) -> bool {
+    let mut items: Vec<(u32, usize)> = keys.iter().copied().zip(0..).collect();
+    algorithm.sort_by_key(&mut items, |&(key, _)| u64::from(key));
+    items.windows(2).all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1))
+}
+
+pub fn ex1_generic_sorts() {
+    println!("泛型排序 示例代码\n\n");
+
+    // T: Ord 的版本
+    let mut numbers = [38, 27, 43, 3, 9, 82, 10];
+    merge_sort(&mut numbers);
+    assert_eq!(numbers, [3, 9, 10, 27, 38, 43, 82]);
+    println!("merge_sort:  {:?}", numbers);
+
+    let mut words = vec!["banana", "apple", "cherry", "apple"];
+    heap_sort(&mut words);
+    assert_eq!(words, ["apple", "apple", "banana", "cherry"]);
+    println!("heap_sort:   {:?}", words);
+
+    // 比较闭包的版本，与 set_types::vec_test 中 sort_unstable_by 的写法相同
+    #[derive(Debug, Clone)]
+    struct Person {
+        name: &'static str,
+        age: u32,
+    }
+    let mut people = vec![
+        Person { name: "Zoe", age: 25 },
+        Person { name: "Al", age: 60 },
+        Person { name: "John", age: 1 },
+    ];
+    quick_sort_by(&mut people, |a, b| b.age.cmp(&a.age));
+    let names: Vec<&str> = people.iter().map(|p| p.name).collect();
+    assert_eq!(names, ["Al", "Zoe", "John"]);
+    println!("quick_sort_by 按年龄降序: {:?}", names);
+
+    // 浮点数没有实现 Ord，用 partial_cmp 比较
+    let mut floats = vec![1.5, -0.5, 3.25, 0.0];
+    tim_sort_by(&mut floats, |a: &f64, b: &f64| a.partial_cmp(b).unwrap());
+    assert_eq!(floats, [-0.5, 0.0, 1.5, 3.25]);
+    println!("tim_sort_by 浮点数: {:?}", floats);
+
+    // 基数排序不做比较，直接按整数的字节分配
+    let mut ids: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66];
+    let stats = radix_sort(&mut ids);
+    assert_eq!(ids, [2, 24, 45, 66, 75, 90, 170, 802]);
+    assert_eq!(stats.comparisons, 0);
+    println!("radix_sort:  {:?}", ids);
+}
+
+pub fn ex2_operation_counts() {
+    println!("比较与交换次数 示例代码\n\n");
+
+    let n = 1000;
+    let sorted: Vec<u32> = (0..n).collect();
+    let reversed: Vec<u32> = sorted.iter().rev().copied().collect();
+    let mut shuffled = sorted.clone();
+    XorShift64::new(7).shuffle(&mut shuffled);
+    let inputs = [("随机", &shuffled), ("有序", &sorted), ("逆序", &reversed)];
+
+    println!("{:<10} {:<6} {:>10} {:>10} {:>10}", "算法", "输入", "比较", "交换", "写入");
+    for algorithm in Algorithm::ALL {
+        for (label, input) in inputs {
+            let mut v = input.clone();
+            let stats = algorithm.sort_by_key(&mut v, |&x| u64::from(x));
+            assert_eq!(v, sorted);
+            println!(
+                "{:<10} {:<6} {:>10} {:>10} {:>10}",
+                algorithm.name(),
+                label,
+                stats.comparisons,
+                stats.swaps,
+                stats.writes
+            );
+        }
+    }
+
+    // 插入排序在有序输入上只比较 n - 1 次，逆序时交换 n(n-1)/2 次
+    let count = |algorithm: Algorithm, input: &Vec<u32>| algorithm.sort_by_key(&mut input.clone(), |&x| u64::from(x));
+    assert_eq!(count(Algorithm::Insertion, &sorted).comparisons, u64::from(n) - 1);
+    assert_eq!(count(Algorithm::Insertion, &reversed).swaps, u64::from(n * (n - 1) / 2));
+    // Tim 排序把整个逆序的输入当作一段，翻转之后就结束了
+    let tim = count(Algorithm::Tim, &reversed);
+    assert_eq!((tim.comparisons, tim.swaps, tim.writes), (u64::from(n) - 1, u64::from(n / 2), 0));
+    assert_eq!(count(Algorithm::Tim, &sorted), Stats { comparisons: u64::from(n) - 1, swaps: 0, writes: 0 });
+}
+
+pub fn ex3_stability() {
+    println!("稳定性 示例代码\n\n");
+
+    // 按成绩排序，成绩相同的学生保持原来按学号排列的顺序
+    let students = [("01", 90), ("02", 85), ("03", 90), ("04", 70), ("05", 85)];
+    let mut by_score = students.to_vec();
+    Algorithm::Merge.sort_by_key(&mut by_score, |&(_, score)| 100 - score);
+    let ids: Vec<&str> = by_score.iter().map(|s| s.0).collect();
+    assert_eq!(ids, ["01", "03", "02", "05", "04"]);
+    println!("归并排序按成绩降序: {:?}", ids);
+
+    // 用随机输入验证稳定性，不稳定的算法找出第一个反例
+    let mut rng = XorShift64::new(11);
+    let inputs: Vec<Vec<u32>> = (0..200).map(|_| (0..rng.below(12) as usize).map(|_| rng.below(3) as u32).collect()).collect();
+    for algorithm in Algorithm::ALL {
+        let counterexample = inputs.iter().find(|keys| !is_stable_on(algorithm, keys));
+        assert_eq!(counterexample.is_none(), algorithm.is_stable());
+        match counterexample {
+            None => println!("{:<10} 稳定，200 组随机输入都保持了相等元素的顺序", algorithm.name()),
+            Some(keys) => println!("{:<10} 不稳定，反例: {:?}", algorithm.name(), keys),
+        }
+    }
+}
+
+pub fn ex4_steps() {
+    println!("逐步观察 示例代码\n\n");
+
+    let mut v = [5, 2, 4, 6, 1, 3];
+    println!("初始       {:?}", v);
+    let stats = Algorithm::Insertion.trace_by_key(&mut v, |&x| x, |v, step, _| {
+        println!("{:<10} {:?}", format!("{:?}", step), v);
+    });
+    assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(stats, Stats { comparisons: 12, swaps: 9, writes: 0 });
+
+    // 记录每一步，终端动画就是把这些帧依次画出来
+    let mut frames = Vec::new();
+    let mut v: Vec<u32> = vec![3, 7, 1, 8, 2, 6, 4, 5];
+    let stats = Algorithm::Quick.trace_by_key(&mut v, |&x| u64::from(x), |v, step, stats| {
+        frames.push(visual::frame(Algorithm::Quick, v, Some(step), stats, 4));
+    });
+    assert_eq!(frames.len() as u64, stats.swaps);
+    println!("\n快速排序共 {} 帧，最后一帧:\n{}", frames.len(), frames.last().unwrap());
+    println!("运行 rust_code_examples animate quick 可以在终端中观看动画");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inversions(v: &[u32]) -> u64 {
+        let mut count = 0;
+        for i in 0..v.len() {
+            count += v[i + 1..].iter().filter(|&&x| x < v[i]).count() as u64;
+        }
+        count
+    }
+
+    // 输入包括随机、大量重复、已经有序和逆序的数组；稳定的算法还要求相等元素的顺序与稳定的 `sort` 完全相同
+    #[test]
+    fn matches_std_sort() {
+        let mut rng = XorShift64::new(21);
+        for round in 0..300 {
+            let n = rng.below(200) as usize;
+            let range = [2, 10, 1000, u64::from(u32::MAX)][rng.below(4) as usize];
+            let mut keys: Vec<u32> = (0..n).map(|_| rng.below(range) as u32).collect();
+            match rng.below(4) {
+                0 => keys.sort(),
+                1 => keys.sort_by(|a, b| b.cmp(a)),
+                _ => {}
+            }
+            let items: Vec<(u32, usize)> = keys.iter().copied().zip(0..).collect();
+            let mut expected = items.clone();
+            expected.sort_by_key(|&(key, _)| key);
+
+            for algorithm in Algorithm::ALL {
+                let mut actual = items.clone();
+                let stats = algorithm.sort_by_key(&mut actual, |&(key, _)| u64::from(key));
+                let context = format!("第 {} 轮 {} n = {}", round, algorithm.name(), n);
+                if algorithm.is_stable() {
+                    assert_eq!(actual, expected, "{}", context);
+                } else {
+                    assert!(actual.iter().map(|e| e.0).eq(expected.iter().map(|e| e.0)), "{}", context);
+                }
+                match algorithm {
+                    // 插入排序每次交换消除一个逆序对
+                    Algorithm::Insertion => assert_eq!(stats.swaps, inversions(&keys), "{}", context),
+                    Algorithm::Radix => assert_eq!(stats.comparisons, 0, "{}", context),
+                    _ => {}
+                }
+            }
+
+            // 比较闭包版本：按降序排列
+            let mut descending = keys.clone();
+            descending.sort_by(|a, b| b.cmp(a));
+            type SortBy = fn(&mut [u32], fn(&u32, &u32) -> Ordering) -> Stats;
+            let by: [SortBy; 5] = [insertion_sort_by, merge_sort_by, quick_sort_by, heap_sort_by, tim_sort_by];
+            for sort_by in by {
+                let mut actual = keys.clone();
+                sort_by(&mut actual, |a, b| b.cmp(a));
+                assert_eq!(actual, descending, "第 {} 轮 降序", round);
+            }
+            let mut actual = keys.clone();
+            radix_sort(&mut actual);
+            assert!(actual.iter().rev().eq(descending.iter()), "第 {} 轮 radix_sort", round);
+        }
+    }
+
+    #[test]
+    fn empty_and_single_element_inputs() {
+        for algorithm in Algorithm::ALL {
+            let mut empty: [u32; 0] = [];
+            assert_eq!(algorithm.sort_by_key(&mut empty, |&x| u64::from(x)), Stats::default(), "{}", algorithm.name());
+            let mut single = [7u32];
+            let stats = algorithm.sort_by_key(&mut single, |&x| u64::from(x));
+            assert_eq!(single, [7]);
+            // 基数排序每一轮都把元素写回一次，比较排序对单个元素什么也不用做
+            let writes = u64::from(algorithm == Algorithm::Radix);
+            assert_eq!(stats, Stats { writes, ..Stats::default() }, "{}", algorithm.name());
+        }
+    }
+
+    #[test]
+    fn sorted_and_reversed_inputs() {
+        let sorted: Vec<u32> = (0..100).collect();
+        let reversed: Vec<u32> = (0..100).rev().collect();
+        for algorithm in Algorithm::ALL {
+            for input in [&sorted, &reversed] {
+                let mut v = input.clone();
+                algorithm.sort_by_key(&mut v, |&x| u64::from(x));
+                assert_eq!(v, sorted, "{}", algorithm.name());
+            }
+        }
+        let mut v = sorted.clone();
+        let stats = insertion_sort(&mut v);
+        assert_eq!((stats.comparisons, stats.swaps), (99, 0));
+        assert_eq!(tim_sort(&mut v).comparisons, 99);
+        let mut v = reversed.clone();
+        assert_eq!(insertion_sort(&mut v).swaps, 100 * 99 / 2);
+    }
+
+    #[test]
+    fn all_equal_keys() {
+        let keys = [5; 500];
+        for algorithm in Algorithm::ALL {
+            let stable = is_stable_on(algorithm, &keys);
+            assert!(stable || !algorithm.is_stable(), "{} 打乱了相等的元素", algorithm.name());
+        }
+        // 三路划分一趟就把所有元素归入等于枢轴的部分
+        let mut v = keys;
+        assert!(quick_sort(&mut v).comparisons < 2 * keys.len() as u64);
+    }
+
+    #[test]
+    fn radix_sort_handles_the_full_key_range() {
+        let mut v = [u64::MAX, 0, 1 << 56, u64::MAX - 1, 255, 256];
+        radix_sort(&mut v);
+        assert_eq!(v, [0, 255, 256, 1 << 56, u64::MAX - 1, u64::MAX]);
+    }
+}
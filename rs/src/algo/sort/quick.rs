@@ -0,0 +1,69 @@
+//! 三路划分的快速排序
+
+use std::cmp::Ordering;
+
+use super::{Sorter, Stats};
+
+pub fn quick_sort<T: Ord>(v: &mut [T]) -> Stats {
+    quick_sort_by(v, T::cmp)
+}
+
+pub fn quick_sort_by<T>(v: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) -> Stats {
+    let mut sorter = Sorter::new(&mut compare);
+    sort(v, &mut sorter);
+    sorter.stats
+}
+
+pub(super) fn sort<T>(v: &mut [T], sorter: &mut Sorter<'_, T>) {
+    sort_range(v, 0, v.len(), sorter);
+}
+
+fn sort_range<T>(v: &mut [T], mut lo: usize, mut hi: usize, sorter: &mut Sorter<'_, T>) {
+    // 只对较小的一边递归，较大的一边继续循环，递归深度不超过 log n
+    while hi - lo > 1 {
+        let (lt, gt) = partition(v, lo, hi, sorter);
+        if lt - lo < hi - gt {
+            sort_range(v, lo, lt, sorter);
+            lo = gt;
+        } else {
+            sort_range(v, gt, hi, sorter);
+            hi = lt;
+        }
+    }
+}
+
+// 把 v[lo..hi] 分成小于、等于、大于基准的三段，返回等于基准的区间 lt..gt
+//
+// 基准一直放在 v[lt]：小于基准的元素与它交换，基准随之后移一位，所以不需要把基准复制出来。
+fn partition<T>(v: &mut [T], lo: usize, hi: usize, sorter: &mut Sorter<'_, T>) -> (usize, usize) {
+    let pivot = median_of_three(v, lo, lo + (hi - lo) / 2, hi - 1, sorter);
+    sorter.swap(v, lo, pivot);
+    let (mut lt, mut i, mut gt) = (lo, lo + 1, hi);
+    while i < gt {
+        match sorter.compare(&v[i], &v[lt]) {
+            Ordering::Less => {
+                sorter.swap(v, lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                sorter.swap(v, i, gt);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+// 三个位置中值居中的那个，避免有序输入退化成 O(n²)
+fn median_of_three<T>(v: &[T], a: usize, b: usize, c: usize, sorter: &mut Sorter<'_, T>) -> usize {
+    let (a, b) = if sorter.less(&v[b], &v[a]) { (b, a) } else { (a, b) };
+    if sorter.less(&v[c], &v[a]) {
+        a
+    } else if sorter.less(&v[c], &v[b]) {
+        c
+    } else {
+        b
+    }
+}
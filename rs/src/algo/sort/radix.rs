@@ -0,0 +1,39 @@
+//! LSD 基数排序：从最低字节开始，每一轮按一个字节做稳定的计数排序
+
+use super::{Sorter, Stats};
+
+pub fn radix_sort<K: Copy + Into<u64>>(v: &mut [K]) -> Stats {
+    radix_sort_by_key(v, |&k| k.into())
+}
+
+pub fn radix_sort_by_key<T: Clone>(v: &mut [T], key: impl Fn(&T) -> u64) -> Stats {
+    // 基数排序不调用比较函数
+    let mut compare = |_: &T, _: &T| unreachable!();
+    let mut sorter = Sorter::new(&mut compare);
+    sort(v, &key, &mut sorter);
+    sorter.stats
+}
+
+pub(super) fn sort<T: Clone>(v: &mut [T], key: &dyn Fn(&T) -> u64, sorter: &mut Sorter<'_, T>) {
+    // 只需要处理最大的 key 用到的字节数
+    let max = v.iter().map(key).max().unwrap_or(0);
+    let passes = (u64::BITS - max.leading_zeros()).div_ceil(8);
+    for pass in 0..passes {
+        let digit = |item: &T| (key(item) >> (8 * pass) & 0xff) as usize;
+        let mut offsets = [0; 256];
+        for item in v.iter() {
+            offsets[digit(item)] += 1;
+        }
+        let mut start = 0;
+        for offset in offsets.iter_mut() {
+            (*offset, start) = (start, start + *offset);
+        }
+        // 按原来的顺序把元素写到各自桶中的下一个位置，所以每一轮都是稳定的
+        let items = v.to_vec();
+        for item in items {
+            let d = digit(&item);
+            sorter.write(v, offsets[d], item);
+            offsets[d] += 1;
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! 简化的 Tim 排序
+//!
+//! 把输入切成有序的片段（run），严格降序的片段原地翻转，太短的片段用二分插入补到 `min_run` 的长度，
+//! 再用一个栈按 Timsort 的规则合并相邻的片段。没有实现 galloping 模式。
+
+use std::cmp::Ordering;
+
+use super::{merge, Sorter, Stats};
+
+pub fn tim_sort<T: Ord + Clone>(v: &mut [T]) -> Stats {
+    tim_sort_by(v, T::cmp)
+}
+
+pub fn tim_sort_by<T: Clone>(v: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) -> Stats {
+    let mut sorter = Sorter::new(&mut compare);
+    sort(v, &mut sorter);
+    sorter.stats
+}
+
+// 取 n 的最高 5 位，有剩余的低位时加一，结果在 16..=32 之间，n / min_run 接近 2 的幂
+fn min_run(mut n: usize) -> usize {
+    let mut rest = 0;
+    while n >= 32 {
+        rest |= n & 1;
+        n >>= 1;
+    }
+    n + rest
+}
+
+pub(super) fn sort<T: Clone>(v: &mut [T], sorter: &mut Sorter<'_, T>) {
+    let n = v.len();
+    let min_run = min_run(n);
+    // 待合并的片段 (起点, 长度)
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    let mut lo = 0;
+    while lo < n {
+        let mut len = natural_run(v, lo, sorter);
+        if len < min_run {
+            let forced = min_run.min(n - lo);
+            binary_insertion(v, lo, lo + forced, lo + len, sorter);
+            len = forced;
+        }
+        runs.push((lo, len));
+        collapse(v, &mut runs, sorter);
+        lo += len;
+    }
+    while runs.len() > 1 {
+        let n = runs.len();
+        let at = if n >= 3 && runs[n - 3].1 < runs[n - 1].1 { n - 3 } else { n - 2 };
+        merge_at(v, &mut runs, at, sorter);
+    }
+}
+
+// 从 lo 开始的有序片段的长度，严格降序的片段翻转成升序；只翻转严格降序的，相等元素的顺序不会被打乱
+fn natural_run<T>(v: &mut [T], lo: usize, sorter: &mut Sorter<'_, T>) -> usize {
+    let n = v.len();
+    if lo + 1 >= n {
+        return n - lo;
+    }
+    let mut hi = lo + 2;
+    if sorter.less(&v[lo + 1], &v[lo]) {
+        while hi < n && sorter.less(&v[hi], &v[hi - 1]) {
+            hi += 1;
+        }
+        let (mut i, mut j) = (lo, hi - 1);
+        while i < j {
+            sorter.swap(v, i, j);
+            i += 1;
+            j -= 1;
+        }
+    } else {
+        while hi < n && !sorter.less(&v[hi], &v[hi - 1]) {
+            hi += 1;
+        }
+    }
+    hi - lo
+}
+
+// v[lo..sorted] 已经有序，把 v[sorted..hi] 逐个二分查找位置后插入
+fn binary_insertion<T>(v: &mut [T], lo: usize, hi: usize, sorted: usize, sorter: &mut Sorter<'_, T>) {
+    for i in sorted..hi {
+        // 找第一个大于 v[i] 的位置，插在相等元素之后
+        let (mut left, mut right) = (lo, i);
+        while left < right {
+            let mid = left + (right - left) / 2;
+            if sorter.less(&v[i], &v[mid]) {
+                right = mid;
+            } else {
+                left = mid + 1;
+            }
+        }
+        for j in (left..i).rev() {
+            sorter.swap(v, j, j + 1);
+        }
+    }
+}
+
+// 维持栈上片段长度的不变式：A > B + C 且 B > C（A、B、C 是栈顶的三个片段），
+// 让合并的两段长度接近，栈的深度不超过 log n
+fn collapse<T: Clone>(v: &mut [T], runs: &mut Vec<(usize, usize)>, sorter: &mut Sorter<'_, T>) {
+    while runs.len() > 1 {
+        let n = runs.len();
+        let at = if (n >= 3 && runs[n - 3].1 <= runs[n - 2].1 + runs[n - 1].1)
+            || (n >= 4 && runs[n - 4].1 <= runs[n - 3].1 + runs[n - 2].1)
+        {
+            if runs[n - 3].1 < runs[n - 1].1 {
+                n - 3
+            } else {
+                n - 2
+            }
+        } else if runs[n - 2].1 <= runs[n - 1].1 {
+            n - 2
+        } else {
+            return;
+        };
+        merge_at(v, runs, at, sorter);
+    }
+}
+
+fn merge_at<T: Clone>(v: &mut [T], runs: &mut Vec<(usize, usize)>, at: usize, sorter: &mut Sorter<'_, T>) {
+    let (lo, left) = runs[at];
+    let (_, right) = runs.remove(at + 1);
+    merge::merge(v, lo, lo + left, lo + left + right, sorter);
+    runs[at] = (lo, left + right);
+}
@@ -0,0 +1,74 @@
+//! 在终端中把排序过程画成柱状图
+
+use std::io;
+use std::thread;
+use std::time::Duration;
+
+use console::{Style, Term};
+
+use super::{Algorithm, Stats, Step};
+
+/// 画一帧：标题、柱状图和计数，这一步改变的位置高亮显示
+pub fn frame(algorithm: Algorithm, values: &[u32], step: Option<Step>, stats: Stats, height: usize) -> String {
+    let changed = step.map(Step::positions).unwrap_or_default();
+    let highlight = Style::new().yellow().bold();
+    let max = values.iter().copied().max().unwrap_or(0).max(1);
+    // 每个值占 1..=height 行，0 也画出最底下的一格以便看出位置
+    let levels: Vec<usize> =
+        values.iter().map(|&x| ((u64::from(x) * height as u64).div_ceil(u64::from(max)) as usize).max(1)).collect();
+
+    let mut out = format!("{} {}\n", Style::new().bold().apply_to(algorithm.title()), algorithm.name());
+    for row in (0..height).rev() {
+        let line: String = levels
+            .iter()
+            .enumerate()
+            .map(|(i, &level)| match (level > row, changed.contains(&i)) {
+                (false, _) => "  ".to_string(),
+                (true, false) => "█ ".to_string(),
+                (true, true) => format!("{} ", highlight.apply_to("█")),
+            })
+            .collect();
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    let step = match step {
+        Some(Step::Swap(i, j)) => format!("交换 {} 和 {}", i, j),
+        Some(Step::Write(i)) => format!("写入 {}", i),
+        None => "完成".to_string(),
+    };
+    out.push_str(&format!("比较 {}  交换 {}  写入 {}  {}", stats.comparisons, stats.swaps, stats.writes, step));
+    out
+}
+
+/// 在终端中播放 algorithm 排序 values 的动画，每一步之后暂停 delay
+///
+/// 每一帧覆盖上一帧；标准输出不是终端时只打印排序前后两帧。
+pub fn animate(algorithm: Algorithm, values: &[u32], delay: Duration, height: usize) -> io::Result<Stats> {
+    let term = Term::stdout();
+    let mut v = values.to_vec();
+    if !term.is_term() {
+        term.write_line(&frame(algorithm, &v, None, Stats::default(), height))?;
+        let stats = algorithm.sort_by_key(&mut v, |&x| u64::from(x));
+        term.write_line(&frame(algorithm, &v, None, stats, height))?;
+        return Ok(stats);
+    }
+
+    let first = frame(algorithm, &v, None, Stats::default(), height);
+    let mut drawn = first.lines().count();
+    term.hide_cursor()?;
+    term.write_line(&first)?;
+    // 观察者不能返回错误，记下第一个错误后不再绘制
+    let mut result = Ok(());
+    let stats = algorithm.trace_by_key(&mut v, |&x| u64::from(x), |v, step, stats| {
+        if result.is_ok() {
+            let next = frame(algorithm, v, Some(step), stats, height);
+            result = term.clear_last_lines(drawn).and_then(|()| term.write_line(&next));
+            drawn = next.lines().count();
+            thread::sleep(delay);
+        }
+    });
+    let last = frame(algorithm, &v, None, stats, height);
+    let result = result.and_then(|()| term.clear_last_lines(drawn)).and_then(|()| term.write_line(&last));
+    term.show_cursor()?;
+    result.map(|()| stats)
+}
@@ -0,0 +1,85 @@
+//! Aho-Corasick 多模式匹配
+
+use std::collections::VecDeque;
+
+/// 一次匹配：第 pattern 个模式串出现在 `start..end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// 由所有模式串构成的自动机，一次扫描找出所有模式串的所有出现位置
+///
+/// 先把模式串插入字典树，再按层计算失配指针：状态 s 的失配指针指向 s 对应字符串
+/// 在字典树中存在的最长真后缀。把失配后的转移提前填进转移表，扫描文本时每个字节只查一次表。
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    // 状态 0 是根，转移到 0 既表示回到根，也表示构建字典树时还没有这个子节点（根不会是别的节点的子节点）
+    next: Vec<[u32; 256]>,
+    // 到达某个状态时匹配成功的模式串，沿失配指针能到达的状态的输出也合并进来，长的在前
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// # Panics
+    ///
+    /// 有空的模式串时 panic。
+    pub fn new<P: AsRef<[u8]>>(patterns: &[P]) -> AhoCorasick {
+        let mut ac = AhoCorasick { next: vec![[0; 256]], outputs: vec![Vec::new()], lengths: Vec::new() };
+        for (id, pattern) in patterns.iter().enumerate() {
+            let pattern = pattern.as_ref();
+            assert!(!pattern.is_empty(), "第 {} 个模式串为空", id);
+            let mut state = 0;
+            for &b in pattern {
+                if ac.next[state][b as usize] == 0 {
+                    ac.next[state][b as usize] = ac.next.len() as u32;
+                    ac.next.push([0; 256]);
+                    ac.outputs.push(Vec::new());
+                }
+                state = ac.next[state][b as usize] as usize;
+            }
+            ac.outputs[state].push(id);
+            ac.lengths.push(pattern.len());
+        }
+
+        // 按层遍历，处理一个状态时它的失配指针指向的状态更浅，已经处理完了
+        let mut fail = vec![0; ac.next.len()];
+        let mut queue: VecDeque<usize> = ac.next[0].iter().filter(|&&s| s != 0).map(|&s| s as usize).collect();
+        while let Some(state) = queue.pop_front() {
+            for b in 0..256 {
+                let child = ac.next[state][b] as usize;
+                let fallback = ac.next[fail[state]][b];
+                if child == 0 {
+                    ac.next[state][b] = fallback;
+                } else {
+                    fail[child] = fallback as usize;
+                    let inherited = ac.outputs[fallback as usize].clone();
+                    ac.outputs[child].extend(inherited);
+                    queue.push_back(child);
+                }
+            }
+        }
+        ac
+    }
+
+    /// 自动机的状态数，等于所有模式串的不同前缀个数加一
+    pub fn state_count(&self) -> usize {
+        self.next.len()
+    }
+
+    /// 所有模式串的所有出现位置，按结束位置排列，结束位置相同时长的在前，O(n + 匹配数)
+    pub fn find_all(&self, haystack: impl AsRef<[u8]>) -> Vec<Match> {
+        let mut found = Vec::new();
+        let mut state = 0;
+        for (i, &b) in haystack.as_ref().iter().enumerate() {
+            state = self.next[state][b as usize] as usize;
+            for &pattern in &self.outputs[state] {
+                found.push(Match { pattern, start: i + 1 - self.lengths[pattern], end: i + 1 });
+            }
+        }
+        found
+    }
+}
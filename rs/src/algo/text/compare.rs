@@ -0,0 +1,160 @@
+//! 在同一份语料上对比各个查找算法的耗时，`rust_code_examples textbench` 的实现
+
+use std::time::Duration;
+
+use console::Style;
+
+use super::{naive_find_all, AhoCorasick, Horspool, Kmp, RabinKarp, SuffixArray};
+use crate::benchmark;
+use crate::utils::XorShift64;
+
+/// 参与对比的查找算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Naive,
+    Kmp,
+    Horspool,
+    RabinKarp,
+    AhoCorasick,
+    SuffixArray,
+}
+
+impl Algorithm {
+    pub const ALL: [Algorithm; 6] = [
+        Algorithm::Naive,
+        Algorithm::Kmp,
+        Algorithm::Horspool,
+        Algorithm::RabinKarp,
+        Algorithm::AhoCorasick,
+        Algorithm::SuffixArray,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Algorithm::Naive => "naive",
+            Algorithm::Kmp => "kmp",
+            Algorithm::Horspool => "horspool",
+            Algorithm::RabinKarp => "rabin-karp",
+            Algorithm::AhoCorasick => "aho-corasick",
+            Algorithm::SuffixArray => "suffix-array",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Algorithm> {
+        Algorithm::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    /// 在 corpus 中查找所有的模式串，返回匹配的总数，预处理也计算在内
+    ///
+    /// 单模式的算法对每个模式串各扫描一遍，Aho-Corasick 只扫描一遍，后缀数组构建一次后逐个查询。
+    pub fn count_matches(self, corpus: &[u8], patterns: &[&[u8]]) -> usize {
+        match self {
+            Algorithm::Naive => patterns.iter().map(|p| naive_find_all(corpus, p).len()).sum(),
+            Algorithm::Kmp => patterns.iter().map(|p| Kmp::new(p).find_all(corpus).len()).sum(),
+            Algorithm::Horspool => patterns.iter().map(|p| Horspool::new(p).find_all(corpus).len()).sum(),
+            Algorithm::RabinKarp => patterns.iter().map(|p| RabinKarp::new(p).find_all(corpus).len()).sum(),
+            Algorithm::AhoCorasick => AhoCorasick::new(patterns).find_all(corpus).len(),
+            Algorithm::SuffixArray => {
+                let index = SuffixArray::new(corpus);
+                patterns.iter().map(|p| index.find_all(p).len()).sum()
+            }
+        }
+    }
+}
+
+/// 对比的配置
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// 生成的语料的字节数，从文件读入语料时不使用
+    pub size: usize,
+    pub patterns: Vec<String>,
+    pub algorithms: Vec<Algorithm>,
+    /// 每个算法运行的次数，取中位数
+    pub repeat: usize,
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            size: 1 << 20,
+            patterns: ["algorithm", "data", "rust", "the tree", "字符串", "图"].map(String::from).to_vec(),
+            algorithms: Algorithm::ALL.to_vec(),
+            repeat: 5,
+            seed: 0x5EED,
+        }
+    }
+}
+
+/// 一个算法的测量结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub algorithm: Algorithm,
+    pub matches: usize,
+    /// 多次运行的中位数
+    pub median: Duration,
+}
+
+/// 由常见单词随机组成、中英文混合的语料，大约 size 个字节
+pub fn sample_corpus(size: usize, seed: u64) -> String {
+    const WORDS: &[&str] = &[
+        "the", "a", "rust", "data", "tree", "graph", "string", "algorithm", "search", "pattern", "text", "index",
+        "suffix", "array", "of", "and", "in", "数据", "结构", "字符串", "算法", "图", "树", "查找",
+    ];
+    let mut rng = XorShift64::new(seed);
+    let mut corpus = String::with_capacity(size + 16);
+    while corpus.len() < size {
+        corpus.push_str(WORDS[rng.below(WORDS.len() as u64) as usize]);
+        corpus.push(if rng.below(12) == 0 { '\n' } else { ' ' });
+    }
+    corpus
+}
+
+/// 按配置中的顺序测量每个算法
+///
+/// # Panics
+///
+/// 各个算法找到的匹配数不同时 panic，这说明某个实现有错误。
+pub fn run(corpus: &[u8], config: &Config) -> Vec<Timing> {
+    let patterns: Vec<&[u8]> = config.patterns.iter().map(|p| p.as_bytes()).collect();
+    let timings: Vec<Timing> = config
+        .algorithms
+        .iter()
+        .map(|&algorithm| {
+            let mut matches = 0;
+            let median = benchmark::time(config.repeat, || (), |_| matches = algorithm.count_matches(corpus, &patterns));
+            Timing { algorithm, matches, median }
+        })
+        .collect();
+    if let Some(first) = timings.first() {
+        for t in &timings {
+            assert_eq!(t.matches, first.matches, "{} 与 {} 的匹配数不同", t.algorithm.name(), first.algorithm.name());
+        }
+    }
+    timings
+}
+
+/// 打印对比表，最快的一行高亮
+pub fn print_table(corpus_len: usize, config: &Config, timings: &[Timing]) {
+    let header = Style::new().bold().cyan();
+    let fastest = Style::new().green().bold();
+    println!("语料 {} 字节，{} 个模式串: {}", corpus_len, config.patterns.len(), config.patterns.join(" "));
+    println!("{}", header.apply_to(format!("{:<15}{:>10}{:>14}{:>12}", "algorithm", "matches", "median", "MB/s")));
+    let best = timings.iter().map(|t| t.median).min().unwrap_or_default();
+    for t in timings {
+        let throughput = corpus_len as f64 / t.median.as_secs_f64().max(1e-9) / 1e6;
+        let line = format!(
+            "{:<15}{:>10}{:>14}{:>12.1}",
+            t.algorithm.name(),
+            t.matches,
+            benchmark::format_ns(t.median.as_nanos() as f64),
+            throughput
+        );
+        if t.median == best {
+            println!("{}", fastest.apply_to(line));
+        } else {
+            println!("{}", line);
+        }
+    }
+    println!("\n耗时包括预处理，为 {} 次运行的中位数", config.repeat.max(1));
+}
@@ -0,0 +1,132 @@
+//! 编辑距离（Levenshtein 距离）与对齐
+
+use std::fmt::Display;
+
+use console::measure_text_width;
+
+/// 把 a 变成 b 的一步编辑
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op<T> {
+    Keep(T),
+    /// 把 a 中的元素替换成 b 中的元素
+    Substitute(T, T),
+    /// 插入 b 中的元素
+    Insert(T),
+    /// 删除 a 中的元素
+    Delete(T),
+}
+
+/// 编辑距离和一种代价最小的编辑方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment<T> {
+    pub distance: usize,
+    pub ops: Vec<Op<T>>,
+}
+
+/// 把 a 变成 b 最少需要多少次插入、删除、替换，O(nm) 时间，只保存两行，O(min(n, m)) 空间
+pub fn edit_distance<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, x) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            current[j + 1] = (previous[j] + usize::from(x != y)).min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// 计算完整的动态规划表，再从右下角回溯出一种代价最小的编辑方式
+pub fn align<T: PartialEq + Copy>(a: &[T], b: &[T]) -> Alignment<T> {
+    let (n, m) = (a.len(), b.len());
+    // table[i][j]：a[..i] 变成 b[..j] 的编辑距离
+    let mut table = vec![vec![0; m + 1]; n + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let substitute = table[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            table[i][j] = substitute.min(table[i - 1][j] + 1).min(table[i][j - 1] + 1);
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        // 优先沿对角线回溯，相等时保留、不等时替换
+        if i > 0 && j > 0 && table[i][j] == table[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]) {
+            ops.push(if a[i - 1] == b[j - 1] { Op::Keep(a[i - 1]) } else { Op::Substitute(a[i - 1], b[j - 1]) });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && table[i][j] == table[i - 1][j] + 1 {
+            ops.push(Op::Delete(a[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(Op::Insert(b[j - 1]));
+            j -= 1;
+        }
+    }
+    ops.reverse();
+    Alignment { distance: table[n][m], ops }
+}
+
+/// 按字符（而不是字节）计算两个字符串的编辑距离
+pub fn edit_distance_str(a: &str, b: &str) -> usize {
+    edit_distance(&a.chars().collect::<Vec<_>>(), &b.chars().collect::<Vec<_>>())
+}
+
+/// 按字符对齐两个字符串
+pub fn align_str(a: &str, b: &str) -> Alignment<char> {
+    align(&a.chars().collect::<Vec<_>>(), &b.chars().collect::<Vec<_>>())
+}
+
+impl<T: Copy> Alignment<T> {
+    /// 按编辑方式还原出原来的 a 和 b
+    pub fn sides(&self) -> (Vec<T>, Vec<T>) {
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        for &op in &self.ops {
+            match op {
+                Op::Keep(x) => {
+                    a.push(x);
+                    b.push(x);
+                }
+                Op::Substitute(x, y) => {
+                    a.push(x);
+                    b.push(y);
+                }
+                Op::Insert(y) => b.push(y),
+                Op::Delete(x) => a.push(x),
+            }
+        }
+        (a, b)
+    }
+}
+
+impl<T: Display> Alignment<T> {
+    /// 上下对齐的三行：a、每一列的编辑、b，空缺处是 `-`
+    ///
+    /// 编辑的标记：`|` 相同，`*` 替换，`+` 插入，`-` 删除。按显示宽度对齐，中文也能对齐。
+    pub fn render(&self) -> [String; 3] {
+        let mut lines = [String::new(), String::new(), String::new()];
+        for op in &self.ops {
+            let (top, mark, bottom) = match op {
+                Op::Keep(x) => (x.to_string(), "|", x.to_string()),
+                Op::Substitute(x, y) => (x.to_string(), "*", y.to_string()),
+                Op::Insert(y) => ("-".to_string(), "+", y.to_string()),
+                Op::Delete(x) => (x.to_string(), "-", "-".to_string()),
+            };
+            let width = measure_text_width(&top).max(measure_text_width(&bottom));
+            for (line, cell) in lines.iter_mut().zip([top.as_str(), mark, bottom.as_str()]) {
+                line.push_str(cell);
+                line.push_str(&" ".repeat(width - measure_text_width(cell) + 1));
+            }
+        }
+        lines.map(|line| line.trim_end().to_string())
+    }
+}
@@ -0,0 +1,49 @@
+//! Boyer-Moore-Horspool 算法
+
+/// 预处理过的模式串
+#[derive(Debug, Clone)]
+pub struct Horspool {
+    pattern: Vec<u8>,
+    // 窗口最后一个字节是 b 时窗口可以右移的距离
+    shift: [usize; 256],
+}
+
+impl Horspool {
+    /// # Panics
+    ///
+    /// 模式串为空时 panic。
+    pub fn new(pattern: impl AsRef<[u8]>) -> Horspool {
+        let pattern = pattern.as_ref().to_vec();
+        assert!(!pattern.is_empty(), "模式串不能为空");
+        let m = pattern.len();
+        let mut shift = [m; 256];
+        // 最后一个字节不参与，否则它的移动距离会是 0
+        for (i, &b) in pattern[..m - 1].iter().enumerate() {
+            shift[b as usize] = m - 1 - i;
+        }
+        Horspool { pattern, shift }
+    }
+
+    /// 窗口最后一个字节是 b 时右移的距离，b 不在模式串中（最后一个字节除外）时可以移动整个模式串的长度
+    pub fn shift(&self, b: u8) -> usize {
+        self.shift[b as usize]
+    }
+
+    /// 所有出现位置的起始字节下标，允许重叠
+    ///
+    /// 从右向左比较窗口，无论是否匹配都按窗口最后一个字节查表右移。
+    /// 字母表大、模式串长时平均每次跳过接近 m 个字节，最坏情况 O(nm)。
+    pub fn find_all(&self, haystack: impl AsRef<[u8]>) -> Vec<usize> {
+        let (haystack, m) = (haystack.as_ref(), self.pattern.len());
+        let mut found = Vec::new();
+        let mut start = 0;
+        while start + m <= haystack.len() {
+            let window = &haystack[start..start + m];
+            if window.iter().rev().eq(self.pattern.iter().rev()) {
+                found.push(start);
+            }
+            start += self.shift(window[m - 1]);
+        }
+        found
+    }
+}
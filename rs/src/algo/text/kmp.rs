@@ -0,0 +1,56 @@
+//! KMP（Knuth-Morris-Pratt）算法
+
+/// 预处理过的模式串，可以在多个文本中重复查找
+#[derive(Debug, Clone)]
+pub struct Kmp {
+    pattern: Vec<u8>,
+    // failure[i]：pattern[..=i] 最长的相等真前缀和真后缀的长度
+    failure: Vec<usize>,
+}
+
+impl Kmp {
+    /// # Panics
+    ///
+    /// 模式串为空时 panic。
+    pub fn new(pattern: impl AsRef<[u8]>) -> Kmp {
+        let pattern = pattern.as_ref().to_vec();
+        assert!(!pattern.is_empty(), "模式串不能为空");
+        let mut failure = vec![0; pattern.len()];
+        let mut k = 0;
+        for i in 1..pattern.len() {
+            while k > 0 && pattern[i] != pattern[k] {
+                k = failure[k - 1];
+            }
+            if pattern[i] == pattern[k] {
+                k += 1;
+            }
+            failure[i] = k;
+        }
+        Kmp { pattern, failure }
+    }
+
+    /// 部分匹配表，失配时模式串跳到 `failure[已匹配长度 - 1]` 处继续比较
+    pub fn failure(&self) -> &[usize] {
+        &self.failure
+    }
+
+    /// 所有出现位置的起始字节下标，允许重叠，O(n)：文本中的每个字节只看一次，不会回退
+    pub fn find_all(&self, haystack: impl AsRef<[u8]>) -> Vec<usize> {
+        let (pattern, m) = (&self.pattern, self.pattern.len());
+        let mut found = Vec::new();
+        let mut k = 0;
+        for (i, &b) in haystack.as_ref().iter().enumerate() {
+            while k > 0 && b != pattern[k] {
+                k = self.failure[k - 1];
+            }
+            if b == pattern[k] {
+                k += 1;
+            }
+            if k == m {
+                found.push(i + 1 - m);
+                k = self.failure[k - 1];
+            }
+        }
+        found
+    }
+}
@@ -0,0 +1,343 @@
+/*!
+字符串查找与文本算法
+
+| 算法 | 类型 | 预处理 | 查找 |
+|------|------|--------|------|
+| KMP | [`Kmp`] | O(m) | O(n)，最坏情况也是线性 |
+| Boyer-Moore-Horspool | [`Horspool`] | O(m + 256) | 平均亚线性，最坏 O(nm) |
+| Rabin-Karp | [`RabinKarp`] | O(m) | 期望 O(n + m)，滚动哈希 |
+| Aho-Corasick | [`AhoCorasick`] | O(Σm · 256) | 多个模式串一次扫描，O(n + 匹配数) |
+| 后缀数组 + LCP | [`SuffixArray`] | O(n log n) | 每次查询 O(m log n) |
+| 编辑距离 | [`edit_distance`] / [`align`] | | O(nm)，并给出对齐方式 |
+
+查找算法都接受 `impl AsRef<[u8]>`，`&str`、`String`、`&[u8]`、`Vec<u8>` 都可以直接传入，
+返回的位置是字节下标。UTF-8 中一个字符的首字节不会出现在其他字符的中间，所以在 `&str` 中找到的位置一定是字符边界，
+可以像 `basics::compound_types::string_slice_type` 中那样直接用 `&text[start..start + pattern.len()]` 切片。
+编辑距离则对任意 `&[T]` 计算，`_str` 版本按字符（而不是字节）比较。
+
+[`compare`] 在同一份语料上对比各算法的耗时，命令行中运行 `rust_code_examples textbench`。
+
+```
+use rust_code_examples::algo::text::{self, AhoCorasick, Kmp, SuffixArray};
+
+let text = "切片的下标是字节下标";
+let found = Kmp::new("下标").find_all(text);
+assert_eq!(found, [9, 24]);
+assert_eq!(&text[found[1]..found[1] + "下标".len()], "下标");
+assert_eq!(Kmp::new(b"aa").find_all(b"aaaa"), [0, 1, 2]);
+
+let matches = AhoCorasick::new(&["he", "she", "hers"]).find_all("ushers");
+assert_eq!(matches.len(), 3);
+
+let index = SuffixArray::new("banana");
+assert_eq!(index.suffixes(), [5, 3, 1, 0, 4, 2]);
+assert_eq!(index.find_all("ana"), [1, 3]);
+
+assert_eq!(text::edit_distance_str("kitten", "sitting"), 3);
+```
+*/
+
+mod aho_corasick;
+pub mod compare;
+mod edit_distance;
+mod horspool;
+mod kmp;
+mod rabin_karp;
+mod suffix_array;
+
+pub use aho_corasick::{AhoCorasick, Match};
+pub use edit_distance::{align, align_str, edit_distance, edit_distance_str, Alignment, Op};
+pub use horspool::Horspool;
+pub use kmp::Kmp;
+pub use rabin_karp::RabinKarp;
+pub use suffix_array::SuffixArray;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_single_pattern, "单模式查找", "KMP、Horspool、Rabin-Karp 在 &str 和 &[u8] 中查找，结果可以直接用来切片"),
+    example!(ex2_multi_pattern, "多模式查找", "Aho-Corasick 一次扫描找出所有敏感词并打码"),
+    example!(ex3_suffix_array, "后缀数组", "后缀数组和 LCP 数组，用于查找、最长重复子串和统计不同子串"),
+    example!(ex4_edit_distance, "编辑距离", "计算编辑距离并输出对齐方式，中文按字符对齐"),
+    example!(ex5_compare, "对比各个算法", "同一份语料上各算法找到的匹配数一致，耗时用 textbench 对比"),
+];
+
+/// 朴素算法：在每个位置比较整个模式串，O(nm)，作为其他算法的对照
+pub fn naive_find_all(haystack: impl AsRef<[u8]>, pattern: impl AsRef<[u8]>) -> Vec<usize> {
+    let pattern = pattern.as_ref();
+    assert!(!pattern.is_empty(), "模式串不能为空");
+    haystack.as_ref().windows(pattern.len()).enumerate().filter(|(_, w)| *w == pattern).map(|(i, _)| i).collect()
+}
+
+pub fn ex1_single_pattern() {
+    println!("单模式查找 示例代码\n\n");
+
+    let text = "Rust 的字符串切片 &str 是 UTF-8 字节序列的切片，切片的下标也是字节下标";
+    let pattern = "切片";
+    let found = Kmp::new(pattern).find_all(text);
+    assert_eq!(Horspool::new(pattern).find_all(text), found);
+    assert_eq!(RabinKarp::new(pattern).find_all(text), found);
+    // 与 string_slice_type 中一样按字节范围切片，找到的位置一定在字符边界上
+    for &start in &found {
+        println!("字节 {:>2}: {}|{}|{}", start, &text[..start], &text[start..start + pattern.len()], &text[start + pattern.len()..]);
+    }
+    assert_eq!(found.len(), 3);
+
+    // &[u8] 也一样，匹配可以重叠
+    let bytes: &[u8] = &[0, 1, 0, 1, 0, 1, 1];
+    assert_eq!(Kmp::new([0, 1, 0]).find_all(bytes), [0, 2]);
+    assert_eq!(Horspool::new(b"\x01\x01").find_all(bytes), [5]);
+
+    // KMP 的部分匹配表：失配时不回退文本，只把模式串移到最长的相等前后缀处
+    let kmp = Kmp::new("ababaca");
+    println!("\nababaca 的部分匹配表: {:?}", kmp.failure());
+    assert_eq!(kmp.failure(), [0, 0, 1, 2, 3, 0, 1]);
+
+    // Horspool 的移动表：窗口最后一个字节不在模式串中时，整个窗口跳过去
+    let horspool = Horspool::new("example");
+    let shifts: Vec<String> = "exampz".bytes().map(|b| format!("{}:{}", b as char, horspool.shift(b))).collect();
+    println!("example 的移动距离: {}", shifts.join(" "));
+    assert_eq!((horspool.shift(b'e'), horspool.shift(b'l'), horspool.shift(b'z')), (6, 1, 7));
+
+    // Rabin-Karp：模数很小时哈希冲突很多，但逐字节验证保证结果仍然正确
+    let haystack = "abcabdabcxyzabcbca";
+    let exact = RabinKarp::new("abc");
+    let tiny = RabinKarp::with_modulus("abc", 7);
+    assert_eq!(tiny.find_all(haystack), exact.find_all(haystack));
+    println!("\n模数 2^61-1 的冲突次数: {}，模数 7 的冲突次数: {}", exact.spurious_hits(haystack), tiny.spurious_hits(haystack));
+    assert_eq!(exact.spurious_hits(haystack), 0);
+    assert!(tiny.spurious_hits(haystack) > 0);
+}
+
+pub fn ex2_multi_pattern() {
+    println!("多模式查找 示例代码\n\n");
+
+    let patterns = ["he", "she", "his", "hers"];
+    let ac = AhoCorasick::new(&patterns);
+    println!("{} 个模式串，自动机有 {} 个状态", patterns.len(), ac.state_count());
+    for m in ac.find_all("ushers") {
+        println!("  {:<4} 出现在 {}..{}", patterns[m.pattern], m.start, m.end);
+    }
+    let found: Vec<&str> = ac.find_all("ushers").iter().map(|m| patterns[m.pattern]).collect();
+    assert_eq!(found, ["she", "he", "hers"]);
+
+    // 敏感词打码：匹配的字节范围都在字符边界上，按字符替换成 *
+    let words = ["苹果", "苹果手机", "手机", "密码"];
+    let text = "我的苹果手机忘记了密码，只好用苹果电脑找回";
+    let mut masked = vec![false; text.len()];
+    for m in AhoCorasick::new(&words).find_all(text) {
+        masked[m.start..m.end].iter_mut().for_each(|b| *b = true);
+    }
+    let filtered: String = text.char_indices().map(|(i, c)| if masked[i] { '*' } else { c }).collect();
+    println!("\n{}\n{}", text, filtered);
+    assert_eq!(filtered, "我的****忘记了**，只好用**电脑找回");
+}
+
+pub fn ex3_suffix_array() {
+    println!("后缀数组 示例代码\n\n");
+
+    let index = SuffixArray::new("banana");
+    println!("{:>4} {:>5}  后缀", "起点", "LCP");
+    for (&start, &lcp) in index.suffixes().iter().zip(index.lcp()) {
+        println!("{:>6} {:>5}  {}", start, lcp, String::from_utf8_lossy(&index.text()[start..]));
+    }
+    assert_eq!(index.lcp(), [0, 1, 3, 0, 0, 2]);
+    assert_eq!(index.find_all("ana"), [1, 3]);
+    assert_eq!(index.longest_repeat(), Some(1..4));
+    // 6 个后缀共有 21 个前缀，减去相邻后缀重复的 6 个
+    assert_eq!(index.distinct_substrings(), 15);
+
+    let text = "数据结构与算法，算法与数据结构";
+    let index = SuffixArray::new(text);
+    let positions = index.find_all("算法");
+    println!("\n{}\n\"算法\" 出现在字节 {:?}", text, positions);
+    assert_eq!(positions, [15, 24]);
+    let range = index.longest_repeat().unwrap();
+    println!("最长的重复子串: {}", &text[range.clone()]);
+    assert_eq!(&text[range], "数据结构");
+}
+
+pub fn ex4_edit_distance() {
+    println!("编辑距离 示例代码\n\n");
+
+    let pairs = [("kitten", "sitting"), ("数据结构与算法", "数据结构和算法分析"), ("intention", "execution")];
+    for (a, b) in pairs {
+        let alignment = align_str(a, b);
+        println!("{} -> {}: {}", a, b, alignment.distance);
+        for line in alignment.render() {
+            println!("    {}", line);
+        }
+        assert_eq!(alignment.distance, edit_distance_str(a, b));
+    }
+    assert_eq!(edit_distance_str("kitten", "sitting"), 3);
+    assert_eq!(edit_distance_str("数据结构与算法", "数据结构和算法分析"), 3);
+    assert_eq!(edit_distance_str("intention", "execution"), 5);
+
+    // 对任意 &[T] 都可以计算，&str 按字节比较时一个汉字要改 3 个字节
+    assert_eq!(edit_distance("与".as_bytes(), "和".as_bytes()), 3);
+    assert_eq!(edit_distance(&[1, 2, 3, 4], &[2, 3, 4, 5]), 2);
+}
+
+pub fn ex5_compare() {
+    println!("对比各个算法 示例代码\n\n");
+
+    let config = compare::Config::default();
+    let corpus = compare::sample_corpus(50_000, config.seed);
+    let patterns: Vec<&[u8]> = config.patterns.iter().map(|p| p.as_bytes()).collect();
+    println!("语料 {} 字节，模式串: {}", corpus.len(), config.patterns.join(" "));
+    let counts: Vec<usize> = compare::Algorithm::ALL
+        .iter()
+        .map(|&algorithm| {
+            let matches = algorithm.count_matches(corpus.as_bytes(), &patterns);
+            println!("{:<15} {} 个匹配", algorithm.name(), matches);
+            matches
+        })
+        .collect();
+    assert!(counts.iter().all(|&c| c == counts[0]));
+    println!("\n运行 rust_code_examples textbench 可以对比耗时，建议用 --release 构建");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 文本和模式串取自很小的字母表，匹配和重叠都很常见；Rabin-Karp 还用很小的模数制造哈希冲突。
+    // 另外检查后缀数组与直接排序、LCP 与逐个比较的结果相同，编辑距离的对齐方式能还原出两个字符串。
+    #[test]
+    fn matches_naive_search() {
+        let mut rng = XorShift64::new(23);
+        for round in 0..1000 {
+            let alphabet = 1 + rng.below(4) as u8;
+            let len = rng.below(60) as usize;
+            let mut random_bytes = |len: usize| -> Vec<u8> { (0..len).map(|_| b'a' + rng.below(alphabet as u64) as u8).collect() };
+            let text = random_bytes(len);
+            let patterns: Vec<Vec<u8>> = (0..1 + round % 4).map(|i| random_bytes(1 + (round + i) % 4)).collect();
+            let context = format!("第 {} 轮 文本 {:?} 模式串 {:?}", round, String::from_utf8_lossy(&text), patterns);
+
+            let index = SuffixArray::new(&text);
+            for pattern in &patterns {
+                let expected = naive_find_all(&text, pattern);
+                assert_eq!(Kmp::new(pattern).find_all(&text), expected, "KMP {}", context);
+                assert_eq!(Horspool::new(pattern).find_all(&text), expected, "Horspool {}", context);
+                assert_eq!(RabinKarp::new(pattern).find_all(&text), expected, "Rabin-Karp {}", context);
+                assert_eq!(RabinKarp::with_modulus(pattern, 3).find_all(&text), expected, "Rabin-Karp 模数 3 {}", context);
+                assert_eq!(index.find_all(pattern), expected, "后缀数组 {}", context);
+            }
+
+            let mut expected: Vec<(usize, usize)> = patterns
+                .iter()
+                .enumerate()
+                .flat_map(|(id, p)| naive_find_all(&text, p).into_iter().map(move |start| (start, id)))
+                .collect();
+            expected.sort_unstable();
+            let matches = AhoCorasick::new(&patterns).find_all(&text);
+            assert!(matches.windows(2).all(|w| w[0].end <= w[1].end), "Aho-Corasick 顺序 {}", context);
+            let mut actual: Vec<(usize, usize)> = matches.iter().map(|m| (m.start, m.pattern)).collect();
+            actual.sort_unstable();
+            assert_eq!(actual, expected, "Aho-Corasick {}", context);
+
+            let mut suffixes: Vec<usize> = (0..text.len()).collect();
+            suffixes.sort_by_key(|&i| &text[i..]);
+            assert_eq!(index.suffixes(), suffixes, "后缀数组 {}", context);
+            let lcp: Vec<usize> = (0..text.len())
+                .map(|i| match i {
+                    0 => 0,
+                    _ => text[suffixes[i - 1]..].iter().zip(&text[suffixes[i]..]).take_while(|(a, b)| a == b).count(),
+                })
+                .collect();
+            assert_eq!(index.lcp(), lcp, "LCP {}", context);
+            let substrings: HashSet<&[u8]> = (0..text.len()).flat_map(|i| (i + 1..=text.len()).map(move |j| (i, j))).map(|(i, j)| &text[i..j]).collect();
+            assert_eq!(index.distinct_substrings(), substrings.len(), "不同子串 {}", context);
+            if let Some(range) = index.longest_repeat() {
+                assert!(naive_find_all(&text, &text[range.clone()]).len() >= 2, "最长重复子串 {}", context);
+                assert!(lcp.iter().all(|&l| l <= range.len()), "最长重复子串 {}", context);
+            }
+
+            let (a, b) = (&text[..text.len() / 2], &patterns[0][..]);
+            let alignment = align(a, b);
+            assert_eq!(alignment.distance, edit_distance(a, b), "编辑距离 {}", context);
+            assert_eq!(alignment.distance, edit_distance(b, a), "编辑距离不对称 {}", context);
+            assert_eq!(alignment.sides(), (a.to_vec(), b.to_vec()), "对齐 {}", context);
+            let edits = alignment.ops.iter().filter(|op| !matches!(op, Op::Keep(_))).count();
+            assert_eq!(edits, alignment.distance, "对齐的代价 {}", context);
+            assert!(a.len().abs_diff(b.len()) <= alignment.distance && alignment.distance <= a.len().max(b.len()), "{}", context);
+        }
+
+        // 多字节字符：找到的位置都在字符边界上，切出来的正是模式串
+        let chars = ['a', 'é', '中', '文', '😀'];
+        for round in 0..1000 {
+            let len = rng.below(30) as usize;
+            let mut random_string = |len: usize| -> String { (0..len).map(|_| chars[rng.below(chars.len() as u64) as usize]).collect() };
+            let text = random_string(len);
+            let pattern = random_string(1 + round % 3);
+            let found = Kmp::new(&pattern).find_all(&text);
+            assert_eq!(found, naive_find_all(&text, &pattern), "{:?} 中查找 {:?}", text, pattern);
+            for start in found {
+                assert_eq!(text.get(start..start + pattern.len()), Some(pattern.as_str()), "{:?} 中查找 {:?}", text, pattern);
+            }
+            assert_eq!(edit_distance_str(&text, &pattern), align_str(&text, &pattern).distance, "{:?} {:?}", text, pattern);
+        }
+    }
+
+    // 各个单模式算法找到的位置
+    fn find_with_all(text: &str, pattern: &str) -> [Vec<usize>; 4] {
+        [
+            Kmp::new(pattern).find_all(text),
+            Horspool::new(pattern).find_all(text),
+            RabinKarp::new(pattern).find_all(text),
+            SuffixArray::new(text).find_all(pattern),
+        ]
+    }
+
+    #[test]
+    fn empty_text_and_pattern_longer_than_text() {
+        for (text, pattern) in [("", "a"), ("ab", "abc"), ("中", "中文")] {
+            assert!(find_with_all(text, pattern).iter().all(Vec::is_empty), "{:?} 中查找 {:?}", text, pattern);
+        }
+        assert!(AhoCorasick::new(&["a", "abc"]).find_all("").is_empty());
+        assert!(AhoCorasick::new::<&str>(&[]).find_all("abc").is_empty());
+
+        let index = SuffixArray::new("");
+        assert!(index.suffixes().is_empty() && index.lcp().is_empty());
+        assert_eq!((index.distinct_substrings(), index.longest_repeat()), (0, None));
+        assert_eq!((edit_distance_str("", ""), edit_distance_str("", "中文")), (0, 2));
+        assert!(align_str("", "").ops.is_empty());
+    }
+
+    #[test]
+    fn single_characters() {
+        assert!(find_with_all("a", "a").iter().all(|found| *found == [0]));
+        assert!(find_with_all("a", "b").iter().all(Vec::is_empty));
+        let index = SuffixArray::new("a");
+        assert_eq!((index.suffixes(), index.distinct_substrings(), index.longest_repeat()), (&[0][..], 1, None));
+        assert_eq!((edit_distance_str("a", "a"), edit_distance_str("a", "b")), (0, 1));
+    }
+
+    #[test]
+    fn overlapping_matches() {
+        assert!(find_with_all("aaaa", "aa").iter().all(|found| *found == [0, 1, 2]));
+        assert!(find_with_all("abababa", "aba").iter().all(|found| *found == [0, 2, 4]));
+        assert!(find_with_all("abc", "abc").iter().all(|found| *found == [0]));
+        // 一个模式串是另一个的后缀，沿输出链接也要报告出来
+        let matches = AhoCorasick::new(&["aa", "a"]).find_all("aaa");
+        let found: Vec<(usize, usize)> = matches.iter().map(|m| (m.start, m.pattern)).collect();
+        assert_eq!(found, [(0, 1), (0, 0), (1, 1), (1, 0), (2, 1)]);
+        assert_eq!(SuffixArray::new("aaaa").longest_repeat().map(|r| r.len()), Some(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "模式串不能为空")]
+    fn empty_pattern_panics() {
+        Horspool::new("");
+    }
+
+    #[test]
+    #[should_panic(expected = "第 1 个模式串为空")]
+    fn empty_pattern_in_aho_corasick_panics() {
+        AhoCorasick::new(&["a", ""]);
+    }
+}
@@ -0,0 +1,101 @@
+//! Rabin-Karp 算法
+
+// 2^61 - 1 是梅森素数，取模可以用移位和加法完成
+const MERSENNE_61: u64 = (1 << 61) - 1;
+const BASE: u64 = 256;
+
+/// 预处理过的模式串
+#[derive(Debug, Clone)]
+pub struct RabinKarp {
+    pattern: Vec<u8>,
+    modulus: u64,
+    hash: u64,
+    // BASE^(m-1)，滚动时移出窗口最左边的字节要用到
+    high: u64,
+}
+
+impl RabinKarp {
+    /// 模数为 2^61 - 1，哈希冲突的概率可以忽略
+    pub fn new(pattern: impl AsRef<[u8]>) -> RabinKarp {
+        RabinKarp::with_modulus(pattern, MERSENNE_61)
+    }
+
+    /// 指定模数，用很小的模数可以观察到哈希冲突；冲突的窗口会被逐字节的比较排除，结果仍然正确
+    ///
+    /// # Panics
+    ///
+    /// 模式串为空或者模数不在 `2..=2^61 - 1` 中时 panic。
+    pub fn with_modulus(pattern: impl AsRef<[u8]>, modulus: u64) -> RabinKarp {
+        let pattern = pattern.as_ref().to_vec();
+        assert!(!pattern.is_empty(), "模式串不能为空");
+        assert!((2..=MERSENNE_61).contains(&modulus), "模数需要在 2 到 2^61 - 1 之间");
+        let mut rk = RabinKarp { pattern: Vec::new(), modulus, hash: 0, high: 1 };
+        rk.hash = rk.hash_of(&pattern);
+        for _ in 1..pattern.len() {
+            rk.high = rk.mul(rk.high, BASE);
+        }
+        rk.pattern = pattern;
+        rk
+    }
+
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        let product = a as u128 * b as u128;
+        if self.modulus != MERSENNE_61 {
+            return (product % self.modulus as u128) as u64;
+        }
+        // 2^61 ≡ 1，高位部分直接加到低 61 位上
+        self.reduce((product as u64 & MERSENNE_61) + (product >> 61) as u64)
+    }
+
+    fn hash_of(&self, bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0, |h, &b| self.push(h, b))
+    }
+
+    // 哈希值右边添上一个字节；两项都小于模数时和小于两倍模数，减一次就够了
+    fn push(&self, hash: u64, b: u8) -> u64 {
+        let b = b as u64;
+        self.reduce(self.mul(hash, BASE) + if b < self.modulus { b } else { b % self.modulus })
+    }
+
+    fn reduce(&self, x: u64) -> u64 {
+        if x >= self.modulus { x - self.modulus } else { x }
+    }
+
+    // 对每个哈希值相同的窗口调用 visit(起点, 是否真的相等)
+    fn scan(&self, haystack: &[u8], mut visit: impl FnMut(usize, bool)) {
+        let m = self.pattern.len();
+        if haystack.len() < m {
+            return;
+        }
+        let mut hash = self.hash_of(&haystack[..m]);
+        for start in 0..=haystack.len() - m {
+            if start > 0 {
+                // 移出 haystack[start - 1]，移入 haystack[start + m - 1]
+                let out = self.mul(haystack[start - 1] as u64, self.high);
+                hash = self.reduce(hash + self.modulus - out);
+                hash = self.push(hash, haystack[start + m - 1]);
+            }
+            if hash == self.hash {
+                visit(start, haystack[start..start + m] == self.pattern[..]);
+            }
+        }
+    }
+
+    /// 所有出现位置的起始字节下标，允许重叠，期望 O(n + m)
+    pub fn find_all(&self, haystack: impl AsRef<[u8]>) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.scan(haystack.as_ref(), |start, equal| {
+            if equal {
+                found.push(start);
+            }
+        });
+        found
+    }
+
+    /// 哈希值相同、内容却不同的窗口个数
+    pub fn spurious_hits(&self, haystack: impl AsRef<[u8]>) -> usize {
+        let mut count = 0;
+        self.scan(haystack.as_ref(), |_, equal| count += usize::from(!equal));
+        count
+    }
+}
@@ -0,0 +1,114 @@
+//! 后缀数组与最长公共前缀（LCP）数组
+
+use std::ops::Range;
+
+use crate::algo::sort;
+
+/// 文本所有后缀按字典序排列后的起始位置
+#[derive(Debug, Clone)]
+pub struct SuffixArray {
+    text: Vec<u8>,
+    suffixes: Vec<usize>,
+    // lcp[i]：排序后第 i 个和第 i - 1 个后缀的最长公共前缀长度，lcp[0] = 0
+    lcp: Vec<usize>,
+}
+
+impl SuffixArray {
+    /// 倍增法构建，O(n log n)
+    ///
+    /// 第 k 轮按每个后缀前 2^k 个字节排名：前 2^(k+1) 个字节的排名由 (前半段的排名, 后半段的排名) 决定，
+    /// 两个排名都不超过 max(n, 256)，拼成一个 u64 后用基数排序，每一轮 O(n)。
+    pub fn new(text: impl AsRef<[u8]>) -> SuffixArray {
+        let text = text.as_ref().to_vec();
+        let n = text.len();
+        let mut suffixes: Vec<usize> = (0..n).collect();
+        // 排名从 1 开始，0 留给越过文本末尾的空后缀
+        let mut rank: Vec<u64> = text.iter().map(|&b| b as u64 + 1).collect();
+        let base = n.max(256) as u64 + 1;
+        let mut width = 1;
+        while width < n {
+            // (前半段的排名, 后半段的排名) 拼成 rank[i] * base + rank[i + width]，先算好再排序
+            let key = |i: usize| rank[i] * base + rank.get(i + width).copied().unwrap_or(0);
+            let mut keyed: Vec<(u64, usize)> = suffixes.iter().map(|&i| (key(i), i)).collect();
+            sort::radix_sort_by_key(&mut keyed, |&(k, _)| k);
+            let mut next = vec![0; n];
+            next[keyed[0].1] = 1;
+            for w in keyed.windows(2) {
+                next[w[1].1] = next[w[0].1] + u64::from(w[0].0 != w[1].0);
+            }
+            suffixes = keyed.into_iter().map(|(_, i)| i).collect();
+            rank = next;
+            // 排名两两不同时顺序已经确定
+            if rank[suffixes[n - 1]] == n as u64 {
+                break;
+            }
+            width *= 2;
+        }
+        let lcp = kasai(&text, &suffixes);
+        SuffixArray { text, suffixes, lcp }
+    }
+
+    pub fn text(&self) -> &[u8] {
+        &self.text
+    }
+
+    pub fn suffixes(&self) -> &[usize] {
+        &self.suffixes
+    }
+
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// pattern 的所有出现位置，按位置排序，O(m log n + 出现次数)
+    ///
+    /// 以 pattern 开头的后缀在后缀数组中是连续的一段，两次二分查找得到它的两端。
+    pub fn find_all(&self, pattern: impl AsRef<[u8]>) -> Vec<usize> {
+        let pattern = pattern.as_ref();
+        assert!(!pattern.is_empty(), "模式串不能为空");
+        let prefix = |start: usize| &self.text[start..(start + pattern.len()).min(self.text.len())];
+        let lo = self.suffixes.partition_point(|&s| prefix(s) < pattern);
+        let hi = self.suffixes.partition_point(|&s| prefix(s) <= pattern);
+        let mut found = self.suffixes[lo..hi].to_vec();
+        found.sort_unstable();
+        found
+    }
+
+    /// 出现至少两次的最长子串的字节范围，没有重复的字节时返回 None
+    ///
+    /// 按字节比较，UTF-8 文本中的结果可能在一个字符的中间结束。
+    pub fn longest_repeat(&self) -> Option<Range<usize>> {
+        let (i, &len) = self.lcp.iter().enumerate().max_by_key(|&(i, &len)| (len, std::cmp::Reverse(i)))?;
+        (len > 0).then(|| self.suffixes[i]..self.suffixes[i] + len)
+    }
+
+    /// 不同的非空子串个数：所有后缀的前缀个数之和，减去相邻后缀重复计算的公共前缀
+    pub fn distinct_substrings(&self) -> usize {
+        let n = self.text.len();
+        n * (n + 1) / 2 - self.lcp.iter().sum::<usize>()
+    }
+}
+
+// Kasai 算法，O(n)：按原文顺序处理后缀，后一个后缀的 LCP 至少是前一个的减一
+fn kasai(text: &[u8], suffixes: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut position = vec![0; n];
+    for (i, &s) in suffixes.iter().enumerate() {
+        position[s] = i;
+    }
+    let mut lcp = vec![0; n];
+    let mut k: usize = 0;
+    for start in 0..n {
+        if position[start] == 0 {
+            k = 0;
+            continue;
+        }
+        let previous = suffixes[position[start] - 1];
+        while start + k < n && previous + k < n && text[start + k] == text[previous + k] {
+            k += 1;
+        }
+        lcp[position[start]] = k;
+        k = k.saturating_sub(1);
+    }
+    lcp
+}
@@ -1,3 +1,14 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(char_type, "字符类型", "char 是 4 字节的 Unicode 标量值"),
+    example!(bool_type, "布尔类型", "bool 的声明与类型标注"),
+    example!(functions_type, "函数", "函数命名、参数类型标注与发散函数"),
+    example!(unit_type, "单元类型", "单元类型 ()"),
+    example!(statements_expressions_type => || { statements_expressions_type(); }, "语句与表达式", "语句不返回值，表达式返回值"),
+];
+
 
 
 pub fn char_type() {
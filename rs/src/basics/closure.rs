@@ -0,0 +1,179 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_closure_syntax, "闭包语法", "闭包的几种写法与类型推断"),
+    example!(ex2_capture_by_ref, "按引用捕获", "闭包默认以不可变引用或可变引用捕获环境中的变量"),
+    example!(ex3_capture_by_move, "move 捕获", "move 关键字强制闭包取得变量的所有权"),
+    example!(ex4_fn_traits, "Fn、FnMut、FnOnce", "闭包实现哪个特征取决于它如何使用捕获的变量"),
+    example!(ex5_return_closure, "返回闭包", "impl Fn 与 Box<dyn Fn> 两种返回方式"),
+];
+
+pub fn ex1_closure_syntax() {
+    println!("闭包语法 示例代码\n\n");
+
+    // 函数与闭包的写法对比，闭包的参数和返回值类型通常可以省略，由编译器推断
+    fn add_one_v1(x: u32) -> u32 {
+        x + 1
+    }
+    let add_one_v2 = |x: u32| -> u32 { x + 1 };
+    let add_one_v3 = |x| x + 1;
+
+    assert_eq!(add_one_v1(1), 2);
+    assert_eq!(add_one_v2(1), 2);
+    // add_one_v3 第一次被调用时，参数类型被推断为 u32，之后就固定下来
+    assert_eq!(add_one_v3(1u32), 2);
+    // 再用 String 调用会编译失败：add_one_v3(String::from("1"));
+    println!("add_one_v1(1) = {}, add_one_v2(1) = {}, add_one_v3(1) = {}", add_one_v1(1), add_one_v2(1), add_one_v3(1));
+
+    // 闭包可以没有参数，也可以是多行的代码块
+    let greeting = || {
+        let name = "Rust";
+        format!("hello, {}", name)
+    };
+    assert_eq!(greeting(), "hello, Rust");
+    println!("{}", greeting());
+}
+
+pub fn ex2_capture_by_ref() {
+    println!("按引用捕获 示例代码\n\n");
+
+    // 只读取 list，闭包以不可变引用捕获，闭包存在期间仍然可以读取 list
+    let list = vec![1, 2, 3];
+    let only_borrows = || println!("闭包中读取: {:?}", list);
+    println!("定义闭包之后读取: {:?}", list);
+    only_borrows();
+    assert_eq!(list, [1, 2, 3]);
+
+    // 修改 list，闭包以可变引用捕获，闭包最后一次使用之前不能再借用 list
+    let mut list = vec![1, 2, 3];
+    let mut borrows_mutably = || list.push(4);
+    // println!("{:?}", list); // 这里会报错：list 已经被闭包可变借用
+    borrows_mutably();
+    borrows_mutably();
+    assert_eq!(list, [1, 2, 3, 4, 4]);
+    println!("可变引用捕获之后: {:?}", list);
+
+    // 捕获的是变量本身，闭包看到的是调用时的值
+    let mut counter = 0;
+    let mut increase = || {
+        counter += 1;
+        counter
+    };
+    assert_eq!(increase(), 1);
+    assert_eq!(increase(), 2);
+    assert_eq!(counter, 2);
+    println!("counter = {}", counter);
+}
+
+pub fn ex3_capture_by_move() {
+    println!("move 捕获 示例代码\n\n");
+
+    // move 把 data 的所有权转移到闭包中，新线程可能比当前函数活得更久，所以必须 move
+    let data: Vec<i32> = (1..=3).collect();
+    let handle = std::thread::spawn(move || data.iter().sum::<i32>());
+    // println!("{:?}", data); // 这里会报错：data 已经移动到闭包中
+    let sum = handle.join().unwrap();
+    assert_eq!(sum, 6);
+    println!("在新线程中求和: {}", sum);
+
+    // 对实现了 Copy 的类型，move 捕获的是一份拷贝，原变量仍然可用
+    let mut x = 10;
+    let mut add_to_copy = move |n: i32| {
+        x += n;
+        x
+    };
+    assert_eq!(add_to_copy(5), 15);
+    assert_eq!(add_to_copy(5), 20);
+    // 闭包修改的是自己的拷贝
+    assert_eq!(x, 10);
+    println!("闭包内部的 x 为 20，外部的 x 仍然是 {}", x);
+
+    // move 闭包可以作为值返回或保存，不再依赖原来的作用域
+    let name = String::from("闭包");
+    let owns_name = move || name.len();
+    // name 已经移动，但闭包仍然可以使用它
+    assert_eq!(owns_name(), "闭包".len());
+    println!("\"闭包\" 的字节长度为 {}", owns_name());
+}
+
+// 只能调用一次，参数实现 FnOnce 即可，三种闭包都可以传入
+fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+// 需要多次调用并且允许修改捕获的变量
+fn call_twice<F: FnMut() -> i32>(mut f: F) -> i32 {
+    f() + f()
+}
+
+// 多次调用且不修改任何东西
+fn call_with<F: Fn(i32) -> i32>(f: F, value: i32) -> i32 {
+    f(value)
+}
+
+pub fn ex4_fn_traits() {
+    println!("Fn、FnMut、FnOnce 示例代码\n\n");
+    println!("FnOnce: 闭包把捕获的值移出，只能调用一次");
+    println!("FnMut : 闭包修改捕获的值，可以调用多次");
+    println!("Fn    : 闭包只读取捕获的值，或者不捕获任何值\n");
+
+    // 把 s 移出闭包，只实现了 FnOnce
+    let s = String::from("moved out");
+    let consume = move || s;
+    assert_eq!(call_once(consume), "moved out");
+    // call_once(consume); // 这里会报错：consume 已经被移动
+
+    // 修改 count，实现了 FnMut（也就实现了 FnOnce）
+    let mut count = 0;
+    let result = call_twice(|| {
+        count += 1;
+        count
+    });
+    assert_eq!(result, 3);
+    assert_eq!(count, 2);
+    println!("call_twice 返回 {}，count = {}", result, count);
+
+    // 只读取 factor，实现了 Fn（也就实现了 FnMut 和 FnOnce）
+    let factor = 3;
+    let triple = |x| x * factor;
+    assert_eq!(call_with(triple, 7), 21);
+    assert_eq!(call_twice(|| triple(1)), 6);
+    println!("call_with(triple, 7) = {}", call_with(triple, 7));
+
+    // 不捕获任何变量的闭包还可以转换为函数指针
+    let square: fn(i32) -> i32 = |x| x * x;
+    assert_eq!(call_with(square, 4), 16);
+    println!("不捕获变量的闭包可以转换为 fn 指针: square(4) = {}", square(4));
+}
+
+// 返回具体的闭包类型，没有堆分配
+fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+type BinOp = Box<dyn Fn(i32, i32) -> i32>;
+
+// 不同分支返回不同的闭包，类型不同，需要放到 Box 里
+fn make_op(op: char) -> BinOp {
+    match op {
+        '+' => Box::new(|a, b| a + b),
+        '*' => Box::new(|a, b| a * b),
+        _ => Box::new(|a, _| a),
+    }
+}
+
+pub fn ex5_return_closure() {
+    println!("返回闭包 示例代码\n\n");
+
+    let add_five = make_adder(5);
+    assert_eq!(add_five(10), 15);
+    println!("make_adder(5)(10) = {}", add_five(10));
+
+    let ops: Vec<(char, BinOp)> = "+*?".chars().map(|c| (c, make_op(c))).collect();
+    let results: Vec<i32> = ops.iter().map(|(_, op)| op(6, 7)).collect();
+    assert_eq!(results, [13, 42, 6]);
+    for (c, op) in &ops {
+        println!("6 {} 7 = {}", c, op(6, 7));
+    }
+}
@@ -1,5 +1,15 @@
 /*! 这里是包或者模块注释 */
 
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(comment_line, "行注释", "// 与 /* */ 注释"),
+    example!(comment_doc, "文档注释", "///、/** */ 与 /*! */ 文档注释"),
+    example!(doc_test => || { doc_test(1); }, "文档测试", "文档注释中的 # Examples 会作为测试运行"),
+    example!(panic_test => || { panic_test(10, 0); }, "文档测试 should_panic", "# Panics 小节与 should_panic 文档测试，除数为 0 时 panic").should_panic(),
+];
+
 
 /** `add_two` 将指定值加2
 let arg = 5;
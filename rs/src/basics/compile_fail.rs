@@ -0,0 +1,190 @@
+/*!
+借用检查器的编译失败示例
+
+`variables::ownership_borrow` 和 `compound_types::struct_type` 中注释掉的代码放在 `compile_fail/` 目录下，
+每个文件都是一段应当编译失败的代码，并记录了预期的 rustc 错误码。
+
+- 每个用例都生成一个 ` ```compile_fail ` 文档测试，`cargo test` 会确认它确实无法编译
+- 稳定版 rustdoc 不检查错误码，所以 [`CASES`] 上的文档测试会调用 rustc 确认错误码一致
+- 作为示例运行时，会打印说明、源码、rustc 报出的错误码和完整的诊断信息；诊断信息随 rustc 版本变化，升级工具链后需要 `verify --bless`
+*/
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use crate::example;
+use crate::registry::Example;
+use crate::utils::TempDir;
+
+/// 一个应当编译失败的用例
+#[derive(Debug)]
+pub struct Case {
+    pub id: &'static str,
+    /// 用例文件，相对于 crate 根目录
+    pub file: &'static str,
+    /// 预期的 rustc 错误码，例如 `E0499`
+    pub error_code: &'static str,
+    pub explanation: &'static str,
+    pub source: &'static str,
+}
+
+/// rustc 对一个用例给出的诊断信息
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub compiled: bool,
+    pub stderr: String,
+    /// 诊断中出现的所有错误码，按出现顺序去重
+    pub codes: Vec<String>,
+}
+
+impl Case {
+    /// 调用 rustc（优先使用环境变量 `RUSTC`）把源码当作库编译，只生成元数据
+    pub fn diagnose(&self) -> io::Result<Diagnostic> {
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let dir = TempDir::new(&format!("compile_fail_{}", self.id))?;
+        let output_path = dir.path().join("out.rmeta");
+        let mut child = Command::new(rustc)
+            .args(["--edition=2021", "--crate-type=lib", "--crate-name=compile_fail_case"])
+            .args(["--emit=metadata", "--error-format=human", "--color=never", "--cap-lints=allow"])
+            .arg("-o")
+            .arg(&output_path)
+            .arg("-")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child.stdin.take().expect("stdin 已设置为 piped").write_all(self.source.as_bytes())?;
+        let output = child.wait_with_output()?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr).replace("\r\n", "\n");
+        let mut codes: Vec<String> = Vec::new();
+        for (start, _) in stderr.match_indices("error[E") {
+            let code = &stderr[start + "error[".len()..];
+            let code = &code[..code.find(']').unwrap_or(code.len())];
+            if !codes.iter().any(|c| c == code) {
+                codes.push(code.to_string());
+            }
+        }
+        Ok(Diagnostic { compiled: output.status.success(), stderr, codes })
+    }
+
+    /// 确认用例无法编译，并且报出预期的错误码
+    pub fn check(&self) -> Result<Diagnostic, String> {
+        let diagnostic = self.diagnose().map_err(|e| format!("{}: 无法运行 rustc: {}", self.id, e))?;
+        if diagnostic.compiled {
+            return Err(format!("{}: 预期编译失败，但编译通过了", self.id));
+        }
+        if !diagnostic.codes.iter().any(|c| c == self.error_code) {
+            return Err(format!("{}: 预期错误码 {}，实际为 {:?}", self.id, self.error_code, diagnostic.codes));
+        }
+        Ok(diagnostic)
+    }
+}
+
+// 为每个用例生成常量、` ```compile_fail ` 文档测试，以及打印说明和诊断信息的示例函数
+macro_rules! compile_fail_case {
+    ($name:ident, $func:ident, $file:literal, $code:literal, $explanation:literal) => {
+        #[doc = $explanation]
+        #[doc = ""]
+        #[doc = concat!("```compile_fail,", $code)]
+        #[doc = include_str!(concat!("../../compile_fail/", $file))]
+        #[doc = "```"]
+        pub const $name: Case = Case {
+            id: stringify!($func),
+            file: concat!("compile_fail/", $file),
+            error_code: $code,
+            explanation: $explanation,
+            source: include_str!(concat!("../../compile_fail/", $file)),
+        };
+
+        pub fn $func() {
+            show(&$name);
+        }
+    };
+}
+
+compile_fail_case!(
+    TWO_MUTABLE_BORROWS,
+    two_mutable_borrows,
+    "e0499_two_mutable_borrows.rs",
+    "E0499",
+    "同一作用域，特定数据只能有一个可变引用：r1 还在使用时不能再创建 r2"
+);
+compile_fail_case!(
+    SHARED_AND_MUTABLE_BORROW,
+    shared_and_mutable_borrow,
+    "e0502_shared_and_mutable_borrow.rs",
+    "E0502",
+    "可变引用与不可变引用不能同时存在：r1、r2 还在使用时不能创建可变引用 r3"
+);
+compile_fail_case!(
+    USE_AFTER_MOVE,
+    use_after_move,
+    "e0382_use_after_move.rs",
+    "E0382",
+    "`let s2 = s1` 把 String 的所有权移动给了 s2，之后不能再使用 s1"
+);
+compile_fail_case!(
+    STRUCT_MISSING_LIFETIME,
+    struct_missing_lifetime,
+    "e0106_struct_missing_lifetime.rs",
+    "E0106",
+    "结构体从别处借用数据时，`&str` 字段需要生命周期标识符"
+);
+
+/// 所有编译失败用例
+///
+/// 下面的文档测试调用 rustc 检查每个用例报出的错误码：
+///
+/// ```
+/// use rust_code_examples::basics::compile_fail::CASES;
+///
+/// for case in CASES {
+///     if let Err(message) = case.check() {
+///         panic!("{}", message);
+///     }
+/// }
+/// ```
+pub const CASES: &[Case] = &[TWO_MUTABLE_BORROWS, SHARED_AND_MUTABLE_BORROW, USE_AFTER_MOVE, STRUCT_MISSING_LIFETIME];
+
+// 展示源码时展示用例文件，而不是生成示例函数的宏
+pub const EXAMPLES: &[Example] = &[
+    example!(two_mutable_borrows, "E0499 两个可变引用", "同时持有两个 &mut s")
+        .with_source(TWO_MUTABLE_BORROWS.file, TWO_MUTABLE_BORROWS.source),
+    example!(shared_and_mutable_borrow, "E0502 可变与不可变引用共存", "&s 与 &mut s 混用")
+        .with_source(SHARED_AND_MUTABLE_BORROW.file, SHARED_AND_MUTABLE_BORROW.source),
+    example!(use_after_move, "E0382 使用已移动的值", "s1 移动给 s2 之后继续使用 s1")
+        .with_source(USE_AFTER_MOVE.file, USE_AFTER_MOVE.source),
+    example!(struct_missing_lifetime, "E0106 缺少生命周期", "User 结构体的 &str 字段没有生命周期")
+        .with_source(STRUCT_MISSING_LIFETIME.file, STRUCT_MISSING_LIFETIME.source),
+];
+
+// 打印说明、源码和 rustc 的诊断信息，错误码不符时 panic
+fn show(case: &Case) {
+    println!("{} [{}]\n", case.explanation, case.error_code);
+    println!("{}", case.source);
+    match case.check() {
+        Ok(diagnostic) => {
+            println!("rustc 报出了预期的错误 {}\n", case.error_code);
+            println!("{}", diagnostic.stderr);
+        }
+        Err(message) => panic!("{}", message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn cases_can_be_checked_concurrently() {
+        thread::scope(|scope| {
+            let handles: Vec<_> = CASES.iter().chain(CASES).map(|case| scope.spawn(move || case.check())).collect();
+            for handle in handles {
+                handle.join().unwrap().unwrap();
+            }
+        });
+    }
+}
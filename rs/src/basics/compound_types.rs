@@ -1,3 +1,14 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(array_type, "数组", "定长数组、数组切片与越界访问"),
+    example!(enum_type, "枚举", "枚举、携带数据的枚举成员与 Option"),
+    example!(string_slice_type, "字符串与切片", "String、&str、UTF-8 切片与字符串操作"),
+    example!(struct_type, "结构体", "结构体、元组结构体、单元结构体与更新语法"),
+    example!(tuple_type, "元组", "元组的创建、解构与索引访问"),
+];
+
 
 
 pub fn string_slice_type() {
@@ -285,7 +296,7 @@ pub fn struct_type() {
 
     // 在之前的 `User` 结构体的定义中，有一处细节：我们使用了自身拥有所有权的 `String` 类型而不是基于引用的 `&str` 字符串切片类型。
     // 这是一个有意而为之的选择：因为我们想要这个结构体拥有它所有的数据，而不是从其它地方借用数据。
-    // 也可以让 `User` 结构体从其它对象借用数据，不过这么做，就需要引入生命周期(lifetimes)这个新概念,下面的例子编译器会抱怨它需要生命周期标识符
+    // 也可以让 `User` 结构体从其它对象借用数据，不过这么做，就需要引入生命周期(lifetimes)这个新概念,下面的例子编译器会抱怨它需要生命周期标识符，见 compile_fail/e0106_struct_missing_lifetime.rs
     // struct User {
     //     username: &str,
     //     email: &str,
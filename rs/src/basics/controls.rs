@@ -1,3 +1,11 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(condition_control_run, "条件控制", "if else 表达式与多分支"),
+    example!(loop_control_run, "循环控制", "for、while、loop、continue 与 break"),
+];
+
 
 
 pub fn loop_control_run(){
@@ -0,0 +1,210 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::mem::size_of;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_as_conversion, "as 类型转换", "数值之间的 as 转换：截断、符号与饱和"),
+    example!(ex2_from_into, "From 与 TryFrom", "不会失败的转换与可能失败的转换"),
+    example!(ex3_newtype_alias, "newtype 与类型别名", "newtype 是新类型，类型别名只是另一个名字"),
+    example!(ex4_sized_dst, "Sized 与动态大小类型", "切片、str、特征对象通过胖指针使用"),
+    example!(ex5_enum_integer, "枚举与整数互转", "as 把枚举转为整数，TryFrom 把整数转回枚举"),
+];
+
+pub fn ex1_as_conversion() {
+    println!("as 类型转换 示例代码\n\n");
+
+    // 小类型转大类型不会丢失信息
+    let a: u8 = 200;
+    let b = a as u32;
+    assert_eq!(b, 200);
+
+    // 大类型转小类型直接截断高位：300 = 0x12C，截断后为 0x2C = 44
+    let c = 300_i32 as u8;
+    assert_eq!(c, 44);
+    println!("300_i32 as u8 = {}", c);
+
+    // 有符号与无符号之间按位重新解释
+    let d = -1_i8 as u8;
+    assert_eq!(d, 255);
+    println!("-1_i8 as u8 = {}", d);
+
+    // 浮点转整数向零取整，超出范围时饱和，NaN 转为 0
+    let nan = "NaN".parse::<f64>().unwrap();
+    assert_eq!(3.99_f64 as i32, 3);
+    assert_eq!(-3.99_f64 as i32, -3);
+    assert_eq!(1e10_f64 as i32, i32::MAX);
+    assert_eq!(nan as i32, 0);
+    println!("3.99 as i32 = {}, 1e10 as i32 = {}, NaN as i32 = {}", 3.99_f64 as i32, 1e10_f64 as i32, nan as i32);
+
+    // char 与整数，char 转 u8 会截断到低 8 位，只有 ASCII 字符是安全的
+    assert_eq!('A' as u32, 65);
+    assert_eq!(97_u8 as char, 'a');
+    assert_eq!('中' as u32, 0x4E2D);
+    assert!(u8::try_from('中').is_err());
+    println!("'A' as u32 = {}, 97 as char = {}, '中' as u32 = {:#X}", 'A' as u32, 97_u8 as char, '中' as u32);
+}
+
+pub fn ex2_from_into() {
+    println!("From 与 TryFrom 示例代码\n\n");
+
+    // 不会丢失信息的转换实现了 From，对应的 Into 自动实现
+    let big = i64::from(10_i32);
+    let also_big: i64 = 10_i32.into();
+    assert_eq!(big, also_big);
+    let s = String::from("hello");
+    let v: Vec<u8> = s.into();
+    assert_eq!(v, b"hello");
+    println!("i64::from(10_i32) = {}, String -> Vec<u8> = {:?}", big, v);
+
+    // 可能失败的转换实现了 TryFrom，返回 Result
+    assert_eq!(u8::try_from(255_i32), Ok(255));
+    assert!(u8::try_from(256_i32).is_err());
+    assert!(u8::try_from(-1_i32).is_err());
+    let n: Result<u8, _> = 1000_u32.try_into();
+    println!("u8::try_from(255) = {:?}, 1000 转 u8: {:?}", u8::try_from(255_i32), n);
+
+    // 为自己的类型实现 From，? 运算符的错误转换也依赖它
+    #[derive(Debug, PartialEq)]
+    struct Celsius(f64);
+    struct Fahrenheit(f64);
+    impl From<Fahrenheit> for Celsius {
+        fn from(f: Fahrenheit) -> Celsius {
+            Celsius((f.0 - 32.0) * 5.0 / 9.0)
+        }
+    }
+    let c: Celsius = Fahrenheit(212.0).into();
+    assert_eq!(c, Celsius(100.0));
+    println!("212°F = {:?}", c);
+}
+
+// newtype：包装 Vec<String> 后可以为它实现外部特征 Display（孤儿规则不允许直接为 Vec 实现）
+struct Wrapper(Vec<String>);
+
+impl fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.0.join(", "))
+    }
+}
+
+// newtype 让单位不同的数值不能混用
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(u32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Millimeters(u32);
+
+impl From<Meters> for Millimeters {
+    fn from(m: Meters) -> Millimeters {
+        Millimeters(m.0 * 1000)
+    }
+}
+
+// 类型别名只是一个新名字，与原类型完全等价
+type Kilometers = u32;
+type Thunk = Box<dyn Fn() -> u32>;
+
+pub fn ex3_newtype_alias() {
+    println!("newtype 与类型别名 示例代码\n\n");
+
+    let w = Wrapper(vec![String::from("hello"), String::from("world")]);
+    assert_eq!(w.to_string(), "[hello, world]");
+    println!("w = {}", w);
+
+    let mm: Millimeters = Meters(3).into();
+    assert_eq!(mm, Millimeters(3000));
+    // Meters(3) == Millimeters(3000) 无法编译，两者是不同的类型
+    println!("{:?} = {:?}", Meters(3), mm);
+
+    let x: u32 = 5;
+    let y: Kilometers = 5;
+    // 别名与原类型可以直接比较、相加
+    assert_eq!(x + y, 10);
+    let f: Thunk = Box::new(|| 42);
+    assert_eq!(f(), 42);
+    println!("u32 + Kilometers = {}，Thunk 返回 {}", x + y, f());
+
+    // newtype 在运行时没有额外开销
+    assert_eq!(size_of::<Meters>(), size_of::<u32>());
+    println!("size_of::<Meters>() = {}", size_of::<Meters>());
+}
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+// 默认 T: Sized，?Sized 放宽限制后可以接受 str、[T]、dyn Trait，但只能通过引用使用
+fn byte_len<T: ?Sized + AsRef<[u8]>>(value: &T) -> usize {
+    value.as_ref().len()
+}
+
+pub fn ex4_sized_dst() {
+    println!("Sized 与动态大小类型 示例代码\n\n");
+
+    let word = size_of::<usize>();
+    // 普通引用是一个指针的大小
+    assert_eq!(size_of::<&u8>(), word);
+    // 动态大小类型的引用是胖指针：指针 + 长度，或者指针 + 虚表
+    assert_eq!(size_of::<&str>(), 2 * word);
+    assert_eq!(size_of::<&[i32]>(), 2 * word);
+    assert_eq!(size_of::<Box<str>>(), 2 * word);
+    assert_eq!(size_of::<&dyn Shape>(), 2 * word);
+    println!("以机器字为单位: &u8 = 1, &str = 2, &[i32] = 2, Box<str> = 2, &dyn Shape = 2");
+
+    // 数组的长度是类型的一部分，大小在编译期已知
+    assert_eq!(size_of::<[i32; 4]>(), 16);
+
+    let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Square(2.0)), Box::new(Square(3.0))];
+    let total: f64 = shapes.iter().map(|s| s.area()).sum();
+    assert_eq!(total, 13.0);
+    println!("特征对象的面积之和 = {}", total);
+
+    assert_eq!(byte_len("中文"), 6);
+    assert_eq!(byte_len(&[1u8, 2, 3][..]), 3);
+    println!("byte_len(\"中文\") = {}", byte_len("中文"));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Weekday {
+    Monday = 1,
+    Tuesday,
+    Wednesday,
+}
+
+impl TryFrom<i32> for Weekday {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            x if x == Weekday::Monday as i32 => Ok(Weekday::Monday),
+            x if x == Weekday::Tuesday as i32 => Ok(Weekday::Tuesday),
+            x if x == Weekday::Wednesday as i32 => Ok(Weekday::Wednesday),
+            _ => Err(format!("{} 不是有效的 Weekday", value)),
+        }
+    }
+}
+
+pub fn ex5_enum_integer() {
+    println!("枚举与整数互转 示例代码\n\n");
+
+    // 没有指定值的成员在前一个成员的基础上加 1
+    assert_eq!(Weekday::Monday as i32, 1);
+    assert_eq!(Weekday::Wednesday as i32, 3);
+    println!("Weekday::Tuesday as i32 = {}", Weekday::Tuesday as i32);
+
+    // 整数不能用 as 转为枚举，需要自己实现 TryFrom
+    assert_eq!(Weekday::try_from(2), Ok(Weekday::Tuesday));
+    let bad: Result<Weekday, _> = 7.try_into();
+    assert!(bad.is_err());
+    println!("Weekday::try_from(2) = {:?}", Weekday::try_from(2));
+    println!("7.try_into() = {:?}", bad);
+}
@@ -0,0 +1,169 @@
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_result_match, "处理 Result", "match、unwrap_or、map 与 and_then"),
+    example!(ex2_question_mark, "? 传播错误", "? 在出错时提前返回，并用 From 转换错误类型"),
+    example!(ex3_option_question, "Option 上的 ?", "在返回 Option 的函数中用 ? 传播 None"),
+    example!(ex4_box_dyn_error, "Box<dyn Error>", "不同来源的错误统一为特征对象，并通过 source 追溯原因"),
+];
+
+pub fn ex1_result_match() {
+    println!("处理 Result 示例代码\n\n");
+
+    // 用 match 分别处理成功和失败
+    let describe = |input: &str| match input.parse::<i32>() {
+        Ok(n) => format!("{} 解析为 {}", input, n),
+        Err(e) => format!("{} 解析失败: {}", input, e),
+    };
+    assert_eq!(describe("42"), "42 解析为 42");
+    println!("{}", describe("42"));
+    println!("{}", describe("四十二"));
+
+    // 出错时给默认值
+    assert_eq!("x".parse::<i32>().unwrap_or(0), 0);
+    // map 只处理成功的值，and_then 继续执行可能失败的操作
+    let doubled = "21".parse::<i32>().map(|n| n * 2);
+    assert_eq!(doubled, Ok(42));
+    let checked = "200".parse::<i32>().map_err(|e| e.to_string()).and_then(|n| {
+        if n <= 100 {
+            Ok(n)
+        } else {
+            Err(format!("{} 超过上限 100", n))
+        }
+    });
+    assert_eq!(checked, Err("200 超过上限 100".to_string()));
+    println!("\"21\" 加倍: {:?}", doubled);
+    println!("\"200\" 检查上限: {:?}", checked);
+}
+
+// 配置解析的错误，包装了底层的 ParseIntError
+#[derive(Debug, PartialEq)]
+enum ConfigError {
+    Missing(&'static str),
+    BadNumber(ParseIntError),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Missing(key) => write!(f, "缺少配置项 {}", key),
+            ConfigError::BadNumber(e) => write!(f, "数字格式错误: {}", e),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Missing(_) => None,
+            ConfigError::BadNumber(e) => Some(e),
+        }
+    }
+}
+
+// 有了这个实现，? 会自动把 ParseIntError 转换为 ConfigError
+impl From<ParseIntError> for ConfigError {
+    fn from(e: ParseIntError) -> ConfigError {
+        ConfigError::BadNumber(e)
+    }
+}
+
+fn lookup<'a>(config: &'a str, key: &'static str) -> Result<&'a str, ConfigError> {
+    config
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(k, _)| k.trim() == key)
+        .map(|(_, v)| v.trim())
+        .ok_or(ConfigError::Missing(key))
+}
+
+// 每个 ? 出错时都提前返回，成功时取出里面的值
+fn parse_window(config: &str) -> Result<(u32, u32), ConfigError> {
+    let width = lookup(config, "width")?.parse::<u32>()?;
+    let height = lookup(config, "height")?.parse::<u32>()?;
+    Ok((width, height))
+}
+
+pub fn ex2_question_mark() {
+    println!("? 传播错误 示例代码\n\n");
+
+    assert_eq!(parse_window("width = 800\nheight = 600"), Ok((800, 600)));
+    assert_eq!(parse_window("width = 800"), Err(ConfigError::Missing("height")));
+    assert!(matches!(parse_window("width = 8OO\nheight = 600"), Err(ConfigError::BadNumber(_))));
+
+    for config in ["width = 800\nheight = 600", "width = 800", "width = 8OO\nheight = 600"] {
+        match parse_window(config) {
+            Ok((w, h)) => println!("{:?} => {}x{}", config, w, h),
+            Err(e) => println!("{:?} => 错误: {}", config, e),
+        }
+    }
+}
+
+// 返回 Option 的函数中，? 遇到 None 时直接返回 None
+fn last_char_of_first_line(text: &str) -> Option<char> {
+    text.lines().next()?.chars().last()
+}
+
+pub fn ex3_option_question() {
+    println!("Option 上的 ? 示例代码\n\n");
+
+    assert_eq!(last_char_of_first_line("Hello, world\nHow are you"), Some('d'));
+    assert_eq!(last_char_of_first_line(""), None);
+    assert_eq!(last_char_of_first_line("\nhi"), None);
+    println!("\"Hello, world\\nHow are you\" => {:?}", last_char_of_first_line("Hello, world\nHow are you"));
+    println!("\"\" => {:?}", last_char_of_first_line(""));
+
+    // Option 和 Result 之间用 ok_or / ok 转换，不能在同一个函数中直接混用 ?
+    let as_result: Result<char, &str> = last_char_of_first_line("").ok_or("空文本");
+    assert_eq!(as_result, Err("空文本"));
+    println!("ok_or 转为 Result: {:?}", as_result);
+}
+
+// 任何实现了 Error 的类型都可以用 ? 转换为 Box<dyn Error>
+fn sum_numbers(text: &str) -> Result<i64, Box<dyn Error>> {
+    let mut total = 0;
+    for word in text.split_whitespace() {
+        total += word.parse::<i64>()?;
+    }
+    if total < 0 {
+        return Err("总和不能为负数".into());
+    }
+    if let Some(window) = text.strip_prefix("window:") {
+        let (w, h) = parse_window(window)?;
+        total += i64::from(w * h);
+    }
+    Ok(total)
+}
+
+pub fn ex4_box_dyn_error() {
+    println!("Box<dyn Error> 示例代码\n\n");
+
+    assert_eq!(sum_numbers("1 2 3").unwrap(), 6);
+    let errors = [sum_numbers("1 two 3").unwrap_err(), sum_numbers("-5 1").unwrap_err()];
+    assert_eq!(errors[0].to_string(), "invalid digit found in string");
+    assert_eq!(errors[1].to_string(), "总和不能为负数");
+    println!("sum_numbers(\"1 2 3\") = {:?}", sum_numbers("1 2 3").unwrap());
+    for e in &errors {
+        println!("错误: {}", e);
+    }
+
+    // 沿 source 链找到最底层的原因，还可以 downcast 回具体类型
+    let e = ConfigError::from("x".parse::<u8>().unwrap_err());
+    let mut chain = vec![e.to_string()];
+    let mut source = e.source();
+    while let Some(cause) = source {
+        chain.push(cause.to_string());
+        source = cause.source();
+    }
+    assert_eq!(chain, ["数字格式错误: invalid digit found in string", "invalid digit found in string"]);
+    println!("错误链: {}", chain.join(" <- "));
+
+    let boxed: Box<dyn Error> = Box::new(e);
+    assert!(boxed.downcast_ref::<ConfigError>().is_some());
+    println!("downcast_ref::<ConfigError>() 成功");
+}
@@ -0,0 +1,123 @@
+use std::fmt::{self, Write};
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_placeholder, "占位符", "位置参数、具名参数与内联变量"),
+    example!(ex2_width_precision, "宽度与精度", "填充、对齐、精度、符号与进制"),
+    example!(ex3_display_debug, "Display 与 Debug", "为自定义类型实现 Display，以及 {:?} 与 {:#?}"),
+    example!(ex4_format_macros, "格式化宏", "format!、write!、print! 与 eprint! 的区别"),
+];
+
+pub fn ex1_placeholder() {
+    println!("占位符 示例代码\n\n");
+
+    // {} 依次使用后面的参数
+    assert_eq!(format!("{} + {} = {}", 1, 2, 3), "1 + 2 = 3");
+    // {0} {1} 按位置引用，可以重复使用
+    assert_eq!(format!("{1} {0} {1}", "a", "b"), "b a b");
+    // 具名参数
+    assert_eq!(format!("{name} 今年 {age} 岁", name = "小明", age = 18), "小明 今年 18 岁");
+    // 直接捕获作用域中的变量
+    let lang = "Rust";
+    assert_eq!(format!("hello, {lang}"), "hello, Rust");
+    // 输出花括号需要写两次
+    assert_eq!(format!("{{}} 里的值是 {}", 5), "{} 里的值是 5");
+
+    let (a, b, name, age) = ("a", "b", "小明", 18);
+    println!("{} + {} = {}", 1, 2, 3);
+    println!("{1} {0} {1}", a, b);
+    println!("{name} 今年 {age} 岁", name = name, age = age);
+    println!("hello, {lang}");
+    println!("{{}} 里的值是 {}", 5);
+}
+
+pub fn ex2_width_precision() {
+    println!("宽度与精度 示例代码\n\n");
+
+    // 宽度：数字默认右对齐，字符串默认左对齐
+    assert_eq!(format!("[{:5}]", 42), "[   42]");
+    assert_eq!(format!("[{:5}]", "ab"), "[ab   ]");
+    // < ^ > 指定对齐，前面可以加填充字符
+    assert_eq!(format!("[{:-^7}]", "ab"), "[--ab---]");
+    assert_eq!(format!("[{:*>5}]", 7), "[****7]");
+    // 0 填充，符号位放在最前
+    assert_eq!(format!("{:05}", -42), "-0042");
+    assert_eq!(format!("{:+}", 42), "+42");
+    // 宽度和精度也可以来自参数
+    assert_eq!(format!("[{:>width$}]", 1, width = 3), "[  1]");
+    assert_eq!(format!("{:.*}", 2, 1.23456), "1.23");
+
+    // 精度：浮点数保留小数位（四舍五入），字符串截断
+    assert_eq!(format!("{:.2}", 1.23456), "1.23");
+    assert_eq!(format!("{:8.3}|", 2.5), "   2.500|");
+    assert_eq!(format!("{:.3}", "abcdef"), "abc");
+
+    // 进制与科学计数法，# 加上前缀
+    assert_eq!(format!("{:b} {:o} {:x} {:X}", 10, 10, 255, 255), "1010 12 ff FF");
+    assert_eq!(format!("{:#b} {:#x} {:#010b}", 5, 255, 5), "0b101 0xff 0b00000101");
+    assert_eq!(format!("{:e}", 1234.5), "1.2345e3");
+
+    println!("[{:5}] [{:5}] [{:-^7}] [{:*>5}]", 42, "ab", "ab", 7);
+    println!("{:05} {:+} {:.2} {:8.3}|", -42, 42, 1.23456, 2.5);
+    println!("{:b} {:o} {:x} {:X} {:#010b} {:e}", 10, 10, 255, 255, 5, 1234.5);
+}
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+// Display 面向用户，需要手动实现；Debug 面向开发者，可以 derive
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // {:#} 会设置 alternate 标志，可以据此给出更详细的形式
+        if f.alternate() {
+            write!(f, "Point(x = {}, y = {})", self.x, self.y)
+        } else {
+            write!(f, "({}, {})", self.x, self.y)
+        }
+    }
+}
+
+pub fn ex3_display_debug() {
+    println!("Display 与 Debug 示例代码\n\n");
+
+    let p = Point { x: 1, y: -2 };
+    assert_eq!(p.to_string(), "(1, -2)");
+    assert_eq!(format!("{:#}", p), "Point(x = 1, y = -2)");
+    assert_eq!(format!("{:?}", p), "Point { x: 1, y: -2 }");
+    assert_eq!(format!("{:#?}", p), "Point {\n    x: 1,\n    y: -2,\n}");
+
+    println!("{{}}    => {}", p);
+    println!("{{:#}}  => {:#}", p);
+    println!("{{:?}}  => {:?}", p);
+    println!("{{:#?}} => {:#?}", p);
+
+    // 字符串的 Debug 输出带引号并转义
+    assert_eq!(format!("{:?}", "a\"b\n"), r#""a\"b\n""#);
+    println!("{:?}", "a\"b\n");
+}
+
+pub fn ex4_format_macros() {
+    println!("格式化宏 示例代码\n\n");
+    println!("format!  : 返回 String");
+    println!("print!   : 输出到标准输出，println! 末尾加换行");
+    println!("eprint!  : 输出到标准错误，eprintln! 末尾加换行");
+    println!("write!   : 写入任何实现了 fmt::Write 或 io::Write 的对象\n");
+
+    // write! 追加到已有的 String，避免多次分配
+    let mut table = String::new();
+    for (name, score) in [("张三", 90), ("李四", 85)] {
+        writeln!(table, "{:<4}{:>4}", name, score).unwrap();
+    }
+    assert_eq!(table, "张三    90\n李四    85\n");
+    print!("{}", table);
+
+    // format_args! 不分配内存，是其它格式化宏的基础
+    let s = fmt::format(format_args!("{}-{}", 1, 2));
+    assert_eq!(s, "1-2");
+    println!("fmt::format(format_args!(..)) = {}", s);
+}
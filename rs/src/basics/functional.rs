@@ -0,0 +1,187 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_fn_pointer, "函数指针", "函数作为值传递，fn 指针与闭包的关系"),
+    example!(ex2_higher_order, "高阶函数", "接受函数、返回函数，以及函数组合"),
+    example!(ex3_combinators, "Option 与 Result 组合子", "用 map、and_then、filter 代替层层嵌套的 match"),
+    example!(ex4_pattern_matching, "模式匹配", "match 守卫、绑定与解构，用表达式代替语句"),
+];
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+fn square(x: i32) -> i32 {
+    x * x
+}
+
+type NamedFn = (&'static str, fn(i32) -> i32);
+
+// fn(i32) -> i32 是函数指针类型，大小与普通指针相同
+fn apply_twice(f: fn(i32) -> i32, x: i32) -> i32 {
+    f(f(x))
+}
+
+pub fn ex1_fn_pointer() {
+    println!("函数指针 示例代码\n\n");
+
+    assert_eq!(apply_twice(double, 3), 12);
+    assert_eq!(apply_twice(square, 3), 81);
+    println!("apply_twice(double, 3) = {}", apply_twice(double, 3));
+    println!("apply_twice(square, 3) = {}", apply_twice(square, 3));
+
+    // 函数指针可以放进集合，按名字查找
+    let table: [NamedFn; 2] = [("double", double), ("square", square)];
+    let results: Vec<i32> = table.iter().map(|(_, f)| f(5)).collect();
+    assert_eq!(results, [10, 25]);
+    for (name, f) in table {
+        println!("{}(5) = {}", name, f(5));
+    }
+
+    // 元组结构体和枚举成员的构造器本身也是函数
+    #[derive(Debug, PartialEq)]
+    struct Id(u32);
+    let ids: Vec<Id> = (1..=3).map(Id).collect();
+    assert_eq!(ids, [Id(1), Id(2), Id(3)]);
+    let wrapped: Vec<Option<u32>> = (1..=2).map(Some).collect();
+    assert_eq!(wrapped, [Some(1), Some(2)]);
+    println!("(1..=3).map(Id) = {:?}", ids);
+}
+
+// 返回闭包的闭包：先固定一个参数（柯里化）
+fn multiplier(factor: i32) -> impl Fn(i32) -> i32 {
+    move |x| x * factor
+}
+
+// 函数组合：compose(f, g)(x) == g(f(x))
+fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+pub fn ex2_higher_order() {
+    println!("高阶函数 示例代码\n\n");
+
+    let times3 = multiplier(3);
+    assert_eq!(times3(7), 21);
+    println!("multiplier(3)(7) = {}", times3(7));
+
+    let double_then_square = compose(double, square);
+    let square_then_double = compose(square, double);
+    assert_eq!(double_then_square(3), 36);
+    assert_eq!(square_then_double(3), 18);
+    println!("compose(double, square)(3) = {}", double_then_square(3));
+    println!("compose(square, double)(3) = {}", square_then_double(3));
+
+    // 组合任意多个函数
+    let pipeline: Vec<Box<dyn Fn(i32) -> i32>> = vec![Box::new(double), Box::new(|x| x + 1), Box::new(multiplier(10))];
+    let result = pipeline.iter().fold(4, |acc, f| f(acc));
+    assert_eq!(result, 90);
+    println!("4 依次经过 double、+1、*10 = {}", result);
+}
+
+#[derive(Debug)]
+struct User {
+    name: &'static str,
+    email: Option<&'static str>,
+}
+
+fn find_user(id: u32) -> Option<User> {
+    match id {
+        1 => Some(User { name: "alice", email: Some("alice@example.com") }),
+        2 => Some(User { name: "bob", email: None }),
+        _ => None,
+    }
+}
+
+// 没有用户或没有邮箱时都返回 None，不需要嵌套的 match
+fn email_domain(id: u32) -> Option<&'static str> {
+    find_user(id).and_then(|u| u.email).and_then(|e| e.split_once('@')).map(|(_, domain)| domain)
+}
+
+pub fn ex3_combinators() {
+    println!("Option 与 Result 组合子 示例代码\n\n");
+
+    assert_eq!(email_domain(1), Some("example.com"));
+    assert_eq!(email_domain(2), None);
+    assert_eq!(email_domain(3), None);
+    for id in 1..=3 {
+        let name = find_user(id).map_or("(不存在)", |u| u.name);
+        println!("用户 {} {}: 邮箱域名 {:?}", id, name, email_domain(id));
+    }
+
+    // filter 保留满足条件的值，unwrap_or_else 只在 None 时才计算默认值
+    let even = Some(4).filter(|n| n % 2 == 0);
+    let odd = Some(3).filter(|n| n % 2 == 0);
+    assert_eq!((even, odd), (Some(4), None));
+    assert_eq!(odd.unwrap_or_else(|| even.map_or(0, |n| n * 10)), 40);
+
+    // Result 的组合子
+    let parsed: Result<i32, String> = "12".parse::<i32>().map_err(|e| e.to_string()).map(|n| n + 1);
+    assert_eq!(parsed, Ok(13));
+    // 收集时遇到第一个错误就停止
+    let all: Result<Vec<i32>, _> = ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    let some_bad: Result<Vec<i32>, _> = ["1", "x", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    assert_eq!(all, Ok(vec![1, 2, 3]));
+    assert!(some_bad.is_err());
+    println!("[\"1\", \"2\", \"3\"] => {:?}", all);
+    println!("[\"1\", \"x\", \"3\"] => 出错: {}", some_bad.is_err());
+}
+
+#[derive(Debug)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect { width: f64, height: f64 },
+    Triangle(f64, f64, f64),
+}
+
+fn classify(shape: &Shape) -> String {
+    // match 是表达式，每个分支返回一个值
+    match shape {
+        Shape::Circle { radius } if *radius <= 0.0 => "无效的圆".to_string(),
+        Shape::Circle { radius } => format!("圆，面积 {:.2}", std::f64::consts::PI * radius * radius),
+        Shape::Rect { width, height } if width == height => format!("正方形，边长 {}", width),
+        Shape::Rect { width, height } => format!("矩形，面积 {}", width * height),
+        Shape::Triangle(a, b, c) if a == b && b == c => format!("等边三角形，边长 {}", a),
+        Shape::Triangle(..) => "三角形".to_string(),
+    }
+}
+
+pub fn ex4_pattern_matching() {
+    println!("模式匹配 示例代码\n\n");
+
+    let shapes = [
+        Shape::Circle { radius: 1.0 },
+        Shape::Circle { radius: -1.0 },
+        Shape::Rect { width: 2.0, height: 2.0 },
+        Shape::Rect { width: 2.0, height: 3.0 },
+        Shape::Triangle(1.0, 1.0, 1.0),
+        Shape::Triangle(3.0, 4.0, 5.0),
+    ];
+    let names: Vec<String> = shapes.iter().map(classify).collect();
+    assert_eq!(names[0], "圆，面积 3.14");
+    assert_eq!(names[2], "正方形，边长 2");
+    assert_eq!(names[5], "三角形");
+    for (shape, name) in shapes.iter().zip(&names) {
+        println!("{:?} => {}", shape, name);
+    }
+
+    // @ 绑定匹配到的值，| 匹配多个模式，切片模式解构开头和结尾
+    let describe = |n: u32| match n {
+        0 => "零".to_string(),
+        small @ 1..=9 => format!("个位数 {}", small),
+        10 | 100 | 1000 => "10 的幂".to_string(),
+        _ => "其它".to_string(),
+    };
+    assert_eq!(describe(7), "个位数 7");
+    assert_eq!(describe(100), "10 的幂");
+    let ends = |v: &[i32]| match v {
+        [] => None,
+        [only] => Some((*only, *only)),
+        [first, .., last] => Some((*first, *last)),
+    };
+    assert_eq!(ends(&[1, 2, 3, 4]), Some((1, 4)));
+    assert_eq!(ends(&[]), None);
+    println!("describe(7) = {}, describe(100) = {}", describe(7), describe(100));
+    println!("ends(&[1, 2, 3, 4]) = {:?}", ends(&[1, 2, 3, 4]));
+}
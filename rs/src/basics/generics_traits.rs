@@ -1,3 +1,11 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(const_fn_run, "const 函数与 const 泛型", "编译期计算缓冲区大小"),
+    example!(trait_example, "特征", "为不同类型实现同一个特征"),
+];
+
 
 
 
@@ -96,11 +104,12 @@ fn display_array<T: std::fmt::Debug, const N: usize>(arr: [T; N]) {
 
 // 将 `const fn` 与 `const 泛型` 结合，可以实现更加灵活和高效的代码设计。例如，创建一个固定大小的缓冲区结构，其中缓冲区大小由编译期计算确定：
 
+// 只能构造和查看大小，完整的定长环形缓冲区见 `data_structure::ring_buffer::RingBuffer`
 struct Buffer<const N: usize> {
     data: [u8; N],
 }
 
-const fn compute_buffer_size(factor: usize) -> usize {
+pub const fn compute_buffer_size(factor: usize) -> usize {
     factor * 1024
 }
 
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::thread;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_const_static, "const 与 static", "常量在使用处内联，静态变量有固定的内存地址"),
+    example!(ex2_static_mut_atomic, "可变的全局变量", "static mut 需要 unsafe，原子类型和 Mutex 可以安全地修改"),
+    example!(ex3_once_lock, "OnceLock 延迟初始化", "运行时只初始化一次的全局变量"),
+    example!(ex4_lazy_lock, "LazyLock", "首次访问时自动初始化的全局集合"),
+];
+
+// 常量：编译期求值，每次使用都相当于把值复制到使用的地方
+const MAX_USERS: usize = 100;
+const GREETING: &str = "你好";
+
+// 静态变量：整个程序运行期间只有一份，'static 生命周期
+static APP_NAME: &str = "rust_code_examples";
+static PRIMES: [u32; 5] = [2, 3, 5, 7, 11];
+
+// const fn 可以在编译期调用，用来计算常量和静态变量的初始值
+const fn kb(n: usize) -> usize {
+    n * 1024
+}
+const BUFFER_SIZE: usize = kb(4);
+
+pub fn ex1_const_static() {
+    println!("const 与 static 示例代码\n\n");
+
+    assert_eq!(MAX_USERS, 100);
+    assert_eq!(BUFFER_SIZE, 4096);
+    println!("MAX_USERS = {}, BUFFER_SIZE = kb(4) = {}, GREETING = {}", MAX_USERS, BUFFER_SIZE, GREETING);
+
+    // 多次取静态变量的地址得到的是同一个位置
+    let a: *const [u32; 5] = &PRIMES;
+    let b: *const [u32; 5] = &PRIMES;
+    assert!(std::ptr::eq(a, b));
+    assert_eq!(PRIMES.iter().sum::<u32>(), 28);
+    println!("APP_NAME = {}, PRIMES 之和 = {}", APP_NAME, PRIMES.iter().sum::<u32>());
+    println!("两次取 &PRIMES 的地址相同: {}", std::ptr::eq(a, b));
+}
+
+static mut UNSAFE_COUNTER: u32 = 0;
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+static NAMES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// 多线程同时访问 static mut 会产生数据竞争，所以读写都需要 unsafe，由调用者保证安全
+fn add_unsafe(n: u32) {
+    unsafe {
+        UNSAFE_COUNTER += n;
+    }
+}
+
+pub fn ex2_static_mut_atomic() {
+    println!("可变的全局变量 示例代码\n\n");
+
+    add_unsafe(2);
+    add_unsafe(3);
+    // 只在当前线程中读写，这里是安全的；按值读取，不创建引用
+    let value = unsafe { UNSAFE_COUNTER };
+    assert!(value >= 5);
+    println!("static mut 需要 unsafe 读写");
+
+    // 原子类型：多个线程同时修改也不会丢失更新
+    let before = COUNTER.load(Ordering::SeqCst);
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            thread::spawn(|| {
+                for _ in 0..1000 {
+                    COUNTER.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(COUNTER.load(Ordering::SeqCst) - before, 4000);
+    println!("4 个线程各加 1000 次，COUNTER 增加了 {}", COUNTER.load(Ordering::SeqCst) - before);
+
+    // Mutex::new 是 const fn，可以直接作为静态变量的初始值
+    let mut names = NAMES.lock().unwrap();
+    names.clear();
+    names.push("张三".to_string());
+    names.push("李四".to_string());
+    assert_eq!(names.len(), 2);
+    println!("NAMES = {:?}", *names);
+}
+
+#[derive(Debug)]
+struct Config {
+    name: String,
+    workers: usize,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+static INIT_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn config() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        INIT_CALLS.fetch_add(1, Ordering::SeqCst);
+        Config { name: "默认配置".to_string(), workers: 4 }
+    })
+}
+
+pub fn ex3_once_lock() {
+    println!("OnceLock 延迟初始化 示例代码\n\n");
+
+    // 多个线程同时访问，初始化函数只会执行一次
+    let handles: Vec<_> = (0..8).map(|_| thread::spawn(|| config().workers)).collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 4);
+    }
+    assert_eq!(INIT_CALLS.load(Ordering::SeqCst), 1);
+    // 每次得到的都是同一个实例
+    assert!(std::ptr::eq(config(), config()));
+    println!("8 个线程访问 config()，初始化次数: {}", INIT_CALLS.load(Ordering::SeqCst));
+    println!("config() = {:?}", config());
+
+    // 已经初始化之后，set 会失败并把值还回来
+    let rejected = CONFIG.set(Config { name: "另一个配置".to_string(), workers: 1 });
+    assert!(rejected.is_err());
+    assert_eq!(config().name, "默认配置");
+    println!("再次 set 失败，配置仍然是 {}", config().name);
+}
+
+// LazyLock 把初始化函数和变量写在一起，第一次解引用时执行
+static UNITS: LazyLock<HashMap<&'static str, u64>> = LazyLock::new(|| {
+    let mut map = HashMap::new();
+    map.insert("KB", 1 << 10);
+    map.insert("MB", 1 << 20);
+    map.insert("GB", 1 << 30);
+    map
+});
+
+fn to_bytes(size: &str) -> Option<u64> {
+    let split = size.find(|c: char| !c.is_ascii_digit())?;
+    let (number, unit) = size.split_at(split);
+    Some(number.parse::<u64>().ok()? * UNITS.get(unit)?)
+}
+
+pub fn ex4_lazy_lock() {
+    println!("LazyLock 示例代码\n\n");
+
+    assert_eq!(to_bytes("4KB"), Some(4096));
+    assert_eq!(to_bytes("2MB"), Some(2 * 1024 * 1024));
+    assert_eq!(to_bytes("1TB"), None);
+    for size in ["4KB", "2MB", "1TB"] {
+        println!("{} = {:?} 字节", size, to_bytes(size));
+    }
+    let mut units: Vec<_> = UNITS.iter().collect();
+    units.sort_by_key(|(_, bytes)| **bytes);
+    println!("UNITS = {:?}", units);
+}
@@ -0,0 +1,214 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_iter_next, "迭代器与 for 循环", "for 循环通过 IntoIterator 和 next 工作，iter/iter_mut/into_iter 的区别"),
+    example!(ex2_adapters, "迭代器适配器", "map、filter、zip 等适配器是惰性的"),
+    example!(ex3_consumers, "消费者", "sum、fold、collect、any、position 等消耗迭代器"),
+    example!(ex4_custom_iterator, "自定义迭代器", "实现 next 之后，所有适配器方法都可以使用"),
+];
+
+pub fn ex1_iter_next() {
+    println!("迭代器与 for 循环 示例代码\n\n");
+
+    // for 循环等价于不断调用 next，直到返回 None
+    let v = [1, 2, 3];
+    let mut iter = v.iter();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+    println!("手动调用 next: Some(&1), Some(&2), Some(&3), None");
+
+    // iter 产生 &T，iter_mut 产生 &mut T，into_iter 产生 T 并消耗集合
+    let mut v = vec![1, 2, 3];
+    for x in v.iter_mut() {
+        *x *= 10;
+    }
+    let borrowed: Vec<&i32> = v.iter().collect();
+    assert_eq!(borrowed, [&10, &20, &30]);
+    let owned: Vec<i32> = v.into_iter().collect();
+    // v 已经被 into_iter 消耗，不能再使用
+    assert_eq!(owned, [10, 20, 30]);
+    println!("iter_mut 修改之后: {:?}", owned);
+
+    // for x in &v 相当于 v.iter()，for x in &mut v 相当于 v.iter_mut()
+    let names = vec!["a".to_string(), "b".to_string()];
+    let mut total = 0;
+    for name in &names {
+        total += name.len();
+    }
+    assert_eq!(total, 2);
+    println!("for name in &names 之后 names 仍然可用: {:?}", names);
+}
+
+pub fn ex2_adapters() {
+    println!("迭代器适配器 示例代码\n\n");
+
+    // 适配器只是包装迭代器，不消费就不会执行
+    let calls = Cell::new(0);
+    let lazy = [1, 2, 3].iter().map(|x| {
+        calls.set(calls.get() + 1);
+        x * 2
+    });
+    assert_eq!(calls.get(), 0);
+    let doubled: Vec<i32> = lazy.collect();
+    assert_eq!(calls.get(), 3);
+    assert_eq!(doubled, [2, 4, 6]);
+    println!("collect 之前闭包调用 0 次，之后调用 {} 次", calls.get());
+
+    let evens: Vec<i32> = (1..=10).filter(|x| x % 2 == 0).collect();
+    assert_eq!(evens, [2, 4, 6, 8, 10]);
+    println!("filter 偶数: {:?}", evens);
+
+    let pairs: Vec<(char, i32)> = "abc".chars().zip(1..).collect();
+    assert_eq!(pairs, [('a', 1), ('b', 2), ('c', 3)]);
+    println!("zip: {:?}", pairs);
+
+    let indexed: Vec<(usize, &str)> = ["x", "y"].into_iter().enumerate().collect();
+    assert_eq!(indexed, [(0, "x"), (1, "y")]);
+    println!("enumerate: {:?}", indexed);
+
+    let window: Vec<u32> = (1..).skip(2).step_by(3).take(4).collect();
+    assert_eq!(window, [3, 6, 9, 12]);
+    println!("(1..).skip(2).step_by(3).take(4): {:?}", window);
+
+    let chained: Vec<i32> = [1, 2].iter().chain(&[3]).rev().copied().collect();
+    assert_eq!(chained, [3, 2, 1]);
+    println!("chain 之后 rev: {:?}", chained);
+
+    let words = ["hello world", "rust"];
+    let flat: Vec<&str> = words.iter().flat_map(|s| s.split(' ')).collect();
+    assert_eq!(flat, ["hello", "world", "rust"]);
+    println!("flat_map: {:?}", flat);
+
+    let taken: Vec<i32> = [1, 3, 5, 6, 7].into_iter().take_while(|x| x % 2 == 1).collect();
+    assert_eq!(taken, [1, 3, 5]);
+    println!("take_while 奇数: {:?}", taken);
+}
+
+pub fn ex3_consumers() {
+    println!("消费者 示例代码\n\n");
+
+    let numbers = [3, 1, 4, 1, 5, 9, 2, 6];
+    let sum: i32 = numbers.iter().sum();
+    let product: i64 = numbers.iter().map(|&x| x as i64).product();
+    assert_eq!(sum, 31);
+    assert_eq!(product, 6480);
+    println!("sum = {}, product = {}", sum, product);
+
+    // fold 从初始值开始累积
+    let digits = numbers.iter().fold(String::new(), |acc, x| acc + &x.to_string());
+    assert_eq!(digits, "31415926");
+    println!("fold 拼接: {}", digits);
+
+    assert_eq!(numbers.iter().max(), Some(&9));
+    assert_eq!(numbers.iter().min(), Some(&1));
+    assert_eq!(numbers.iter().count(), 8);
+    assert!(numbers.iter().any(|&x| x > 8));
+    assert!(numbers.iter().all(|&x| x > 0));
+    assert_eq!(numbers.iter().position(|&x| x == 5), Some(4));
+    assert_eq!(numbers.iter().find(|&&x| x % 2 == 0), Some(&4));
+    println!("max = 9, min = 1, 第一个 5 在位置 {:?}", numbers.iter().position(|&x| x == 5));
+
+    // collect 可以构造多种集合，由目标类型决定
+    let text = "the quick the lazy the end";
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+    // HashMap 的顺序不固定，排序后再输出
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    assert_eq!(counts[0], ("the", 3));
+    println!("词频: {:?}", counts);
+
+    let (small, big): (Vec<i32>, Vec<i32>) = numbers.iter().partition(|&&x| x < 4);
+    assert_eq!(small, [3, 1, 1, 2]);
+    assert_eq!(big, [4, 5, 9, 6]);
+    println!("partition: {:?} {:?}", small, big);
+
+    // 字符串也可以由迭代器收集而成
+    let joined: String = ["a", "b", "c"].iter().map(|s| s.to_uppercase()).collect();
+    assert_eq!(joined, "ABC");
+    println!("collect 为 String: {}", joined);
+}
+
+// 斐波那契数列，每次 next 计算下一项
+struct Fibonacci {
+    current: u64,
+    next: u64,
+}
+
+impl Fibonacci {
+    fn new() -> Fibonacci {
+        Fibonacci { current: 0, next: 1 }
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let value = self.current;
+        // 溢出时结束迭代
+        let next = self.current.checked_add(self.next)?;
+        self.current = self.next;
+        self.next = next;
+        Some(value)
+    }
+}
+
+// 倒数计数，实现 DoubleEndedIterator 之后可以 rev
+struct Countdown {
+    from: u32,
+    to: u32,
+}
+
+impl Iterator for Countdown {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.from <= self.to {
+            return None;
+        }
+        self.from -= 1;
+        Some(self.from + 1)
+    }
+}
+
+impl DoubleEndedIterator for Countdown {
+    fn next_back(&mut self) -> Option<u32> {
+        if self.from <= self.to {
+            return None;
+        }
+        self.to += 1;
+        Some(self.to)
+    }
+}
+
+pub fn ex4_custom_iterator() {
+    println!("自定义迭代器 示例代码\n\n");
+
+    let first: Vec<u64> = Fibonacci::new().take(10).collect();
+    assert_eq!(first, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    println!("斐波那契前 10 项: {:?}", first);
+
+    let even_sum: u64 = Fibonacci::new().take_while(|&x| x < 100).filter(|x| x % 2 == 0).sum();
+    assert_eq!(even_sum, 44);
+    println!("小于 100 的偶数项之和: {}", even_sum);
+
+    // checked_add 溢出时返回 None，迭代器是有限的
+    let total = Fibonacci::new().count();
+    assert_eq!(total, 92);
+    println!("u64 范围内共有 {} 项", total);
+
+    let down: Vec<u32> = Countdown { from: 5, to: 0 }.collect();
+    let up: Vec<u32> = Countdown { from: 5, to: 0 }.rev().collect();
+    assert_eq!(down, [5, 4, 3, 2, 1]);
+    assert_eq!(up, [1, 2, 3, 4, 5]);
+    println!("Countdown: {:?}，rev: {:?}", down, up);
+}
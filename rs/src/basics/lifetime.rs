@@ -0,0 +1,186 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_dangling, "生命周期防止悬垂引用", "借用检查器比较引用与被引用数据的作用域"),
+    example!(ex2_lifetime_annotation, "生命周期标注", "longest 返回的引用与两个参数中较短的生命周期一样长"),
+    example!(ex3_elision, "生命周期消除", "三条消除规则，以及什么时候必须手动标注"),
+    example!(ex4_struct_lifetime, "结构体中的引用", "持有引用的结构体不能比它引用的数据活得更久"),
+    example!(ex5_static_bound, "'static 与生命周期约束", "'static 引用、T: 'static 与 T: 'a"),
+];
+
+pub fn ex1_dangling() {
+    println!("生命周期防止悬垂引用 示例代码\n\n");
+
+    // 下面的代码无法编译：x 在内部作用域结束时被释放，r 会指向无效的内存
+    // let r;
+    // {
+    //     let x = 5;
+    //     r = &x;
+    // }
+    // println!("r: {}", r);
+
+    // 被引用的数据活得比引用更久，就没有问题
+    let x = 5;
+    let r = &x;
+    assert_eq!(*r, 5);
+    println!("r: {}", r);
+
+    // 函数不能返回指向局部变量的引用，应当返回拥有所有权的值
+    fn make_greeting() -> String {
+        let s = String::from("hello");
+        // &s 会在函数返回后失效，所以直接返回 s
+        s
+    }
+    assert_eq!(make_greeting(), "hello");
+    println!("返回 String 而不是 &String: {}", make_greeting());
+}
+
+// 返回值的生命周期 'a 是 x 和 y 生命周期的交集
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+// 返回值只与 x 有关，y 不需要同样的生命周期
+fn first_of<'a>(x: &'a str, _y: &str) -> &'a str {
+    x
+}
+
+pub fn ex2_lifetime_annotation() {
+    println!("生命周期标注 示例代码\n\n");
+
+    let string1 = String::from("long string is long");
+    let result;
+    {
+        let string2 = String::from("xyz");
+        // result 只在 string2 有效的范围内使用
+        let inner = longest(string1.as_str(), string2.as_str());
+        assert_eq!(inner, "long string is long");
+        println!("longest = {}", inner);
+        result = first_of(string1.as_str(), string2.as_str());
+    }
+    // first_of 的返回值只借用 string1，可以在 string2 释放之后使用
+    assert_eq!(result, "long string is long");
+    println!("first_of 的结果在 string2 释放后仍然可用: {}", result);
+    // 换成 longest 会编译失败：返回值可能借用了已经释放的 string2
+}
+
+// 规则一：每个引用参数都有自己的生命周期
+// 规则二：只有一个输入生命周期时，它被赋给所有输出
+fn first_word(s: &str) -> &str {
+    s.split(' ').next().unwrap_or("")
+}
+
+// 上面的函数在消除之前是这样的
+#[allow(clippy::needless_lifetimes)]
+fn first_word_explicit<'a>(s: &'a str) -> &'a str {
+    s.split(' ').next().unwrap_or("")
+}
+
+struct Parser {
+    text: String,
+}
+
+impl Parser {
+    // 规则三：方法有 &self 时，self 的生命周期被赋给所有输出，即使还有其它引用参数
+    fn line_with(&self, keyword: &str) -> &str {
+        self.text.lines().find(|line| line.contains(keyword)).unwrap_or("")
+    }
+}
+
+pub fn ex3_elision() {
+    println!("生命周期消除 示例代码\n\n");
+    println!("1. 每个引用参数都获得独立的生命周期");
+    println!("2. 只有一个输入生命周期时，它被赋给所有输出生命周期");
+    println!("3. 有 &self 或 &mut self 时，self 的生命周期被赋给所有输出生命周期\n");
+
+    assert_eq!(first_word("hello world"), "hello");
+    assert_eq!(first_word_explicit("hello world"), first_word("hello world"));
+    println!("first_word(\"hello world\") = {}", first_word("hello world"));
+
+    let parser = Parser { text: "name = rust\nversion = 2021".to_string() };
+    let line = {
+        let keyword = String::from("version");
+        // 返回值借用 parser，与 keyword 无关，keyword 释放后仍然可用
+        parser.line_with(&keyword)
+    };
+    assert_eq!(line, "version = 2021");
+    println!("parser.line_with(\"version\") = {}", line);
+
+    // 有两个引用参数且没有 self 时无法消除，longest 必须手动标注
+    println!("longest(x: &str, y: &str) -> &str 不满足消除规则，需要手动标注 'a");
+}
+
+// 结构体持有 &str，实例不能比 part 引用的字符串活得更久
+#[derive(Debug)]
+struct ImportantExcerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> ImportantExcerpt<'a> {
+    fn level(&self) -> i32 {
+        3
+    }
+
+    // 返回的引用来自 self.part，可以比 &self 这次借用活得更久
+    fn part(&self) -> &'a str {
+        self.part
+    }
+}
+
+pub fn ex4_struct_lifetime() {
+    println!("结构体中的引用 示例代码\n\n");
+
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence = novel.split('.').next().expect("Could not find a '.'");
+    let excerpt = ImportantExcerpt { part: first_sentence };
+    assert_eq!(excerpt.part, "Call me Ishmael");
+    assert_eq!(excerpt.level(), 3);
+    println!("{:?}", excerpt);
+
+    let part = {
+        let borrowed = &excerpt;
+        borrowed.part()
+    };
+    assert_eq!(part, "Call me Ishmael");
+    println!("part() 的返回值与 novel 的生命周期相同: {}", part);
+}
+
+// T: 'static 表示 T 不包含非 'static 的引用，拥有所有权的类型都满足
+fn keep_forever<T: std::fmt::Debug + 'static>(value: T) -> String {
+    format!("{:?}", value)
+}
+
+// T: 'a 表示 T 中的引用至少和 'a 一样长
+struct Ref<'a, T: 'a> {
+    value: &'a T,
+}
+
+pub fn ex5_static_bound() {
+    println!("'static 与生命周期约束 示例代码\n\n");
+
+    // 字符串字面量存放在程序的只读数据中，生命周期是 'static
+    let s: &'static str = "我一直都在";
+    assert_eq!(s.len(), 15);
+    println!("{}", s);
+
+    // String 拥有数据，满足 T: 'static；&'static str 也满足
+    assert_eq!(keep_forever(String::from("owned")), "\"owned\"");
+    assert_eq!(keep_forever(s), "\"我一直都在\"");
+    // 借用局部变量的引用不满足：keep_forever(&String::from("x")) 会编译失败
+    println!("keep_forever(String) 与 keep_forever(&'static str) 都可以");
+
+    // Box::leak 可以在运行时得到 'static 引用，代价是这块内存不再释放
+    let leaked: &'static str = Box::leak(String::from("leaked").into_boxed_str());
+    assert_eq!(keep_forever(leaked), "\"leaked\"");
+    println!("Box::leak 得到 &'static str: {}", leaked);
+
+    let number = 42;
+    let r = Ref { value: &number };
+    assert_eq!(*r.value, 42);
+    println!("Ref {{ value: {} }}", r.value);
+}
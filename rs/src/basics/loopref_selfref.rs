@@ -0,0 +1,204 @@
+use std::cell::RefCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::rc::{Rc, Weak};
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_rc_cycle, "Rc 循环引用", "两个 Rc 互相引用时引用计数永远不会归零，造成内存泄漏"),
+    example!(ex2_weak_tree, "Weak 打破循环", "树中子节点用 Weak 指向父节点"),
+    example!(ex3_selfref_index, "用索引代替自引用", "保存位置而不是引用，结构体可以自由移动"),
+    example!(ex4_pin_selfref, "Pin 与自引用结构体", "用 Pin 和 PhantomPinned 保证自引用结构体不会被移动"),
+];
+
+// 链表节点，next 可以指向另一个节点，于是有可能形成环
+struct Node {
+    name: &'static str,
+    next: RefCell<Option<Rc<Node>>>,
+    drops: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        self.drops.borrow_mut().push(self.name);
+    }
+}
+
+pub fn ex1_rc_cycle() {
+    println!("Rc 循环引用 示例代码\n\n");
+
+    let drops = Rc::new(RefCell::new(Vec::new()));
+    let a = Rc::new(Node { name: "a", next: RefCell::new(None), drops: Rc::clone(&drops) });
+    let b = Rc::new(Node { name: "b", next: RefCell::new(Some(Rc::clone(&a))), drops: Rc::clone(&drops) });
+    assert_eq!(Rc::strong_count(&a), 2);
+    assert_eq!(Rc::strong_count(&b), 1);
+    println!("b -> a 之后: a 的强引用计数 = {}, b 的强引用计数 = {}", Rc::strong_count(&a), Rc::strong_count(&b));
+
+    // a -> b，形成环 a -> b -> a
+    *a.next.borrow_mut() = Some(Rc::clone(&b));
+    assert_eq!(Rc::strong_count(&a), 2);
+    assert_eq!(Rc::strong_count(&b), 2);
+    println!("a -> b 之后: a 的强引用计数 = {}, b 的强引用计数 = {}", Rc::strong_count(&a), Rc::strong_count(&b));
+
+    // 沿着环可以一直走下去
+    let third = a.next.borrow().as_ref().unwrap().next.borrow().as_ref().unwrap().name;
+    assert_eq!(third, "a");
+
+    // 离开作用域时各减 1，计数仍为 1，两个节点都不会被释放
+    let weak_a = Rc::downgrade(&a);
+    drop(a);
+    drop(b);
+    assert!(drops.borrow().is_empty());
+    assert_eq!(weak_a.strong_count(), 1);
+    println!("drop(a)、drop(b) 之后被释放的节点: {:?}，a 的强引用计数仍为 {}", drops.borrow(), weak_a.strong_count());
+
+    // 手动断开环，节点才会被释放
+    let a = weak_a.upgrade().unwrap();
+    let b = a.next.borrow_mut().take().unwrap();
+    drop(a);
+    drop(b);
+    assert_eq!(*drops.borrow(), ["b", "a"]);
+    println!("断开环之后被释放的节点: {:?}", drops.borrow());
+}
+
+#[derive(Debug)]
+struct TreeNode {
+    value: i32,
+    parent: RefCell<Weak<TreeNode>>,
+    children: RefCell<Vec<Rc<TreeNode>>>,
+}
+
+impl TreeNode {
+    fn new(value: i32) -> Rc<TreeNode> {
+        Rc::new(TreeNode { value, parent: RefCell::new(Weak::new()), children: RefCell::new(Vec::new()) })
+    }
+}
+
+pub fn ex2_weak_tree() {
+    println!("Weak 打破循环 示例代码\n\n");
+
+    let leaf = TreeNode::new(3);
+    assert!(leaf.parent.borrow().upgrade().is_none());
+    println!("leaf 的父节点: {:?}", leaf.parent.borrow().upgrade().map(|p| p.value));
+
+    {
+        let branch = TreeNode::new(5);
+        branch.children.borrow_mut().push(Rc::clone(&leaf));
+        // 父节点拥有子节点（Rc），子节点只是指向父节点（Weak）
+        *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+
+        assert_eq!(leaf.parent.borrow().upgrade().map(|p| p.value), Some(5));
+        assert_eq!((Rc::strong_count(&branch), Rc::weak_count(&branch)), (1, 1));
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (2, 0));
+        println!("leaf 的父节点: {:?}", leaf.parent.borrow().upgrade().map(|p| p.value));
+        println!("branch strong = {}, weak = {}", Rc::strong_count(&branch), Rc::weak_count(&branch));
+        println!("leaf strong = {}, weak = {}", Rc::strong_count(&leaf), Rc::weak_count(&leaf));
+    }
+
+    // branch 离开作用域后被释放，Weak 无法再升级
+    assert!(leaf.parent.borrow().upgrade().is_none());
+    assert_eq!(Rc::strong_count(&leaf), 1);
+    println!("branch 释放之后 leaf 的父节点: {:?}，leaf strong = {}", leaf.parent.borrow().upgrade().map(|p| p.value), Rc::strong_count(&leaf));
+}
+
+// 想让 words 借用 text 会得到自引用结构体，移动结构体后引用就失效了；保存字节范围则没有这个问题
+#[derive(Debug)]
+struct Document {
+    text: String,
+    words: Vec<(usize, usize)>,
+}
+
+impl Document {
+    fn new(text: &str) -> Document {
+        let mut words = Vec::new();
+        let mut start = None;
+        for (i, c) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+            match (c.is_whitespace(), start) {
+                (true, Some(s)) => {
+                    words.push((s, i));
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => {}
+            }
+        }
+        Document { text: text.to_string(), words }
+    }
+
+    fn word(&self, index: usize) -> Option<&str> {
+        self.words.get(index).map(|&(start, end)| &self.text[start..end])
+    }
+}
+
+pub fn ex3_selfref_index() {
+    println!("用索引代替自引用 示例代码\n\n");
+
+    let doc = Document::new("借用 检查器 不允许 自引用");
+    // 移动到 Box 中，索引仍然有效
+    let moved = Box::new(doc);
+    assert_eq!(moved.words.len(), 4);
+    assert_eq!(moved.word(1), Some("检查器"));
+    assert_eq!(moved.word(4), None);
+    let words: Vec<&str> = (0..moved.words.len()).filter_map(|i| moved.word(i)).collect();
+    assert_eq!(words, ["借用", "检查器", "不允许", "自引用"]);
+    println!("移动之后的单词: {:?}", words);
+}
+
+// slice 指向同一个结构体中的 data，结构体一旦移动，指针就会悬垂
+struct SelfRef {
+    data: String,
+    slice: NonNull<String>,
+    // 让 SelfRef 不实现 Unpin，Pin 之后无法在安全代码中拿到 &mut SelfRef
+    _pin: PhantomPinned,
+}
+
+impl SelfRef {
+    fn new(data: &str) -> Pin<Box<SelfRef>> {
+        let value = SelfRef { data: data.to_string(), slice: NonNull::dangling(), _pin: PhantomPinned };
+        let mut boxed = Box::pin(value);
+        let slice = NonNull::from(&boxed.data);
+        // 修改 Pin 中的字段需要 unsafe，这里只修改指针，不会移动整个值
+        unsafe {
+            boxed.as_mut().get_unchecked_mut().slice = slice;
+        }
+        boxed
+    }
+
+    fn data(self: Pin<&Self>) -> &str {
+        &self.get_ref().data
+    }
+
+    fn slice(self: Pin<&Self>) -> &str {
+        // 值被固定在堆上，指针在整个生命周期内都有效
+        unsafe { self.get_ref().slice.as_ref() }
+    }
+
+    fn points_to_self(self: Pin<&Self>) -> bool {
+        std::ptr::eq(self.slice.as_ptr(), &self.get_ref().data)
+    }
+}
+
+pub fn ex4_pin_selfref() {
+    println!("Pin 与自引用结构体 示例代码\n\n");
+
+    let pinned = SelfRef::new("hello");
+    assert_eq!(pinned.as_ref().slice(), "hello");
+    assert!(pinned.as_ref().points_to_self());
+
+    // 移动的是 Pin<Box<_>> 这个指针，堆上的 SelfRef 没有移动，自引用仍然有效
+    let named = ("moved", pinned);
+    assert!(named.1.as_ref().points_to_self());
+    assert_eq!(named.1.as_ref().slice(), named.1.as_ref().data());
+    println!("Pin<Box<SelfRef>> 移动到元组之后 slice 仍然指向自己的 data: {}", named.1.as_ref().slice());
+
+    // std::mem::swap(&mut *a, &mut *b) 无法编译：SelfRef 没有实现 Unpin，拿不到 &mut SelfRef
+    // 实现了 Unpin 的类型（大多数类型）即使被 Pin 包装也可以随意移动
+    let mut x = 5;
+    let mut pinned_int = Pin::new(&mut x);
+    *pinned_int.as_mut() = 6;
+    assert_eq!(*pinned_int, 6);
+    println!("i32 实现了 Unpin，Pin<&mut i32> 可以直接修改: {}", *pinned_int);
+}
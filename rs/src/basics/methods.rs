@@ -1,3 +1,10 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(impl_method, "方法", "impl 块、关联函数与 &self 方法"),
+];
+
 /* 
 Rust 使用 `impl` 来定义方法
 Rust 的方法往往跟结构体、枚举、特征(Trait)一起使用
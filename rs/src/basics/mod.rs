@@ -1,62 +1,64 @@
+// 最早的这几个模块为了演示语法，刻意保留了未使用的变量、非标准的命名等写法，只在这里关闭警告
+#[allow(warnings)]
 pub mod variables;
+#[allow(warnings)]
 pub mod basic_types;
+#[allow(warnings)]
 pub mod controls;
+#[allow(warnings)]
 pub mod methods;
+#[allow(warnings)]
 pub mod compound_types;
+#[allow(warnings)]
 pub mod generics_traits;
+#[allow(warnings)]
 pub mod set_types;
 pub mod pointer;
+#[allow(warnings)]
 pub mod comments;
+pub mod closure;
+pub mod deepintypes;
+pub mod error_handle;
+pub mod fmt;
+pub mod functional;
+pub mod global_variable;
+pub mod iter;
+pub mod lifetime;
+pub mod loopref_selfref;
+pub mod package_module;
+pub mod panic_result;
+pub mod compile_fail;
+
+use crate::registry::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.module("basics", "Rust 基础语法");
+    registry.register(variables::EXAMPLES);
+    registry.register(basic_types::EXAMPLES);
+    registry.register(compound_types::EXAMPLES);
+    registry.register(controls::EXAMPLES);
+    registry.register(methods::EXAMPLES);
+    registry.register(generics_traits::EXAMPLES);
+    registry.register(set_types::EXAMPLES);
+    registry.register(pointer::EXAMPLES);
+    registry.register(closure::EXAMPLES);
+    registry.register(deepintypes::EXAMPLES);
+    registry.register(error_handle::EXAMPLES);
+    registry.register(fmt::EXAMPLES);
+    registry.register(functional::EXAMPLES);
+    registry.register(global_variable::EXAMPLES);
+    registry.register(iter::EXAMPLES);
+    registry.register(lifetime::EXAMPLES);
+    registry.register(loopref_selfref::EXAMPLES);
+    registry.register(package_module::EXAMPLES);
+    registry.register(panic_result::EXAMPLES);
+    registry.register(comments::EXAMPLES);
+    registry.register(compile_fail::EXAMPLES);
+}
 
 pub fn run_all() {
     println!("==== ==== ==== ==== 运行 basics 模块所有示例 ==== ==== ==== ====");
-    variables::ex1_variable_run();
-    variables::ex2_name_rules();
-    variables::ex3_unpack_run();
-    variables::ex4_const_run();
-    variables::ex5_variable_shadowing_run();
-    variables::ownership_borrow();
-
-    basic_types::char_type();
-    basic_types::functions_type();
-    basic_types::unit_type();
-    basic_types::statements_expressions_type();
-
-    compound_types::array_type();
-    compound_types::enum_type();
-    compound_types::string_slice_type();
-    compound_types::struct_type();
-    compound_types::tuple_type();
-
-
-    controls::condition_control_run();
-    controls::loop_control_run();
-
-
-    methods::impl_method();
-
-    generics_traits::const_fn_run();
-
-    set_types::vec_test();
-    set_types::binary_heap_test();
-    set_types::btree_map_test();
-    set_types::btree_set_test();
-    set_types::hashmap_test();
-    set_types::hashset_test();
-    set_types::link_list_test();
-    set_types::vec_deque_test();
-    
-    pointer::box_pointer_test();
-
-
-
-    comments::comment_line();
-    comments::comment_doc();
-    comments::doc_test(1);
-    comments::panic_test(1, 1);
-
-
-
-
-    
-}
\ No newline at end of file
+    let mut registry = Registry::new();
+    register(&mut registry);
+    registry.run_module("basics").print_summary();
+}
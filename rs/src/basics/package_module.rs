@@ -0,0 +1,171 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_module_tree, "模块树", "mod 定义模块，crate、self、super 构成的路径"),
+    example!(ex2_visibility, "可见性", "pub、pub(crate)、pub(super)，以及结构体和枚举的可见性"),
+    example!(ex3_use_reexport, "use 与重导出", "use 引入路径、as 重命名、pub use 重导出"),
+];
+
+// 一个餐厅的模块树：
+// crate
+//  └── restaurant
+//      ├── front_of_house
+//      │   ├── hosting
+//      │   └── serving
+//      └── back_of_house
+mod restaurant {
+    pub mod front_of_house {
+        pub mod hosting {
+            pub fn add_to_waitlist() -> &'static str {
+                "加入等位名单"
+            }
+
+            pub fn seat_at_table() -> String {
+                // super 指向父模块 front_of_house
+                format!("{} -> {}", add_to_waitlist(), super::serving::take_order())
+            }
+        }
+
+        pub mod serving {
+            pub fn take_order() -> &'static str {
+                "点餐"
+            }
+
+            // 没有 pub，只能在 serving 及其子模块中使用
+            fn take_payment() -> &'static str {
+                "结账"
+            }
+
+            pub fn finish() -> &'static str {
+                take_payment()
+            }
+        }
+    }
+
+    pub mod back_of_house {
+        // 结构体设为 pub，字段仍然默认私有
+        #[derive(Debug)]
+        pub struct Breakfast {
+            pub toast: String,
+            seasonal_fruit: String,
+        }
+
+        impl Breakfast {
+            // 有私有字段时，外部只能通过关联函数构造
+            pub fn summer(toast: &str) -> Breakfast {
+                Breakfast { toast: toast.to_string(), seasonal_fruit: String::from("桃子") }
+            }
+
+            pub fn fruit(&self) -> &str {
+                &self.seasonal_fruit
+            }
+        }
+
+        // 枚举设为 pub，所有成员都是 pub
+        #[derive(Debug, PartialEq)]
+        pub enum Appetizer {
+            Soup,
+            Salad,
+        }
+
+        // 整个 crate 内可见
+        pub(crate) fn chef() -> &'static str {
+            "主厨"
+        }
+
+        // 只在父模块 restaurant 中可见
+        pub(super) fn secret_recipe() -> &'static str {
+            "秘方"
+        }
+    }
+
+    pub fn open() -> String {
+        // self 指向当前模块，crate 指向 crate 根，这里两种写法等价
+        let a = self::back_of_house::secret_recipe();
+        let b = crate::basics::package_module::restaurant::back_of_house::secret_recipe();
+        assert_eq!(a, b);
+        format!("{}准备好了{}", back_of_house::chef(), a)
+    }
+
+    // 重导出：外部可以用 restaurant::hosting 代替 restaurant::front_of_house::hosting
+    pub use self::front_of_house::hosting;
+}
+
+pub fn ex1_module_tree() {
+    println!("模块树 示例代码\n\n");
+
+    // 绝对路径从 crate 开始，相对路径从当前模块开始
+    let absolute = crate::basics::package_module::restaurant::front_of_house::hosting::add_to_waitlist();
+    let relative = restaurant::front_of_house::hosting::add_to_waitlist();
+    assert_eq!(absolute, relative);
+    println!("绝对路径与相对路径调用同一个函数: {}", absolute);
+
+    let seat = restaurant::front_of_house::hosting::seat_at_table();
+    assert_eq!(seat, "加入等位名单 -> 点餐");
+    println!("通过 super 调用兄弟模块: {}", seat);
+
+    assert_eq!(restaurant::open(), "主厨准备好了秘方");
+    println!("{}", restaurant::open());
+}
+
+pub fn ex2_visibility() {
+    println!("可见性 示例代码\n\n");
+    println!("pub         : 任何地方可见");
+    println!("pub(crate)  : 当前 crate 内可见");
+    println!("pub(super)  : 父模块内可见");
+    println!("pub(in path): 指定的祖先模块内可见");
+    println!("默认        : 当前模块及其子模块内可见\n");
+
+    use restaurant::back_of_house::{Appetizer, Breakfast};
+
+    let mut meal = Breakfast::summer("黑麦面包");
+    // 公有字段可以修改
+    meal.toast = String::from("小麦面包");
+    assert_eq!(meal.toast, "小麦面包");
+    // meal.seasonal_fruit = String::from("蓝莓"); // 这里会报错：字段是私有的
+    assert_eq!(meal.fruit(), "桃子");
+    println!("早餐: {}，水果只能通过方法读取: {}", meal.toast, meal.fruit());
+
+    let orders = [Appetizer::Soup, Appetizer::Salad];
+    assert_eq!(orders.len(), 2);
+    println!("枚举成员都是公有的: {:?}", orders);
+
+    // pub(crate) 在 crate 内任何位置都能访问
+    assert_eq!(restaurant::back_of_house::chef(), "主厨");
+    // restaurant::back_of_house::secret_recipe(); // 这里会报错：只在 restaurant 内可见
+    // restaurant::front_of_house::serving::take_payment(); // 这里会报错：私有函数
+    assert_eq!(restaurant::front_of_house::serving::finish(), "结账");
+    println!("私有函数通过公有函数间接调用: {}", restaurant::front_of_house::serving::finish());
+}
+
+pub fn ex3_use_reexport() {
+    println!("use 与重导出 示例代码\n\n");
+
+    // 函数习惯引入到父模块，调用时保留模块名，能看出函数来自哪里
+    use restaurant::front_of_house::serving;
+    assert_eq!(serving::take_order(), "点餐");
+
+    // 结构体、枚举习惯引入完整路径；同名时用 as 重命名
+    use std::fmt::Result;
+    use std::io::Result as IoResult;
+    let fmt_ok: Result = Ok(());
+    let io_ok: IoResult<u8> = Ok(1);
+    assert!(fmt_ok.is_ok() && io_ok.is_ok());
+    println!("std::fmt::Result 与 std::io::Result 通过 as 区分");
+
+    // 嵌套路径和 self 合并多个 use
+    use std::collections::{self, BTreeSet};
+    let set: BTreeSet<i32> = [3, 1, 2].into_iter().collect();
+    let map: collections::BTreeMap<i32, i32> = set.iter().map(|&x| (x, x * x)).collect();
+    assert_eq!(map[&3], 9);
+    println!("use std::collections::{{self, BTreeSet}}: {:?}", map);
+
+    // 通过 pub use 重导出的短路径
+    assert_eq!(restaurant::hosting::add_to_waitlist(), restaurant::front_of_house::hosting::add_to_waitlist());
+    println!("restaurant::hosting 是 restaurant::front_of_house::hosting 的重导出");
+
+    // 本项目也是这样组织的：lib.rs 中的 pub mod 声明模块树，basics/mod.rs 再声明子模块
+    println!("本项目中这个示例的路径: {}", module_path!());
+    assert_eq!(module_path!(), "rust_code_examples::basics::package_module");
+}
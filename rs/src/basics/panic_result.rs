@@ -0,0 +1,150 @@
+use std::panic;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_panic, "不可恢复的错误", "数组越界会 panic，程序在这里停止").should_panic(),
+    example!(ex2_result, "可恢复的错误", "用 Result 把错误交给调用者处理"),
+    example!(ex3_unwrap_expect, "unwrap 与 expect", "出错时 panic 的两种快捷方式，以及 catch_unwind"),
+    example!(ex4_when_to_panic, "何时 panic", "违反约定时 panic，预期会发生的错误返回 Result"),
+];
+
+pub fn ex1_panic() {
+    println!("不可恢复的错误 示例代码\n\n");
+    println!("panic 时默认会展开（unwind）栈并清理数据，也可以在 Cargo.toml 中设置 panic = 'abort' 直接终止");
+    println!("设置环境变量 RUST_BACKTRACE=1 可以看到调用栈\n");
+
+    let v = [1, 2, 3];
+    let index = v.len() + 96;
+    // 越界访问不会读到无效内存，而是直接 panic
+    println!("访问 v[{}]", index);
+    let _ = v[index];
+}
+
+#[derive(Debug, PartialEq)]
+enum WithdrawError {
+    InsufficientFunds { needed: u64 },
+    AccountFrozen,
+}
+
+struct Account {
+    balance: u64,
+    frozen: bool,
+}
+
+impl Account {
+    // 余额不足是正常业务中会发生的情况，应当返回 Result 而不是 panic
+    fn withdraw(&mut self, amount: u64) -> Result<u64, WithdrawError> {
+        if self.frozen {
+            return Err(WithdrawError::AccountFrozen);
+        }
+        if amount > self.balance {
+            return Err(WithdrawError::InsufficientFunds { needed: amount - self.balance });
+        }
+        self.balance -= amount;
+        Ok(self.balance)
+    }
+}
+
+pub fn ex2_result() {
+    println!("可恢复的错误 示例代码\n\n");
+
+    let mut account = Account { balance: 100, frozen: false };
+    assert_eq!(account.withdraw(30), Ok(70));
+    assert_eq!(account.withdraw(100), Err(WithdrawError::InsufficientFunds { needed: 30 }));
+    // 失败的操作不会改变余额
+    assert_eq!(account.balance, 70);
+
+    for amount in [30, 100] {
+        match account.withdraw(amount) {
+            Ok(balance) => println!("取出 {}，余额 {}", amount, balance),
+            Err(WithdrawError::InsufficientFunds { needed }) => println!("取出 {} 失败，还差 {}", amount, needed),
+            Err(e) => println!("取出 {} 失败: {:?}", amount, e),
+        }
+    }
+
+    let mut frozen = Account { balance: 100, frozen: true };
+    assert_eq!(frozen.withdraw(1), Err(WithdrawError::AccountFrozen));
+    println!("冻结的账户: {:?}", frozen.withdraw(1));
+}
+
+pub fn ex3_unwrap_expect() {
+    println!("unwrap 与 expect 示例代码\n\n");
+
+    // 确定不会出错时，unwrap 直接取出值
+    let n: i32 = "42".parse().unwrap();
+    assert_eq!(n, 42);
+    println!("\"42\".parse().unwrap() = {}", n);
+
+    // catch_unwind 捕获 panic，得到 panic 的载荷；它不是用来做错误处理的，只用于隔离边界（例如线程池、FFI）
+    let caught = panic::catch_unwind(|| "abc".parse::<i32>().unwrap());
+    let payload = caught.unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert_eq!(message, "called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }");
+    println!("unwrap 的 panic 信息: {}", message);
+
+    // expect 可以说明为什么认为这里不会出错，panic 信息更容易定位
+    let caught = panic::catch_unwind(|| "abc".parse::<i32>().expect("配置中的端口号应当是数字"));
+    let payload = caught.unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("配置中的端口号应当是数字: "));
+    println!("expect 的 panic 信息: {}", message);
+
+    // Option 同样有 unwrap 和 expect
+    let empty: Vec<i32> = Vec::new();
+    let caught = panic::catch_unwind(|| *empty.first().expect("列表不应为空"));
+    let payload = caught.unwrap_err();
+    let message = payload.downcast_ref::<String>().cloned().or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()));
+    assert_eq!(message.as_deref(), Some("列表不应为空"));
+    println!("Option::expect 的 panic 信息: {}", message.unwrap());
+}
+
+// 通过构造函数保证取值范围，之后使用 Guess 的代码不需要再检查
+#[derive(Debug)]
+struct Guess {
+    value: i32,
+}
+
+impl Guess {
+    // 调用者传入超出范围的值是程序的 bug，panic；外部输入应先用 try_new 校验
+    fn new(value: i32) -> Guess {
+        Guess::try_new(value).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn try_new(value: i32) -> Result<Guess, String> {
+        if (1..=100).contains(&value) {
+            Ok(Guess { value })
+        } else {
+            Err(format!("猜测的数字必须在 1 到 100 之间，实际为 {}", value))
+        }
+    }
+
+    fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+pub fn ex4_when_to_panic() {
+    println!("何时 panic 示例代码\n\n");
+    println!("- 示例、原型和测试中可以直接 unwrap");
+    println!("- 代码进入了不应该出现的状态（违反约定、不变量被破坏）时 panic");
+    println!("- 失败是可以预期的（用户输入、网络、文件）时返回 Result\n");
+
+    assert_eq!(Guess::new(50).value(), 50);
+    println!("Guess::new(50) = {:?}", Guess::new(50));
+
+    // 外部输入先校验
+    for input in ["42", "0", "abc"] {
+        let guess = input.parse::<i32>().map_err(|e| e.to_string()).and_then(Guess::try_new);
+        match guess {
+            Ok(g) => println!("输入 {:?} => {:?}", input, g),
+            Err(e) => println!("输入 {:?} => 错误: {}", input, e),
+        }
+    }
+    assert!(Guess::try_new(0).is_err());
+
+    let caught = panic::catch_unwind(|| Guess::new(200));
+    assert!(caught.is_err());
+    println!("Guess::new(200) 触发 panic");
+}
@@ -1,3 +1,10 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(box_pointer_test, "Box", "Box 堆分配、所有权转移与 Box::leak"),
+];
+
 
 
 // Box
@@ -74,9 +81,12 @@ pub fn box_pointer_test() {
 
 
 // Rc
+// 单线程的引用计数指针，克隆只增加计数。持久化数据结构用它让新旧版本共享节点，
+// 见 `data_structure::persistent`，其中的示例用 `Rc::strong_count` 观察共享的节点
 
 
 // Arc
+// 原子引用计数，可以在线程之间共享。`data_structure::persistent::ArcVec` / `ArcMap` 因此是 `Send + Sync` 的
 
 
 // Cell
@@ -1,3 +1,17 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(vec_test, "Vec", "动态数组的创建、访问、容量与排序"),
+    example!(binary_heap_test, "BinaryHeap", "最大堆、用 Reverse 实现最小堆与 peek_mut"),
+    example!(btree_map_test, "BTreeMap", "有序映射的 entry 更新与范围查询"),
+    example!(btree_set_test, "BTreeSet", "有序集合的范围查询、集合运算与拆分"),
+    example!(hashmap_test, "HashMap", "哈希表的创建、查询与 entry 更新"),
+    example!(hashset_test, "HashSet", "哈希集合的去重与集合运算"),
+    example!(link_list_test, "LinkedList", "双向链表在中间位置的拆分、插入与拼接"),
+    example!(vec_deque_test, "VecDeque", "双端队列的两端操作、旋转与 make_contiguous"),
+];
+
 // ======================= Vector =======================
 // 动态数组类型用 `Vec<T>` 表示
 // 动态数组允许你存储多个值，这些值在内存中一个紧挨着另一个排列，因此访问其中某个元素的成本非常低。动态数组只能存储相同类型的元素，如果你想存储不同类型的元素，可以使用之前讲过的枚举类型或者特征对象
@@ -157,6 +171,7 @@ pub fn vec_test() {
         Person::new("John".to_string(), 1),
     ];
     // 定义一个按照年龄倒序排序的对比函数
+    // 这些排序算法的实现和比较、交换次数见 algo::sort
     people.sort_unstable_by(|a, b| b.age.cmp(&a.age));
 
     println!("{:?}", people);
@@ -188,8 +203,11 @@ pub fn hashmap_test() {
     ];
 
     let teams_map: HashMap<_,_> = teams_list.into_iter().collect();
-    
-    println!("{:?}",teams_map);
+
+    // `HashMap` 的遍历顺序是不确定的，每次运行都可能不同，需要稳定输出时先排序
+    let mut teams: Vec<_> = teams_map.iter().collect();
+    teams.sort();
+    println!("{:?}",teams);
 
     // `get` 方法可以获取元素
     // - `get` 方法返回一个 `Option<&i32>` 类型：当查询不到时，会返回一个 `None`，查询到时返回 `Some(&i32)`
@@ -268,48 +286,390 @@ pub fn hashmap_test() {
 
 
 // ================================= VecDeque ==============================
- 
- pub fn vec_deque_test() {
-
- }
- 
- 
- 
- 
- 
- 
- 
- 
- 
- 
+// `VecDeque<T>` 是用环形缓冲区实现的双端队列，在头部和尾部插入、删除都是 O(1)
+use std::collections::VecDeque;
+
+pub fn vec_deque_test() {
+    // 创建
+    let mut deque: VecDeque<i32> = VecDeque::with_capacity(8);
+    assert!(deque.is_empty());
+    let from_vec = VecDeque::from(vec![1, 2, 3]);
+    let collected: VecDeque<i32> = (1..=3).collect();
+    assert_eq!(from_vec, collected);
+
+    // 两端插入与删除
+    deque.push_back(2);
+    deque.push_back(3);
+    deque.push_front(1);
+    deque.push_front(0);
+    assert_eq!(deque, [0, 1, 2, 3]);
+    assert_eq!(deque.front(), Some(&0));
+    assert_eq!(deque.back(), Some(&3));
+    assert_eq!(deque.pop_front(), Some(0));
+    assert_eq!(deque.pop_back(), Some(3));
+    assert_eq!(deque, [1, 2]);
+    println!("两端操作之后: {:?}", deque);
+
+    // 按索引访问，与 Vec 一样
+    deque.extend([3, 4, 5]);
+    assert_eq!(deque[0], 1);
+    assert_eq!(deque.get(4), Some(&5));
+    assert_eq!(deque.get(5), None);
+    deque[0] = 10;
+    deque.insert(1, 11);
+    assert_eq!(deque.remove(2), Some(2));
+    assert_eq!(deque, [10, 11, 3, 4, 5]);
+    println!("索引访问、insert、remove 之后: {:?}", deque);
+
+    // 旋转：把开头的元素移到末尾，或者反过来
+    let mut ring: VecDeque<i32> = (1..=5).collect();
+    ring.rotate_left(2);
+    assert_eq!(ring, [3, 4, 5, 1, 2]);
+    ring.rotate_right(2);
+    assert_eq!(ring, [1, 2, 3, 4, 5]);
+    println!("rotate_left(2) 再 rotate_right(2): {:?}", ring);
+
+    // 环形缓冲区中的数据可能分成两段，as_slices 返回这两段
+    let mut wrapped = VecDeque::with_capacity(4);
+    wrapped.extend([1, 2, 3]);
+    wrapped.pop_front();
+    wrapped.pop_front();
+    wrapped.push_back(4);
+    wrapped.push_back(5);
+    wrapped.push_front(0);
+    let (first, second) = wrapped.as_slices();
+    assert_eq!([first, second].concat(), [0, 3, 4, 5]);
+
+    // make_contiguous 把数据整理成一段连续内存，之后可以当作切片排序、二分查找
+    wrapped.push_front(9);
+    let slice = wrapped.make_contiguous();
+    slice.sort();
+    assert_eq!(slice, [0, 3, 4, 5, 9]);
+    assert_eq!(wrapped.as_slices(), (&[0, 3, 4, 5, 9][..], &[][..]));
+    assert_eq!(wrapped.binary_search(&5), Ok(3));
+    println!("make_contiguous 之后排序: {:?}", wrapped);
+
+    // 滑动窗口：固定长度，新元素从尾部进入，旧元素从头部移出
+    let mut window = VecDeque::new();
+    let mut sums = Vec::new();
+    for x in [1, 3, 5, 7, 9] {
+        window.push_back(x);
+        if window.len() > 3 {
+            window.pop_front();
+        }
+        sums.push(window.iter().sum::<i32>());
+    }
+    assert_eq!(sums, [1, 4, 9, 15, 21]);
+    println!("长度为 3 的滑动窗口之和: {:?}", sums);
+}
+
 //  ============================ LinkedList =========================================
+// `LinkedList<T>` 是双向链表，拼接两个链表是 O(1)，但不支持按索引访问，大多数情况下 `Vec` 或 `VecDeque` 更快
+use std::collections::LinkedList;
 
 pub fn link_list_test() {
+    let mut list: LinkedList<&str> = LinkedList::new();
+    list.push_back("b");
+    list.push_back("c");
+    list.push_front("a");
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.front(), Some(&"a"));
+    assert_eq!(list.back(), Some(&"c"));
+    assert!(list.contains(&"b"));
+    println!("创建: {:?}", list);
+
+    // 游标（cursor）API 还没有稳定，在中间位置编辑用 split_off + append 完成：
+    // 在 "a" 之后插入 "x"：先在位置 1 拆开，再把三段拼起来
+    let mut tail = list.split_off(1);
+    assert_eq!(list, LinkedList::from(["a"]));
+    assert_eq!(tail, LinkedList::from(["b", "c"]));
+    list.push_back("x");
+    list.append(&mut tail);
+    // append 之后 tail 被清空，节点直接移动过去，没有复制元素
+    assert!(tail.is_empty());
+    assert_eq!(list, LinkedList::from(["a", "x", "b", "c"]));
+    println!("在位置 1 插入 x: {:?}", list);
+
+    // 删除位置 2 的元素
+    let mut rest = list.split_off(2);
+    let removed = rest.pop_front();
+    list.append(&mut rest);
+    assert_eq!(removed, Some("b"));
+    assert_eq!(list, LinkedList::from(["a", "x", "c"]));
+    println!("删除位置 2 的元素 {:?}: {:?}", removed, list);
+
+    // 就地修改每个元素，以及从两端遍历
+    let mut numbers: LinkedList<i32> = (1..=5).collect();
+    for n in numbers.iter_mut() {
+        *n *= 10;
+    }
+    let backward: Vec<i32> = numbers.iter().rev().copied().collect();
+    assert_eq!(backward, [50, 40, 30, 20, 10]);
+    println!("iter_mut 之后倒序遍历: {:?}", backward);
+
+    // 按条件删除：重新收集一个链表
+    let odd_tens: LinkedList<i32> = numbers.into_iter().filter(|n| n / 10 % 2 == 1).collect();
+    assert_eq!(odd_tens, LinkedList::from([10, 30, 50]));
+    println!("保留 10、30、50: {:?}", odd_tens);
 }
 
-
-// BTreeMap
+// ================================= BTreeMap ==============================
+// `BTreeMap<K, V>` 按键排序存储，遍历顺序确定，支持按范围查询，键需要实现 `Ord`
+use std::collections::BTreeMap;
 
 pub fn btree_map_test() {
+    let mut scores = BTreeMap::new();
+    scores.insert("王五", 72);
+    scores.insert("张三", 90);
+    scores.insert("李四", 85);
+    // 遍历时总是按键的顺序，与插入顺序无关
+    let names: Vec<&str> = scores.keys().copied().collect();
+    assert_eq!(names, ["张三", "李四", "王五"]);
+    println!("按键排序: {:?}", scores);
+
+    // entry API：不存在时插入，存在时修改
+    *scores.entry("李四").or_insert(0) += 5;
+    scores.entry("赵六").or_insert(60);
+    scores.entry("王五").and_modify(|s| *s += 10).or_insert(0);
+    assert_eq!(scores["李四"], 90);
+    assert_eq!(scores["赵六"], 60);
+    assert_eq!(scores["王五"], 82);
+
+    // entry 也可以按是否存在分别处理
+    use std::collections::btree_map::Entry;
+    match scores.entry("张三") {
+        Entry::Occupied(e) => {
+            assert_eq!(e.remove(), 90);
+        }
+        Entry::Vacant(e) => {
+            e.insert(0);
+        }
+    }
+    assert!(!scores.contains_key("张三"));
+    println!("entry 更新之后: {:?}", scores);
 
+    // 有序带来的查询：最小、最大、范围
+    let mut temps: BTreeMap<u32, f64> = BTreeMap::new();
+    for (hour, t) in [(0, 12.5), (3, 11.0), (6, 10.5), (9, 15.0), (12, 21.0), (15, 23.5), (18, 19.0), (21, 15.5)] {
+        temps.insert(hour, t);
+    }
+    assert_eq!(temps.first_key_value(), Some((&0, &12.5)));
+    assert_eq!(temps.last_key_value(), Some((&21, &15.5)));
+
+    // 9 点到 15 点（含）之间的记录
+    let daytime: Vec<(u32, f64)> = temps.range(9..=15).map(|(&h, &t)| (h, t)).collect();
+    assert_eq!(daytime, [(9, 15.0), (12, 21.0), (15, 23.5)]);
+    println!("9..=15 点: {:?}", daytime);
+
+    // 不晚于 10 点的最后一条记录，以及 10 点之后的第一条
+    assert_eq!(temps.range(..=10).next_back(), Some((&9, &15.0)));
+    assert_eq!(temps.range(10..).next(), Some((&12, &21.0)));
+    println!("10 点之前最近的记录: {:?}，之后最近的记录: {:?}", temps.range(..=10).next_back(), temps.range(10..).next());
+
+    // split_off 把大于等于给定键的部分拆成新的 BTreeMap
+    let afternoon = temps.split_off(&12);
+    assert_eq!(temps.len(), 4);
+    assert_eq!(afternoon.keys().copied().collect::<Vec<_>>(), [12, 15, 18, 21]);
+    println!("split_off(&12) 之后: 上午 {:?}，下午 {:?}", temps.keys().collect::<Vec<_>>(), afternoon.keys().collect::<Vec<_>>());
+
+    // retain 按条件保留
+    temps.retain(|_, t| *t >= 11.0);
+    assert_eq!(temps.keys().copied().collect::<Vec<_>>(), [0, 3, 9]);
 }
 
+// ================================= HashSet ==============================
+// `HashSet<T>` 是只有键的 `HashMap`，元素不重复，遍历顺序不确定
+use std::collections::HashSet;
 
-// HashSet
-pub fn hashset_test() {
-
+// HashSet 的遍历顺序每次运行都可能不同，输出前先排序
+fn sorted<T: Ord + Clone>(set: &HashSet<T>) -> Vec<T> {
+    let mut items: Vec<T> = set.iter().cloned().collect();
+    items.sort();
+    items
 }
 
+pub fn hashset_test() {
+    // 插入重复的元素会失败
+    let mut languages = HashSet::new();
+    assert!(languages.insert("Rust"));
+    assert!(languages.insert("Go"));
+    assert!(!languages.insert("Rust"));
+    assert_eq!(languages.len(), 2);
+    assert!(languages.contains("Go"));
+    assert!(languages.remove("Go"));
+    assert!(!languages.contains("Go"));
+    println!("重复插入 Rust 之后: {:?}", sorted(&languages));
+
+    // 去重
+    let words = ["apple", "banana", "apple", "cherry", "banana"];
+    let unique: HashSet<&str> = words.iter().copied().collect();
+    assert_eq!(unique.len(), 3);
+    println!("去重: {:?}", sorted(&unique));
+
+    // 集合运算
+    let a: HashSet<i32> = [1, 2, 3, 4].into_iter().collect();
+    let b: HashSet<i32> = [3, 4, 5, 6].into_iter().collect();
+
+    let union: HashSet<i32> = a.union(&b).copied().collect();
+    let intersection: HashSet<i32> = a.intersection(&b).copied().collect();
+    let difference: HashSet<i32> = a.difference(&b).copied().collect();
+    let symmetric: HashSet<i32> = a.symmetric_difference(&b).copied().collect();
+    assert_eq!(sorted(&union), [1, 2, 3, 4, 5, 6]);
+    assert_eq!(sorted(&intersection), [3, 4]);
+    assert_eq!(sorted(&difference), [1, 2]);
+    assert_eq!(sorted(&symmetric), [1, 2, 5, 6]);
+    // 运算符版本，返回新的集合
+    assert_eq!(&a | &b, union);
+    assert_eq!(&a & &b, intersection);
+    assert_eq!(&a - &b, difference);
+    assert_eq!(&a ^ &b, symmetric);
+    println!("a = {:?}, b = {:?}", sorted(&a), sorted(&b));
+    println!("并集 {:?}，交集 {:?}，差集 {:?}，对称差 {:?}", sorted(&union), sorted(&intersection), sorted(&difference), sorted(&symmetric));
+
+    // 子集与不相交
+    let small: HashSet<i32> = [3, 4].into_iter().collect();
+    assert!(small.is_subset(&a) && small.is_subset(&b));
+    assert!(a.is_superset(&small));
+    assert!(!a.is_disjoint(&b));
+    assert!(difference.is_disjoint(&b));
+    println!("{{3, 4}} 是 a 和 b 的子集，a - b 与 b 不相交");
+
+    // 判断是否出现过：找出第一个重复的元素
+    let mut seen = HashSet::new();
+    let first_repeat = [5, 1, 4, 1, 5].into_iter().find(|x| !seen.insert(*x));
+    assert_eq!(first_repeat, Some(1));
+    println!("第一个重复出现的元素: {:?}", first_repeat);
+}
 
-
-// BTreeSet
+// ================================= BTreeSet ==============================
+// `BTreeSet<T>` 是有序的集合，遍历按从小到大的顺序
+use std::collections::BTreeSet;
 
 pub fn btree_set_test() {
-
+    let mut set: BTreeSet<i32> = [5, 1, 9, 3, 7, 3].into_iter().collect();
+    // 自动去重并排序
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 3, 5, 7, 9]);
+    assert_eq!(set.first(), Some(&1));
+    assert_eq!(set.last(), Some(&9));
+    println!("创建: {:?}", set);
+
+    // 范围查询
+    let middle: Vec<i32> = set.range(3..8).copied().collect();
+    assert_eq!(middle, [3, 5, 7]);
+    // 大于 4 的最小元素，小于 4 的最大元素
+    assert_eq!(set.range(5..).next(), Some(&5));
+    assert_eq!(set.range(..4).next_back(), Some(&3));
+    println!("range(3..8) = {:?}，大于 4 的最小元素 {:?}，小于 4 的最大元素 {:?}", middle, set.range(5..).next(), set.range(..4).next_back());
+
+    // 从两端取出元素
+    assert_eq!(set.pop_first(), Some(1));
+    assert_eq!(set.pop_last(), Some(9));
+    assert_eq!(set, BTreeSet::from([3, 5, 7]));
+
+    // 集合运算的结果同样是有序的，不需要再排序
+    let a = BTreeSet::from(['a', 'b', 'c', 'd']);
+    let b = BTreeSet::from(['c', 'd', 'e']);
+    assert_eq!(a.union(&b).collect::<String>(), "abcde");
+    assert_eq!(a.intersection(&b).collect::<String>(), "cd");
+    assert_eq!(a.difference(&b).collect::<String>(), "ab");
+    assert_eq!(a.symmetric_difference(&b).collect::<String>(), "abe");
+    assert_eq!(&a - &b, BTreeSet::from(['a', 'b']));
+    println!("a = {:?}, b = {:?}", a, b);
+    println!("并集 {}，交集 {}，差集 {}，对称差 {}",
+        a.union(&b).collect::<String>(),
+        a.intersection(&b).collect::<String>(),
+        a.difference(&b).collect::<String>(),
+        a.symmetric_difference(&b).collect::<String>());
+
+    // split_off 拆成两个集合
+    let mut letters: BTreeSet<char> = ('a'..='h').collect();
+    let second_half = letters.split_off(&'e');
+    assert_eq!(letters.iter().collect::<String>(), "abcd");
+    assert_eq!(second_half.iter().collect::<String>(), "efgh");
+    println!("split_off(&'e'): {:?} {:?}", letters, second_half);
+
+    // 自定义类型只要实现 Ord 就可以放进 BTreeSet，按派生的顺序（先 priority 再 name）排序
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Task {
+        priority: u8,
+        name: &'static str,
+    }
+    let tasks: BTreeSet<Task> = [
+        Task { priority: 2, name: "写文档" },
+        Task { priority: 1, name: "修复 bug" },
+        Task { priority: 2, name: "代码评审" },
+    ]
+    .into_iter()
+    .collect();
+    let order: Vec<&str> = tasks.iter().map(|t| t.name).collect();
+    assert_eq!(order, ["修复 bug", "代码评审", "写文档"]);
+    println!("按优先级排序的任务: {:?}", order);
 }
 
-
-// BinaryHeap
+// ================================= BinaryHeap ==============================
+// `BinaryHeap<T>` 是最大堆，`peek` 和 `pop` 总是得到最大的元素，插入和删除都是 O(log n)
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 
 pub fn binary_heap_test() {
+    let mut heap = BinaryHeap::new();
+    for x in [3, 1, 4, 1, 5, 9, 2, 6] {
+        heap.push(x);
+    }
+    assert_eq!(heap.len(), 8);
+    assert_eq!(heap.peek(), Some(&9));
+
+    // 依次弹出，得到从大到小的顺序
+    let mut popped = Vec::new();
+    while let Some(x) = heap.pop() {
+        popped.push(x);
+    }
+    assert_eq!(popped, [9, 6, 5, 4, 3, 2, 1, 1]);
+    println!("依次 pop: {:?}", popped);
+
+    // into_sorted_vec 得到升序的 Vec（堆排序）
+    let heap: BinaryHeap<i32> = [3, 1, 4, 1, 5].into_iter().collect();
+    assert_eq!(heap.into_sorted_vec(), [1, 1, 3, 4, 5]);
+
+    // 用 Reverse 包装元素，比较顺序反过来，就得到最小堆
+    let mut min_heap = BinaryHeap::new();
+    for x in [3, 1, 4, 1, 5] {
+        min_heap.push(Reverse(x));
+    }
+    assert_eq!(min_heap.peek(), Some(&Reverse(1)));
+    let ascending: Vec<i32> = std::iter::from_fn(|| min_heap.pop().map(|Reverse(x)| x)).collect();
+    assert_eq!(ascending, [1, 1, 3, 4, 5]);
+    println!("Reverse 最小堆依次 pop: {:?}", ascending);
+
+    // 前 k 大：维护一个大小为 k 的最小堆，比堆顶大的元素才替换堆顶
+    let data = [15, 3, 22, 8, 42, 16, 4, 23];
+    let k = 3;
+    let mut top: BinaryHeap<Reverse<i32>> = BinaryHeap::with_capacity(k + 1);
+    for &x in &data {
+        top.push(Reverse(x));
+        if top.len() > k {
+            top.pop();
+        }
+    }
+    let mut largest: Vec<i32> = top.into_iter().map(|Reverse(x)| x).collect();
+    largest.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(largest, [42, 23, 22]);
+    println!("前 {} 大: {:?}", k, largest);
+
+    // peek_mut 可以修改堆顶，PeekMut 被丢弃时堆会重新调整
+    let mut heap = BinaryHeap::from(vec![5, 8, 2]);
+    if let Some(mut top) = heap.peek_mut() {
+        *top = 1;
+    }
+    assert_eq!(heap.peek(), Some(&5));
+    println!("把堆顶 8 改成 1 之后，新的堆顶: {:?}", heap.peek());
+
+    // 元组按字典序比较，可以用来实现按优先级调度的任务队列
+    let mut tasks = BinaryHeap::new();
+    tasks.push((2, "写文档"));
+    tasks.push((5, "修复线上故障"));
+    tasks.push((3, "代码评审"));
+    let order: Vec<&str> = std::iter::from_fn(|| tasks.pop().map(|(_, name)| name)).collect();
+    assert_eq!(order, ["修复线上故障", "代码评审", "写文档"]);
+    println!("按优先级处理任务: {:?}", order);
 }
@@ -1,3 +1,15 @@
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_variable_run, "变量使用", "let 绑定、mut 可变性、类型推断与数值类型后缀"),
+    example!(ex2_name_rules, "命名规则", "Rust 命名规范与 as_/to_/into_、iter/iter_mut/into_iter 惯例"),
+    example!(ex3_unpack_run, "解构赋值", "元组、数组、结构体的解构式赋值"),
+    example!(ex4_const_run, "常量", "const 常量的声明"),
+    example!(ex5_variable_shadowing_run, "变量遮蔽", "同名变量在不同作用域中的遮蔽"),
+    example!(ownership_borrow, "所有权与借用", "move、Copy、clone、引用与可变引用规则"),
+];
+
 
 
 
@@ -121,7 +133,7 @@ pub fn ownership_borrow() {
 
     let s1 = String::from("hello");
     let s2 = s1;
-    // 这句话会报错因为s1已经无效，所有权从s1转到s2
+    // 这句话会报错因为s1已经无效，所有权从s1转到s2，见 compile_fail/e0382_use_after_move.rs
     // println!("{}, world!", s1);
 
     // 拷贝指针、长度和容量而不拷贝数据听起来就像浅拷贝，但是又因为 Rust 同时使第一个变量 `s1` 无效了，因此这个操作被称为 **移动(move)**
@@ -228,7 +240,7 @@ pub fn ownership_borrow() {
 
     change(&mut s);
 
-    // 下面这段代码会被borrow checker拦下来
+    // 下面这段代码会被borrow checker拦下来，见 compile_fail/e0499_two_mutable_borrows.rs
     // let mut s = String::from("hello");
 
     // let r1 = &mut s;
@@ -238,7 +250,7 @@ pub fn ownership_borrow() {
 
 
 
-    // 可变引用与不可变引用不能同时存在
+    // 可变引用与不可变引用不能同时存在，见 compile_fail/e0502_shared_and_mutable_borrow.rs
     // let mut s = String::from("hello");
 
     // let r1 = &s; // 没问题
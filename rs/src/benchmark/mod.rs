@@ -0,0 +1,445 @@
+/*!
+集合的复杂度基准测试
+
+`basics::set_types` 和 `basics::controls` 的注释中提到了不少性能上的结论，例如按索引访问有边界检查、
+`VecDeque` 在头部删除比 `Vec` 快、`sort_unstable` 比 `sort` 快，这里用实际的计时来对比。
+
+- 数据结构：`Vec`、`VecDeque`、`LinkedList`、`HashMap`、`BTreeMap`、`BinaryHeap`
+- 工作负载：插入、查找、遍历、按索引遍历、从头部 / 中间 / 尾部删除、稳定排序与非稳定排序
+- 每个组合在若干规模下运行多次，取中位数，结果打印成对比表并保存为 CSV
+
+只使用标准库的 [`Instant`] 计时，不依赖外部的基准测试框架。
+调试构建的结果没有参考意义，应当使用 `cargo run --release -- bench`。
+
+```
+use rust_code_examples::benchmark::{self, Config, Structure, Workload};
+
+let config = Config {
+    sizes: vec![64],
+    structures: vec![Structure::Vec, Structure::BinaryHeap],
+    workloads: vec![Workload::Lookup, Workload::RemoveMiddle],
+    ops: 16,
+    repeat: 1,
+    seed: 1,
+};
+let results = benchmark::run(&config);
+// BinaryHeap 不支持从中间删除，这个组合被跳过
+assert_eq!(results.len(), 3);
+assert!(benchmark::measure(Structure::BinaryHeap, Workload::RemoveMiddle, 64, &config).is_none());
+
+let csv = benchmark::to_csv(&results);
+assert!(csv.starts_with("structure,workload,size,ops,median_ns,ns_per_op\n"));
+assert_eq!(csv.lines().count(), 4);
+```
+*/
+
+use std::collections::{BTreeMap, BinaryHeap, HashMap, LinkedList, VecDeque};
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use console::Style;
+
+use crate::utils::XorShift64;
+
+/// 参与对比的数据结构
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Structure {
+    Vec,
+    VecDeque,
+    LinkedList,
+    HashMap,
+    BTreeMap,
+    BinaryHeap,
+}
+
+impl Structure {
+    pub const ALL: [Structure; 6] = [
+        Structure::Vec,
+        Structure::VecDeque,
+        Structure::LinkedList,
+        Structure::HashMap,
+        Structure::BTreeMap,
+        Structure::BinaryHeap,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Structure::Vec => "Vec",
+            Structure::VecDeque => "VecDeque",
+            Structure::LinkedList => "LinkedList",
+            Structure::HashMap => "HashMap",
+            Structure::BTreeMap => "BTreeMap",
+            Structure::BinaryHeap => "BinaryHeap",
+        }
+    }
+
+    /// 不区分大小写，例如 `vec`、`btreemap`
+    pub fn parse(name: &str) -> Option<Structure> {
+        Structure::ALL.into_iter().find(|s| s.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// 工作负载，规模为 n 的结构上执行 `ops` 次操作；遍历和排序按 n 个元素计
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Workload {
+    /// 在自然的位置插入新元素：序列追加到尾部，映射插入新键，堆 push
+    Insert,
+    /// 查找随机的已有元素：序列和堆线性查找，映射按键查找
+    Lookup,
+    /// 用迭代器遍历全部元素
+    Iterate,
+    /// 用下标 `collection[i]` 遍历全部元素，只有 Vec 和 VecDeque 支持
+    Index,
+    /// 从头部删除：序列删除第一个元素，映射删除最小的键，堆弹出堆顶
+    RemoveFront,
+    /// 从中间删除，堆不支持
+    RemoveMiddle,
+    /// 从尾部删除：序列删除最后一个元素，映射删除最大的键，堆不支持
+    RemoveBack,
+    /// 稳定排序打乱的数据，只有 Vec 和 VecDeque 支持
+    Sort,
+    /// 非稳定排序打乱的数据，只有 Vec 和 VecDeque 支持
+    SortUnstable,
+}
+
+impl Workload {
+    pub const ALL: [Workload; 9] = [
+        Workload::Insert,
+        Workload::Lookup,
+        Workload::Iterate,
+        Workload::Index,
+        Workload::RemoveFront,
+        Workload::RemoveMiddle,
+        Workload::RemoveBack,
+        Workload::Sort,
+        Workload::SortUnstable,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Workload::Insert => "insert",
+            Workload::Lookup => "lookup",
+            Workload::Iterate => "iterate",
+            Workload::Index => "index",
+            Workload::RemoveFront => "remove-front",
+            Workload::RemoveMiddle => "remove-middle",
+            Workload::RemoveBack => "remove-back",
+            Workload::Sort => "sort",
+            Workload::SortUnstable => "sort-unstable",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Workload> {
+        Workload::ALL.into_iter().find(|w| w.name() == name)
+    }
+
+    // 实际执行的操作次数
+    fn ops(self, size: usize, ops: usize) -> usize {
+        match self {
+            Workload::Iterate | Workload::Index | Workload::Sort | Workload::SortUnstable => size,
+            Workload::RemoveFront | Workload::RemoveMiddle | Workload::RemoveBack => ops.min(size),
+            Workload::Insert | Workload::Lookup => ops,
+        }
+    }
+}
+
+/// 基准测试的配置
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub sizes: Vec<usize>,
+    pub structures: Vec<Structure>,
+    pub workloads: Vec<Workload>,
+    /// 插入、查找、删除的操作次数
+    pub ops: usize,
+    /// 每个组合运行的次数，取中位数
+    pub repeat: usize,
+    /// 生成测试数据的随机数种子
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            sizes: vec![100, 1_000, 10_000],
+            structures: Structure::ALL.to_vec(),
+            workloads: Workload::ALL.to_vec(),
+            ops: 1_000,
+            repeat: 5,
+            seed: 0x5EED,
+        }
+    }
+}
+
+/// 一个组合的测量结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub structure: Structure,
+    pub workload: Workload,
+    pub size: usize,
+    pub ops: usize,
+    /// 多次运行的中位数
+    pub median: Duration,
+}
+
+impl Measurement {
+    pub fn ns_per_op(&self) -> f64 {
+        self.median.as_nanos() as f64 / self.ops.max(1) as f64
+    }
+}
+
+// 每次运行前用 setup 重新准备数据（不计时），只对 op 计时，返回中位数
+pub(crate) fn time<S, R>(repeat: usize, mut setup: impl FnMut() -> S, mut op: impl FnMut(&mut S) -> R) -> Duration {
+    let mut samples: Vec<Duration> = (0..repeat.max(1))
+        .map(|_| {
+            let mut state = setup();
+            let start = Instant::now();
+            black_box(op(&mut state));
+            let elapsed = start.elapsed();
+            drop(black_box(state));
+            elapsed
+        })
+        .collect();
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+// 测试数据：打乱的 0..n，以及 ops 个要查找的随机键
+struct Data {
+    values: Vec<u64>,
+    probes: Vec<u64>,
+}
+
+impl Data {
+    fn new(size: usize, ops: usize, seed: u64) -> Data {
+        let mut rng = XorShift64::new(seed ^ size as u64);
+        let mut values: Vec<u64> = (0..size as u64).collect();
+        rng.shuffle(&mut values);
+        let probes = (0..ops).map(|_| rng.below(size.max(1) as u64)).collect();
+        Data { values, probes }
+    }
+}
+
+fn bench_vec(workload: Workload, data: &Data, ops: usize, repeat: usize) -> Option<Duration> {
+    let setup = || data.values.clone();
+    let n = data.values.len() as u64;
+    Some(match workload {
+        Workload::Insert => time(repeat, setup, |v| (n..n + ops as u64).for_each(|x| v.push(x))),
+        Workload::Lookup => time(repeat, setup, |v| data.probes.iter().filter(|p| v.contains(p)).count()),
+        Workload::Iterate => time(repeat, setup, |v| v.iter().sum::<u64>()),
+        Workload::Index => time(repeat, setup, |v| {
+            let mut sum = 0;
+            #[allow(clippy::needless_range_loop)]
+            for i in 0..v.len() {
+                sum += v[i];
+            }
+            sum
+        }),
+        Workload::RemoveFront => time(repeat, setup, |v| (0..ops).map(|_| v.remove(0)).sum::<u64>()),
+        Workload::RemoveMiddle => time(repeat, setup, |v| (0..ops).map(|_| v.remove(v.len() / 2)).sum::<u64>()),
+        Workload::RemoveBack => time(repeat, setup, |v| (0..ops).filter_map(|_| v.pop()).sum::<u64>()),
+        Workload::Sort => time(repeat, setup, |v| v.sort()),
+        Workload::SortUnstable => time(repeat, setup, |v| v.sort_unstable()),
+    })
+}
+
+fn bench_vec_deque(workload: Workload, data: &Data, ops: usize, repeat: usize) -> Option<Duration> {
+    let setup = || data.values.iter().copied().collect::<VecDeque<u64>>();
+    let n = data.values.len() as u64;
+    Some(match workload {
+        Workload::Insert => time(repeat, setup, |d| (n..n + ops as u64).for_each(|x| d.push_back(x))),
+        Workload::Lookup => time(repeat, setup, |d| data.probes.iter().filter(|p| d.contains(p)).count()),
+        Workload::Iterate => time(repeat, setup, |d| d.iter().sum::<u64>()),
+        Workload::Index => time(repeat, setup, |d| {
+            let mut sum = 0;
+            #[allow(clippy::needless_range_loop)]
+            for i in 0..d.len() {
+                sum += d[i];
+            }
+            sum
+        }),
+        Workload::RemoveFront => time(repeat, setup, |d| (0..ops).filter_map(|_| d.pop_front()).sum::<u64>()),
+        Workload::RemoveMiddle => time(repeat, setup, |d| (0..ops).filter_map(|_| d.remove(d.len() / 2)).sum::<u64>()),
+        Workload::RemoveBack => time(repeat, setup, |d| (0..ops).filter_map(|_| d.pop_back()).sum::<u64>()),
+        Workload::Sort => time(repeat, setup, |d| d.make_contiguous().sort()),
+        Workload::SortUnstable => time(repeat, setup, |d| d.make_contiguous().sort_unstable()),
+    })
+}
+
+fn bench_linked_list(workload: Workload, data: &Data, ops: usize, repeat: usize) -> Option<Duration> {
+    let setup = || data.values.iter().copied().collect::<LinkedList<u64>>();
+    let n = data.values.len() as u64;
+    Some(match workload {
+        Workload::Insert => time(repeat, setup, |l| (n..n + ops as u64).for_each(|x| l.push_back(x))),
+        Workload::Lookup => time(repeat, setup, |l| data.probes.iter().filter(|p| l.contains(p)).count()),
+        Workload::Iterate => time(repeat, setup, |l| l.iter().sum::<u64>()),
+        Workload::RemoveFront => time(repeat, setup, |l| (0..ops).filter_map(|_| l.pop_front()).sum::<u64>()),
+        // 没有稳定的游标 API，只能先走到中间拆开，删除后再拼回去
+        Workload::RemoveMiddle => time(repeat, setup, |l| {
+            (0..ops)
+                .filter_map(|_| {
+                    let mut tail = l.split_off(l.len() / 2);
+                    let removed = tail.pop_front();
+                    l.append(&mut tail);
+                    removed
+                })
+                .sum::<u64>()
+        }),
+        Workload::RemoveBack => time(repeat, setup, |l| (0..ops).filter_map(|_| l.pop_back()).sum::<u64>()),
+        Workload::Index | Workload::Sort | Workload::SortUnstable => return None,
+    })
+}
+
+fn bench_hash_map(workload: Workload, data: &Data, ops: usize, repeat: usize) -> Option<Duration> {
+    let setup = || data.values.iter().map(|&k| (k, k)).collect::<HashMap<u64, u64>>();
+    let n = data.values.len() as u64;
+    let ops = ops as u64;
+    Some(match workload {
+        Workload::Insert => time(repeat, setup, |m| (n..n + ops).for_each(|k| {
+            m.insert(k, k);
+        })),
+        Workload::Lookup => time(repeat, setup, |m| data.probes.iter().filter_map(|p| m.get(p)).sum::<u64>()),
+        Workload::Iterate => time(repeat, setup, |m| m.values().sum::<u64>()),
+        // HashMap 没有顺序，“头部”“中间”“尾部”指键的大小
+        Workload::RemoveFront => time(repeat, setup, |m| (0..ops.min(n)).filter_map(|k| m.remove(&k)).sum::<u64>()),
+        Workload::RemoveMiddle => {
+            time(repeat, setup, |m| (n / 2..(n / 2 + ops).min(n)).filter_map(|k| m.remove(&k)).sum::<u64>())
+        }
+        Workload::RemoveBack => {
+            time(repeat, setup, |m| (n.saturating_sub(ops)..n).rev().filter_map(|k| m.remove(&k)).sum::<u64>())
+        }
+        Workload::Index | Workload::Sort | Workload::SortUnstable => return None,
+    })
+}
+
+fn bench_btree_map(workload: Workload, data: &Data, ops: usize, repeat: usize) -> Option<Duration> {
+    let setup = || data.values.iter().map(|&k| (k, k)).collect::<BTreeMap<u64, u64>>();
+    let n = data.values.len() as u64;
+    let ops = ops as u64;
+    Some(match workload {
+        Workload::Insert => time(repeat, setup, |m| (n..n + ops).for_each(|k| {
+            m.insert(k, k);
+        })),
+        Workload::Lookup => time(repeat, setup, |m| data.probes.iter().filter_map(|p| m.get(p)).sum::<u64>()),
+        Workload::Iterate => time(repeat, setup, |m| m.values().sum::<u64>()),
+        Workload::RemoveFront => time(repeat, setup, |m| (0..ops).filter_map(|_| m.pop_first()).map(|(k, _)| k).sum::<u64>()),
+        Workload::RemoveMiddle => {
+            time(repeat, setup, |m| (n / 2..(n / 2 + ops).min(n)).filter_map(|k| m.remove(&k)).sum::<u64>())
+        }
+        Workload::RemoveBack => time(repeat, setup, |m| (0..ops).filter_map(|_| m.pop_last()).map(|(k, _)| k).sum::<u64>()),
+        Workload::Index | Workload::Sort | Workload::SortUnstable => return None,
+    })
+}
+
+fn bench_binary_heap(workload: Workload, data: &Data, ops: usize, repeat: usize) -> Option<Duration> {
+    let setup = || data.values.iter().copied().collect::<BinaryHeap<u64>>();
+    let n = data.values.len() as u64;
+    Some(match workload {
+        Workload::Insert => time(repeat, setup, |h| (n..n + ops as u64).for_each(|x| h.push(x))),
+        Workload::Lookup => time(repeat, setup, |h| data.probes.iter().filter(|p| h.iter().any(|x| x == *p)).count()),
+        Workload::Iterate => time(repeat, setup, |h| h.iter().sum::<u64>()),
+        Workload::RemoveFront => time(repeat, setup, |h| (0..ops).filter_map(|_| h.pop()).sum::<u64>()),
+        Workload::Index | Workload::RemoveMiddle | Workload::RemoveBack | Workload::Sort | Workload::SortUnstable => {
+            return None
+        }
+    })
+}
+
+/// 测量一个组合，数据结构不支持该工作负载时返回 None
+pub fn measure(structure: Structure, workload: Workload, size: usize, config: &Config) -> Option<Measurement> {
+    let ops = workload.ops(size, config.ops);
+    let data = Data::new(size, config.ops, config.seed);
+    let bench = match structure {
+        Structure::Vec => bench_vec,
+        Structure::VecDeque => bench_vec_deque,
+        Structure::LinkedList => bench_linked_list,
+        Structure::HashMap => bench_hash_map,
+        Structure::BTreeMap => bench_btree_map,
+        Structure::BinaryHeap => bench_binary_heap,
+    };
+    let median = bench(workload, &data, ops, config.repeat)?;
+    Some(Measurement { structure, workload, size, ops, median })
+}
+
+/// 按工作负载、规模、数据结构的顺序测量所有组合
+pub fn run(config: &Config) -> Vec<Measurement> {
+    let mut results = Vec::new();
+    for &workload in &config.workloads {
+        for &size in &config.sizes {
+            for &structure in &config.structures {
+                results.extend(measure(structure, workload, size, config));
+            }
+        }
+    }
+    results
+}
+
+pub(crate) fn format_ns(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+/// 打印对比表：每行一个工作负载和规模，每列一个数据结构，单元格为每次操作的耗时，最快的一列高亮
+pub fn print_table(config: &Config, results: &[Measurement]) {
+    const CELL: usize = 12;
+    let fastest = Style::new().green().bold();
+    let header = Style::new().bold().cyan();
+
+    print!("{}", header.apply_to(format!("{:<15}{:>8}", "workload", "size")));
+    for structure in &config.structures {
+        print!("{}", header.apply_to(format!("{:>CELL$}", structure.name())));
+    }
+    println!();
+
+    for &workload in &config.workloads {
+        for &size in &config.sizes {
+            let row: Vec<Option<&Measurement>> = config
+                .structures
+                .iter()
+                .map(|&s| results.iter().find(|m| m.structure == s && m.workload == workload && m.size == size))
+                .collect();
+            if row.iter().all(Option::is_none) {
+                continue;
+            }
+            let best = row.iter().flatten().map(|m| m.ns_per_op()).fold(f64::INFINITY, f64::min);
+
+            print!("{:<15}{:>8}", workload.name(), size);
+            for cell in row {
+                match cell {
+                    Some(m) if m.ns_per_op() == best => print!("{}", fastest.apply_to(format!("{:>CELL$}", format_ns(m.ns_per_op())))),
+                    Some(m) => print!("{:>CELL$}", format_ns(m.ns_per_op())),
+                    None => print!("{:>CELL$}", "-"),
+                }
+            }
+            println!();
+        }
+    }
+    println!("\n单元格为每次操作的耗时（{} 次运行的中位数），- 表示不支持该操作", config.repeat.max(1));
+}
+
+/// 生成 CSV，每行一个组合
+pub fn to_csv(results: &[Measurement]) -> String {
+    let mut csv = String::from("structure,workload,size,ops,median_ns,ns_per_op\n");
+    for m in results {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{:.3}\n",
+            m.structure.name(),
+            m.workload.name(),
+            m.size,
+            m.ops,
+            m.median.as_nanos(),
+            m.ns_per_op()
+        ));
+    }
+    csv
+}
+
+/// 默认的 CSV 输出位置：crate 根目录下的 `target/benchmark.csv`
+pub fn default_csv_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("target").join("benchmark.csv")
+}
@@ -0,0 +1,107 @@
+// 练习模式的命令：exercises、next、check、hint
+use console::Style;
+
+use super::{EXIT_FAILED, EXIT_NOT_FOUND, EXIT_OK};
+use crate::exercises::{self, CheckResult, Exercise, Progress};
+
+fn load_progress() -> Progress {
+    Progress::load().unwrap_or_else(|e| {
+        eprintln!("无法读取进度文件 {}: {}，从头开始", Progress::path().display(), e);
+        Progress::default()
+    })
+}
+
+fn save_progress(progress: &Progress) {
+    if let Err(e) = progress.save() {
+        eprintln!("无法保存进度文件 {}: {}", Progress::path().display(), e);
+    }
+}
+
+// 指定了练习名时查找该练习，否则取当前（第一个未完成的）练习
+fn select(progress: &Progress, name: Option<&str>) -> Result<&'static Exercise, i32> {
+    match name {
+        Some(name) => exercises::find(name).ok_or_else(|| {
+            eprintln!("没有找到练习: {}", name);
+            EXIT_NOT_FOUND
+        }),
+        None => progress.current().ok_or_else(|| {
+            println!("所有练习都已完成！");
+            EXIT_OK
+        }),
+    }
+}
+
+pub fn list() -> i32 {
+    let progress = load_progress();
+    let done = Style::new().green();
+    for exercise in exercises::EXERCISES {
+        let status = if progress.is_done(exercise.name) { done.apply_to("✓") } else { Style::new().apply_to("·") };
+        println!("{} {:<16} {:<16} {}", status, exercise.name, exercise.topic, exercise.path().display());
+    }
+    EXIT_OK
+}
+
+pub fn next() -> i32 {
+    let progress = load_progress();
+    let exercise = match select(&progress, None) {
+        Ok(exercise) => exercise,
+        Err(code) => return code,
+    };
+    let finished = exercises::EXERCISES.iter().filter(|e| progress.is_done(e.name)).count();
+    println!("进度 {}/{}", finished, exercises::EXERCISES.len());
+    println!("下一个练习: {} ({})", Style::new().bold().apply_to(exercise.name), exercise.topic);
+    println!("修改 {} 中的 todo!()，然后运行 `check`，需要帮助时运行 `hint`", exercise.path().display());
+    EXIT_OK
+}
+
+pub fn check(name: Option<&str>) -> i32 {
+    let mut progress = load_progress();
+    let exercise = match select(&progress, name) {
+        Ok(exercise) => exercise,
+        Err(code) => return code,
+    };
+
+    let bad = Style::new().red().bold();
+    match exercises::check(exercise) {
+        Err(e) => {
+            eprintln!("无法检查 {}: {}", exercise.name, e);
+            EXIT_FAILED
+        }
+        Ok(CheckResult::CompileError(stderr)) => {
+            println!("{} {} 无法编译\n\n{}", bad.apply_to("✗"), exercise.name, stderr);
+            EXIT_FAILED
+        }
+        Ok(CheckResult::TestFailed(stdout)) => {
+            println!("{} {} 没有通过测试\n\n{}", bad.apply_to("✗"), exercise.name, stdout);
+            EXIT_FAILED
+        }
+        Ok(CheckResult::Passed(_)) => {
+            println!("{} {} 通过！", Style::new().green().bold().apply_to("✓"), exercise.name);
+            progress.mark_done(exercise.name);
+            save_progress(&progress);
+            match progress.current() {
+                Some(next) => println!("下一个练习: {}", next.name),
+                None => println!("所有练习都已完成！"),
+            }
+            EXIT_OK
+        }
+    }
+}
+
+pub fn hint(name: Option<&str>) -> i32 {
+    let mut progress = load_progress();
+    let exercise = match select(&progress, name) {
+        Ok(exercise) => exercise,
+        Err(code) => return code,
+    };
+    let shown = progress.reveal_hint(exercise);
+    save_progress(&progress);
+
+    for (i, hint) in exercise.hints[..shown].iter().enumerate() {
+        println!("提示 {}/{}: {}", i + 1, exercise.hints.len(), hint);
+    }
+    if shown == exercise.hints.len() {
+        println!("已经是最后一条提示了");
+    }
+    EXIT_OK
+}
@@ -0,0 +1,85 @@
+// 交互式菜单
+use dialoguer::Select;
+use console::Style;
+use indicatif::{ProgressBar, ProgressStyle};
+
+use super::{EXIT_FAILED, EXIT_OK};
+use crate::registry::Registry;
+use crate::runner;
+use crate::source::Layout;
+
+pub fn interact(registry: &Registry) -> i32 {
+    // 标题
+    let title = Style::new().bold().cyan();
+    println!("{}", title.apply_to("请选择要运行的示例模块:"));
+
+    // 所有示例模块列表，由注册表生成
+    let modules: Vec<String> = registry
+        .modules()
+        .iter()
+        .map(|m| format!("{:<16} {} ({} 个示例)", m.name, m.title, registry.in_module(m.name).len()))
+        .collect();
+
+    // 交互式选择
+    let selection = Select::new()
+        .items(&modules)
+        .default(0)
+        .interact()
+        .unwrap();
+    let module = registry.modules()[selection].name;
+    let examples = registry.in_module(module);
+    if examples.is_empty() {
+        println!("{} 模块暂无示例", module);
+        return EXIT_OK;
+    }
+
+    // 选择模块中的某个示例，第一项为运行全部
+    let mut items = vec!["全部运行".to_string()];
+    items.extend(examples.iter().map(|e| format!("{:<40} {}", e.path(), e.title())));
+    let selection = Select::new()
+        .with_prompt("请选择要运行的示例")
+        .items(&items)
+        .default(0)
+        .interact()
+        .unwrap();
+
+    // 单个示例可以同时展示源码
+    let layout = if selection == 0 {
+        None
+    } else {
+        let modes = ["只看输出", "源码在上、输出在下", "源码和输出左右并排"];
+        match Select::new().with_prompt("展示方式").items(modes).default(0).interact().unwrap() {
+            0 => None,
+            1 => Some(Layout::Stacked),
+            _ => Some(Layout::SideBySide),
+        }
+    };
+
+    // 扫描所有模块
+    let pb = ProgressBar::new(registry.examples().len() as u64);
+    pb.set_style(
+        ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] {bar:40.cyan/blue} {pos}/{len}")
+            .unwrap()
+            .progress_chars("#>-")
+    );
+    for _ in registry.examples() {
+        pb.inc(1);
+        std::thread::sleep(std::time::Duration::from_millis(30));
+    }
+    pb.finish_with_message("开始运行模块!");
+
+    // 根据选择调用对应的示例
+    if let Some(layout) = layout {
+        return super::show_example(examples[selection - 1], layout, true);
+    }
+    let report = match selection {
+        0 => registry.run_module(module),
+        n => runner::run(&examples[n - 1..n]),
+    };
+    report.print_summary();
+    if report.is_success() {
+        EXIT_OK
+    } else {
+        EXIT_FAILED
+    }
+}
@@ -0,0 +1,757 @@
+/*!
+命令行入口
+
+- 不带参数且标准输入是终端时进入交互式菜单
+- `list [模块]` 列出示例
+- `run <模块>[::<示例>]` / `run --all` 运行示例，`--skip` 跳过部分示例，`--json` / `--junit` 写出运行报告
+- `search <关键字>` 按路径、标题、说明搜索示例
+- `show <目标> [--layout stacked|side] [--no-pager]` 展示示例的源码和输出
+- `verify [目标] [--bless]` 与快照比较示例输出，`--bless` 更新快照
+- `bench [选项]` 对比集合在不同工作负载下的耗时，打印对比表并保存 CSV
+- `animate [算法] [选项]` 在终端中逐步播放排序算法的动画
+- `textbench [选项]` 在同一份语料上对比各个字符串查找算法的耗时
+- `exercises` / `next` / `check [练习]` / `hint [练习]` 练习模式
+
+另有一个不在帮助中列出的内部命令 `exec <示例>`：只运行一个示例，不打印标题和汇总，
+`run --json`、`show`、`verify` 在子进程中用它捕获输出。
+
+退出码：0 成功，1 没有匹配的示例，2 参数错误，3 有示例运行失败
+*/
+
+mod exercise;
+mod menu;
+
+use std::io::{self, IsTerminal, Write};
+use std::time::Duration;
+
+use console::Style;
+
+use crate::algo::sort::{self, Algorithm};
+use crate::algo::text::compare;
+use crate::benchmark;
+use crate::registry::{Example, Registry};
+use crate::runner::{self, report};
+use crate::snapshot::{self, Verdict};
+use crate::source::{self, Layout};
+use crate::utils::XorShift64;
+
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_NOT_FOUND: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_FAILED: i32 = 3;
+
+const USAGE: &str = "\
+用法:
+    rust_code_examples                       交互式菜单（仅当标准输入是终端时）
+    rust_code_examples list [模块]            列出示例
+    rust_code_examples run <模块>[::<示例>]   运行某个模块或某个示例，例如 basics::set_types::vec_test
+    rust_code_examples run --all             运行所有示例
+        [--skip <目标>]...                    跳过某个模块或示例，可以重复
+        [--json <文件>] [--junit <文件>]       在子进程中逐个运行并捕获输出，写出 JSON / JUnit XML 报告
+    rust_code_examples search <关键字>        按路径、标题、说明搜索示例
+    rust_code_examples show <目标> [--layout stacked|side] [--no-pager]
+                                             展示示例函数的源码和输出，默认源码在上、输出在下
+    rust_code_examples verify [目标] [--bless] 与 snapshots/ 中的快照比较示例输出，--bless 更新快照
+    rust_code_examples bench                 对比集合在不同工作负载下的耗时，建议用 --release 构建
+        [--sizes 100,1000] [--structures vec,hashmap] [--workloads insert,lookup]
+        [--ops <次数>] [--repeat <次数>] [--csv <文件>]
+                                             CSV 默认保存到 target/benchmark.csv
+    rust_code_examples animate [算法,...]      逐步播放排序动画，默认依次播放所有算法
+        [--size <个数>] [--delay <毫秒>] [--seed <种子>]
+                                             算法: insertion merge quick heap radix tim
+    rust_code_examples textbench             对比字符串查找算法的耗时，建议用 --release 构建
+        [--corpus <文件>] [--size <字节数>] [--patterns rust,数据]
+        [--algorithms kmp,horspool] [--repeat <次数>]
+                                             不指定语料文件时随机生成，算法: naive kmp horspool
+                                             rabin-karp aho-corasick suffix-array
+    rust_code_examples exercises             列出练习及完成情况
+    rust_code_examples next                  显示下一个未完成的练习
+    rust_code_examples check [练习]           编译并测试练习，默认为当前练习
+    rust_code_examples hint [练习]            逐条显示练习的提示
+    rust_code_examples help                  显示本帮助";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Menu,
+    Help,
+    List { module: Option<String> },
+    Run { target: Option<String>, options: RunOptions },
+    Search { keyword: String },
+    Show { target: String, layout: Layout, paging: bool },
+    Verify { target: Option<String>, bless: bool },
+    Exec { path: String },
+    Bench { config: benchmark::Config, csv: Option<String> },
+    Animate { algorithms: Vec<Algorithm>, options: AnimateOptions },
+    TextBench { config: compare::Config, corpus: Option<String> },
+    Exercises,
+    Next,
+    Check { exercise: Option<String> },
+    Hint { exercise: Option<String> },
+}
+
+/// `run` 的可选参数
+#[derive(Debug, Default, PartialEq)]
+pub struct RunOptions {
+    pub skip: Vec<String>,
+    pub json: Option<String>,
+    pub junit: Option<String>,
+}
+
+impl RunOptions {
+    /// 需要写报告时在子进程中运行，才能捕获每个示例的输出
+    fn capture(&self) -> bool {
+        self.json.is_some() || self.junit.is_some()
+    }
+}
+
+/// `animate` 的可选参数
+#[derive(Debug, PartialEq)]
+pub struct AnimateOptions {
+    /// 要排序的元素个数
+    pub size: usize,
+    /// 每一步之后暂停的毫秒数
+    pub delay: u64,
+    /// 打乱输入用的随机种子
+    pub seed: u64,
+}
+
+impl Default for AnimateOptions {
+    fn default() -> AnimateOptions {
+        AnimateOptions { size: 30, delay: 40, seed: 1 }
+    }
+}
+
+impl Command {
+    /// 解析命令行参数（不含程序名）
+    pub fn parse(args: &[String]) -> Result<Command, String> {
+        let mut args = args.iter().map(String::as_str);
+        let command = match args.next() {
+            None => return Ok(Command::Menu),
+            Some(command) => command,
+        };
+
+        let command = match command {
+            "help" | "-h" | "--help" => Command::Help,
+            "list" => Command::List { module: args.next().map(str::to_string) },
+            "run" => {
+                let mut target = None;
+                let mut all = false;
+                let mut options = RunOptions::default();
+                while let Some(arg) = args.next() {
+                    let mut value = |flag: &str| {
+                        args.next().map(str::to_string).ok_or_else(|| format!("{} 需要一个参数", flag))
+                    };
+                    match arg {
+                        "--all" => all = true,
+                        "--skip" => options.skip.push(value(arg)?),
+                        "--json" => options.json = Some(value(arg)?),
+                        "--junit" => options.junit = Some(value(arg)?),
+                        flag if flag.starts_with('-') => return Err(format!("未知参数: {}", flag)),
+                        _ if target.is_some() || all => return Err(format!("多余的参数: {}", arg)),
+                        _ => target = Some(arg.to_string()),
+                    }
+                }
+                match (target, all) {
+                    (Some(_), true) => return Err("run 不能同时指定目标和 --all".to_string()),
+                    (None, false) => return Err("run 需要指定 <模块>[::<示例>] 或者 --all".to_string()),
+                    (target, _) => Command::Run { target, options },
+                }
+            }
+            "search" => match args.next() {
+                Some(keyword) => Command::Search { keyword: keyword.to_string() },
+                None => return Err("search 需要指定关键字".to_string()),
+            },
+            "show" => {
+                let mut target = None;
+                let mut layout = Layout::Stacked;
+                let mut paging = true;
+                while let Some(arg) = args.next() {
+                    match arg {
+                        "--layout" => {
+                            let name = args.next().unwrap_or_default();
+                            layout = Layout::parse(name).ok_or_else(|| format!("未知布局: {}，可选 stacked 或 side", name))?;
+                        }
+                        "--no-pager" => paging = false,
+                        flag if flag.starts_with('-') => return Err(format!("未知参数: {}", flag)),
+                        _ if target.is_some() => return Err(format!("多余的参数: {}", arg)),
+                        _ => target = Some(arg.to_string()),
+                    }
+                }
+                match target {
+                    Some(target) => Command::Show { target, layout, paging },
+                    None => return Err("show 需要指定 <模块>[::<示例>]".to_string()),
+                }
+            }
+            "verify" => {
+                let mut target = None;
+                let mut bless = false;
+                for arg in args.by_ref() {
+                    match arg {
+                        "--bless" => bless = true,
+                        flag if flag.starts_with('-') => return Err(format!("未知参数: {}", flag)),
+                        _ if target.is_some() => return Err(format!("多余的参数: {}", arg)),
+                        _ => target = Some(arg.to_string()),
+                    }
+                }
+                Command::Verify { target, bless }
+            }
+            // 内部命令，供 run_in_child 在子进程中使用，不在 USAGE 中列出
+            "exec" => match args.next() {
+                Some(path) => Command::Exec { path: path.to_string() },
+                None => return Err("exec 需要指定示例的完整路径".to_string()),
+            },
+            "bench" => {
+                let mut config = benchmark::Config::default();
+                let mut csv = None;
+                while let Some(flag) = args.next() {
+                    let value = args.next().ok_or_else(|| format!("{} 需要一个参数", flag))?;
+                    match flag {
+                        "--sizes" => config.sizes = parse_list(value, |s| s.parse().ok().filter(|&size| size > 0))?,
+                        "--structures" => config.structures = parse_list(value, benchmark::Structure::parse)?,
+                        "--workloads" => config.workloads = parse_list(value, benchmark::Workload::parse)?,
+                        "--ops" => config.ops = value.parse().map_err(|_| format!("无效的次数: {}", value))?,
+                        "--repeat" => config.repeat = value.parse().map_err(|_| format!("无效的次数: {}", value))?,
+                        "--csv" => csv = Some(value.to_string()),
+                        _ => return Err(format!("未知参数: {}", flag)),
+                    }
+                }
+                Command::Bench { config, csv }
+            }
+            "animate" => {
+                let mut algorithms = Vec::new();
+                let mut options = AnimateOptions::default();
+                while let Some(arg) = args.next() {
+                    if !arg.starts_with('-') {
+                        algorithms.extend(parse_list(arg, Algorithm::parse)?);
+                        continue;
+                    }
+                    let value = args.next().ok_or_else(|| format!("{} 需要一个参数", arg))?;
+                    let invalid = |_| format!("无效的数字: {}", value);
+                    match arg {
+                        "--size" => options.size = value.parse().map_err(invalid)?,
+                        "--delay" => options.delay = value.parse().map_err(invalid)?,
+                        "--seed" => options.seed = value.parse().map_err(invalid)?,
+                        _ => return Err(format!("未知参数: {}", arg)),
+                    }
+                }
+                if !(2..=60).contains(&options.size) {
+                    return Err(format!("--size 需要在 2 到 60 之间: {}", options.size));
+                }
+                if algorithms.is_empty() {
+                    algorithms = Algorithm::ALL.to_vec();
+                }
+                Command::Animate { algorithms, options }
+            }
+            "textbench" => {
+                let mut config = compare::Config::default();
+                let mut corpus = None;
+                while let Some(flag) = args.next() {
+                    let value = args.next().ok_or_else(|| format!("{} 需要一个参数", flag))?;
+                    match flag {
+                        "--corpus" => corpus = Some(value.to_string()),
+                        "--size" => config.size = value.parse().map_err(|_| format!("无效的字节数: {}", value))?,
+                        "--patterns" => {
+                            config.patterns = parse_list(value, |s| Some(s.to_string()).filter(|s| !s.is_empty()))?
+                        }
+                        "--algorithms" => config.algorithms = parse_list(value, compare::Algorithm::parse)?,
+                        "--repeat" => config.repeat = value.parse().map_err(|_| format!("无效的次数: {}", value))?,
+                        _ => return Err(format!("未知参数: {}", flag)),
+                    }
+                }
+                Command::TextBench { config, corpus }
+            }
+            "exercises" => Command::Exercises,
+            "next" => Command::Next,
+            "check" => Command::Check { exercise: args.next().map(str::to_string) },
+            "hint" => Command::Hint { exercise: args.next().map(str::to_string) },
+            other => return Err(format!("未知命令: {}", other)),
+        };
+
+        match args.next() {
+            Some(extra) => Err(format!("多余的参数: {}", extra)),
+            None => Ok(command),
+        }
+    }
+}
+
+// 逗号分隔的列表，例如 `--sizes 100,1000`
+fn parse_list<T>(value: &str, parse: impl Fn(&str) -> Option<T>) -> Result<Vec<T>, String> {
+    value
+        .split(',')
+        .map(|item| parse(item.trim()).ok_or_else(|| format!("无效的取值: {}", item)))
+        .collect()
+}
+
+/// 解析参数并执行，返回进程退出码
+pub fn run(registry: &Registry, args: &[String]) -> i32 {
+    let command = match Command::parse(args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+
+    match command {
+        Command::Menu if std::io::stdin().is_terminal() => menu::interact(registry),
+        Command::Menu => {
+            eprintln!("标准输入不是终端，无法进入交互式菜单\n\n{}", USAGE);
+            EXIT_USAGE
+        }
+        Command::Help => match writeln!(io::stdout().lock(), "{}", USAGE) {
+            Ok(()) => EXIT_OK,
+            Err(e) => write_failed(e),
+        },
+        Command::List { module } => list(registry, module.as_deref()),
+        Command::Run { target, options } => run_target(registry, target.as_deref(), &options),
+        Command::Search { keyword } => search(registry, &keyword),
+        Command::Show { target, layout, paging } => show(registry, &target, layout, paging),
+        Command::Verify { target, bless } => verify(registry, target.as_deref(), bless),
+        Command::Exec { path } => exec(registry, &path),
+        Command::Bench { config, csv } => bench(&config, csv.as_deref()),
+        Command::Animate { algorithms, options } => animate(&algorithms, &options),
+        Command::TextBench { config, corpus } => text_bench(&config, corpus.as_deref()),
+        Command::Exercises => exercise::list(),
+        Command::Next => exercise::next(),
+        Command::Check { exercise } => exercise::check(exercise.as_deref()),
+        Command::Hint { exercise } => exercise::hint(exercise.as_deref()),
+    }
+}
+
+/// 按 `run` 的目标查找示例：完整路径、模块前缀，最后尝试唯一的路径后缀，例如 `set_types::vec_test`
+pub fn resolve(registry: &Registry, target: &str) -> Vec<&'static Example> {
+    if let Some(example) = registry.find(target) {
+        return vec![example];
+    }
+    let examples = registry.in_module(target);
+    if !examples.is_empty() {
+        return examples;
+    }
+    let suffix = format!("::{}", target);
+    let matched: Vec<_> = registry.examples().iter().copied().filter(|e| e.path().ends_with(&suffix)).collect();
+    if matched.len() == 1 {
+        matched
+    } else {
+        Vec::new()
+    }
+}
+
+fn list(registry: &Registry, module: Option<&str>) -> i32 {
+    match write_list(&mut io::stdout().lock(), registry, module) {
+        Ok(true) => EXIT_OK,
+        Ok(false) => {
+            let module = module.unwrap_or_default();
+            match module.split_once("::") {
+                Some((top, _)) if registry.modules().iter().any(|m| m.name == top) => eprintln!("没有找到子模块: {}", module),
+                _ => eprintln!("没有找到模块: {}", module),
+            }
+            EXIT_NOT_FOUND
+        }
+        Err(e) => write_failed(e),
+    }
+}
+
+// 返回是否找到了模块或子模块
+fn write_list(out: &mut impl Write, registry: &Registry, module: Option<&str>) -> io::Result<bool> {
+    let title = Style::new().bold().cyan();
+    let mut found = false;
+    for m in registry.modules() {
+        if module.is_some_and(|name| name.split("::").next() != Some(m.name)) {
+            continue;
+        }
+        let examples = registry.in_module(module.unwrap_or(m.name));
+        // 子模块过滤后为空时连模块标题也不打印
+        if examples.is_empty() && module != Some(m.name) {
+            continue;
+        }
+        found = true;
+        writeln!(out, "{} {}", title.apply_to(m.name), m.title)?;
+        print_examples(out, &examples)?;
+    }
+    Ok(found)
+}
+
+fn run_target(registry: &Registry, target: Option<&str>, options: &RunOptions) -> i32 {
+    let examples = match target {
+        None => registry.examples().to_vec(),
+        Some(target) => resolve(registry, target),
+    };
+    if examples.is_empty() {
+        eprintln!("没有找到示例: {}", target.unwrap_or_default());
+        return EXIT_NOT_FOUND;
+    }
+
+    let report = runner::run_with(&examples, &options.skip, options.capture());
+    report.print_summary();
+
+    let reports = [(&options.json, report::to_json as fn(&runner::Report) -> String), (&options.junit, report::to_junit)];
+    for (path, format) in reports {
+        let Some(path) = path else { continue };
+        if let Err(e) = std::fs::write(path, format(&report)) {
+            eprintln!("无法写入报告 {}: {}", path, e);
+            return EXIT_FAILED;
+        }
+        println!("报告已写入 {}", path);
+    }
+
+    if report.is_success() {
+        EXIT_OK
+    } else {
+        EXIT_FAILED
+    }
+}
+
+fn exec(registry: &Registry, path: &str) -> i32 {
+    let example = match registry.find(path) {
+        Some(example) => example,
+        None => {
+            eprintln!("没有找到示例: {}", path);
+            return EXIT_NOT_FOUND;
+        }
+    };
+    let outcome = runner::run_isolated(example);
+    runner::print_child_result(&outcome);
+    if outcome.status.is_success() {
+        EXIT_OK
+    } else {
+        EXIT_FAILED
+    }
+}
+
+/// 在子进程中运行示例，按布局展示它的源码和输出
+pub fn show_example(example: &'static Example, layout: Layout, paging: bool) -> i32 {
+    let outcome = match runner::run_in_child(example) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("无法运行 {}: {}", example.path(), e);
+            return EXIT_FAILED;
+        }
+    };
+    let width = match console::Term::stdout().size_checked() {
+        Some((_, width)) => width as usize,
+        None => 120,
+    };
+    let lines = source::render(example, outcome.stdout.as_deref().unwrap_or_default(), layout, width);
+    if let Err(e) = source::page(&lines, paging) {
+        if e.kind() != io::ErrorKind::BrokenPipe {
+            eprintln!("无法写入标准输出: {}", e);
+            return EXIT_FAILED;
+        }
+    }
+    if outcome.status.is_success() {
+        EXIT_OK
+    } else {
+        EXIT_FAILED
+    }
+}
+
+fn show(registry: &Registry, target: &str, layout: Layout, paging: bool) -> i32 {
+    let examples = resolve(registry, target);
+    if examples.is_empty() {
+        eprintln!("没有找到示例: {}", target);
+        return EXIT_NOT_FOUND;
+    }
+    examples.iter().map(|&example| show_example(example, layout, paging)).max().unwrap_or(EXIT_OK)
+}
+
+fn verify(registry: &Registry, target: Option<&str>, bless: bool) -> i32 {
+    let examples = match target {
+        None => registry.examples().to_vec(),
+        Some(target) => resolve(registry, target),
+    };
+    if examples.is_empty() {
+        eprintln!("没有找到示例: {}", target.unwrap_or_default());
+        return EXIT_NOT_FOUND;
+    }
+
+    let ok = Style::new().green();
+    let bad = Style::new().red().bold();
+    let mut failed = 0;
+    for example in &examples {
+        let outcome = match runner::run_in_child(example) {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                eprintln!("无法运行 {}: {}", example.path(), e);
+                return EXIT_FAILED;
+            }
+        };
+        if !outcome.status.is_success() {
+            failed += 1;
+            println!("{} {}", bad.apply_to("failed  "), example.path());
+            continue;
+        }
+        match snapshot::verify(example, outcome.stdout.as_deref().unwrap_or_default(), bless) {
+            Ok(Verdict::Matched) => println!("{} {}", ok.apply_to("ok      "), example.path()),
+            Ok(Verdict::Blessed) => println!("{} {}", ok.apply_to("blessed "), example.path()),
+            Ok(Verdict::Missing) => {
+                failed += 1;
+                println!("{} {}  (使用 --bless 生成快照)", bad.apply_to("missing "), example.path());
+            }
+            Ok(Verdict::Changed(diff)) => {
+                failed += 1;
+                println!("{} {}  {}", bad.apply_to("changed "), example.path(), snapshot::snapshot_path(example).display());
+                print!("{}", diff);
+            }
+            Err(e) => {
+                eprintln!("无法读写 {} 的快照: {}", example.path(), e);
+                return EXIT_FAILED;
+            }
+        }
+    }
+
+    println!("共 {} 个示例，{} 个与快照不一致", examples.len(), failed);
+    if failed == 0 {
+        EXIT_OK
+    } else {
+        EXIT_FAILED
+    }
+}
+
+fn search(registry: &Registry, keyword: &str) -> i32 {
+    let keyword = keyword.to_lowercase();
+    let matched: Vec<_> = registry
+        .examples()
+        .iter()
+        .copied()
+        .filter(|e| {
+            [e.path().as_str(), e.title(), e.description()]
+                .iter()
+                .any(|field| field.to_lowercase().contains(&keyword))
+        })
+        .collect();
+    if matched.is_empty() {
+        eprintln!("没有匹配 \"{}\" 的示例", keyword);
+        return EXIT_NOT_FOUND;
+    }
+    match print_examples(&mut io::stdout().lock(), &matched) {
+        Ok(()) => EXIT_OK,
+        Err(e) => write_failed(e),
+    }
+}
+
+fn print_examples(out: &mut impl Write, examples: &[&Example]) -> io::Result<()> {
+    let dim = Style::new().dim();
+    for example in examples {
+        writeln!(out, "  {:<48} {}  {}", example.path(), example.title(), dim.apply_to(example.description()))?;
+    }
+    Ok(())
+}
+
+// 输出接在 `| head` 这样的管道后面时，读的一端提前关闭不算出错
+fn write_failed(e: io::Error) -> i32 {
+    if e.kind() == io::ErrorKind::BrokenPipe {
+        EXIT_OK
+    } else {
+        eprintln!("无法写入标准输出: {}", e);
+        EXIT_FAILED
+    }
+}
+
+fn bench(config: &benchmark::Config, csv: Option<&str>) -> i32 {
+    if cfg!(debug_assertions) {
+        eprintln!("提示：当前是调试构建，结果没有参考意义，请使用 cargo run --release -- bench\n");
+    }
+    let results = benchmark::run(config);
+    benchmark::print_table(config, &results);
+
+    let path = csv.map_or_else(benchmark::default_csv_path, std::path::PathBuf::from);
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    match std::fs::write(&path, benchmark::to_csv(&results)) {
+        Ok(()) => {
+            println!("CSV 已保存到 {}", path.display());
+            EXIT_OK
+        }
+        Err(e) => {
+            eprintln!("无法写入 {}: {}", path.display(), e);
+            EXIT_FAILED
+        }
+    }
+}
+
+fn animate(algorithms: &[Algorithm], options: &AnimateOptions) -> i32 {
+    // 1..=size 的一个随机排列，柱子高度各不相同
+    let mut values: Vec<u32> = (1..=options.size as u32).collect();
+    XorShift64::new(options.seed).shuffle(&mut values);
+    for &algorithm in algorithms {
+        if let Err(e) = sort::visual::animate(algorithm, &values, Duration::from_millis(options.delay), 12) {
+            eprintln!("无法绘制动画: {}", e);
+            return EXIT_FAILED;
+        }
+        println!();
+    }
+    EXIT_OK
+}
+
+fn text_bench(config: &compare::Config, corpus: Option<&str>) -> i32 {
+    if cfg!(debug_assertions) {
+        eprintln!("提示：当前是调试构建，结果没有参考意义，请使用 cargo run --release -- textbench\n");
+    }
+    let corpus = match corpus {
+        None => compare::sample_corpus(config.size, config.seed).into_bytes(),
+        Some(path) => match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                eprintln!("无法读取 {}: {}", path, e);
+                return EXIT_FAILED;
+            }
+        },
+    };
+    let timings = compare::run(&corpus, config);
+    compare::print_table(corpus.len(), config, &timings);
+    EXIT_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    fn fine() {}
+
+    fn boom() {
+        panic!("示例失败");
+    }
+
+    static EXAMPLES: &[Example] = &[example!(fine, "通过", "正常返回"), example!(boom, "失败", "直接 panic")];
+
+    // 只有 cli::tests 下两个示例的注册表，一个通过、一个失败
+    fn test_registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.module("cli", "命令行");
+        registry.register(EXAMPLES);
+        registry
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(Command::parse(&[]), Ok(Command::Menu));
+        assert_eq!(Command::parse(&args("-h")), Ok(Command::Help));
+        assert_eq!(Command::parse(&args("list basics")), Ok(Command::List { module: Some("basics".to_string()) }));
+        assert_eq!(
+            Command::parse(&args("run --all --skip basics --skip algo::dp --junit out.xml")),
+            Ok(Command::Run {
+                target: None,
+                options: RunOptions {
+                    skip: vec!["basics".to_string(), "algo::dp".to_string()],
+                    json: None,
+                    junit: Some("out.xml".to_string()),
+                },
+            })
+        );
+        assert_eq!(
+            Command::parse(&args("show vec_test --layout side --no-pager")),
+            Ok(Command::Show { target: "vec_test".to_string(), layout: Layout::SideBySide, paging: false })
+        );
+        assert_eq!(Command::parse(&args("verify --bless")), Ok(Command::Verify { target: None, bless: true }));
+        assert_eq!(
+            Command::parse(&args("animate quick,heap --size 10")),
+            Ok(Command::Animate {
+                algorithms: vec![Algorithm::Quick, Algorithm::Heap],
+                options: AnimateOptions { size: 10, ..AnimateOptions::default() },
+            })
+        );
+        assert_eq!(Command::parse(&args("check")), Ok(Command::Check { exercise: None }));
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        for line in [
+            "frobnicate",
+            "run",
+            "run basics --all",
+            "run basics algo",
+            "run --json",
+            "run basics --verbose",
+            "search",
+            "show",
+            "show a --layout diagonal",
+            "verify a b",
+            "list a b",
+            "bench --ops many",
+            "animate --size 1",
+            "animate bogo",
+            "textbench --algorithms grep",
+        ] {
+            assert!(Command::parse(&args(line)).is_err(), "{}", line);
+        }
+        assert_eq!(Command::parse(&args("run a b")), Err("多余的参数: b".to_string()));
+    }
+
+    #[test]
+    fn exit_codes() {
+        let registry = test_registry();
+        let code = |line: &str| run(&registry, &args(line));
+        assert_eq!(code("run cli::tests::fine"), EXIT_OK);
+        assert_eq!(code("run --all --skip cli::tests::boom"), EXIT_OK);
+        assert_eq!(code("list nope"), EXIT_NOT_FOUND);
+        assert_eq!(code("list cli::nope"), EXIT_NOT_FOUND);
+        assert_eq!(code("run nope"), EXIT_NOT_FOUND);
+        assert_eq!(code("search nope"), EXIT_NOT_FOUND);
+        assert_eq!(code("frobnicate"), EXIT_USAGE);
+        assert_eq!(code("run cli --all"), EXIT_USAGE);
+        assert_eq!(code("run cli"), EXIT_FAILED);
+        assert_eq!(code("run boom"), EXIT_FAILED);
+    }
+
+    // 读的一端已经关闭的管道
+    struct ClosedPipe;
+
+    impl Write for ClosedPipe {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn closed_pipe_is_a_normal_exit() {
+        let registry = crate::registry();
+        let error = write_list(&mut ClosedPipe, &registry, None).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(write_failed(error), EXIT_OK);
+
+        let examples = registry.examples();
+        let error = print_examples(&mut ClosedPipe, &examples[..1]).unwrap_err();
+        assert_eq!(write_failed(error), EXIT_OK);
+        assert_eq!(write_failed(io::Error::other("磁盘已满")), EXIT_FAILED);
+    }
+
+    #[test]
+    fn list_skips_modules_without_matches() {
+        let registry = test_registry();
+        let listed = |module| {
+            let mut out = Vec::new();
+            let found = write_list(&mut out, &registry, module).unwrap();
+            (found, console::strip_ansi_codes(&String::from_utf8(out).unwrap()).into_owned())
+        };
+        assert_eq!(listed(Some("cli::nope")), (false, String::new()));
+        let (found, text) = listed(Some("cli::tests::fine"));
+        assert!(found);
+        assert!(text.starts_with("cli 命令行\n"), "{}", text);
+        assert!(!text.contains("boom"), "{}", text);
+    }
+
+    #[test]
+    fn exec_is_hidden_from_help() {
+        assert!(!USAGE.contains("exec"));
+        assert_eq!(Command::parse(&["exec".to_string(), "a::b".to_string()]), Ok(Command::Exec { path: "a::b".to_string() }));
+    }
+
+    #[test]
+    fn bench_rejects_zero_size() {
+        let args = |list: &str| ["bench", "--sizes", list].map(String::from);
+        assert_eq!(Command::parse(&args("0")), Err("无效的取值: 0".to_string()));
+        assert!(Command::parse(&args("100,0")).is_err());
+        assert_eq!(run(&crate::registry(), &args("0")), EXIT_USAGE);
+        assert!(matches!(Command::parse(&args("1,10")), Ok(Command::Bench { config, .. }) if config.sizes == [1, 10]));
+    }
+}
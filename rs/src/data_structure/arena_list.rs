@@ -0,0 +1,1307 @@
+/*!
+基于 arena 的双向链表
+
+节点存放在 [`Arena`] 的 `Vec` 中，`prev` / `next` 是下标而不是指针，不会出现 `Rc<RefCell<_>>` 版本的循环引用。
+删除的节点放进空闲链表，之后插入时复用。[`ArenaList`] 只是一个句柄，记录首尾下标和长度，
+同一个 arena 可以存放任意多个链表，通过 `arena.list(&list)` / `arena.list_mut(&mut list)` 得到可以操作的视图。
+
+- 两端的插入和删除、游标处的插入和删除都是 O(1)
+- 节点一直待在自己的槽位上，所以游标处的 `splice_*`（并入同一个 arena 中的另一个链表）和 `split_*`（拆出一个链表）
+  只需要重新链接边界上的节点，也是 O(1)
+- 迭代器遵循 C-ITER 命名：`iter` 产生 `&T`，`iter_mut` 产生 `&mut T`；句柄本身不能迭代，
+  `ArenaList::into_iter_in` 消耗句柄产生 `T`，名字中的 `_in` 表示需要传入 arena
+- 句柄不知道自己的 arena 在哪里，丢掉句柄不会释放节点，要用 `into_iter_in` 或 `clear` 归还；在别的 arena 上使用句柄会 panic
+- `FromIterator` 没有地方传入 arena，所以实现在独占一个 arena 的 [`OwnedList`] 上；已有 arena 时用 [`Arena::collect`]
+- 只有 `iter_mut` 用到了 `unsafe`，它沿着链接同时借出多个 `&mut T`
+
+```
+use rust_code_examples::data_structure::arena_list::{Arena, ArenaList};
+
+let mut arena = Arena::new();
+let mut list = arena.collect(1..=3);
+let mut view = arena.list_mut(&mut list);
+let mut cursor = view.cursor_front_mut();
+cursor.move_next();
+cursor.insert_before(10);
+assert_eq!(cursor.remove_current(), Some(2));
+assert_eq!(arena.list(&list), [1, 10, 3]);
+
+// 拆出来的链表仍然在同一个 arena 中，拼回去不需要搬动节点
+let tail = arena.list_mut(&mut list).split_off(1);
+let slots = arena.slots();
+arena.list_mut(&mut list).append(tail);
+assert_eq!(arena.slots(), slots);
+assert_eq!(arena.list(&list), [1, 10, 3]);
+```
+*/
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_basic_ops, "ArenaList 基本操作", "两端的插入删除、空闲槽位的复用"),
+    example!(ex2_cursor_editing, "游标编辑", "用游标在链表中间移动、插入和删除"),
+    example!(ex3_split_splice, "拆分与拼接", "在游标处拆出链表、并入链表，只重新链接边界节点"),
+    example!(ex4_iterators, "三种迭代器", "iter、iter_mut、into_iter_in 与 FromIterator、Extend"),
+];
+
+// 每个 arena 有不同的编号，用来发现用错 arena 的句柄
+static NEXT_ARENA_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+enum Slot<T> {
+    Occupied(Node<T>),
+    /// 空闲槽位，指向下一个空闲槽位
+    Vacant(Option<usize>),
+}
+
+/// 存放链表节点的 arena，可以同时容纳多个链表
+pub struct Arena<T> {
+    id: usize,
+    slots: Vec<Slot<T>>,
+    free: Option<usize>,
+    // 所有链表的节点总数
+    len: usize,
+}
+
+/// 链表句柄，只记录首尾节点的下标和长度，元素存放在创建它的 [`Arena`] 中
+///
+/// 句柄不能克隆，一个节点只属于一个句柄。
+pub struct ArenaList<T> {
+    // 第一次插入元素时绑定到 arena，空链表可以在任何 arena 上使用
+    arena: Option<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ArenaList<T> {
+    pub const fn new() -> ArenaList<T> {
+        ArenaList { arena: None, head: None, tail: None, len: 0, _marker: PhantomData }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 消耗句柄，按顺序取出所有元素并释放它们的槽位；迭代器没有走完就被丢掉时，剩下的元素也会释放
+    pub fn into_iter_in(self, arena: &mut Arena<T>) -> IntoIter<'_, T> {
+        arena.check(&self);
+        IntoIter { arena, list: self }
+    }
+}
+
+impl<T> Default for ArenaList<T> {
+    fn default() -> ArenaList<T> {
+        ArenaList::new()
+    }
+}
+
+impl<T> fmt::Debug for ArenaList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArenaList").field("head", &self.head).field("tail", &self.tail).field("len", &self.len).finish()
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Arena<T> {
+        Arena::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        let id = NEXT_ARENA_ID.fetch_add(1, Ordering::Relaxed);
+        Arena { id, slots: Vec::with_capacity(capacity), free: None, len: 0 }
+    }
+
+    /// 所有链表的元素总数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 槽位数，包括空闲的槽位
+    pub fn slots(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// 把 iter 的元素依次放进一个新链表
+    pub fn collect<I: IntoIterator<Item = T>>(&mut self, iter: I) -> ArenaList<T> {
+        let mut list = ArenaList::new();
+        self.list_mut(&mut list).extend(iter);
+        list
+    }
+
+    /// 只读视图
+    pub fn list<'a>(&'a self, list: &'a ArenaList<T>) -> ListRef<'a, T> {
+        self.check(list);
+        ListRef { arena: self, list }
+    }
+
+    /// 可变视图，插入、删除、游标等操作都在视图上进行
+    pub fn list_mut<'a>(&'a mut self, list: &'a mut ArenaList<T>) -> ListMut<'a, T> {
+        self.check(list);
+        ListMut { arena: self, list }
+    }
+
+    fn check(&self, list: &ArenaList<T>) {
+        assert!(list.arena.is_none_or(|id| id == self.id) || list.is_empty(), "链表句柄不属于这个 arena");
+    }
+
+    fn node(&self, index: usize) -> &Node<T> {
+        match &self.slots[index] {
+            Slot::Occupied(node) => node,
+            Slot::Vacant(_) => unreachable!("链接指向了空闲槽位 {}", index),
+        }
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Node<T> {
+        match &mut self.slots[index] {
+            Slot::Occupied(node) => node,
+            Slot::Vacant(_) => unreachable!("链接指向了空闲槽位 {}", index),
+        }
+    }
+
+    // 分配一个未链接的节点，优先复用空闲槽位
+    fn alloc(&mut self, value: T) -> usize {
+        let node = Slot::Occupied(Node { value, prev: None, next: None });
+        self.len += 1;
+        match self.free {
+            Some(index) => {
+                self.free = match self.slots[index] {
+                    Slot::Vacant(next_free) => next_free,
+                    Slot::Occupied(_) => unreachable!("空闲链表指向了已占用的槽位 {}", index),
+                };
+                self.slots[index] = node;
+                index
+            }
+            None => {
+                self.slots.push(node);
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    // 把 index 链接到 prev 与 next 之间，两者为 None 时分别表示链表头和尾
+    fn link(&mut self, list: &mut ArenaList<T>, index: usize, prev: Option<usize>, next: Option<usize>) {
+        self.link_range(list, index, index, prev, next, 1);
+    }
+
+    // 把已经首尾相连的一段节点 first..=last 链接到 prev 与 next 之间
+    fn link_range(
+        &mut self,
+        list: &mut ArenaList<T>,
+        first: usize,
+        last: usize,
+        prev: Option<usize>,
+        next: Option<usize>,
+        count: usize,
+    ) {
+        self.node_mut(first).prev = prev;
+        self.node_mut(last).next = next;
+        match prev {
+            Some(p) => self.node_mut(p).next = Some(first),
+            None => list.head = Some(first),
+        }
+        match next {
+            Some(n) => self.node_mut(n).prev = Some(last),
+            None => list.tail = Some(last),
+        }
+        list.len += count;
+        list.arena = Some(self.id);
+    }
+
+    // 把 first..=last 这段（共 count 个）从链表中摘下，组成一个新链表，节点不动
+    fn detach(&mut self, list: &mut ArenaList<T>, first: usize, last: usize, count: usize) -> ArenaList<T> {
+        let prev = self.node(first).prev;
+        let next = self.node(last).next;
+        match prev {
+            Some(p) => self.node_mut(p).next = next,
+            None => list.head = next,
+        }
+        match next {
+            Some(n) => self.node_mut(n).prev = prev,
+            None => list.tail = prev,
+        }
+        list.len -= count;
+        self.node_mut(first).prev = None;
+        self.node_mut(last).next = None;
+        ArenaList { arena: Some(self.id), head: Some(first), tail: Some(last), len: count, _marker: PhantomData }
+    }
+
+    // 断开 index 并释放槽位，返回节点的值
+    fn unlink(&mut self, list: &mut ArenaList<T>, index: usize) -> T {
+        self.detach(list, index, index, 1);
+        self.len -= 1;
+        match std::mem::replace(&mut self.slots[index], Slot::Vacant(self.free)) {
+            Slot::Occupied(node) => {
+                self.free = Some(index);
+                node.value
+            }
+            Slot::Vacant(_) => unreachable!("重复释放槽位 {}", index),
+        }
+    }
+
+    // 从 head 或 tail 走到位置 at，选较近的一端
+    fn walk_to(&self, list: &ArenaList<T>, at: usize) -> Option<usize> {
+        if at >= list.len {
+            None
+        } else if at <= list.len / 2 {
+            (0..at).fold(list.head, |i, _| self.node(i.unwrap()).next)
+        } else {
+            (at + 1..list.len).fold(list.tail, |i, _| self.node(i.unwrap()).prev)
+        }
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Arena<T> {
+        Arena::new()
+    }
+}
+
+impl<T> fmt::Debug for Arena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Arena").field("len", &self.len).field("slots", &self.slots.len()).finish()
+    }
+}
+
+/// [`Arena::list`] 返回的只读视图
+pub struct ListRef<'a, T> {
+    arena: &'a Arena<T>,
+    list: &'a ArenaList<T>,
+}
+
+impl<T> Clone for ListRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListRef<'_, T> {}
+
+impl<'a, T> ListRef<'a, T> {
+    pub fn len(&self) -> usize {
+        self.list.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.len == 0
+    }
+
+    pub fn front(&self) -> Option<&'a T> {
+        self.list.head.map(|index| &self.arena.node(index).value)
+    }
+
+    pub fn back(&self) -> Option<&'a T> {
+        self.list.tail.map(|index| &self.arena.node(index).value)
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter { arena: self.arena, front: self.list.head, back: self.list.tail, remaining: self.list.len }
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'a, T> {
+        Cursor { arena: self.arena, list: self.list, current: self.list.head, index: 0 }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'a, T> {
+        Cursor { arena: self.arena, list: self.list, current: self.list.tail, index: self.list.len.saturating_sub(1) }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ListRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ListRef<'_, T> {
+    fn eq(&self, other: &ListRef<'_, T>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for ListRef<'_, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.len() == N && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T> IntoIterator for ListRef<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// [`Arena::list_mut`] 返回的可变视图
+pub struct ListMut<'a, T> {
+    arena: &'a mut Arena<T>,
+    list: &'a mut ArenaList<T>,
+}
+
+impl<'a, T> ListMut<'a, T> {
+    pub fn len(&self) -> usize {
+        self.list.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.len == 0
+    }
+
+    /// 只读视图，借用期间不能修改
+    pub fn as_list(&self) -> ListRef<'_, T> {
+        ListRef { arena: self.arena, list: self.list }
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let index = self.arena.alloc(value);
+        self.arena.link(self.list, index, None, self.list.head);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let index = self.arena.alloc(value);
+        self.arena.link(self.list, index, self.list.tail, None);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.head.map(|index| self.arena.unlink(self.list, index))
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.tail.map(|index| self.arena.unlink(self.list, index))
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.list.head.map(|index| &self.arena.node(index).value)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.list.tail.map(|index| &self.arena.node(index).value)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.head.map(|index| &mut self.arena.node_mut(index).value)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.list.tail.map(|index| &mut self.arena.node_mut(index).value)
+    }
+
+    /// 删除所有元素，释放它们的槽位，O(n)
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// 把 other 的全部元素移到末尾，other 必须在同一个 arena 中，O(1)
+    pub fn append(&mut self, other: ArenaList<T>) {
+        self.cursor_back_mut().splice_after(other);
+    }
+
+    /// 在位置 at 拆成两个链表，返回 `[at, len)` 部分；从较近的一端走到 at，拆分本身是 O(1)
+    pub fn split_off(&mut self, at: usize) -> ArenaList<T> {
+        assert!(at <= self.list.len, "拆分位置 {} 超出长度 {}", at, self.list.len);
+        if at == self.list.len {
+            return ArenaList::new();
+        }
+        self.cursor_at_mut(at).split_before_inclusive()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_list().iter()
+    }
+
+    /// 沿着链接遍历，不需要额外的空间
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            slots: self.arena.slots.as_mut_ptr(),
+            front: self.list.head,
+            back: self.list.tail,
+            remaining: self.list.len,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { current: self.list.head, index: 0, arena: self.arena, list: self.list }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let index = self.list.len.saturating_sub(1);
+        CursorMut { current: self.list.tail, index, arena: self.arena, list: self.list }
+    }
+
+    /// 指向位置 at 的游标，at 等于长度时指向幽灵位置
+    pub fn cursor_at_mut(&mut self, at: usize) -> CursorMut<'_, T> {
+        assert!(at <= self.list.len, "位置 {} 超出长度 {}", at, self.list.len);
+        let current = self.arena.walk_to(self.list, at);
+        CursorMut { current, index: at, arena: self.arena, list: self.list }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ListMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_list().fmt(f)
+    }
+}
+
+impl<T> Extend<T> for ListMut<'_, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.arena.slots.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<'b, T: Copy + 'b> Extend<&'b T> for ListMut<'_, T> {
+    fn extend<I: IntoIterator<Item = &'b T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, T> IntoIterator for ListMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        IterMut {
+            slots: self.arena.slots.as_mut_ptr(),
+            front: self.list.head,
+            back: self.list.tail,
+            remaining: self.list.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// 独占一个 arena 的链表
+///
+/// `FromIterator::from_iter` 只拿到迭代器，不能为需要 arena 的句柄实现，所以 `collect` 的结果是 arena 和句柄的组合，
+/// 之后可以用 `into_parts` 拆开，在同一个 arena 中继续放入别的链表。
+///
+/// ```
+/// use rust_code_examples::data_structure::arena_list::OwnedList;
+///
+/// let mut owned: OwnedList<i32> = (1..=3).collect();
+/// owned.extend([4]);
+/// assert_eq!(owned.list(), [1, 2, 3, 4]);
+///
+/// let (mut arena, list) = owned.into_parts();
+/// let other = arena.collect([5, 6]);
+/// assert_eq!(arena.len(), 6);
+/// assert_eq!(list.into_iter_in(&mut arena).sum::<i32>(), 10);
+/// assert_eq!(arena.list(&other), [5, 6]);
+/// ```
+pub struct OwnedList<T> {
+    arena: Arena<T>,
+    list: ArenaList<T>,
+}
+
+impl<T> OwnedList<T> {
+    pub fn list(&self) -> ListRef<'_, T> {
+        self.arena.list(&self.list)
+    }
+
+    pub fn list_mut(&mut self) -> ListMut<'_, T> {
+        self.arena.list_mut(&mut self.list)
+    }
+
+    pub fn into_parts(self) -> (Arena<T>, ArenaList<T>) {
+        (self.arena, self.list)
+    }
+}
+
+impl<T> FromIterator<T> for OwnedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> OwnedList<T> {
+        let mut arena = Arena::new();
+        let list = arena.collect(iter);
+        OwnedList { arena, list }
+    }
+}
+
+impl<T> Extend<T> for OwnedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list_mut().extend(iter);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OwnedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.list().fmt(f)
+    }
+}
+
+/// `iter` 返回的迭代器，产生 `&T`
+pub struct Iter<'a, T> {
+    arena: &'a Arena<T>,
+    front: Option<usize>,
+    back: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.arena.node(self.front?);
+        self.front = node.next;
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.arena.node(self.back?);
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// `iter_mut` 返回的迭代器，产生 `&mut T`
+///
+/// 借用检查器无法知道沿着链接走到的节点互不相同，所以这里持有槽位的裸指针，
+/// 和 `std::collections::LinkedList` 的 `IterMut` 一样。
+pub struct IterMut<'a, T> {
+    slots: *mut Slot<T>,
+    front: Option<usize>,
+    back: Option<usize>,
+    remaining: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    fn node(&mut self, index: usize) -> &'a mut Node<T> {
+        // SAFETY: slots 来自迭代器存在期间一直被可变借用的 arena，index 是链表中的节点；
+        // 链表没有环，remaining 保证前后两端合起来每个节点只访问一次，所以借出的 &mut 互不重叠
+        match unsafe { &mut *self.slots.add(index) } {
+            Slot::Occupied(node) => node,
+            Slot::Vacant(_) => unreachable!("链接指向了空闲槽位 {}", index),
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.node(self.front?);
+        self.front = node.next;
+        self.remaining -= 1;
+        Some(&mut node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.node(self.back?);
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some(&mut node.value)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// [`ArenaList::into_iter_in`] 返回的迭代器，产生 `T`
+pub struct IntoIter<'a, T> {
+    arena: &'a mut Arena<T>,
+    list: ArenaList<T>,
+}
+
+impl<T> Iterator for IntoIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.head.map(|index| self.arena.unlink(&mut self.list, index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.tail.map(|index| self.arena.unlink(&mut self.list, index))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<'_, T> {}
+impl<T> FusedIterator for IntoIter<'_, T> {}
+
+impl<T> Drop for IntoIter<'_, T> {
+    fn drop(&mut self) {
+        self.by_ref().for_each(drop);
+    }
+}
+
+/// 只读游标
+///
+/// 与 std 中尚未稳定的游标一样，尾部之后、头部之前有一个“幽灵”位置，`current` 在那里返回 None，
+/// 从幽灵位置 `move_next` 回到头部，`move_prev` 回到尾部。
+pub struct Cursor<'a, T> {
+    arena: &'a Arena<T>,
+    list: &'a ArenaList<T>,
+    current: Option<usize>,
+    index: usize,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// 当前位置的下标，幽灵位置返回 None
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|i| &self.arena.node(i).value)
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(i) => {
+                self.current = self.arena.node(i).next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(i) => {
+                self.current = self.arena.node(i).prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next = match self.current {
+            Some(i) => self.arena.node(i).next,
+            None => self.list.head,
+        };
+        next.map(|i| &self.arena.node(i).value)
+    }
+
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        let prev = match self.current {
+            Some(i) => self.arena.node(i).prev,
+            None => self.list.tail,
+        };
+        prev.map(|i| &self.arena.node(i).value)
+    }
+}
+
+/// 可变游标，可以在当前位置插入、删除、拆分和拼接
+pub struct CursorMut<'a, T> {
+    arena: &'a mut Arena<T>,
+    list: &'a mut ArenaList<T>,
+    current: Option<usize>,
+    index: usize,
+}
+
+impl<T> CursorMut<'_, T> {
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|i| &mut self.arena.node_mut(i).value)
+    }
+
+    /// 只读视图，借用期间不能修改
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor { arena: self.arena, list: self.list, current: self.current, index: self.index }
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(i) => {
+                self.current = self.arena.node(i).next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(i) => {
+                self.current = self.arena.node(i).prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            Some(i) => self.arena.node(i).next,
+            None => self.list.head,
+        };
+        next.map(|i| &mut self.arena.node_mut(i).value)
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = match self.current {
+            Some(i) => self.arena.node(i).prev,
+            None => self.list.tail,
+        };
+        prev.map(|i| &mut self.arena.node_mut(i).value)
+    }
+
+    // 当前位置两侧的节点，幽灵位置的两侧是尾和头
+    fn neighbours(&self) -> (Option<usize>, Option<usize>) {
+        match self.current {
+            Some(i) => (self.arena.node(i).prev, self.arena.node(i).next),
+            None => (self.list.tail, self.list.head),
+        }
+    }
+
+    /// 在当前元素之后插入，在幽灵位置时插入到头部
+    pub fn insert_after(&mut self, value: T) {
+        let index = self.arena.alloc(value);
+        let (_, next) = self.neighbours();
+        self.arena.link(self.list, index, self.current, next);
+        if self.current.is_none() {
+            // 幽灵位置的下标始终等于长度
+            self.index = self.list.len;
+        }
+    }
+
+    /// 在当前元素之前插入，在幽灵位置时插入到尾部；游标仍然指向原来的元素
+    pub fn insert_before(&mut self, value: T) {
+        let index = self.arena.alloc(value);
+        let (prev, _) = self.neighbours();
+        self.arena.link(self.list, index, prev, self.current);
+        self.index += 1;
+    }
+
+    /// 删除当前元素并移动到下一个元素，幽灵位置上什么也不做
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current?;
+        self.current = self.arena.node(current).next;
+        let value = self.arena.unlink(self.list, current);
+        if self.current.is_none() {
+            self.index = self.list.len;
+        }
+        Some(value)
+    }
+
+    /// 把 other 整体插入到当前元素之后，在幽灵位置时插入到头部；other 必须在同一个 arena 中，O(1)
+    pub fn splice_after(&mut self, other: ArenaList<T>) {
+        self.arena.check(&other);
+        let (_, next) = self.neighbours();
+        if let (Some(first), Some(last)) = (other.head, other.tail) {
+            self.arena.link_range(self.list, first, last, self.current, next, other.len);
+            if self.current.is_none() {
+                self.index = self.list.len;
+            }
+        }
+    }
+
+    /// 把 other 整体插入到当前元素之前，在幽灵位置时插入到尾部；other 必须在同一个 arena 中，O(1)
+    pub fn splice_before(&mut self, other: ArenaList<T>) {
+        self.arena.check(&other);
+        let (prev, _) = self.neighbours();
+        if let (Some(first), Some(last)) = (other.head, other.tail) {
+            self.arena.link_range(self.list, first, last, prev, self.current, other.len);
+            self.index += other.len;
+        }
+    }
+
+    /// 拆出当前元素之后的所有元素，在幽灵位置时拆出整个链表，O(1)
+    pub fn split_after(&mut self) -> ArenaList<T> {
+        let first = match self.current {
+            Some(i) => self.arena.node(i).next,
+            None => self.list.head,
+        };
+        let (Some(first), Some(last)) = (first, self.list.tail) else {
+            return ArenaList::new();
+        };
+        let count = match self.current {
+            Some(_) => self.list.len - self.index - 1,
+            None => self.list.len,
+        };
+        let split = self.arena.detach(self.list, first, last, count);
+        if self.current.is_none() {
+            self.index = 0;
+        }
+        split
+    }
+
+    /// 拆出当前元素之前的所有元素，在幽灵位置时拆出整个链表，O(1)
+    pub fn split_before(&mut self) -> ArenaList<T> {
+        let last = match self.current {
+            Some(i) => self.arena.node(i).prev,
+            None => self.list.tail,
+        };
+        let (Some(first), Some(last)) = (self.list.head, last) else {
+            return ArenaList::new();
+        };
+        let count = match self.current {
+            Some(_) => self.index,
+            None => self.list.len,
+        };
+        let split = self.arena.detach(self.list, first, last, count);
+        self.index = if self.current.is_some() { 0 } else { self.list.len };
+        split
+    }
+
+    // 拆出当前元素及其之后的所有元素，供 split_off 使用
+    fn split_before_inclusive(&mut self) -> ArenaList<T> {
+        let (Some(first), Some(last)) = (self.current, self.list.tail) else {
+            return ArenaList::new();
+        };
+        let count = self.list.len - self.index;
+        self.arena.detach(self.list, first, last, count)
+    }
+}
+
+pub fn ex1_basic_ops() {
+    println!("ArenaList 基本操作 示例代码\n\n");
+
+    let mut arena = Arena::new();
+    let mut handle = ArenaList::new();
+    let mut list = arena.list_mut(&mut handle);
+    list.push_back(2);
+    list.push_back(3);
+    list.push_front(1);
+    assert_eq!((list.front(), list.back()), (Some(&1), Some(&3)));
+    println!("push_front / push_back 之后: {:?}", list);
+
+    if let Some(back) = list.back_mut() {
+        *back *= 10;
+    }
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_back(), Some(30));
+    assert_eq!(list.len(), 1);
+    println!("pop_front、修改尾部并 pop_back 之后: {:?}", list);
+
+    // 删除后的槽位进入空闲链表，再次插入时复用，arena 不会增长
+    let slots = arena.slots();
+    let mut list = arena.list_mut(&mut handle);
+    list.push_back(4);
+    list.push_front(0);
+    assert_eq!(arena.slots(), slots);
+    assert_eq!(arena.list(&handle), [0, 2, 4]);
+    println!("复用空闲槽位之后: {:?}，arena 槽位数仍为 {}", arena.list(&handle), arena.slots());
+    assert!(arena.list(&handle).contains(&4));
+
+    // 一个 arena 可以存放多个链表
+    let other = arena.collect([7, 8]);
+    assert_eq!(arena.len(), 5);
+    println!("再放入一个链表 {:?}，arena 中共有 {} 个元素", arena.list(&other), arena.len());
+}
+
+pub fn ex2_cursor_editing() {
+    println!("游标编辑 示例代码\n\n");
+
+    let mut arena = Arena::new();
+    let mut handle = arena.collect("abcde".chars());
+    let mut list = arena.list_mut(&mut handle);
+    let mut cursor = list.cursor_front_mut();
+    // 移动到 c
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 'c'));
+    assert_eq!(cursor.index(), Some(2));
+    assert_eq!(cursor.as_cursor().peek_prev(), Some(&'b'));
+
+    cursor.insert_before('X');
+    cursor.insert_after('Y');
+    assert_eq!(cursor.index(), Some(3));
+    // 删除 c，游标移到 Y
+    assert_eq!(cursor.remove_current(), Some('c'));
+    assert_eq!(cursor.current(), Some(&mut 'Y'));
+    if let Some(c) = cursor.current() {
+        *c = 'y';
+    }
+    assert_eq!(list.iter().collect::<String>(), "abXyde");
+    println!("在 c 前后插入 X、Y，删除 c，把 Y 改成小写: {}", list.iter().collect::<String>());
+
+    // 走到尾部之后是幽灵位置，再走一步回到头部
+    let mut cursor = arena.list(&handle).cursor_back();
+    assert_eq!(cursor.current(), Some(&'e'));
+    cursor.move_next();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_next(), Some(&'a'));
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&'a'));
+    println!("尾部 -> 幽灵位置 -> 头部: e -> None -> a");
+
+    // 删除所有元音
+    let mut list = arena.list_mut(&mut handle);
+    let mut cursor = list.cursor_front_mut();
+    while let Some(&mut c) = cursor.current() {
+        if "aeiou".contains(c) {
+            cursor.remove_current();
+        } else {
+            cursor.move_next();
+        }
+    }
+    assert_eq!(list.iter().collect::<String>(), "bXyd");
+    println!("删除元音之后: {}", list.iter().collect::<String>());
+}
+
+pub fn ex3_split_splice() {
+    println!("拆分与拼接 示例代码\n\n");
+
+    let mut arena = Arena::new();
+    let mut handle = arena.collect(1..=6);
+    let slots = arena.slots();
+    let mut list = arena.list_mut(&mut handle);
+    let mut cursor = list.cursor_at_mut(2);
+    assert_eq!(cursor.current(), Some(&mut 3));
+
+    // 拆出 3 之后的部分，拆出 3 之前的部分
+    let after = cursor.split_after();
+    let before = cursor.split_before();
+    assert_eq!(arena.list(&after), [4, 5, 6]);
+    assert_eq!(arena.list(&before), [1, 2]);
+    assert_eq!(arena.list(&handle), [3]);
+    println!("在 3 处拆分: {:?} {:?} {:?}", arena.list(&before), arena.list(&handle), arena.list(&after));
+
+    // 反过来拼接：after 放在 3 之前，before 放在 3 之后
+    let mut list = arena.list_mut(&mut handle);
+    let mut cursor = list.cursor_front_mut();
+    cursor.splice_before(after);
+    cursor.splice_after(before);
+    assert_eq!(cursor.index(), Some(3));
+    assert_eq!(arena.list(&handle), [4, 5, 6, 3, 1, 2]);
+    println!("交换两段之后: {:?}", arena.list(&handle));
+
+    // split_off 与 append
+    let mut tail = arena.list_mut(&mut handle).split_off(4);
+    assert_eq!(arena.list(&tail), [1, 2]);
+    arena.list_mut(&mut tail).append(handle);
+    assert_eq!(arena.list(&tail), [1, 2, 4, 5, 6, 3]);
+    println!("split_off(4) 再 append: {:?}", arena.list(&tail));
+
+    // 拆分和拼接只改了边界节点的链接，没有分配或者释放槽位
+    assert_eq!(arena.slots(), slots);
+    println!("arena 槽位数始终为 {}", arena.slots());
+}
+
+pub fn ex4_iterators() {
+    println!("三种迭代器 示例代码\n\n");
+    println!("iter         -> Iter<'_, T>     : 产生 &T");
+    println!("iter_mut     -> IterMut<'_, T>  : 产生 &mut T");
+    println!("into_iter_in -> IntoIter<'_, T> : 产生 T，消耗句柄并释放槽位\n");
+
+    let mut arena = Arena::new();
+    let mut handle = arena.collect(["one", "two", "three"].iter().map(|s| s.to_string()));
+    let lengths: Vec<usize> = arena.list(&handle).iter().map(String::len).collect();
+    assert_eq!(lengths, [3, 3, 5]);
+
+    for s in arena.list_mut(&mut handle).iter_mut() {
+        s.make_ascii_uppercase();
+    }
+    // 可变视图本身也可以直接用在 for 循环中
+    for s in arena.list_mut(&mut handle) {
+        s.push('!');
+    }
+    let backwards: Vec<&String> = arena.list(&handle).iter().rev().collect();
+    assert_eq!(backwards, ["THREE!", "TWO!", "ONE!"]);
+    assert_eq!(arena.list(&handle).iter().len(), 3);
+    println!("iter_mut 修改之后倒序: {:?}", backwards);
+
+    let owned: Vec<String> = handle.into_iter_in(&mut arena).collect();
+    assert_eq!(owned, ["ONE!", "TWO!", "THREE!"]);
+    assert!(arena.is_empty());
+    println!("into_iter_in 收集为 Vec: {:?}", owned);
+
+    // FromIterator 与 Extend
+    let mut numbers: OwnedList<i32> = (1..=3).collect();
+    numbers.extend([4, 5]);
+    let mut list = numbers.list_mut();
+    list.extend(&[6]);
+    assert_eq!(list.iter().sum::<i32>(), 21);
+    println!("collect 之后 extend: {:?}", numbers);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::LinkedList;
+
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // std 的游标还没有稳定，std 一侧的中间位置操作用 `split_off` + `append` 模拟
+    #[test]
+    fn matches_linked_list_on_random_ops() {
+        let mut rng = XorShift64::new(13);
+        let mut arena: Arena<u32> = Arena::new();
+        let mut ours = ArenaList::new();
+        let mut std_list: LinkedList<u32> = LinkedList::new();
+
+        for step in 0..20_000 {
+            let value = rng.below(1000) as u32;
+            let len = std_list.len();
+            let at = rng.below(len as u64 + 1) as usize;
+            let op = rng.below(10);
+            let mut list = arena.list_mut(&mut ours);
+            match op {
+                0 => {
+                    list.push_front(value);
+                    std_list.push_front(value);
+                }
+                1 => {
+                    list.push_back(value);
+                    std_list.push_back(value);
+                }
+                2 => assert_eq!(list.pop_front(), std_list.pop_front(), "第 {} 步 pop_front", step),
+                3 => assert_eq!(list.pop_back(), std_list.pop_back(), "第 {} 步 pop_back", step),
+                // 在位置 at 之前插入
+                4 => {
+                    list.cursor_at_mut(at).insert_before(value);
+                    let mut tail = std_list.split_off(at);
+                    std_list.push_back(value);
+                    std_list.append(&mut tail);
+                }
+                // 删除位置 at 的元素
+                5 if at < len => {
+                    let removed = list.cursor_at_mut(at).remove_current();
+                    let mut tail = std_list.split_off(at);
+                    assert_eq!(removed, tail.pop_front(), "第 {} 步 remove_current", step);
+                    std_list.append(&mut tail);
+                }
+                // 拆开再拼回去
+                6 => {
+                    let ours_tail = list.split_off(at);
+                    let mut std_tail = std_list.split_off(at);
+                    assert!(arena.list(&ours_tail).iter().eq(std_tail.iter()), "第 {} 步 split_off", step);
+                    arena.list_mut(&mut ours).append(ours_tail);
+                    std_list.append(&mut std_tail);
+                }
+                // 在位置 at 之后并入一个同一个 arena 中的新链表
+                7 => {
+                    let extra: Vec<u32> = (0..rng.below(4)).map(|i| value + i as u32).collect();
+                    let other = arena.collect(extra.iter().copied());
+                    let mut list = arena.list_mut(&mut ours);
+                    if at < len {
+                        list.cursor_at_mut(at).splice_after(other);
+                        let mut tail = std_list.split_off(at + 1);
+                        std_list.extend(extra);
+                        std_list.append(&mut tail);
+                    } else {
+                        list.append(other);
+                        std_list.extend(extra);
+                    }
+                }
+                // 通过游标拆出前半部分，再从头部并回去
+                8 => {
+                    let front = list.cursor_at_mut(at).split_before();
+                    let mut cursor = list.cursor_front_mut();
+                    cursor.move_prev();
+                    cursor.splice_after(front);
+                }
+                _ => {
+                    for v in list.iter_mut() {
+                        *v = v.wrapping_add(1);
+                    }
+                    for v in std_list.iter_mut() {
+                        *v = v.wrapping_add(1);
+                    }
+                }
+            }
+
+            let list = arena.list(&ours);
+            assert_eq!(list.len(), std_list.len(), "第 {} 步之后长度不同", step);
+            assert!(list.iter().eq(std_list.iter()), "第 {} 步之后内容不同", step);
+            assert!(list.iter().rev().eq(std_list.iter().rev()), "第 {} 步之后反向遍历不同", step);
+            assert_eq!(list.front(), std_list.front());
+            assert_eq!(list.back(), std_list.back());
+            // 拆分和拼接不会留下无主的节点
+            assert_eq!(arena.len(), std_list.len(), "第 {} 步之后 arena 中有多余的节点", step);
+        }
+    }
+
+    #[test]
+    fn cursor_wraps_through_the_ghost_position() {
+        let mut arena = Arena::new();
+        let mut list = arena.collect([1, 2, 3]);
+        let mut view = arena.list_mut(&mut list);
+        let mut cursor = view.cursor_front_mut();
+        cursor.move_prev();
+        assert_eq!((cursor.index(), cursor.current()), (None, None));
+        assert_eq!(cursor.remove_current(), None);
+        cursor.move_prev();
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(2), Some(3)));
+        cursor.move_next();
+        // 幽灵位置上 insert_after 插到头部，insert_before 插到尾部
+        cursor.insert_after(0);
+        cursor.insert_before(4);
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(0), Some(0)));
+        assert_eq!(arena.list(&list), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn empty_and_single_element_lists() {
+        let mut arena = Arena::new();
+        let mut list = ArenaList::new();
+        let mut view = arena.list_mut(&mut list);
+        assert_eq!((view.pop_front(), view.pop_back()), (None, None));
+        let mut cursor = view.cursor_front_mut();
+        assert!(cursor.split_after().is_empty());
+        assert!(cursor.split_before().is_empty());
+        cursor.splice_after(ArenaList::new());
+        cursor.insert_after(5);
+        assert_eq!(cursor.index(), None);
+        let taken = view.split_off(0);
+        assert!(view.is_empty());
+        view.append(taken);
+        assert_eq!(arena.list(&list), [5]);
+
+        // 只有一个元素时两侧都拆不出东西，删掉它之后游标回到幽灵位置
+        let mut single = arena.collect([7]);
+        let mut view = arena.list_mut(&mut single);
+        let mut cursor = view.cursor_front_mut();
+        assert!(cursor.split_after().is_empty());
+        assert!(cursor.split_before().is_empty());
+        assert_eq!(cursor.remove_current(), Some(7));
+        assert_eq!((cursor.index(), cursor.current()), (None, None));
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn split_off_at_both_ends_keeps_slots() {
+        let mut arena = Arena::new();
+        let mut list = arena.collect(1..=4);
+        let slots = arena.slots();
+        let all = arena.list_mut(&mut list).split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(arena.list(&all), [1, 2, 3, 4]);
+        arena.list_mut(&mut list).append(all);
+        let none = arena.list_mut(&mut list).split_off(4);
+        assert!(none.is_empty());
+        arena.list_mut(&mut list).append(none);
+        assert_eq!(arena.list(&list), [1, 2, 3, 4]);
+        assert_eq!((arena.slots(), arena.len()), (slots, 4));
+    }
+
+    #[test]
+    fn freed_slots_are_reused() {
+        let mut arena = Arena::new();
+        let mut list = arena.collect(1..=3);
+        let mut view = arena.list_mut(&mut list);
+        view.pop_front();
+        view.pop_back();
+        view.push_front(10);
+        view.push_back(20);
+        assert_eq!(arena.list(&list), [10, 2, 20]);
+        assert_eq!((arena.slots(), arena.len()), (3, 3));
+    }
+
+    #[test]
+    fn dropping_a_partial_into_iter_frees_the_rest() {
+        let mut arena = Arena::new();
+        let list = arena.collect(["a", "b", "c", "d"].map(String::from));
+        let mut iter = list.into_iter_in(&mut arena);
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        assert_eq!(iter.next_back().as_deref(), Some("d"));
+        assert_eq!(iter.len(), 2);
+        drop(iter);
+        assert!(arena.is_empty());
+        let again = arena.collect(["e"].map(String::from));
+        assert_eq!(arena.slots(), 4);
+        assert_eq!(arena.list(&again).len(), 1);
+    }
+
+    #[test]
+    fn iter_mut_from_both_ends_visits_each_element_once() {
+        let mut arena = Arena::new();
+        let mut list = arena.collect(0..5);
+        let mut view = arena.list_mut(&mut list);
+        let mut iter = view.iter_mut();
+        let mut seen = Vec::new();
+        while let Some(v) = if seen.len() % 2 == 0 { iter.next() } else { iter.next_back() } {
+            *v *= 10;
+            seen.push(*v);
+        }
+        assert_eq!(seen, [0, 40, 10, 30, 20]);
+        assert_eq!(iter.next(), None);
+        assert_eq!(arena.list(&list), [0, 10, 20, 30, 40]);
+    }
+
+    #[test]
+    #[should_panic(expected = "链表句柄不属于这个 arena")]
+    fn rejects_handle_from_another_arena() {
+        let mut first = Arena::new();
+        let list = first.collect([1, 2, 3]);
+        let second: Arena<i32> = Arena::new();
+        second.list(&list);
+    }
+}
@@ -0,0 +1,290 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+
+use super::{is_expired, Chain, Clock, EvictCallback, EvictReason, Slab, Stats, SystemClock};
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    // 所在的次数桶在 buckets 中的下标
+    bucket: usize,
+    expires: Option<Duration>,
+}
+
+// 访问次数相同的条目，头部是最近使用的
+struct Bucket {
+    frequency: u64,
+    entries: Chain,
+}
+
+/// 淘汰访问次数最少的条目的缓存，次数相同时淘汰其中最久没有使用的
+///
+/// 每个出现过的访问次数对应一个桶，桶里是这些条目的链表；桶本身也按次数从小到大连成一条双向链表，
+/// 每个桶指向下一个更大的次数。条目被访问时从次数 f 的桶移到紧随其后的 f + 1 的桶（没有就在后面插入一个），
+/// 桶空了就从链表中摘下，所以最小次数总是第一个桶，`get` / `put` / `remove` 和淘汰都是 O(1)。
+pub struct LfuCache<K, V, C = SystemClock> {
+    capacity: usize,
+    map: HashMap<K, usize>,
+    slab: Slab<Entry<K, V>>,
+    buckets: Slab<Bucket>,
+    // 头部是次数最小的桶
+    order: Chain,
+    ttl: Option<Duration>,
+    clock: C,
+    stats: Stats,
+    on_evict: Option<EvictCallback<K, V>>,
+}
+
+impl<K: Hash + Eq + Clone, V> LfuCache<K, V> {
+    pub fn new(capacity: usize) -> LfuCache<K, V> {
+        LfuCache::with_clock(capacity, SystemClock::new())
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, C: Clock> LfuCache<K, V, C> {
+    pub fn with_clock(capacity: usize, clock: C) -> LfuCache<K, V, C> {
+        assert!(capacity > 0, "容量必须大于 0");
+        LfuCache {
+            capacity,
+            map: HashMap::with_capacity(capacity),
+            slab: Slab::with_capacity(capacity),
+            buckets: Slab::with_capacity(0),
+            order: Chain::default(),
+            ttl: None,
+            clock,
+            stats: Stats::default(),
+            on_evict: None,
+        }
+    }
+
+    /// `put` 使用的默认过期时间
+    pub fn with_ttl(mut self, ttl: Duration) -> LfuCache<K, V, C> {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// 条目因容量或过期被淘汰时调用，`remove` 和 `clear` 不会调用
+    pub fn on_evict(mut self, callback: impl FnMut(K, V, EvictReason) + 'static) -> LfuCache<K, V, C> {
+        self.on_evict = Some(Box::new(callback));
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 包括还没有被清理的过期条目
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.lookup(key)?;
+        Some(&self.slab.get(index).value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.lookup(key)?;
+        Some(&mut self.slab.get_mut(index).value)
+    }
+
+    /// 只读取，不增加访问次数，也不计入统计
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.slab.get(*self.map.get(key)?);
+        (!is_expired(entry.expires, self.clock.now())).then_some(&entry.value)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.peek(key).is_some()
+    }
+
+    /// 条目的访问次数，放入算一次，之后每次 `get` 或更新加一
+    pub fn frequency<Q>(&self, key: &Q) -> Option<u64>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.slab.get(*self.map.get(key)?);
+        (!is_expired(entry.expires, self.clock.now())).then_some(self.buckets.get(entry.bucket).frequency)
+    }
+
+    /// 放入条目并返回旧值，使用默认过期时间；更新已有的条目也算一次访问
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.insert(key, value, self.ttl)
+    }
+
+    pub fn put_with_ttl(&mut self, key: K, value: V, ttl: Duration) -> Option<V> {
+        self.insert(key, value, Some(ttl))
+    }
+
+    /// 放入一个永不过期的条目
+    pub fn put_without_ttl(&mut self, key: K, value: V) -> Option<V> {
+        self.insert(key, value, None)
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.remove(key)?;
+        self.detach(index);
+        let entry = self.slab.remove(index);
+        (!is_expired(entry.expires, self.clock.now())).then_some(entry.value)
+    }
+
+    /// 删除所有过期条目，返回删除的数量，O(n)
+    pub fn purge_expired(&mut self) -> usize {
+        let now = self.clock.now();
+        let expired: Vec<usize> = self.map.values().copied().filter(|&i| is_expired(self.slab.get(i).expires, now)).collect();
+        for &index in &expired {
+            self.evict(index, EvictReason::Expired);
+        }
+        expired.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.slab.clear();
+        self.buckets.clear();
+        self.order = Chain::default();
+    }
+
+    /// 顺序不确定，跳过过期条目
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let now = self.clock.now();
+        self.map
+            .values()
+            .map(|&i| self.slab.get(i))
+            .filter(move |entry| !is_expired(entry.expires, now))
+            .map(|entry| (&entry.key, &entry.value))
+    }
+
+    fn lookup<Q>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(&index) = self.map.get(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        if is_expired(self.slab.get(index).expires, self.clock.now()) {
+            self.evict(index, EvictReason::Expired);
+            self.stats.misses += 1;
+            return None;
+        }
+        self.stats.hits += 1;
+        self.touch(index);
+        Some(index)
+    }
+
+    // 从所在的桶中摘下，桶空了就删除
+    fn detach(&mut self, index: usize) {
+        let bucket = self.slab.get(index).bucket;
+        let entries = &mut self.buckets.get_mut(bucket).entries;
+        entries.unlink(&mut self.slab, index);
+        if entries.is_empty() {
+            self.order.unlink(&mut self.buckets, bucket);
+            self.buckets.remove(bucket);
+        }
+    }
+
+    // 在 prev 之后插入一个次数为 frequency 的空桶，prev 为 None 时插在最前面
+    fn new_bucket(&mut self, prev: Option<usize>, frequency: u64) -> usize {
+        let bucket = self.buckets.insert(Bucket { frequency, entries: Chain::default() });
+        match prev {
+            Some(prev) => self.order.insert_after(&mut self.buckets, prev, bucket),
+            None => self.order.push_front(&mut self.buckets, bucket),
+        }
+        bucket
+    }
+
+    // 访问次数加一，移到下一个桶的头部
+    fn touch(&mut self, index: usize) {
+        let bucket = self.slab.get(index).bucket;
+        let frequency = self.buckets.get(bucket).frequency;
+        let next = match self.buckets.node(bucket).next {
+            Some(next) if self.buckets.get(next).frequency == frequency + 1 => next,
+            _ => self.new_bucket(Some(bucket), frequency + 1),
+        };
+        // 先插入新桶再摘下，旧桶被删除时新桶仍然链接在正确的位置
+        self.detach(index);
+        self.slab.get_mut(index).bucket = next;
+        self.buckets.get_mut(next).entries.push_front(&mut self.slab, index);
+    }
+
+    fn insert(&mut self, key: K, value: V, ttl: Option<Duration>) -> Option<V> {
+        let now = self.clock.now();
+        let expires = ttl.map(|ttl| now + ttl);
+        if let Some(&index) = self.map.get(&key) {
+            if is_expired(self.slab.get(index).expires, now) {
+                self.evict(index, EvictReason::Expired);
+            } else {
+                self.touch(index);
+                let entry = self.slab.get_mut(index);
+                entry.expires = expires;
+                return Some(std::mem::replace(&mut entry.value, value));
+            }
+        }
+
+        if self.map.len() == self.capacity {
+            let min = self.order.head.expect("缓存已满时至少有一个桶");
+            let victim = self.buckets.get(min).entries.tail.expect("桶不为空");
+            let reason =
+                if is_expired(self.slab.get(victim).expires, now) { EvictReason::Expired } else { EvictReason::Capacity };
+            self.evict(victim, reason);
+        }
+        let first = match self.order.head {
+            Some(head) if self.buckets.get(head).frequency == 1 => head,
+            _ => self.new_bucket(None, 1),
+        };
+        let index = self.slab.insert(Entry { key: key.clone(), value, bucket: first, expires });
+        self.buckets.get_mut(first).entries.push_front(&mut self.slab, index);
+        self.map.insert(key, index);
+        None
+    }
+
+    fn evict(&mut self, index: usize, reason: EvictReason) {
+        self.detach(index);
+        let entry = self.slab.remove(index);
+        self.map.remove(&entry.key);
+        match reason {
+            EvictReason::Capacity => self.stats.evictions += 1,
+            EvictReason::Expired => self.stats.expirations += 1,
+        }
+        if let Some(callback) = &mut self.on_evict {
+            callback(entry.key, entry.value, reason);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+
+use super::{is_expired, Chain, Clock, EvictCallback, EvictReason, Slab, Stats, SystemClock};
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    expires: Option<Duration>,
+}
+
+/// 淘汰最久没有使用的条目的缓存
+pub struct LruCache<K, V, C = SystemClock> {
+    capacity: usize,
+    map: HashMap<K, usize>,
+    slab: Slab<Entry<K, V>>,
+    // 头部是最近使用的，尾部是下一个被淘汰的
+    order: Chain,
+    ttl: Option<Duration>,
+    clock: C,
+    stats: Stats,
+    on_evict: Option<EvictCallback<K, V>>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    pub fn new(capacity: usize) -> LruCache<K, V> {
+        LruCache::with_clock(capacity, SystemClock::new())
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, C: Clock> LruCache<K, V, C> {
+    pub fn with_clock(capacity: usize, clock: C) -> LruCache<K, V, C> {
+        assert!(capacity > 0, "容量必须大于 0");
+        LruCache {
+            capacity,
+            map: HashMap::with_capacity(capacity),
+            slab: Slab::with_capacity(capacity),
+            order: Chain::default(),
+            ttl: None,
+            clock,
+            stats: Stats::default(),
+            on_evict: None,
+        }
+    }
+
+    /// `put` 使用的默认过期时间
+    pub fn with_ttl(mut self, ttl: Duration) -> LruCache<K, V, C> {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// 条目因容量或过期被淘汰时调用，`remove` 和 `clear` 不会调用
+    pub fn on_evict(mut self, callback: impl FnMut(K, V, EvictReason) + 'static) -> LruCache<K, V, C> {
+        self.on_evict = Some(Box::new(callback));
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 包括还没有被清理的过期条目
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.lookup(key)?;
+        Some(&self.slab.get(index).value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.lookup(key)?;
+        Some(&mut self.slab.get_mut(index).value)
+    }
+
+    /// 只读取，不改变使用顺序，也不计入统计
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.slab.get(*self.map.get(key)?);
+        (!is_expired(entry.expires, self.clock.now())).then_some(&entry.value)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.peek(key).is_some()
+    }
+
+    /// 放入条目并返回旧值，使用默认过期时间
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.insert(key, value, self.ttl)
+    }
+
+    pub fn put_with_ttl(&mut self, key: K, value: V, ttl: Duration) -> Option<V> {
+        self.insert(key, value, Some(ttl))
+    }
+
+    /// 放入一个永不过期的条目
+    pub fn put_without_ttl(&mut self, key: K, value: V) -> Option<V> {
+        self.insert(key, value, None)
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.remove(key)?;
+        self.order.unlink(&mut self.slab, index);
+        let entry = self.slab.remove(index);
+        (!is_expired(entry.expires, self.clock.now())).then_some(entry.value)
+    }
+
+    /// 删除所有过期条目，返回删除的数量，O(n)
+    pub fn purge_expired(&mut self) -> usize {
+        let now = self.clock.now();
+        let expired: Vec<usize> =
+            self.order.indices(&self.slab).filter(|&i| is_expired(self.slab.get(i).expires, now)).collect();
+        for &index in &expired {
+            self.evict(index, EvictReason::Expired);
+        }
+        expired.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.slab.clear();
+        self.order = Chain::default();
+    }
+
+    /// 从最近使用到最久没有使用，跳过过期条目
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let now = self.clock.now();
+        self.order
+            .indices(&self.slab)
+            .map(|i| self.slab.get(i))
+            .filter(move |entry| !is_expired(entry.expires, now))
+            .map(|entry| (&entry.key, &entry.value))
+    }
+
+    // 查找并更新使用顺序和统计，过期条目在这里被删除
+    fn lookup<Q>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(&index) = self.map.get(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        if is_expired(self.slab.get(index).expires, self.clock.now()) {
+            self.evict(index, EvictReason::Expired);
+            self.stats.misses += 1;
+            return None;
+        }
+        self.stats.hits += 1;
+        self.order.move_to_front(&mut self.slab, index);
+        Some(index)
+    }
+
+    fn insert(&mut self, key: K, value: V, ttl: Option<Duration>) -> Option<V> {
+        let now = self.clock.now();
+        let expires = ttl.map(|ttl| now + ttl);
+        if let Some(&index) = self.map.get(&key) {
+            if is_expired(self.slab.get(index).expires, now) {
+                self.evict(index, EvictReason::Expired);
+            } else {
+                self.order.move_to_front(&mut self.slab, index);
+                let entry = self.slab.get_mut(index);
+                entry.expires = expires;
+                return Some(std::mem::replace(&mut entry.value, value));
+            }
+        }
+
+        if self.map.len() == self.capacity {
+            let victim = self.order.tail.expect("缓存已满时链表不为空");
+            let reason =
+                if is_expired(self.slab.get(victim).expires, now) { EvictReason::Expired } else { EvictReason::Capacity };
+            self.evict(victim, reason);
+        }
+        let index = self.slab.insert(Entry { key: key.clone(), value, expires });
+        self.order.push_front(&mut self.slab, index);
+        self.map.insert(key, index);
+        None
+    }
+
+    fn evict(&mut self, index: usize, reason: EvictReason) {
+        self.order.unlink(&mut self.slab, index);
+        let entry = self.slab.remove(index);
+        self.map.remove(&entry.key);
+        match reason {
+            EvictReason::Capacity => self.stats.evictions += 1,
+            EvictReason::Expired => self.stats.expirations += 1,
+        }
+        if let Some(callback) = &mut self.on_evict {
+            callback(entry.key, entry.value, reason);
+        }
+    }
+}
@@ -0,0 +1,569 @@
+/*!
+有容量上限的缓存：LRU 与 LFU
+
+两种缓存都由 `HashMap<K, usize>` 加一个用下标链接的节点池组成：哈希表负责 O(1) 查找，
+节点之间的双向链接负责 O(1) 地调整顺序和找到要淘汰的条目。
+
+- `get` / `put` 都是 O(1)，`peek` 只读取，不改变最近使用顺序和访问频率，也不计入统计
+- 容量满时淘汰一个条目，已过期的条目被淘汰时原因是 `EvictReason::Expired`
+- 过期时间由 `Clock` 提供，测试中可以换成手动推进的 `ManualClock`
+- 过期是惰性的：访问到过期条目时才删除，`purge_expired` 可以一次清理全部
+
+```
+use std::time::Duration;
+use rust_code_examples::data_structure::cache::{LruCache, ManualClock};
+
+let clock = ManualClock::new();
+let mut cache = LruCache::with_clock(2, clock.clone()).with_ttl(Duration::from_secs(10));
+cache.put("a", 1);
+cache.put("b", 2);
+assert_eq!(cache.get(&"a"), Some(&1));
+cache.put("c", 3); // 淘汰最久没有使用的 b
+assert_eq!(cache.peek(&"b"), None);
+
+clock.advance(Duration::from_secs(10));
+assert_eq!(cache.get(&"a"), None);
+assert_eq!((cache.stats().hits, cache.stats().misses), (1, 1));
+```
+*/
+
+mod lfu;
+mod lru;
+
+pub use lfu::LfuCache;
+pub use lru::LruCache;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_lru, "LRU 缓存", "淘汰最久没有使用的条目，peek 不改变使用顺序"),
+    example!(ex2_lfu, "LFU 缓存", "淘汰访问次数最少的条目，次数相同时淘汰最久没有使用的"),
+    example!(ex3_ttl, "过期时间", "用手动推进的时钟测试过期条目"),
+    example!(ex4_callback_stats, "淘汰回调与命中统计", "记录被淘汰的条目和原因，统计命中率"),
+];
+
+/// 缓存使用的时钟，返回从某个固定起点开始经过的时间
+pub trait Clock {
+    fn now(&self) -> Duration;
+}
+
+/// 基于 `Instant` 的真实时钟
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock { start: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// 手动推进的时钟，克隆出的副本共享同一个时间
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+
+    pub fn set(&self, now: Duration) {
+        self.now.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
+
+/// 条目被淘汰的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictReason {
+    /// 容量已满，为新条目腾出位置
+    Capacity,
+    /// 超过了过期时间
+    Expired,
+}
+
+/// 命中统计，只统计 `get` / `get_mut`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    /// 因容量淘汰的条目数
+    pub evictions: u64,
+    /// 因过期被删除的条目数
+    pub expirations: u64,
+}
+
+impl Stats {
+    pub fn lookups(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    /// 没有查询过时返回 0
+    pub fn hit_rate(&self) -> f64 {
+        match self.lookups() {
+            0 => 0.0,
+            n => self.hits as f64 / n as f64,
+        }
+    }
+}
+
+type EvictCallback<K, V> = Box<dyn FnMut(K, V, EvictReason)>;
+
+fn is_expired(expires: Option<Duration>, now: Duration) -> bool {
+    expires.is_some_and(|at| now >= at)
+}
+
+struct Linked<T> {
+    item: T,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+// 节点池，删除的槽位放进 free 之后复用
+struct Slab<T> {
+    slots: Vec<Option<Linked<T>>>,
+    free: Vec<usize>,
+}
+
+impl<T> Slab<T> {
+    fn with_capacity(capacity: usize) -> Slab<T> {
+        Slab { slots: Vec::with_capacity(capacity), free: Vec::new() }
+    }
+
+    fn insert(&mut self, item: T) -> usize {
+        let node = Some(Linked { item, prev: None, next: None });
+        match self.free.pop() {
+            Some(index) => {
+                self.slots[index] = node;
+                index
+            }
+            None => {
+                self.slots.push(node);
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        let node = self.slots[index].take().expect("删除了空槽位");
+        self.free.push(index);
+        node.item
+    }
+
+    fn node(&self, index: usize) -> &Linked<T> {
+        self.slots[index].as_ref().expect("访问了空槽位")
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Linked<T> {
+        self.slots[index].as_mut().expect("访问了空槽位")
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self.node(index).item
+    }
+
+    fn get_mut(&mut self, index: usize) -> &mut T {
+        &mut self.node_mut(index).item
+    }
+
+    fn clear(&mut self) {
+        self.slots.clear();
+        self.free.clear();
+    }
+}
+
+// 节点池中的一条双向链表，头部是最近使用的一端
+#[derive(Debug, Clone, Copy, Default)]
+struct Chain {
+    head: Option<usize>,
+    tail: Option<usize>,
+}
+
+impl Chain {
+    fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    fn push_front<T>(&mut self, slab: &mut Slab<T>, index: usize) {
+        let node = slab.node_mut(index);
+        node.prev = None;
+        node.next = self.head;
+        match self.head {
+            Some(head) => slab.node_mut(head).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+
+    fn unlink<T>(&mut self, slab: &mut Slab<T>, index: usize) {
+        let (prev, next) = {
+            let node = slab.node(index);
+            (node.prev, node.next)
+        };
+        match prev {
+            Some(p) => slab.node_mut(p).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => slab.node_mut(n).prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    // 把 index 插到 prev 之后
+    fn insert_after<T>(&mut self, slab: &mut Slab<T>, prev: usize, index: usize) {
+        let next = slab.node(prev).next;
+        let node = slab.node_mut(index);
+        node.prev = Some(prev);
+        node.next = next;
+        slab.node_mut(prev).next = Some(index);
+        match next {
+            Some(n) => slab.node_mut(n).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+    }
+
+    fn move_to_front<T>(&mut self, slab: &mut Slab<T>, index: usize) {
+        if self.head != Some(index) {
+            self.unlink(slab, index);
+            self.push_front(slab, index);
+        }
+    }
+
+    // 从头到尾的下标
+    fn indices<'a, T>(&self, slab: &'a Slab<T>) -> impl Iterator<Item = usize> + 'a {
+        std::iter::successors(self.head, move |&i| slab.node(i).next)
+    }
+}
+
+fn keys<'a>(cache: &LruCache<&'a str, i32>) -> Vec<&'a str> {
+    cache.iter().map(|(k, _)| *k).collect()
+}
+
+pub fn ex1_lru() {
+    println!("LRU 缓存 示例代码\n\n");
+
+    let mut cache = LruCache::new(3);
+    for (key, value) in [("a", 1), ("b", 2), ("c", 3)] {
+        cache.put(key, value);
+    }
+    assert_eq!(keys(&cache), ["c", "b", "a"]);
+    println!("依次放入 a b c，从新到旧: {:?}", keys(&cache));
+
+    // get 把 a 变成最近使用的
+    assert_eq!(cache.get(&"a"), Some(&1));
+    // peek 只读取，b 仍然是最久没有使用的
+    assert_eq!(cache.peek(&"b"), Some(&2));
+    assert_eq!(keys(&cache), ["a", "c", "b"]);
+    println!("get(a)、peek(b) 之后: {:?}", keys(&cache));
+
+    cache.put("d", 4);
+    assert_eq!(cache.peek(&"b"), None);
+    assert_eq!(keys(&cache), ["d", "a", "c"]);
+    println!("放入 d，淘汰 b: {:?}", keys(&cache));
+
+    // 更新已有的键返回旧值，不会淘汰
+    assert_eq!(cache.put("c", 30), Some(3));
+    if let Some(v) = cache.get_mut(&"a") {
+        *v += 10;
+    }
+    assert_eq!(cache.len(), 3);
+    let entries: Vec<(&str, i32)> = cache.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(entries, [("a", 11), ("c", 30), ("d", 4)]);
+    println!("更新 c、修改 a 之后: {:?}", entries);
+}
+
+pub fn ex2_lfu() {
+    println!("LFU 缓存 示例代码\n\n");
+
+    let mut cache = LfuCache::new(3);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    cache.put("c", 3);
+    for _ in 0..3 {
+        cache.get(&"a");
+    }
+    cache.get(&"b");
+    assert_eq!(cache.frequency(&"a"), Some(4));
+    assert_eq!(cache.frequency(&"b"), Some(2));
+    assert_eq!(cache.frequency(&"c"), Some(1));
+    println!("访问次数: a = 4, b = 2, c = 1");
+
+    // c 的访问次数最少，被淘汰
+    cache.put("d", 4);
+    assert!(!cache.contains_key(&"c"));
+    println!("放入 d，淘汰访问次数最少的 c");
+
+    // 次数相同（d 和新放入的 e 都是 1）时，淘汰最久没有使用的 d
+    cache.put("e", 5);
+    assert!(!cache.contains_key(&"d"));
+    // peek 不增加访问次数
+    assert_eq!(cache.peek(&"e"), Some(&5));
+    assert_eq!(cache.frequency(&"e"), Some(1));
+    let mut keys: Vec<&str> = cache.iter().map(|(k, _)| *k).collect();
+    keys.sort_unstable();
+    assert_eq!(keys, ["a", "b", "e"]);
+    println!("放入 e，淘汰次数同为 1 但更早使用的 d，剩余: {:?}", keys);
+}
+
+pub fn ex3_ttl() {
+    println!("过期时间 示例代码\n\n");
+
+    let clock = ManualClock::new();
+    let mut cache = LruCache::with_clock(10, clock.clone()).with_ttl(Duration::from_secs(60));
+    cache.put("session", "alice");
+    cache.put_with_ttl("token", "xyz", Duration::from_secs(5));
+    cache.put_without_ttl("config", "debug");
+
+    clock.advance(Duration::from_secs(5));
+    assert_eq!(cache.get(&"token"), None);
+    assert_eq!(cache.get(&"session"), Some(&"alice"));
+    println!("5 秒之后: token 已过期，session 仍然有效");
+
+    clock.advance(Duration::from_secs(55));
+    // peek 看不到过期条目，但不会删除它
+    assert_eq!(cache.peek(&"session"), None);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.purge_expired(), 1);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.get(&"config"), Some(&"debug"));
+    println!("60 秒之后: session 过期被清理，没有过期时间的 config 仍然有效");
+    assert_eq!(cache.stats().expirations, 2);
+
+    let mut lfu = LfuCache::with_clock(2, clock.clone()).with_ttl(Duration::from_millis(100));
+    lfu.put(1, "one");
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(lfu.get(&1), None);
+    println!("LFU 缓存同样支持过期时间");
+}
+
+pub fn ex4_callback_stats() {
+    println!("淘汰回调与命中统计 示例代码\n\n");
+
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let log = Rc::clone(&evicted);
+    let clock = ManualClock::new();
+    let mut cache = LruCache::with_clock(2, clock.clone()).on_evict(move |key, value, reason| {
+        log.borrow_mut().push((key, value, reason));
+    });
+
+    cache.put(1, "one");
+    cache.put_with_ttl(2, "two", Duration::from_secs(1));
+    cache.put(3, "three");
+    clock.advance(Duration::from_secs(1));
+    cache.put(4, "four");
+    // 显式删除不触发回调
+    cache.remove(&4);
+    assert_eq!(*evicted.borrow(), [(1, "one", EvictReason::Capacity), (2, "two", EvictReason::Expired)]);
+    println!("淘汰记录: {:?}", evicted.borrow());
+
+    cache.put(5, "five");
+    for key in [3, 5, 1, 3, 6] {
+        cache.get(&key);
+    }
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.evictions, stats.expirations), (3, 2, 1, 1));
+    assert_eq!(stats.hit_rate(), 0.6);
+    println!("命中 {} 次，未命中 {} 次，命中率 {:.0}%", stats.hits, stats.misses, stats.hit_rate() * 100.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 淘汰顺序与 O(n) 的朴素实现对比：LRU 是按最近使用排序的 Vec，
+    // LFU 按 (访问次数, 最后一次使用的时刻) 取最小值
+    #[test]
+    fn eviction_order_matches_naive_model() {
+        let mut rng = XorShift64::new(14);
+        for capacity in 1..=6 {
+            let mut lru = LruCache::new(capacity);
+            let mut lfu = LfuCache::new(capacity);
+            let mut lru_model: Vec<(u64, u64)> = Vec::new();
+            // (键, 值, 访问次数, 最后一次使用的时刻)
+            let mut lfu_model: Vec<(u64, u64, u64, usize)> = Vec::new();
+
+            for step in 0..3000 {
+                let key = rng.below(10);
+                match rng.below(4) {
+                    0 | 1 => {
+                        let value = rng.below(1000);
+                        let expected = lru_model.iter().position(|&(k, _)| k == key).map(|i| lru_model.remove(i).1);
+                        if expected.is_none() && lru_model.len() == capacity {
+                            lru_model.pop();
+                        }
+                        lru_model.insert(0, (key, value));
+                        assert_eq!(lru.put(key, value), expected, "第 {} 步 LRU put", step);
+
+                        let expected = match lfu_model.iter_mut().find(|e| e.0 == key) {
+                            Some(entry) => {
+                                entry.2 += 1;
+                                entry.3 = step;
+                                Some(std::mem::replace(&mut entry.1, value))
+                            }
+                            None => {
+                                if lfu_model.len() == capacity {
+                                    let victim = (0..lfu_model.len()).min_by_key(|&i| (lfu_model[i].2, lfu_model[i].3)).unwrap();
+                                    lfu_model.remove(victim);
+                                }
+                                lfu_model.push((key, value, 1, step));
+                                None
+                            }
+                        };
+                        assert_eq!(lfu.put(key, value), expected, "第 {} 步 LFU put", step);
+                    }
+                    2 => {
+                        let expected = lru_model.iter().position(|&(k, _)| k == key).map(|i| {
+                            let entry = lru_model.remove(i);
+                            lru_model.insert(0, entry);
+                            entry.1
+                        });
+                        assert_eq!(lru.get(&key).copied(), expected, "第 {} 步 LRU get", step);
+
+                        let expected = lfu_model.iter_mut().find(|e| e.0 == key).map(|entry| {
+                            entry.2 += 1;
+                            entry.3 = step;
+                            entry.1
+                        });
+                        assert_eq!(lfu.get(&key).copied(), expected, "第 {} 步 LFU get", step);
+                    }
+                    _ => {
+                        let expected = lru_model.iter().position(|&(k, _)| k == key).map(|i| lru_model.remove(i).1);
+                        assert_eq!(lru.remove(&key), expected, "第 {} 步 LRU remove", step);
+                        let expected = lfu_model.iter().position(|e| e.0 == key).map(|i| lfu_model.remove(i).1);
+                        assert_eq!(lfu.remove(&key), expected, "第 {} 步 LFU remove", step);
+                    }
+                }
+
+                let order: Vec<(u64, u64)> = lru.iter().map(|(&k, &v)| (k, v)).collect();
+                assert_eq!(order, lru_model, "第 {} 步之后 LRU 顺序不同", step);
+                let mut keys: Vec<u64> = lfu.iter().map(|(&k, _)| k).collect();
+                let mut expected: Vec<u64> = lfu_model.iter().map(|e| e.0).collect();
+                keys.sort_unstable();
+                expected.sort_unstable();
+                assert_eq!(keys, expected, "第 {} 步之后 LFU 内容不同", step);
+            }
+        }
+    }
+
+    #[test]
+    fn lfu_ties_evict_least_recently_used() {
+        let mut cache = LfuCache::new(3);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+        cache.get(&"a");
+        cache.get(&"b");
+        cache.put("d", 4);
+        assert!(!cache.contains_key(&"c"));
+        // a、b、d 的次数都是 2，a 最早升到 2
+        cache.get(&"d");
+        cache.put("e", 5);
+        assert!(!cache.contains_key(&"a"));
+        assert_eq!((cache.frequency(&"b"), cache.frequency(&"d"), cache.frequency(&"e")), (Some(2), Some(2), Some(1)));
+    }
+
+    #[test]
+    fn updating_resets_ttl_and_counts_as_access() {
+        let clock = ManualClock::new();
+        let mut cache = LfuCache::with_clock(2, clock.clone());
+        cache.put_with_ttl("k", 1, Duration::from_secs(5));
+        clock.advance(Duration::from_secs(4));
+        assert_eq!(cache.put_with_ttl("k", 2, Duration::from_secs(5)), Some(1));
+        assert_eq!(cache.frequency(&"k"), Some(2));
+        clock.advance(Duration::from_secs(4));
+        assert_eq!(cache.peek(&"k"), Some(&2));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(cache.frequency(&"k"), None);
+        // 过期的条目再次放入时从头计数，旧值不返回
+        assert_eq!(cache.put("k", 3), None);
+        assert_eq!(cache.frequency(&"k"), Some(1));
+    }
+
+    #[test]
+    fn expired_victim_is_reported_as_expired() {
+        let clock = ManualClock::new();
+        let evicted = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&evicted);
+        let mut cache = LruCache::with_clock(1, clock.clone()).on_evict(move |k, _, reason| log.borrow_mut().push((k, reason)));
+        cache.put_with_ttl("a", 1, Duration::from_secs(1));
+        clock.advance(Duration::from_secs(1));
+        cache.put("b", 2);
+        cache.put("c", 3);
+        assert_eq!(*evicted.borrow(), [("a", EvictReason::Expired), ("b", EvictReason::Capacity)]);
+        assert_eq!((cache.stats().expirations, cache.stats().evictions), (1, 1));
+    }
+
+    #[test]
+    fn clear_then_reuse() {
+        let mut cache = LfuCache::new(2);
+        cache.put(1, "one");
+        cache.get(&1);
+        cache.clear();
+        assert!(cache.is_empty());
+        cache.put(2, "two");
+        cache.put(3, "three");
+        cache.put(4, "four");
+        assert_eq!((cache.len(), cache.frequency(&2), cache.frequency(&4)), (2, None, Some(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "容量必须大于 0")]
+    fn zero_capacity_panics() {
+        LruCache::<u32, u32>::new(0);
+    }
+
+    #[test]
+    fn lfu_buckets_stay_ordered_after_remove_and_expiry() {
+        let clock = ManualClock::new();
+        let mut cache = LfuCache::with_clock(2, clock.clone());
+        cache.put("a", 1);
+        cache.put_with_ttl("b", 2, Duration::from_secs(1));
+        cache.get(&"a");
+        cache.get(&"a");
+        // 删掉唯一一个次数为 1 的条目之后，次数为 3 的桶成为第一个桶
+        assert_eq!(cache.remove(&"b"), Some(2));
+        cache.put("c", 3);
+        cache.put("d", 4);
+        assert_eq!((cache.frequency(&"a"), cache.peek(&"c"), cache.frequency(&"d")), (Some(3), None, Some(1)));
+
+        // d 过期后被惰性删除，新条目要重新建立次数为 1 的桶，并排在 a 的桶之前
+        cache.put_with_ttl("d", 5, Duration::from_secs(1));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(cache.get(&"d"), None);
+        cache.put("e", 6);
+        cache.put("f", 7);
+        assert!(cache.contains_key(&"a") && cache.contains_key(&"f") && !cache.contains_key(&"e"));
+        assert_eq!(cache.stats().expirations, 1);
+    }
+}
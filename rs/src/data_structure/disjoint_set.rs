@@ -0,0 +1,274 @@
+/*!
+并查集（不相交集合）
+
+把 `0..n` 划分成若干个集合，支持合并两个集合、查询两个元素是否在同一个集合中。
+路径压缩让查找时经过的节点直接指向根，按秩合并让矮的树挂到高的树下，
+两者一起使用时单次操作的均摊代价是 O(α(n))，α 是增长极慢的反阿克曼函数，实际中可以看作常数。
+
+```
+use rust_code_examples::data_structure::disjoint_set::DisjointSet;
+
+let mut sets = DisjointSet::new(5);
+assert!(sets.union(0, 1));
+assert!(sets.union(3, 4));
+assert!(!sets.union(1, 0));
+assert!(sets.same(0, 1) && !sets.same(1, 3));
+assert_eq!((sets.set_count(), sets.set_size(4)), (3, 2));
+```
+*/
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_union_find, "合并与查询", "合并集合、判断连通性、查看集合大小"),
+    example!(ex2_path_compression, "路径压缩与按秩合并", "查找之后路径上的节点直接指向根，树的高度保持很低"),
+];
+
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    // 秩是树高的上界，只有根的秩有意义
+    rank: Vec<u8>,
+    // 只有根的大小有意义
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// n 个元素，每个元素单独成为一个集合
+    pub fn new(n: usize) -> DisjointSet {
+        DisjointSet { parent: (0..n).collect(), rank: vec![0; n], size: vec![1; n], sets: n }
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// 集合个数
+    pub fn set_count(&self) -> usize {
+        self.sets
+    }
+
+    /// 添加一个单独成为集合的新元素，返回它的编号
+    pub fn push(&mut self) -> usize {
+        let x = self.parent.len();
+        self.parent.push(x);
+        self.rank.push(0);
+        self.size.push(1);
+        self.sets += 1;
+        x
+    }
+
+    /// x 所在集合的代表元素
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // 第二遍把路径上的节点都直接挂到根上
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// 不做路径压缩的查找，可以在只读借用下使用
+    pub fn find_immutable(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// 合并 a 和 b 所在的集合，原来就在同一个集合时返回 false
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // 秩小的根挂到秩大的根下面，秩相同时新根的秩加一
+        let (child, root) = if self.rank[a] < self.rank[b] { (a, b) } else { (b, a) };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[a] == self.rank[b] {
+            self.rank[root] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// x 所在集合的元素个数
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// 所有集合，集合内部和集合之间都按最小元素排序
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = vec![Vec::new(); self.len()];
+        for x in 0..self.len() {
+            let root = self.find(x);
+            groups[root].push(x);
+        }
+        let mut groups: Vec<Vec<usize>> = groups.into_iter().filter(|g| !g.is_empty()).collect();
+        groups.sort_unstable_by_key(|g| g[0]);
+        groups
+    }
+
+    /// x 到根的距离，用来观察路径压缩的效果
+    pub fn depth(&self, mut x: usize) -> usize {
+        let mut depth = 0;
+        while self.parent[x] != x {
+            x = self.parent[x];
+            depth += 1;
+        }
+        depth
+    }
+}
+
+pub fn ex1_union_find() {
+    println!("合并与查询 示例代码\n\n");
+
+    // 朋友关系：朋友的朋友也在同一个圈子里
+    let names = ["张三", "李四", "王五", "赵六", "孙七", "周八"];
+    let friendships = [(0, 1), (1, 2), (3, 4)];
+    let mut circles = DisjointSet::new(names.len());
+    for (a, b) in friendships {
+        circles.union(a, b);
+        println!("{} 与 {} 成为朋友", names[a], names[b]);
+    }
+
+    assert!(circles.same(0, 2));
+    assert!(!circles.same(2, 3));
+    println!("\n{} 与 {} 在同一个圈子里: {}", names[0], names[2], circles.same(0, 2));
+    println!("{} 与 {} 在同一个圈子里: {}", names[2], names[3], circles.same(2, 3));
+
+    let groups = circles.sets();
+    assert_eq!(groups, [vec![0, 1, 2], vec![3, 4], vec![5]]);
+    for group in &groups {
+        let members: Vec<&str> = group.iter().map(|&i| names[i]).collect();
+        println!("圈子 ({} 人): {:?}", group.len(), members);
+    }
+    assert_eq!(circles.set_count(), 3);
+    assert_eq!(circles.set_size(1), 3);
+}
+
+pub fn ex2_path_compression() {
+    println!("路径压缩与按秩合并 示例代码\n\n");
+
+    // 总是把包含 0 的大集合和一个新元素合并，按秩合并让新元素挂到根下，树的高度是 1
+    let n = 1024;
+    let mut sets = DisjointSet::new(n);
+    for x in 1..n {
+        sets.union(0, x);
+    }
+    let max_depth = (0..n).map(|x| sets.depth(x)).max().unwrap();
+    assert_eq!(max_depth, 1);
+    println!("依次合并 0 与 1..{}: 最大深度 {}", n, max_depth);
+
+    // 两两合并同样大小的集合是按秩合并的最坏情况，高度是 log2(n)
+    let mut sets = DisjointSet::new(n);
+    let mut width = 1;
+    while width < n {
+        for start in (0..n).step_by(2 * width) {
+            sets.union(start, start + width);
+        }
+        width *= 2;
+    }
+    let deepest = (0..n).max_by_key(|&x| sets.depth(x)).unwrap();
+    let before = sets.depth(deepest);
+    assert_eq!(before, 10);
+    println!("两两合并同样大小的集合: 最大深度 {} = log2({})", before, n);
+
+    // 一次查找之后，路径上的所有节点都直接指向根
+    sets.find(deepest);
+    assert_eq!(sets.depth(deepest), 1);
+    assert_eq!((0..n).map(|x| sets.depth(x)).max().unwrap(), 9);
+    println!("find({}) 之后它的深度变为 {}", deepest, sets.depth(deepest));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::XorShift64;
+
+    #[test]
+    fn disjoint_sets_match_brute_force() {
+        let mut rng = XorShift64::new(17);
+        let n = 40;
+        let mut sets = DisjointSet::new(n);
+        // 暴力实现：label[x] 是 x 所在集合的编号，合并时把一个集合的编号全部改掉
+        let mut label: Vec<usize> = (0..n).collect();
+
+        for step in 0..5000 {
+            if rng.below(20) == 0 {
+                assert_eq!(sets.push(), label.len());
+                label.push(label.len());
+            }
+            let n = label.len();
+            let (a, b) = (rng.below(n as u64) as usize, rng.below(n as u64) as usize);
+            if rng.bool() {
+                let (la, lb) = (label[a], label[b]);
+                assert_eq!(sets.union(a, b), la != lb, "第 {} 步 union({}, {})", step, a, b);
+                for l in label.iter_mut().filter(|l| **l == lb) {
+                    *l = la;
+                }
+            } else {
+                assert_eq!(sets.same(a, b), label[a] == label[b], "第 {} 步 same({}, {})", step, a, b);
+            }
+
+            let size = label.iter().filter(|&&l| l == label[a]).count();
+            assert_eq!(sets.set_size(a), size, "第 {} 步 set_size({})", step, a);
+            let mut distinct = label.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            assert_eq!(sets.set_count(), distinct.len(), "第 {} 步之后集合个数不同", step);
+            // 按秩合并保证树高不超过 log2(n)
+            assert!((0..n).all(|x| sets.depth(x) <= n.ilog2() as usize), "第 {} 步之后树太高", step);
+        }
+    }
+
+    #[test]
+    fn union_within_one_set_changes_nothing() {
+        let mut sets = DisjointSet::new(3);
+        assert!(!sets.union(1, 1));
+        assert!(sets.union(0, 1));
+        assert!(!sets.union(1, 0));
+        assert_eq!((sets.set_count(), sets.set_size(0), sets.set_size(2)), (2, 2, 1));
+        assert_eq!(sets.sets(), [vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn find_compresses_only_the_path_it_walks() {
+        let mut sets = DisjointSet::new(8);
+        for (a, b) in [(0, 1), (2, 3), (4, 5), (6, 7), (0, 2), (4, 6), (0, 4)] {
+            assert!(sets.union(a, b));
+        }
+        // 7 -> 6 -> 4 -> 0 是最深的一条路径
+        assert_eq!((sets.depth(7), sets.depth(5), sets.depth(3)), (3, 2, 2));
+        assert_eq!(sets.find_immutable(7), 0);
+        assert_eq!(sets.depth(7), 3);
+        assert_eq!(sets.find(7), 0);
+        assert_eq!((sets.depth(7), sets.depth(6), sets.depth(5), sets.depth(3)), (1, 1, 2, 2));
+    }
+
+    #[test]
+    fn grows_from_empty() {
+        let mut sets = DisjointSet::new(0);
+        assert!(sets.is_empty() && sets.set_count() == 0 && sets.sets().is_empty());
+        assert_eq!((sets.push(), sets.push()), (0, 1));
+        assert!(sets.union(1, 0));
+        assert_eq!((sets.len(), sets.set_count(), sets.set_size(1)), (2, 1, 2));
+    }
+}
@@ -0,0 +1,480 @@
+/*!
+区间树：查询与给定区间重叠的所有区间
+
+区间按 (起点, 终点) 排序存放在一棵 AVL 树中，每个节点额外记录子树中最大的终点。
+查询时如果某棵子树的最大终点不超过查询区间的起点，整棵子树都不可能重叠，可以直接跳过，
+所以查询的代价是 O(log n + k)，k 是结果的个数。
+
+区间是左闭右开的 `Range`，与标准库一致：`1..3` 与 `3..5` 不重叠。
+
+```
+use rust_code_examples::data_structure::interval_tree::IntervalTree;
+
+let mut meetings = IntervalTree::new();
+meetings.insert(9..10, "站会");
+meetings.insert(10..12, "评审");
+meetings.insert(14..15, "面试");
+let busy: Vec<&str> = meetings.overlapping(9..11).map(|(_, name)| *name).collect();
+assert_eq!(busy, ["站会", "评审"]);
+assert_eq!(meetings.containing(12).count(), 0);
+```
+*/
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_overlap_query, "重叠查询", "找出与某个时间段冲突的日程"),
+    example!(ex2_point_query, "包含某个点的区间", "查询哪些区间覆盖了给定的点"),
+];
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+struct Node<K, V> {
+    range: Range<K>,
+    value: V,
+    // 子树中最大的终点
+    max_end: K,
+    height: u32,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+fn height<K, V>(link: &Link<K, V>) -> u32 {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn update<K: Ord + Clone, V>(node: &mut Node<K, V>) {
+    node.height = 1 + height(&node.left).max(height(&node.right));
+    let mut max_end = node.range.end.clone();
+    for child in [&node.left, &node.right].into_iter().flatten() {
+        if child.max_end > max_end {
+            max_end = child.max_end.clone();
+        }
+    }
+    node.max_end = max_end;
+}
+
+fn rotate_right<K: Ord + Clone, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut left = node.left.take().expect("右旋时左子树不为空");
+    node.left = left.right.take();
+    update(&mut node);
+    left.right = Some(node);
+    update(&mut left);
+    left
+}
+
+fn rotate_left<K: Ord + Clone, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut right = node.right.take().expect("左旋时右子树不为空");
+    node.right = right.left.take();
+    update(&mut node);
+    right.left = Some(node);
+    update(&mut right);
+    right
+}
+
+// 与 ordered_map::AvlTree 相同的平衡方式，旋转时一并更新 max_end
+fn rebalance<K: Ord + Clone, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    update(&mut node);
+    let (left, right) = (height(&node.left), height(&node.right));
+    if left > right + 1 {
+        let child = node.left.take().expect("左子树更高");
+        node.left = Some(if height(&child.left) < height(&child.right) { rotate_left(child) } else { child });
+        rotate_right(node)
+    } else if right > left + 1 {
+        let child = node.right.take().expect("右子树更高");
+        node.right = Some(if height(&child.right) < height(&child.left) { rotate_right(child) } else { child });
+        rotate_left(node)
+    } else {
+        node
+    }
+}
+
+fn compare<K: Ord>(a: &Range<K>, b: &Range<K>) -> Ordering {
+    a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end))
+}
+
+fn insert<K: Ord + Clone, V>(link: Link<K, V>, range: Range<K>, value: V) -> (Box<Node<K, V>>, Option<V>) {
+    let Some(mut node) = link else {
+        let max_end = range.end.clone();
+        return (Box::new(Node { range, value, max_end, height: 1, left: None, right: None }), None);
+    };
+    match compare(&range, &node.range) {
+        Ordering::Less => {
+            let (left, old) = insert(node.left.take(), range, value);
+            node.left = Some(left);
+            (rebalance(node), old)
+        }
+        Ordering::Greater => {
+            let (right, old) = insert(node.right.take(), range, value);
+            node.right = Some(right);
+            (rebalance(node), old)
+        }
+        Ordering::Equal => {
+            let old = std::mem::replace(&mut node.value, value);
+            (node, Some(old))
+        }
+    }
+}
+
+fn remove_min<K: Ord + Clone, V>(mut node: Box<Node<K, V>>) -> (Link<K, V>, Box<Node<K, V>>) {
+    match node.left.take() {
+        None => (node.right.take(), node),
+        Some(left) => {
+            let (left, min) = remove_min(left);
+            node.left = left;
+            (Some(rebalance(node)), min)
+        }
+    }
+}
+
+fn remove<K: Ord + Clone, V>(link: Link<K, V>, range: &Range<K>) -> (Link<K, V>, Option<V>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    match compare(range, &node.range) {
+        Ordering::Less => {
+            let (left, old) = remove(node.left.take(), range);
+            node.left = left;
+            (Some(rebalance(node)), old)
+        }
+        Ordering::Greater => {
+            let (right, old) = remove(node.right.take(), range);
+            node.right = right;
+            (Some(rebalance(node)), old)
+        }
+        Ordering::Equal => {
+            let Node { value, left, right, .. } = *node;
+            match (left, right) {
+                (None, child) | (child, None) => (child, Some(value)),
+                (Some(left), Some(right)) => {
+                    let (right, mut successor) = remove_min(right);
+                    successor.left = Some(left);
+                    successor.right = right;
+                    (Some(rebalance(successor)), Some(value))
+                }
+            }
+        }
+    }
+}
+
+/// 区间树，同一个区间只保存一个值
+pub struct IntervalTree<K, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K: Ord + Clone, V> IntervalTree<K, V> {
+    pub fn new() -> IntervalTree<K, V> {
+        IntervalTree { root: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 插入区间，同一个区间已经存在时替换值并返回旧值；空区间不会与任何区间重叠
+    pub fn insert(&mut self, range: Range<K>, value: V) -> Option<V> {
+        let (root, old) = insert(self.root.take(), range, value);
+        self.root = Some(root);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn remove(&mut self, range: &Range<K>) -> Option<V> {
+        let (root, old) = remove(self.root.take(), range);
+        self.root = root;
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    pub fn get(&self, range: &Range<K>) -> Option<&V> {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match compare(range, &node.range) {
+                Ordering::Less => link = &node.left,
+                Ordering::Greater => link = &node.right,
+                Ordering::Equal => return Some(&node.value),
+            }
+        }
+        None
+    }
+
+    /// 按 (起点, 终点) 的顺序迭代所有区间
+    pub fn iter(&self) -> impl Iterator<Item = (&Range<K>, &V)> {
+        let mut out = Vec::with_capacity(self.len);
+        collect(&self.root, &mut |node| out.push((&node.range, &node.value)), &|_| true, &|_| true);
+        out.into_iter()
+    }
+
+    /// 与 query 重叠的所有区间，按 (起点, 终点) 排序
+    pub fn overlapping(&self, query: Range<K>) -> impl Iterator<Item = (&Range<K>, &V)> {
+        let mut out = Vec::new();
+        if query.start < query.end {
+            collect(
+                &self.root,
+                &mut |node| {
+                    let range = &node.range;
+                    if range.start < range.end && range.start < query.end && query.start < range.end {
+                        out.push((&node.range, &node.value));
+                    }
+                },
+                // 子树中最大的终点不超过查询的起点，整棵子树都在左边
+                &|node| node.max_end > query.start,
+                // 右子树的起点都不小于这个节点的起点
+                &|node| node.range.start < query.end,
+            );
+        }
+        out.into_iter()
+    }
+
+    /// 包含 point 的所有区间
+    pub fn containing(&self, point: K) -> impl Iterator<Item = (&Range<K>, &V)> {
+        let mut out = Vec::new();
+        collect(
+            &self.root,
+            &mut |node| {
+                if node.range.contains(&point) {
+                    out.push((&node.range, &node.value));
+                }
+            },
+            &|node| node.max_end > point,
+            &|node| node.range.start <= point,
+        );
+        out.into_iter()
+    }
+
+    /// 检查顺序、平衡条件以及每个节点记录的 max_end
+    pub fn is_valid(&self) -> bool {
+        fn check<K: Ord + Clone, V>(link: &Link<K, V>) -> Option<(u32, usize, Option<K>)> {
+            let Some(node) = link else {
+                return Some((0, 0, None));
+            };
+            let (left, left_count, left_max) = check(&node.left)?;
+            let (right, right_count, right_max) = check(&node.right)?;
+            let ordered = node.left.as_ref().is_none_or(|l| compare(&l.range, &node.range).is_lt())
+                && node.right.as_ref().is_none_or(|r| compare(&r.range, &node.range).is_gt());
+            let max_end = [left_max, right_max].into_iter().flatten().fold(node.range.end.clone(), |a, b| a.max(b));
+            let ok = ordered
+                && left.abs_diff(right) <= 1
+                && node.height == 1 + left.max(right)
+                && node.max_end == max_end;
+            ok.then_some((node.height, left_count + right_count + 1, Some(max_end)))
+        }
+        check(&self.root).is_some_and(|(_, count, _)| count == self.len)
+    }
+}
+
+impl<K: Ord + Clone, V> Default for IntervalTree<K, V> {
+    fn default() -> IntervalTree<K, V> {
+        IntervalTree::new()
+    }
+}
+
+impl<K: Ord + Clone, V> FromIterator<(Range<K>, V)> for IntervalTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (Range<K>, V)>>(iter: I) -> IntervalTree<K, V> {
+        let mut tree = IntervalTree::new();
+        for (range, value) in iter {
+            tree.insert(range, value);
+        }
+        tree
+    }
+}
+
+// 中序遍历，visit_subtree 为 false 时跳过整棵子树，visit_right 为 false 时跳过右子树
+fn collect<'a, K, V>(
+    link: &'a Link<K, V>,
+    visit: &mut impl FnMut(&'a Node<K, V>),
+    visit_subtree: &impl Fn(&Node<K, V>) -> bool,
+    visit_right: &impl Fn(&Node<K, V>) -> bool,
+) {
+    let Some(node) = link else { return };
+    if !visit_subtree(node) {
+        return;
+    }
+    collect(&node.left, visit, visit_subtree, visit_right);
+    visit(node);
+    if visit_right(node) {
+        collect(&node.right, visit, visit_subtree, visit_right);
+    }
+}
+
+pub fn ex1_overlap_query() {
+    println!("重叠查询 示例代码\n\n");
+
+    // 以分钟为单位的日程，9:00 是 540
+    let schedule = [
+        (540..570, "站会"),
+        (600..720, "需求评审"),
+        (690..750, "午餐"),
+        (840..900, "面试"),
+        (870..960, "技术分享"),
+    ];
+    let tree: IntervalTree<u32, &str> = schedule.iter().cloned().collect();
+    let fmt = |m: u32| format!("{:02}:{:02}", m / 60, m % 60);
+
+    for (query, expected) in [(700..760, vec!["需求评审", "午餐"]), (570..600, vec![]), (880..890, vec!["面试", "技术分享"])] {
+        let conflicts: Vec<&str> = tree.overlapping(query.clone()).map(|(_, name)| *name).collect();
+        assert_eq!(conflicts, expected);
+        println!("{}-{} 与 {:?} 冲突", fmt(query.start), fmt(query.end), conflicts);
+    }
+
+    // 左闭右开：站会 9:00-9:30 与 9:30 开始的会议不冲突
+    assert_eq!(tree.overlapping(570..600).count(), 0);
+    assert_eq!(tree.overlapping(569..600).count(), 1);
+    println!("\n9:30 开始的会议与 9:00-9:30 的站会不冲突");
+}
+
+pub fn ex2_point_query() {
+    println!("包含某个点的区间 示例代码\n\n");
+
+    let mut tree = IntervalTree::new();
+    tree.insert(0..10, "a");
+    tree.insert(5..15, "b");
+    tree.insert(12..20, "c");
+    tree.insert(30..40, "d");
+    assert!(tree.is_valid());
+
+    for (point, expected) in [(7, vec!["a", "b"]), (12, vec!["b", "c"]), (25, vec![]), (35, vec!["d"])] {
+        let covering: Vec<&str> = tree.containing(point).map(|(_, name)| *name).collect();
+        assert_eq!(covering, expected);
+        println!("包含 {:>2} 的区间: {:?}", point, covering);
+    }
+
+    assert_eq!(tree.remove(&(5..15)), Some("b"));
+    assert_eq!(tree.containing(12).map(|(_, v)| *v).collect::<Vec<_>>(), ["c"]);
+    println!("删除 5..15 之后包含 12 的区间: [\"c\"]");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::XorShift64;
+
+    #[test]
+    fn interval_tree_matches_brute_force() {
+        let mut rng = XorShift64::new(17);
+        let mut tree = IntervalTree::new();
+        let mut brute: Vec<(Range<i64>, u32)> = Vec::new();
+        let random_range = |rng: &mut XorShift64| {
+            let start = rng.range(0..100);
+            // 也生成空区间，空区间不与任何区间重叠
+            start..start + rng.range(0..20)
+        };
+
+        for step in 0..10_000 {
+            match rng.below(4) {
+                0 | 1 => {
+                    let range = random_range(&mut rng);
+                    let value = rng.below(1000) as u32;
+                    let expected = match brute.iter_mut().find(|(r, _)| *r == range) {
+                        Some(entry) => Some(std::mem::replace(&mut entry.1, value)),
+                        None => {
+                            brute.push((range.clone(), value));
+                            None
+                        }
+                    };
+                    assert_eq!(tree.insert(range.clone(), value), expected, "第 {} 步 insert({:?})", step, range);
+                }
+                2 => {
+                    // 一半的概率删除一个已有的区间
+                    let range = match brute.len() {
+                        0 => random_range(&mut rng),
+                        n if rng.bool() => brute[rng.below(n as u64) as usize].0.clone(),
+                        _ => random_range(&mut rng),
+                    };
+                    let expected = brute.iter().position(|(r, _)| *r == range).map(|i| brute.swap_remove(i).1);
+                    assert_eq!(tree.remove(&range), expected, "第 {} 步 remove({:?})", step, range);
+                }
+                _ => {
+                    brute.sort_by(|a, b| compare(&a.0, &b.0));
+                    let query = random_range(&mut rng);
+                    let expected: Vec<(Range<i64>, u32)> = brute
+                        .iter()
+                        .filter(|(r, _)| !r.is_empty() && !query.is_empty() && r.start < query.end && query.start < r.end)
+                        .cloned()
+                        .collect();
+                    let actual: Vec<(Range<i64>, u32)> = tree.overlapping(query.clone()).map(|(r, &v)| (r.clone(), v)).collect();
+                    assert_eq!(actual, expected, "第 {} 步 overlapping({:?})", step, query);
+
+                    let point = query.start;
+                    let expected: Vec<(Range<i64>, u32)> = brute.iter().filter(|(r, _)| r.contains(&point)).cloned().collect();
+                    let actual: Vec<(Range<i64>, u32)> = tree.containing(point).map(|(r, &v)| (r.clone(), v)).collect();
+                    assert_eq!(actual, expected, "第 {} 步 containing({})", step, point);
+                }
+            }
+            assert_eq!(tree.len(), brute.len(), "第 {} 步之后长度不同", step);
+            assert!(tree.is_valid(), "第 {} 步之后区间树不满足不变量", step);
+        }
+    }
+
+    fn ranges<'a>(iter: impl Iterator<Item = (&'a Range<i64>, &'a u32)>) -> Vec<(i64, i64)> {
+        iter.map(|(r, _)| (r.start, r.end)).collect()
+    }
+
+    #[test]
+    fn touching_intervals_do_not_overlap() {
+        let mut tree = IntervalTree::new();
+        tree.insert(0..5, 0);
+        tree.insert(5..10, 1);
+        assert_eq!(ranges(tree.overlapping(4..6)), [(0, 5), (5, 10)]);
+        assert_eq!(ranges(tree.overlapping(5..10)), [(5, 10)]);
+        assert_eq!(ranges(tree.overlapping(10..20)), []);
+        assert_eq!(ranges(tree.containing(5)), [(5, 10)]);
+        assert_eq!(ranges(tree.containing(10)), []);
+    }
+
+    #[test]
+    fn empty_intervals_are_stored_but_never_match() {
+        let mut tree = IntervalTree::new();
+        tree.insert(0..10, 0);
+        assert_eq!(tree.insert(3..3, 1), None);
+        assert_eq!((tree.len(), tree.get(&(3..3))), (2, Some(&1)));
+        assert_eq!(ranges(tree.overlapping(0..10)), [(0, 10)]);
+        assert_eq!(ranges(tree.overlapping(3..3)), []);
+        assert_eq!(ranges(tree.containing(3)), [(0, 10)]);
+        assert_eq!(tree.remove(&(3..3)), Some(1));
+        assert!(tree.is_valid());
+    }
+
+    #[test]
+    fn same_start_is_ordered_by_end() {
+        let mut tree = IntervalTree::new();
+        tree.insert(0..7, 0);
+        tree.insert(0..5, 1);
+        assert_eq!(tree.insert(0..7, 2), Some(0));
+        assert_eq!(ranges(tree.iter()), [(0, 5), (0, 7)]);
+        assert_eq!(ranges(tree.containing(6)), [(0, 7)]);
+    }
+
+    #[test]
+    fn removing_the_widest_interval_updates_max_end() {
+        let mut tree = IntervalTree::new();
+        for i in 0..15 {
+            tree.insert(i * 10..i * 10 + 5, i as u32);
+        }
+        tree.insert(0..1000, 99);
+        assert_eq!(ranges(tree.containing(500)), [(0, 1000)]);
+        assert_eq!(tree.remove(&(0..1000)), Some(99));
+        assert_eq!(tree.remove(&(0..1000)), None);
+        assert!(tree.is_valid());
+        assert_eq!((tree.len(), ranges(tree.containing(500))), (15, vec![]));
+        // 删除有两个子节点的节点，后继顶替它的位置
+        for i in (0..15).step_by(2) {
+            assert_eq!(tree.remove(&(i * 10..i * 10 + 5)), Some(i as u32));
+            assert!(tree.is_valid(), "删除 {} 之后区间树不满足不变量", i);
+        }
+        assert_eq!(ranges(tree.overlapping(0..150)), (1..15).step_by(2).map(|i| (i * 10, i * 10 + 5)).collect::<Vec<_>>());
+    }
+}
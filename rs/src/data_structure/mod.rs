@@ -0,0 +1,26 @@
+use crate::registry::Registry;
+
+pub mod arena_list;
+pub mod cache;
+pub mod disjoint_set;
+pub mod interval_tree;
+pub mod ordered_map;
+pub mod persistent;
+pub mod ring_buffer;
+pub mod rope;
+pub mod segment_tree;
+pub mod trie;
+
+pub fn register(registry: &mut Registry) {
+    registry.module("data_structure", "数据结构");
+    registry.register(arena_list::EXAMPLES);
+    registry.register(cache::EXAMPLES);
+    registry.register(ordered_map::EXAMPLES);
+    registry.register(trie::EXAMPLES);
+    registry.register(disjoint_set::EXAMPLES);
+    registry.register(interval_tree::EXAMPLES);
+    registry.register(segment_tree::EXAMPLES);
+    registry.register(persistent::EXAMPLES);
+    registry.register(rope::EXAMPLES);
+    registry.register(ring_buffer::EXAMPLES);
+}
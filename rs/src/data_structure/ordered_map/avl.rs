@@ -0,0 +1,274 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+use super::{above_start, below_end, Entries, OrderedMap};
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    // 以该节点为根的子树高度，叶子为 1
+    height: u32,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+fn height<K, V>(link: &Link<K, V>) -> u32 {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn update<K, V>(node: &mut Node<K, V>) {
+    node.height = 1 + height(&node.left).max(height(&node.right));
+}
+
+//     node            left
+//    /    \          /    \
+//  left    c  =>    a     node
+//  /  \                   /  \
+// a    b                 b    c
+fn rotate_right<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut left = node.left.take().expect("右旋时左子树不为空");
+    node.left = left.right.take();
+    update(&mut node);
+    left.right = Some(node);
+    update(&mut left);
+    left
+}
+
+fn rotate_left<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut right = node.right.take().expect("左旋时右子树不为空");
+    node.right = right.left.take();
+    update(&mut node);
+    right.left = Some(node);
+    update(&mut right);
+    right
+}
+
+// 左右子树的高度差超过 1 时旋转，LR 和 RL 的情况先把子树转成 LL 和 RR
+fn rebalance<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    update(&mut node);
+    let (left, right) = (height(&node.left), height(&node.right));
+    if left > right + 1 {
+        let child = node.left.take().expect("左子树更高");
+        node.left = Some(if height(&child.left) < height(&child.right) { rotate_left(child) } else { child });
+        rotate_right(node)
+    } else if right > left + 1 {
+        let child = node.right.take().expect("右子树更高");
+        node.right = Some(if height(&child.right) < height(&child.left) { rotate_right(child) } else { child });
+        rotate_left(node)
+    } else {
+        node
+    }
+}
+
+fn insert<K: Ord, V>(link: Link<K, V>, key: K, value: V) -> (Box<Node<K, V>>, Option<V>) {
+    let Some(mut node) = link else {
+        return (Box::new(Node { key, value, height: 1, left: None, right: None }), None);
+    };
+    match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, old) = insert(node.left.take(), key, value);
+            node.left = Some(left);
+            (rebalance(node), old)
+        }
+        Ordering::Greater => {
+            let (right, old) = insert(node.right.take(), key, value);
+            node.right = Some(right);
+            (rebalance(node), old)
+        }
+        Ordering::Equal => {
+            let old = std::mem::replace(&mut node.value, value);
+            (node, Some(old))
+        }
+    }
+}
+
+// 摘下子树中最小的节点，返回剩下的子树和这个节点
+fn remove_min<K, V>(mut node: Box<Node<K, V>>) -> (Link<K, V>, Box<Node<K, V>>) {
+    match node.left.take() {
+        None => (node.right.take(), node),
+        Some(left) => {
+            let (left, min) = remove_min(left);
+            node.left = left;
+            (Some(rebalance(node)), min)
+        }
+    }
+}
+
+fn remove<K: Ord, V>(link: Link<K, V>, key: &K) -> (Link<K, V>, Option<V>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, old) = remove(node.left.take(), key);
+            node.left = left;
+            (Some(rebalance(node)), old)
+        }
+        Ordering::Greater => {
+            let (right, old) = remove(node.right.take(), key);
+            node.right = right;
+            (Some(rebalance(node)), old)
+        }
+        Ordering::Equal => {
+            let Node { value, left, right, .. } = *node;
+            match (left, right) {
+                (None, child) | (child, None) => (child, Some(value)),
+                // 两个子树都在时，用右子树中最小的节点（后继）顶替
+                (Some(left), Some(right)) => {
+                    let (right, mut successor) = remove_min(right);
+                    successor.left = Some(left);
+                    successor.right = right;
+                    (Some(rebalance(successor)), Some(value))
+                }
+            }
+        }
+    }
+}
+
+/// AVL 树：每个节点的左右子树高度差不超过 1
+///
+/// 插入和删除之后沿着路径向上更新高度，失衡的节点通过一次或两次旋转恢复平衡。
+pub struct AvlTree<K, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K: Ord, V> AvlTree<K, V> {
+    pub fn new() -> AvlTree<K, V> {
+        AvlTree { root: None, len: 0 }
+    }
+
+    /// 空树的高度为 0
+    pub fn height(&self) -> u32 {
+        height(&self.root)
+    }
+
+    /// 检查键的顺序、记录的高度和平衡条件
+    pub fn is_valid(&self) -> bool {
+        // 返回子树的 (高度, 节点数)
+        fn check<K: Ord, V>(link: &Link<K, V>, lower: Option<&K>, upper: Option<&K>) -> Option<(u32, usize)> {
+            let Some(node) = link else {
+                return Some((0, 0));
+            };
+            if lower.is_some_and(|l| l >= &node.key) || upper.is_some_and(|u| u <= &node.key) {
+                return None;
+            }
+            let (left, left_count) = check(&node.left, lower, Some(&node.key))?;
+            let (right, right_count) = check(&node.right, Some(&node.key), upper)?;
+            let balanced = left.abs_diff(right) <= 1 && node.height == 1 + left.max(right);
+            balanced.then_some((node.height, left_count + right_count + 1))
+        }
+        check(&self.root, None, None).is_some_and(|(_, count)| count == self.len)
+    }
+}
+
+impl<K: Ord, V> Default for AvlTree<K, V> {
+    fn default() -> AvlTree<K, V> {
+        AvlTree::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for AvlTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> AvlTree<K, V> {
+        let mut tree = AvlTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for AvlTree<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (root, old) = insert(self.root.take(), key, value);
+        self.root = Some(root);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let (root, old) = remove(self.root.take(), key);
+        self.root = root;
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match key.cmp(&node.key) {
+                Ordering::Less => link = &node.left,
+                Ordering::Greater => link = &node.right,
+                Ordering::Equal => return Some(&node.value),
+            }
+        }
+        None
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn first(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some((&node.key, &node.value))
+    }
+
+    fn last(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some((&node.key, &node.value))
+    }
+
+    fn range<R: RangeBounds<K>>(&self, range: R) -> Entries<'_, K, V>
+    where
+        K: Clone,
+    {
+        // 栈中是还没有输出、且满足下界的祖先节点，栈顶最小
+        let mut stack = Vec::new();
+        let mut link = &self.root;
+        while let Some(node) = link {
+            if above_start(&node.key, range.start_bound()) {
+                stack.push(node.as_ref());
+                link = &node.left;
+            } else {
+                link = &node.right;
+            }
+        }
+        Box::new(Range { stack, end: range.end_bound().cloned() })
+    }
+}
+
+struct Range<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+    end: Bound<K>,
+}
+
+impl<'a, K: Ord, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let node = self.stack.pop()?;
+        if !below_end(&node.key, &self.end) {
+            self.stack.clear();
+            return None;
+        }
+        let mut link = &node.right;
+        while let Some(child) = link {
+            self.stack.push(child);
+            link = &child.left;
+        }
+        Some((&node.key, &node.value))
+    }
+}
@@ -0,0 +1,359 @@
+use std::ops::{Bound, RangeBounds};
+
+use super::{above_start, below_end, Entries, OrderedMap};
+
+struct Node<K, V> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    // 叶子节点没有子节点，内部节点的子节点比键多一个
+    children: Vec<Node<K, V>>,
+}
+
+impl<K, V> Default for Node<K, V> {
+    fn default() -> Node<K, V> {
+        Node { keys: Vec::new(), values: Vec::new(), children: Vec::new() }
+    }
+}
+
+impl<K, V> Node<K, V> {
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// 阶数可配置的 B 树
+///
+/// 阶数 m 是节点最多的子节点数：每个节点最多 m - 1 个键，除根以外至少 ⌈m/2⌉ - 1 个键，所有叶子在同一层。
+/// 插入时节点溢出就从中间分裂，把中间的键提到父节点；删除时节点不足就向兄弟借一个键，借不到就与兄弟合并。
+pub struct BTree<K, V> {
+    root: Node<K, V>,
+    order: usize,
+    len: usize,
+}
+
+impl<K: Ord, V> BTree<K, V> {
+    /// 阶数为 8 的 B 树
+    pub fn new() -> BTree<K, V> {
+        BTree::with_order(8)
+    }
+
+    pub fn with_order(order: usize) -> BTree<K, V> {
+        assert!(order >= 3, "B 树的阶至少为 3");
+        BTree { root: Node::default(), order, len: 0 }
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    fn max_keys(&self) -> usize {
+        self.order - 1
+    }
+
+    fn min_keys(&self) -> usize {
+        self.order.div_ceil(2) - 1
+    }
+
+    /// 从根到叶子的层数
+    pub fn depth(&self) -> usize {
+        let mut depth = 1;
+        let mut node = &self.root;
+        while let Some(child) = node.children.first() {
+            node = child;
+            depth += 1;
+        }
+        depth
+    }
+
+    pub fn node_count(&self) -> usize {
+        fn count<K, V>(node: &Node<K, V>) -> usize {
+            1 + node.children.iter().map(count).sum::<usize>()
+        }
+        count(&self.root)
+    }
+
+    /// 检查键的顺序、节点的大小，以及所有叶子是否在同一层
+    pub fn is_valid(&self) -> bool {
+        fn check<K: Ord, V>(
+            node: &Node<K, V>,
+            lower: Option<&K>,
+            upper: Option<&K>,
+            sizes: (usize, usize),
+            is_root: bool,
+        ) -> Option<usize> {
+            let (min, max) = sizes;
+            let sorted = node.keys.windows(2).all(|pair| pair[0] < pair[1]);
+            let in_bounds = node.keys.first().is_none_or(|k| lower.is_none_or(|l| l < k))
+                && node.keys.last().is_none_or(|k| upper.is_none_or(|u| k < u));
+            let size_ok = node.keys.len() <= max && (is_root || node.keys.len() >= min);
+            if !sorted || !in_bounds || !size_ok || node.keys.len() != node.values.len() {
+                return None;
+            }
+            if node.is_leaf() {
+                return Some(1);
+            }
+            if node.children.len() != node.keys.len() + 1 {
+                return None;
+            }
+            let mut depth = None;
+            for (i, child) in node.children.iter().enumerate() {
+                let lower = if i == 0 { lower } else { Some(&node.keys[i - 1]) };
+                let upper = node.keys.get(i).or(upper);
+                let d = check(child, lower, upper, sizes, false)?;
+                if depth.is_some_and(|depth| depth != d) {
+                    return None;
+                }
+                depth = Some(d);
+            }
+            depth.map(|d| d + 1)
+        }
+        let counted = {
+            let mut stack = vec![&self.root];
+            let mut n = 0;
+            while let Some(node) = stack.pop() {
+                n += node.keys.len();
+                stack.extend(&node.children);
+            }
+            n
+        };
+        counted == self.len && check(&self.root, None, None, (self.min_keys(), self.max_keys()), true).is_some()
+    }
+
+    // 把溢出的第 i 个子节点从中间分裂成两个，中间的键提到 node 中
+    fn split_child(node: &mut Node<K, V>, i: usize) {
+        let child = &mut node.children[i];
+        let mid = child.keys.len() / 2;
+        let right = Node {
+            keys: child.keys.split_off(mid + 1),
+            values: child.values.split_off(mid + 1),
+            children: if child.is_leaf() { Vec::new() } else { child.children.split_off(mid + 1) },
+        };
+        let key = child.keys.pop().expect("分裂的节点不为空");
+        let value = child.values.pop().expect("分裂的节点不为空");
+        node.keys.insert(i, key);
+        node.values.insert(i, value);
+        node.children.insert(i + 1, right);
+    }
+
+    fn insert_into(node: &mut Node<K, V>, key: K, value: V, max_keys: usize) -> Option<V> {
+        match node.keys.binary_search(&key) {
+            Ok(i) => Some(std::mem::replace(&mut node.values[i], value)),
+            Err(i) if node.is_leaf() => {
+                node.keys.insert(i, key);
+                node.values.insert(i, value);
+                None
+            }
+            Err(i) => {
+                let old = Self::insert_into(&mut node.children[i], key, value, max_keys);
+                if node.children[i].keys.len() > max_keys {
+                    Self::split_child(node, i);
+                }
+                old
+            }
+        }
+    }
+
+    fn remove_from(node: &mut Node<K, V>, key: &K, min_keys: usize) -> Option<V> {
+        match node.keys.binary_search(key) {
+            Ok(i) if node.is_leaf() => {
+                node.keys.remove(i);
+                Some(node.values.remove(i))
+            }
+            // 内部节点中的键用前驱（左子树中最大的键）替换
+            Ok(i) => {
+                let (key, value) = Self::remove_max(&mut node.children[i], min_keys);
+                node.keys[i] = key;
+                let old = std::mem::replace(&mut node.values[i], value);
+                Self::fix_child(node, i, min_keys);
+                Some(old)
+            }
+            Err(_) if node.is_leaf() => None,
+            Err(i) => {
+                let old = Self::remove_from(&mut node.children[i], key, min_keys);
+                Self::fix_child(node, i, min_keys);
+                old
+            }
+        }
+    }
+
+    fn remove_max(node: &mut Node<K, V>, min_keys: usize) -> (K, V) {
+        if node.is_leaf() {
+            let key = node.keys.pop().expect("子树不为空");
+            let value = node.values.pop().expect("子树不为空");
+            return (key, value);
+        }
+        let last = node.children.len() - 1;
+        let max = Self::remove_max(&mut node.children[last], min_keys);
+        Self::fix_child(node, last, min_keys);
+        max
+    }
+
+    // 第 i 个子节点的键不足时，向兄弟借一个键，借不到就与兄弟合并
+    fn fix_child(node: &mut Node<K, V>, i: usize, min_keys: usize) {
+        if node.children[i].keys.len() >= min_keys {
+            return;
+        }
+        if i > 0 && node.children[i - 1].keys.len() > min_keys {
+            let (left, right) = node.children.split_at_mut(i);
+            let (left, child) = (&mut left[i - 1], &mut right[0]);
+            let key = std::mem::replace(&mut node.keys[i - 1], left.keys.pop().expect("兄弟有多余的键"));
+            let value = std::mem::replace(&mut node.values[i - 1], left.values.pop().expect("兄弟有多余的键"));
+            child.keys.insert(0, key);
+            child.values.insert(0, value);
+            if let Some(grandchild) = left.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+        } else if i + 1 < node.children.len() && node.children[i + 1].keys.len() > min_keys {
+            let (left, right) = node.children.split_at_mut(i + 1);
+            let (child, right) = (&mut left[i], &mut right[0]);
+            let key = std::mem::replace(&mut node.keys[i], right.keys.remove(0));
+            let value = std::mem::replace(&mut node.values[i], right.values.remove(0));
+            child.keys.push(key);
+            child.values.push(value);
+            if !right.is_leaf() {
+                child.children.push(right.children.remove(0));
+            }
+        } else {
+            // 合并第 j 个和第 j + 1 个子节点，连同它们之间的键
+            let j = if i > 0 { i - 1 } else { i };
+            let right = node.children.remove(j + 1);
+            let key = node.keys.remove(j);
+            let value = node.values.remove(j);
+            let left = &mut node.children[j];
+            left.keys.push(key);
+            left.values.push(value);
+            left.keys.extend(right.keys);
+            left.values.extend(right.values);
+            left.children.extend(right.children);
+        }
+    }
+}
+
+impl<K: Ord, V> Default for BTree<K, V> {
+    fn default() -> BTree<K, V> {
+        BTree::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> BTree<K, V> {
+        let mut tree = BTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for BTree<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let max_keys = self.max_keys();
+        let old = Self::insert_into(&mut self.root, key, value, max_keys);
+        if self.root.keys.len() > max_keys {
+            // 根分裂，树长高一层
+            let old_root = std::mem::take(&mut self.root);
+            self.root.children.push(old_root);
+            Self::split_child(&mut self.root, 0);
+        }
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let min_keys = self.min_keys();
+        let old = Self::remove_from(&mut self.root, key, min_keys)?;
+        if self.root.keys.is_empty() {
+            // 根的键被合并下去，树变矮一层
+            if let Some(child) = self.root.children.pop() {
+                self.root = child;
+            }
+        }
+        self.len -= 1;
+        Some(old)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        let mut node = &self.root;
+        loop {
+            match node.keys.binary_search(key) {
+                Ok(i) => return Some(&node.values[i]),
+                Err(_) if node.is_leaf() => return None,
+                Err(i) => node = &node.children[i],
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn first(&self) -> Option<(&K, &V)> {
+        let mut node = &self.root;
+        while let Some(child) = node.children.first() {
+            node = child;
+        }
+        Some((node.keys.first()?, node.values.first()?))
+    }
+
+    fn last(&self) -> Option<(&K, &V)> {
+        let mut node = &self.root;
+        while let Some(child) = node.children.last() {
+            node = child;
+        }
+        Some((node.keys.last()?, node.values.last()?))
+    }
+
+    fn range<R: RangeBounds<K>>(&self, range: R) -> Entries<'_, K, V>
+    where
+        K: Clone,
+    {
+        // 从根走到第一个满足下界的位置，沿途记录每个节点中下一个要输出的键
+        let mut stack = Vec::new();
+        let mut node = &self.root;
+        loop {
+            let i = node.keys.partition_point(|k| !above_start(k, range.start_bound()));
+            stack.push((node, i));
+            match node.children.get(i) {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        Box::new(Range { stack, end: range.end_bound().cloned() })
+    }
+}
+
+struct Range<'a, K, V> {
+    stack: Vec<(&'a Node<K, V>, usize)>,
+    end: Bound<K>,
+}
+
+impl<'a, K: Ord, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        loop {
+            let (node, i) = self.stack.pop()?;
+            if i == node.keys.len() {
+                continue;
+            }
+            if !below_end(&node.keys[i], &self.end) {
+                self.stack.clear();
+                return None;
+            }
+            // 输出第 i 个键之前，先记下第 i + 1 个子树的最左路径
+            self.stack.push((node, i + 1));
+            if let Some(mut child) = node.children.get(i + 1) {
+                loop {
+                    self.stack.push((child, 0));
+                    match child.children.first() {
+                        Some(next) => child = next,
+                        None => break,
+                    }
+                }
+            }
+            return Some((&node.keys[i], &node.values[i]));
+        }
+    }
+}
@@ -0,0 +1,437 @@
+/*!
+有序映射：B 树、跳表、AVL 树
+
+`OrderedMap` 描述了有序映射的共同操作，三种实现和 `std::collections::BTreeMap` 都实现了它，
+单元测试用同一组随机操作检查它们与一个有序 `Vec` 的行为一致。
+
+| 结构 | 查找 / 插入 / 删除 | 说明 |
+|------|------------------|------|
+| `BTree` | O(log n) | 每个节点存放多个键，阶数可配置，对缓存友好 |
+| `SkipList` | 期望 O(log n) | 随机决定每个节点的层数，实现最简单 |
+| `AvlTree` | O(log n) | 二叉树，左右子树高度差不超过 1 |
+
+```
+use std::collections::BTreeMap;
+use rust_code_examples::data_structure::ordered_map::{AvlTree, BTree, OrderedMap, SkipList};
+
+fn keys<M: OrderedMap<u32, u32>>(mut map: M) -> Vec<u32> {
+    for k in [5, 1, 4, 2, 3] {
+        map.insert(k, k * 10);
+    }
+    map.range(2..5).map(|(k, _)| *k).collect()
+}
+assert_eq!(keys(BTree::with_order(3)), [2, 3, 4]);
+assert_eq!(keys(SkipList::new()), [2, 3, 4]);
+assert_eq!(keys(AvlTree::new()), [2, 3, 4]);
+assert_eq!(keys(BTreeMap::new()), [2, 3, 4]);
+```
+*/
+
+mod avl;
+mod btree;
+mod skip_list;
+
+pub use avl::AvlTree;
+pub use btree::BTree;
+pub use skip_list::SkipList;
+
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds};
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_ordered_map_trait, "OrderedMap trait", "同一个泛型函数作用于四种有序映射"),
+    example!(ex2_btree_order, "B 树的阶", "阶数越大，树越矮，每个节点的键越多"),
+    example!(ex3_skip_list_levels, "跳表的层", "每一层的节点数大约是下一层的一半"),
+    example!(ex4_avl_height, "AVL 树的高度", "顺序插入也能保持平衡，高度不超过 1.44 log2(n)"),
+];
+
+/// 按键的顺序迭代的条目
+pub type Entries<'a, K, V> = Box<dyn Iterator<Item = (&'a K, &'a V)> + 'a>;
+
+/// 有序映射的共同操作
+pub trait OrderedMap<K: Ord, V> {
+    /// 插入条目，键已存在时替换值并返回旧值
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    fn get(&self, key: &K) -> Option<&V>;
+
+    fn len(&self) -> usize;
+
+    /// 最小的条目
+    fn first(&self) -> Option<(&K, &V)>;
+
+    /// 最大的条目
+    fn last(&self) -> Option<(&K, &V)>;
+
+    /// 按顺序迭代键在 range 中的条目
+    fn range<R: RangeBounds<K>>(&self, range: R) -> Entries<'_, K, V>
+    where
+        K: Clone;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    fn iter(&self) -> Entries<'_, K, V>
+    where
+        K: Clone,
+    {
+        self.range(..)
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for BTreeMap<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        BTreeMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn first(&self) -> Option<(&K, &V)> {
+        self.first_key_value()
+    }
+
+    fn last(&self) -> Option<(&K, &V)> {
+        self.last_key_value()
+    }
+
+    fn range<R: RangeBounds<K>>(&self, range: R) -> Entries<'_, K, V>
+    where
+        K: Clone,
+    {
+        Box::new(BTreeMap::range(self, range))
+    }
+}
+
+// 键是否满足范围的下界
+fn above_start<K: Ord>(key: &K, start: Bound<&K>) -> bool {
+    match start {
+        Bound::Included(s) => key >= s,
+        Bound::Excluded(s) => key > s,
+        Bound::Unbounded => true,
+    }
+}
+
+// 键是否满足范围的上界
+fn below_end<K: Ord>(key: &K, end: &Bound<K>) -> bool {
+    match end {
+        Bound::Included(e) => key <= e,
+        Bound::Excluded(e) => key < e,
+        Bound::Unbounded => true,
+    }
+}
+
+// 统计每个单词出现的次数，对任何 OrderedMap 都适用
+fn word_counts<M: OrderedMap<String, usize>>(mut map: M, text: &str) -> M {
+    for word in text.split_whitespace() {
+        let count = map.get(&word.to_string()).copied().unwrap_or(0);
+        map.insert(word.to_string(), count + 1);
+    }
+    map
+}
+
+fn describe<M: OrderedMap<String, usize>>(name: &str, map: &M) -> String {
+    let words: Vec<String> = map.iter().map(|(w, c)| format!("{}:{}", w, c)).collect();
+    let (first, _) = map.first().expect("不为空");
+    let (last, _) = map.last().expect("不为空");
+    format!("{:<10} 共 {} 个单词 [{}]，first = {}，last = {}", name, map.len(), words.join(" "), first, last)
+}
+
+pub fn ex1_ordered_map_trait() {
+    println!("OrderedMap trait 示例代码\n\n");
+
+    let text = "the quick brown fox jumps over the lazy dog the end";
+    let lines = [
+        describe("BTree", &word_counts(BTree::with_order(3), text)),
+        describe("SkipList", &word_counts(SkipList::new(), text)),
+        describe("AvlTree", &word_counts(AvlTree::new(), text)),
+        describe("BTreeMap", &word_counts(BTreeMap::new(), text)),
+    ];
+    for line in &lines {
+        println!("{}", line);
+    }
+    // 只有名字不同，结果完全一样
+    let results: Vec<&str> = lines.iter().map(|line| &line[10..]).collect();
+    assert!(results.windows(2).all(|pair| pair[0] == pair[1]));
+
+    let map = word_counts(AvlTree::new(), text);
+    let between: Vec<&String> = map.range("d".to_string().."p".to_string()).map(|(w, _)| w).collect();
+    assert_eq!(between, ["dog", "end", "fox", "jumps", "lazy", "over"]);
+    println!("\nrange(\"d\"..\"p\"): {:?}", between);
+}
+
+pub fn ex2_btree_order() {
+    println!("B 树的阶 示例代码\n\n");
+
+    for order in [3, 4, 8, 32, 128] {
+        let mut tree = BTree::with_order(order);
+        for k in 0..10_000u32 {
+            tree.insert(k, ());
+        }
+        assert!(tree.is_valid());
+        println!("阶 = {:>3}: 10000 个键，深度 {}，节点数 {}", order, tree.depth(), tree.node_count());
+    }
+
+    let mut tree = BTree::with_order(3);
+    for k in 1..=7 {
+        tree.insert(k, ());
+    }
+    assert_eq!(tree.depth(), 3);
+    for k in 1..=6 {
+        tree.remove(&k);
+    }
+    assert!(tree.is_valid());
+    assert_eq!(tree.depth(), 1);
+    println!("\n2-3 树插入 1..=7 后深度为 3，删除 1..=6 后合并节点，深度回到 1");
+}
+
+pub fn ex3_skip_list_levels() {
+    println!("跳表的层 示例代码\n\n");
+
+    let mut list = SkipList::with_seed(7);
+    for k in 0..4096u32 {
+        list.insert(k, k);
+    }
+    assert!(list.is_valid());
+    let counts = list.level_counts();
+    for (level, count) in counts.iter().enumerate().take(8) {
+        println!("第 {} 层: {:>4} 个节点", level, count);
+    }
+    assert_eq!(counts[0], 4096);
+    // 每层大约是下一层的一半
+    assert!(counts.windows(2).take(5).all(|pair| pair[1] * 3 > pair[0] && pair[1] * 3 < pair[0] * 2));
+
+    for k in (0..4096).step_by(2) {
+        list.remove(&k);
+    }
+    assert!(list.is_valid());
+    assert_eq!(list.len(), 2048);
+    assert_eq!(list.first(), Some((&1, &1)));
+    println!("\n删除所有偶数之后剩 {} 个节点，first = {:?}", list.len(), list.first());
+}
+
+pub fn ex4_avl_height() {
+    println!("AVL 树的高度 示例代码\n\n");
+
+    for n in [10u32, 100, 1000, 10_000] {
+        let mut tree = AvlTree::new();
+        // 顺序插入会让普通二叉搜索树退化成链表
+        for k in 0..n {
+            tree.insert(k, ());
+        }
+        assert!(tree.is_valid());
+        let bound = 1.44 * (n as f64 + 2.0).log2();
+        assert!((tree.height() as f64) < bound);
+        println!("顺序插入 {:>5} 个键: 高度 {:>2}，上界 1.44 log2(n + 2) = {:.1}", n, tree.height(), bound);
+    }
+
+    let mut tree: AvlTree<u32, ()> = (0..1000).map(|k| (k, ())).collect();
+    for k in 0..900 {
+        tree.remove(&k);
+    }
+    assert!(tree.is_valid());
+    println!("\n删除 900 个键之后高度 {}，仍然平衡", tree.height());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 随机生成一个合法的范围，BTreeMap::range 在起点大于终点，或起点等于终点且两端都不包含时会 panic
+    fn random_range(rng: &mut XorShift64, max: u32) -> (Bound<u32>, Bound<u32>) {
+        let a = rng.below(max as u64) as u32;
+        let b = rng.below(max as u64) as u32;
+        let (lo, hi) = (a.min(b), a.max(b));
+        let start = match rng.below(3) {
+            0 => Bound::Included(lo),
+            1 => Bound::Excluded(lo),
+            _ => Bound::Unbounded,
+        };
+        let end = match rng.below(3) {
+            0 => Bound::Included(hi),
+            1 if lo != hi || !matches!(start, Bound::Excluded(_)) => Bound::Excluded(hi),
+            _ => Bound::Unbounded,
+        };
+        (start, end)
+    }
+
+    // 四种映射共用的一致性测试，有序 `Vec` 作为对照，返回 map 以便检查各自的结构
+    fn conformance<M: OrderedMap<u32, u32>>(mut map: M, seed: u64, steps: usize) -> M {
+        assert!(map.is_empty(), "一致性测试需要一个空映射");
+        assert_eq!(map.first(), None);
+        assert_eq!(map.last(), None);
+        assert_eq!(map.remove(&0), None);
+        assert_eq!(map.iter().count(), 0);
+
+        let mut rng = XorShift64::new(seed);
+        let mut model: Vec<(u32, u32)> = Vec::new();
+        let max_key = 50 + rng.below(200) as u32;
+
+        for step in 0..steps {
+            let key = rng.below(max_key as u64) as u32;
+            let found = model.binary_search_by_key(&key, |&(k, _)| k);
+            match rng.below(8) {
+                0..=2 => {
+                    let value = rng.below(10_000) as u32;
+                    let expected = match found {
+                        Ok(i) => Some(std::mem::replace(&mut model[i].1, value)),
+                        Err(i) => {
+                            model.insert(i, (key, value));
+                            None
+                        }
+                    };
+                    assert_eq!(map.insert(key, value), expected, "第 {} 步 insert({})", step, key);
+                }
+                3 | 4 => {
+                    let expected = found.ok().map(|i| model.remove(i).1);
+                    assert_eq!(map.remove(&key), expected, "第 {} 步 remove({})", step, key);
+                }
+                5 => {
+                    let expected = found.ok().map(|i| &model[i].1);
+                    assert_eq!(map.get(&key), expected, "第 {} 步 get({})", step, key);
+                    assert_eq!(map.contains_key(&key), found.is_ok());
+                }
+                _ => {
+                    let (start, end) = random_range(&mut rng, max_key);
+                    let expected: Vec<(u32, u32)> =
+                        model.iter().copied().filter(|&(k, _)| above_start(&k, start.as_ref()) && below_end(&k, &end)).collect();
+                    let actual: Vec<(u32, u32)> = map.range((start, end)).map(|(&k, &v)| (k, v)).collect();
+                    assert_eq!(actual, expected, "第 {} 步 range({:?}, {:?})", step, start, end);
+                }
+            }
+
+            assert_eq!(map.len(), model.len(), "第 {} 步之后长度不同", step);
+            assert_eq!(map.first(), model.first().map(|(k, v)| (k, v)), "第 {} 步之后 first 不同", step);
+            assert_eq!(map.last(), model.last().map(|(k, v)| (k, v)), "第 {} 步之后 last 不同", step);
+        }
+
+        let all: Vec<(u32, u32)> = map.iter().map(|(&k, &v)| (k, v)).collect();
+        assert_eq!(all, model, "最终内容不同");
+        map
+    }
+
+    #[test]
+    fn btree_conforms() {
+        // 阶数不同时分裂和合并的边界不同，奇数阶和偶数阶的最少键数也不一样
+        for order in 3..=8 {
+            assert!(conformance(BTree::with_order(order), order as u64, 3000).is_valid());
+        }
+    }
+
+    #[test]
+    fn skip_list_conforms() {
+        assert!(conformance(SkipList::with_seed(15), 15, 10_000).is_valid());
+    }
+
+    #[test]
+    fn avl_tree_conforms() {
+        assert!(conformance(AvlTree::new(), 15, 10_000).is_valid());
+    }
+
+    #[test]
+    fn std_btree_map_conforms() {
+        conformance(BTreeMap::new(), 15, 10_000);
+    }
+
+    // 在边界上的范围：空映射、起点越过最大键、终点低于最小键、空区间
+    fn check_range_edges<M: OrderedMap<u32, u32>>(mut map: M) {
+        assert_eq!(map.range(..).count(), 0);
+        for k in [10, 20, 30] {
+            map.insert(k, k);
+        }
+        let keys = |range: (Bound<u32>, Bound<u32>)| map.range(range).map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(keys((Bound::Unbounded, Bound::Unbounded)), [10, 20, 30]);
+        assert_eq!(keys((Bound::Excluded(30), Bound::Unbounded)), []);
+        assert_eq!(keys((Bound::Unbounded, Bound::Excluded(10))), []);
+        assert_eq!(keys((Bound::Included(20), Bound::Excluded(20))), []);
+        assert_eq!(keys((Bound::Included(20), Bound::Included(20))), [20]);
+        assert_eq!(keys((Bound::Excluded(10), Bound::Included(30))), [20, 30]);
+        assert_eq!(keys((Bound::Included(11), Bound::Excluded(29))), [20]);
+        // 更新已有的键只替换值
+        assert_eq!(map.insert(20, 21), Some(20));
+        assert_eq!((map.len(), map.get(&20)), (3, Some(&21)));
+    }
+
+    #[test]
+    fn ranges_at_the_edges() {
+        check_range_edges(BTree::with_order(3));
+        check_range_edges(SkipList::with_seed(1));
+        check_range_edges(AvlTree::new());
+        check_range_edges(BTreeMap::new());
+    }
+
+    #[test]
+    fn btree_shrinks_back_to_a_single_leaf() {
+        for order in [3, 4] {
+            // 从左、从右、从中间删除，覆盖向两侧的兄弟借键以及合并节点
+            let ascending: Vec<u32> = (0..200).collect();
+            let descending: Vec<u32> = (0..200).rev().collect();
+            let middle_out: Vec<u32> = (0..100).flat_map(|i| [100 + i, 99 - i]).collect();
+            for removal in [ascending, descending, middle_out] {
+                let mut tree = BTree::with_order(order);
+                for k in 0..200 {
+                    tree.insert(k, k);
+                }
+                assert!(tree.depth() > 3);
+                for (i, key) in removal.iter().enumerate() {
+                    assert_eq!(tree.remove(key), Some(*key));
+                    assert!(tree.is_valid(), "阶 {} 删除 {} 之后", order, key);
+                    assert_eq!(tree.len(), 199 - i);
+                }
+                assert_eq!((tree.depth(), tree.node_count()), (1, 1));
+            }
+        }
+    }
+
+    #[test]
+    fn avl_stays_balanced_on_sorted_input() {
+        let mut tree = AvlTree::new();
+        for k in 0..1023u32 {
+            tree.insert(k, ());
+        }
+        // 1023 个节点的满二叉树高度为 10，AVL 树顺序插入时正好得到它
+        assert_eq!(tree.height(), 10);
+        for k in (0..1023u32).rev().step_by(2) {
+            tree.remove(&k);
+            assert!(tree.is_valid());
+        }
+        assert_eq!(tree.len(), 511);
+        assert!(tree.height() <= 10);
+    }
+
+    #[test]
+    fn skip_list_levels_depend_only_on_the_seed() {
+        let build = || {
+            let mut list = SkipList::with_seed(7);
+            for k in 0..500u32 {
+                list.insert(k, ());
+            }
+            list
+        };
+        let (a, b) = (build(), build());
+        assert_eq!(a.level_counts(), b.level_counts());
+        let counts = a.level_counts();
+        assert_eq!(counts[0], 500);
+        assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]), "{:?}", counts);
+    }
+}
@@ -0,0 +1,222 @@
+use std::ops::{Bound, RangeBounds};
+
+use super::{above_start, below_end, Entries, OrderedMap};
+use crate::utils::XorShift64;
+
+/// 最多的层数，p = 1/2 时足够容纳约 2^32 个节点
+const MAX_LEVEL: usize = 32;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    // next[l] 是第 l 层的后继，长度就是这个节点的层数
+    next: Vec<Option<usize>>,
+}
+
+/// 跳表：第 0 层是包含所有节点的有序链表，每个节点以 1/2 的概率出现在上一层
+///
+/// 查找时从最高层开始向右走，走不动了就下降一层，期望 O(log n) 步。
+/// 和 `ArenaList` 一样，节点存放在 `Vec` 中，链接是下标。
+pub struct SkipList<K, V> {
+    nodes: Vec<Option<Node<K, V>>>,
+    free: Vec<usize>,
+    // 头节点在每一层的后继
+    head: [Option<usize>; MAX_LEVEL],
+    // 当前使用的层数
+    level: usize,
+    len: usize,
+    rng: XorShift64,
+}
+
+impl<K: Ord, V> SkipList<K, V> {
+    pub fn new() -> SkipList<K, V> {
+        SkipList::with_seed(0x5EED)
+    }
+
+    /// 指定决定层数的随机种子，相同的种子和操作序列得到相同的结构
+    pub fn with_seed(seed: u64) -> SkipList<K, V> {
+        SkipList { nodes: Vec::new(), free: Vec::new(), head: [None; MAX_LEVEL], level: 0, len: 0, rng: XorShift64::new(seed) }
+    }
+
+    fn node(&self, index: usize) -> &Node<K, V> {
+        self.nodes[index].as_ref().expect("链接指向了空槽位")
+    }
+
+    // at 为 None 表示头节点
+    fn next_of(&self, at: Option<usize>, level: usize) -> Option<usize> {
+        match at {
+            Some(i) => self.node(i).next[level],
+            None => self.head[level],
+        }
+    }
+
+    fn set_next(&mut self, at: Option<usize>, level: usize, to: Option<usize>) {
+        match at {
+            Some(i) => self.nodes[i].as_mut().expect("链接指向了空槽位").next[level] = to,
+            None => self.head[level] = to,
+        }
+    }
+
+    // 每一层中最后一个满足 go_right 的位置
+    fn predecessors(&self, go_right: impl Fn(&K) -> bool) -> [Option<usize>; MAX_LEVEL] {
+        let mut preds = [None; MAX_LEVEL];
+        let mut at = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next_of(at, level) {
+                if !go_right(&self.node(next).key) {
+                    break;
+                }
+                at = Some(next);
+            }
+            preds[level] = at;
+        }
+        preds
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        let preds = self.predecessors(|k| k < key);
+        self.next_of(preds[0], 0).filter(|&i| self.node(i).key == *key)
+    }
+
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+        while level < MAX_LEVEL && self.rng.bool() {
+            level += 1;
+        }
+        level
+    }
+
+    /// 第 l 个元素是层数大于 l 的节点数
+    pub fn level_counts(&self) -> Vec<usize> {
+        (0..self.level).map(|level| std::iter::successors(self.head[level], |&i| self.node(i).next[level]).count()).collect()
+    }
+
+    /// 检查每一层都有序，且每一层都是下一层的子序列
+    pub fn is_valid(&self) -> bool {
+        let layer = |level: usize| -> Vec<usize> {
+            std::iter::successors(self.head[level], |&i| self.node(i).next[level]).collect()
+        };
+        let bottom = layer(0);
+        if bottom.len() != self.len || bottom.windows(2).any(|pair| self.node(pair[0]).key >= self.node(pair[1]).key) {
+            return false;
+        }
+        let above_top_empty = self.head[self.level..].iter().all(Option::is_none);
+        let layers_ok = (1..self.level).all(|level| {
+            let lower = layer(level - 1);
+            let mut lower = lower.iter();
+            layer(level).iter().all(|i| self.node(*i).next.len() > level && lower.any(|j| j == i))
+        });
+        above_top_empty && layers_ok && (self.level == 0 || self.head[self.level - 1].is_some())
+    }
+}
+
+impl<K: Ord, V> Default for SkipList<K, V> {
+    fn default() -> SkipList<K, V> {
+        SkipList::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SkipList<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> SkipList<K, V> {
+        let mut list = SkipList::new();
+        for (key, value) in iter {
+            list.insert(key, value);
+        }
+        list
+    }
+}
+
+impl<K: Ord, V> OrderedMap<K, V> for SkipList<K, V> {
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let preds = self.predecessors(|k| k < &key);
+        if let Some(i) = self.next_of(preds[0], 0).filter(|&i| self.node(i).key == key) {
+            let node = self.nodes[i].as_mut().expect("链接指向了空槽位");
+            return Some(std::mem::replace(&mut node.value, value));
+        }
+
+        let level = self.random_level();
+        // 新的层中，前驱都是头节点，preds 中这些层本来就是 None
+        self.level = self.level.max(level);
+        let next = (0..level).map(|l| self.next_of(preds[l], l)).collect();
+        let node = Some(Node { key, value, next });
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        for (l, &pred) in preds.iter().enumerate().take(level) {
+            self.set_next(pred, l, Some(index));
+        }
+        self.len += 1;
+        None
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let preds = self.predecessors(|k| k < key);
+        let index = self.next_of(preds[0], 0).filter(|&i| self.node(i).key == *key)?;
+        let node = self.nodes[index].take().expect("链接指向了空槽位");
+        for (l, &next) in node.next.iter().enumerate() {
+            self.set_next(preds[l], l, next);
+        }
+        self.free.push(index);
+        while self.level > 0 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.find(key).map(|i| &self.node(i).value)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn first(&self) -> Option<(&K, &V)> {
+        let node = self.node(self.head[0]?);
+        Some((&node.key, &node.value))
+    }
+
+    // 从最高层一直向右走到底
+    fn last(&self) -> Option<(&K, &V)> {
+        let preds = self.predecessors(|_| true);
+        let node = self.node(preds[0]?);
+        Some((&node.key, &node.value))
+    }
+
+    fn range<R: RangeBounds<K>>(&self, range: R) -> Entries<'_, K, V>
+    where
+        K: Clone,
+    {
+        let preds = self.predecessors(|k| !above_start(k, range.start_bound()));
+        Box::new(Range { list: self, next: self.next_of(preds[0], 0), end: range.end_bound().cloned() })
+    }
+}
+
+struct Range<'a, K, V> {
+    list: &'a SkipList<K, V>,
+    next: Option<usize>,
+    end: Bound<K>,
+}
+
+impl<'a, K: Ord, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let node = self.list.node(self.next?);
+        if !below_end(&node.key, &self.end) {
+            self.next = None;
+            return None;
+        }
+        self.next = node.next[0];
+        Some((&node.key, &node.value))
+    }
+}
+
@@ -0,0 +1,444 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::slice;
+
+use super::{PointerKind, RcKind};
+
+const BITS: u32 = 5;
+const MASK: u64 = (1 << BITS) - 1;
+
+enum Entry<K, V, P: PointerKind> {
+    Leaf(u64, K, V),
+    // 完整的 64 位哈希值都相同的键
+    Collision(u64, P::Ptr<Vec<(K, V)>>),
+    Node(P::Ptr<Node<K, V, P>>),
+}
+
+// bitmap 的第 i 位表示这一层哈希片段为 i 的槽位被占用，entries 只保存被占用的槽位
+struct Node<K, V, P: PointerKind> {
+    bitmap: u32,
+    entries: Vec<Entry<K, V, P>>,
+}
+
+impl<K: Clone, V: Clone, P: PointerKind> Clone for Entry<K, V, P> {
+    fn clone(&self) -> Entry<K, V, P> {
+        match self {
+            Entry::Leaf(hash, key, value) => Entry::Leaf(*hash, key.clone(), value.clone()),
+            Entry::Collision(hash, entries) => Entry::Collision(*hash, entries.clone()),
+            Entry::Node(node) => Entry::Node(node.clone()),
+        }
+    }
+}
+
+impl<K: Clone, V: Clone, P: PointerKind> Clone for Node<K, V, P> {
+    fn clone(&self) -> Node<K, V, P> {
+        Node { bitmap: self.bitmap, entries: self.entries.clone() }
+    }
+}
+
+// 固定密钥的 SipHash，同样的键在每次运行中得到同样的树形
+fn hash_of<K: Hash>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+// 哈希值在 shift 这一层对应的槽位，以及它在压缩后的 entries 中的下标
+fn slot(bitmap: u32, hash: u64, shift: u32) -> (u32, usize) {
+    let bit = 1 << ((hash >> shift) & MASK);
+    (bit, (bitmap & (bit - 1)).count_ones() as usize)
+}
+
+fn entry_hash<K, V, P: PointerKind>(entry: &Entry<K, V, P>) -> u64 {
+    match entry {
+        Entry::Leaf(hash, ..) | Entry::Collision(hash, _) => *hash,
+        Entry::Node(_) => unreachable!("只有叶子和冲突节点需要合并"),
+    }
+}
+
+// 两个哈希值不同的条目放进同一个槽位时，向下建立节点直到它们的哈希片段不同
+fn merge<K, V, P: PointerKind>(shift: u32, a: Entry<K, V, P>, b: Entry<K, V, P>) -> Node<K, V, P> {
+    let (hash_a, hash_b) = (entry_hash(&a), entry_hash(&b));
+    let (bit_a, _) = slot(0, hash_a, shift);
+    let (bit_b, _) = slot(0, hash_b, shift);
+    if bit_a == bit_b {
+        let child = merge(shift + BITS, a, b);
+        Node { bitmap: bit_a, entries: vec![Entry::Node(P::new(child))] }
+    } else if bit_a < bit_b {
+        Node { bitmap: bit_a | bit_b, entries: vec![a, b] }
+    } else {
+        Node { bitmap: bit_a | bit_b, entries: vec![b, a] }
+    }
+}
+
+// 返回新节点和是否增加了一个键
+fn insert_in<K: Clone + Eq, V: Clone, P: PointerKind>(
+    node: &Node<K, V, P>,
+    shift: u32,
+    hash: u64,
+    key: K,
+    value: V,
+) -> (Node<K, V, P>, bool) {
+    let mut node = node.clone();
+    let (bit, i) = slot(node.bitmap, hash, shift);
+    if node.bitmap & bit == 0 {
+        node.bitmap |= bit;
+        node.entries.insert(i, Entry::Leaf(hash, key, value));
+        return (node, true);
+    }
+    let (entry, added) = match &node.entries[i] {
+        Entry::Leaf(h, k, _) if *k == key => (Entry::Leaf(*h, key, value), false),
+        Entry::Leaf(h, k, v) if *h == hash => {
+            (Entry::Collision(hash, P::new(vec![(k.clone(), v.clone()), (key, value)])), true)
+        }
+        Entry::Collision(h, entries) if *h == hash => {
+            let mut entries = (**entries).clone();
+            let added = match entries.iter_mut().find(|(k, _)| *k == key) {
+                Some(entry) => {
+                    entry.1 = value;
+                    false
+                }
+                None => {
+                    entries.push((key, value));
+                    true
+                }
+            };
+            (Entry::Collision(hash, P::new(entries)), added)
+        }
+        Entry::Leaf(..) | Entry::Collision(..) => {
+            let old = node.entries[i].clone();
+            let child = merge(shift + BITS, old, Entry::Leaf(hash, key, value));
+            (Entry::Node(P::new(child)), true)
+        }
+        Entry::Node(child) => {
+            let (child, added) = insert_in(child, shift + BITS, hash, key, value);
+            (Entry::Node(P::new(child)), added)
+        }
+    };
+    node.entries[i] = entry;
+    (node, added)
+}
+
+// 键不存在时返回 None；子节点只剩一个叶子或冲突条目时把它提升到父节点，保持树形规范
+fn remove_in<K: Clone + Eq, V: Clone, P: PointerKind>(
+    node: &Node<K, V, P>,
+    shift: u32,
+    hash: u64,
+    key: &K,
+) -> Option<Node<K, V, P>> {
+    let (bit, i) = slot(node.bitmap, hash, shift);
+    if node.bitmap & bit == 0 {
+        return None;
+    }
+    let replacement = match &node.entries[i] {
+        Entry::Leaf(_, k, _) if k == key => None,
+        Entry::Leaf(..) => return None,
+        Entry::Collision(h, entries) => {
+            let j = entries.iter().position(|(k, _)| k == key)?;
+            let mut entries = (**entries).clone();
+            entries.remove(j);
+            match entries.len() {
+                1 => {
+                    let (k, v) = entries.pop().expect("还剩一个键");
+                    Some(Entry::Leaf(*h, k, v))
+                }
+                _ => Some(Entry::Collision(*h, P::new(entries))),
+            }
+        }
+        Entry::Node(child) => {
+            let child = remove_in(child, shift + BITS, hash, key)?;
+            match child.entries.as_slice() {
+                [] => None,
+                [Entry::Leaf(..) | Entry::Collision(..)] => child.entries.into_iter().next(),
+                _ => Some(Entry::Node(P::new(child))),
+            }
+        }
+    };
+    let mut node = node.clone();
+    match replacement {
+        Some(entry) => node.entries[i] = entry,
+        None => {
+            node.bitmap &= !bit;
+            node.entries.remove(i);
+        }
+    }
+    Some(node)
+}
+
+/// HAMT（哈希数组映射前缀树）实现的持久化哈希映射
+///
+/// 哈希值每 5 位决定一层中的槽位，每个节点最多 32 个槽位，用位图记录哪些被占用。
+/// `insert` / `remove` 返回新版本，只复制从根到键所在位置的路径。
+pub struct PersistentMap<K, V, P: PointerKind = RcKind> {
+    root: P::Ptr<Node<K, V, P>>,
+    len: usize,
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, P: PointerKind> PersistentMap<K, V, P> {
+    pub fn new() -> PersistentMap<K, V, P> {
+        PersistentMap { root: P::new(Node { bitmap: 0, entries: Vec::new() }), len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let hash = hash_of(key);
+        let mut node = &*self.root;
+        let mut shift = 0;
+        loop {
+            let (bit, i) = slot(node.bitmap, hash, shift);
+            if node.bitmap & bit == 0 {
+                return None;
+            }
+            match &node.entries[i] {
+                Entry::Leaf(_, k, v) => return (k == key).then_some(v),
+                Entry::Collision(_, entries) => return entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+                Entry::Node(child) => {
+                    node = child;
+                    shift += BITS;
+                }
+            }
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 返回插入 key 之后的新版本，键已存在时替换值
+    pub fn insert(&self, key: K, value: V) -> PersistentMap<K, V, P> {
+        let (root, added) = insert_in(&self.root, 0, hash_of(&key), key, value);
+        PersistentMap { root: P::new(root), len: self.len + usize::from(added) }
+    }
+
+    /// 返回删除 key 之后的新版本，键不存在时返回与原来共享根的副本
+    pub fn remove(&self, key: &K) -> PersistentMap<K, V, P> {
+        match remove_in(&self.root, 0, hash_of(key), key) {
+            Some(root) => PersistentMap { root: P::new(root), len: self.len - 1 },
+            None => self.clone(),
+        }
+    }
+
+    /// 按哈希值的顺序迭代，顺序与插入顺序无关
+    pub fn iter(&self) -> Iter<'_, K, V, P> {
+        Iter { stack: vec![self.root.entries.iter()], collision: [].iter() }
+    }
+
+    /// 包括根在内的节点数，冲突条目也算一个节点
+    pub fn node_count(&self) -> usize {
+        self.strong_counts().len()
+    }
+
+    /// 树的层数
+    pub fn depth(&self) -> usize {
+        fn depth<K, V, P: PointerKind>(node: &Node<K, V, P>) -> usize {
+            let children = node.entries.iter().map(|entry| match entry {
+                Entry::Node(child) => depth(child),
+                _ => 0,
+            });
+            1 + children.max().unwrap_or(0)
+        }
+        depth(&self.root)
+    }
+
+    /// 只属于这个版本的节点数：遇到引用计数大于 1 的节点就不再向下，它的整棵子树都与其他版本共享
+    pub fn unshared_node_count(&self) -> usize {
+        fn count<K, V, P: PointerKind>(ptr: &P::Ptr<Node<K, V, P>>) -> usize {
+            if P::strong_count(ptr) > 1 {
+                return 0;
+            }
+            let children = ptr.entries.iter().map(|entry| match entry {
+                Entry::Leaf(..) => 0,
+                Entry::Collision(_, entries) => usize::from(P::strong_count(entries) == 1),
+                Entry::Node(child) => count::<K, V, P>(child),
+            });
+            1 + children.sum::<usize>()
+        }
+        count::<K, V, P>(&self.root)
+    }
+
+    /// 前序列出每个节点的引用计数，大于 1 说明这个节点被多个版本共享
+    pub fn strong_counts(&self) -> Vec<usize> {
+        fn walk<K, V, P: PointerKind>(ptr: &P::Ptr<Node<K, V, P>>, out: &mut Vec<usize>) {
+            out.push(P::strong_count(ptr));
+            for entry in &ptr.entries {
+                match entry {
+                    Entry::Leaf(..) => {}
+                    Entry::Collision(_, entries) => out.push(P::strong_count(entries)),
+                    Entry::Node(child) => walk::<K, V, P>(child, out),
+                }
+            }
+        }
+        let mut out = Vec::new();
+        walk::<K, V, P>(&self.root, &mut out);
+        out
+    }
+}
+
+impl<K, V, P: PointerKind> Clone for PersistentMap<K, V, P> {
+    fn clone(&self) -> PersistentMap<K, V, P> {
+        PersistentMap { root: self.root.clone(), len: self.len }
+    }
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, P: PointerKind> Default for PersistentMap<K, V, P> {
+    fn default() -> PersistentMap<K, V, P> {
+        PersistentMap::new()
+    }
+}
+
+impl<K: Clone + Hash + Eq, V: Clone, P: PointerKind> FromIterator<(K, V)> for PersistentMap<K, V, P> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> PersistentMap<K, V, P> {
+        iter.into_iter().fold(PersistentMap::new(), |map, (key, value)| map.insert(key, value))
+    }
+}
+
+impl<K: Clone + Hash + Eq + fmt::Debug, V: Clone + fmt::Debug, P: PointerKind> fmt::Debug for PersistentMap<K, V, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// 深度优先遍历，栈中保存每一层还没有访问的条目
+pub struct Iter<'a, K, V, P: PointerKind> {
+    stack: Vec<slice::Iter<'a, Entry<K, V, P>>>,
+    collision: slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V, P: PointerKind> Iterator for Iter<'a, K, V, P> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        loop {
+            if let Some((key, value)) = self.collision.next() {
+                return Some((key, value));
+            }
+            match self.stack.last_mut()?.next() {
+                None => {
+                    self.stack.pop();
+                }
+                Some(Entry::Leaf(_, key, value)) => return Some((key, value)),
+                Some(Entry::Collision(_, entries)) => self.collision = entries.iter(),
+                Some(Entry::Node(child)) => self.stack.push(child.entries.iter()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 哈希只取 id / 4，每 4 个键的哈希值完全相同，用来覆盖冲突条目的分支
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Colliding(u32);
+
+    impl Hash for Colliding {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            (self.0 / 4).hash(state);
+        }
+    }
+
+    // 与 HashMap 逐步比较，并且每隔 25 步留下一个版本，最后检查它们都没有被之后的操作改变
+    fn check_with<K: Clone + Hash + Eq + fmt::Debug>(seed: u64, steps: usize, make_key: impl Fn(u32) -> K) {
+        let mut rng = XorShift64::new(seed);
+        let mut map: PersistentMap<K, u32> = PersistentMap::new();
+        let mut model: HashMap<K, u32> = HashMap::new();
+        let mut history = Vec::new();
+
+        for step in 0..steps {
+            let key = make_key(rng.below(200) as u32);
+            match rng.below(3) {
+                0 => {
+                    let value = rng.below(1000) as u32;
+                    map = map.insert(key.clone(), value);
+                    model.insert(key, value);
+                }
+                1 => {
+                    map = map.remove(&key);
+                    model.remove(&key);
+                }
+                _ => assert_eq!(map.get(&key), model.get(&key), "第 {} 步 get({:?})", step, key),
+            }
+            assert_eq!(map.len(), model.len(), "第 {} 步之后长度不同", step);
+            if step % 25 == 0 {
+                history.push((map.clone(), model.clone()));
+            }
+        }
+
+        for (i, (map, model)) in history.iter().enumerate() {
+            assert_eq!(map.iter().count(), model.len(), "第 {} 个历史版本的长度被改变", i);
+            for (key, value) in model {
+                assert_eq!(map.get(key), Some(value), "第 {} 个历史版本中 {:?} 的值被改变", i, key);
+            }
+        }
+    }
+
+    #[test]
+    fn versions_match_hash_map_model() {
+        check_with(18, 5000, |id| id);
+    }
+
+    #[test]
+    fn colliding_keys_match_hash_map_model() {
+        check_with(18, 5000, Colliding);
+    }
+
+    #[test]
+    fn collision_entry_shrinks_back_to_a_leaf() {
+        let full: PersistentMap<Colliding, u32> = (0..4).map(|id| (Colliding(id), id)).collect();
+        // 根加上一个冲突条目
+        assert_eq!((full.len(), full.node_count()), (4, 2));
+        let mut map = full.clone();
+        for id in 0..3 {
+            map = map.remove(&Colliding(id));
+            assert_eq!(map.get(&Colliding(id)), None);
+            assert!((id + 1..4).all(|rest| map.get(&Colliding(rest)) == Some(&rest)), "删除 {} 之后丢了键", id);
+        }
+        assert_eq!((map.len(), map.node_count()), (1, 1));
+        assert!((0..4).all(|id| full.get(&Colliding(id)) == Some(&id)));
+    }
+
+    #[test]
+    fn missing_key_with_a_colliding_hash() {
+        let map: PersistentMap<Colliding, u32> = [0, 1, 8, 9].into_iter().map(|id| (Colliding(id), id)).collect();
+        // Colliding(2) 与 0、1 哈希相同但不在映射中，删除时直接共享原来的根
+        let same = map.remove(&Colliding(2));
+        assert_eq!((same.len(), map.strong_counts()[0]), (4, 2));
+        assert_eq!(map.get(&Colliding(2)), None);
+        assert_eq!(map.insert(Colliding(1), 10).get(&Colliding(1)), Some(&10));
+        assert_eq!(map.insert(Colliding(1), 10).len(), 4);
+    }
+
+    #[test]
+    fn removing_collapses_single_child_paths() {
+        let full: PersistentMap<u32, u32> = (0..1000).map(|k| (k, k)).collect();
+        assert!(full.depth() > 2);
+        let one = (1..1000).fold(full.clone(), |map, k| map.remove(&k));
+        assert_eq!((one.len(), one.depth(), one.node_count()), (1, 1, 1));
+        let empty = one.remove(&0);
+        assert!(empty.is_empty() && empty.iter().next().is_none());
+        assert_eq!(full.len(), 1000);
+    }
+
+    #[test]
+    fn each_version_keeps_its_own_value() {
+        let v1 = PersistentMap::<&str, &str>::new().insert("k", "a");
+        let v2 = v1.insert("k", "b");
+        let v3 = v2.remove(&"k");
+        let v4 = v3.insert("k", "c");
+        let values: Vec<Option<&&str>> = [&v1, &v2, &v3, &v4].iter().map(|v| v.get(&"k")).collect();
+        assert_eq!(values, [Some(&"a"), Some(&"b"), None, Some(&"c")]);
+        assert_eq!([v1.len(), v2.len(), v3.len(), v4.len()], [1, 1, 0, 1]);
+    }
+}
@@ -0,0 +1,209 @@
+/*!
+持久化（不可变）数据结构：向量与哈希映射
+
+"修改"不会改动原来的版本，而是返回一个新版本。新版本只复制从根到被修改位置的一条路径，
+其余节点通过引用计数指针与旧版本共享，所以每个版本都可以继续使用，复制的代价是 O(log n)。
+
+| 结构 | 实现 | 说明 |
+|------|------|------|
+| `PersistentVec` | 32 叉前缀树 | 下标每 5 位选择一层中的子节点，百万个元素也只有 4 层 |
+| `PersistentMap` | HAMT（哈希数组映射前缀树） | 哈希值每 5 位选择一层，节点用位图压缩掉空槽 |
+
+节点之间用什么指针由 `PointerKind` 决定：默认的 `RcKind` 使用 `Rc`，开销更小；
+`ArcKind` 使用 `Arc`，对应的 `ArcVec` / `ArcMap` 是 `Send + Sync` 的，可以在线程之间共享。
+
+```
+use rust_code_examples::data_structure::persistent::{PersistentMap, PersistentVec};
+
+let v1: PersistentVec<i32> = (0..100).collect();
+let v2 = v1.set(0, -1).push_back(100);
+assert_eq!((v1.len(), v1.get(0)), (100, Some(&0)));
+assert_eq!((v2.len(), v2.get(0)), (101, Some(&-1)));
+
+let m1: PersistentMap<&str, i32> = PersistentMap::new().insert("a", 1).insert("b", 2);
+let m2 = m1.remove(&"a");
+assert_eq!((m1.get(&"a"), m2.get(&"a"), m2.get(&"b")), (Some(&1), None, Some(&2)));
+```
+
+`Rc` 版本不能发送到其他线程：
+
+```compile_fail
+use rust_code_examples::data_structure::persistent::PersistentVec;
+
+let v: PersistentVec<i32> = (0..10).collect();
+std::thread::spawn(move || v.len());
+```
+*/
+
+mod hamt;
+mod vector;
+
+pub use hamt::PersistentMap;
+pub use vector::PersistentVec;
+
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_vector_versions, "持久化向量", "每次修改都产生新版本，旧版本保持不变"),
+    example!(ex2_vector_sharing, "向量的结构共享", "用 Rc::strong_count 观察新旧版本共享了哪些叶子节点"),
+    example!(ex3_map_sharing, "HAMT 的结构共享", "插入只复制根到叶子的一条路径，其余节点都被共享"),
+    example!(ex4_arc_threads, "Arc 版本跨线程共享", "多个线程各自从同一个版本派生出新版本"),
+];
+
+/// 节点之间使用的引用计数指针，`Ptr<T>` 是 `Rc<T>` 或 `Arc<T>`
+pub trait PointerKind {
+    type Ptr<T>: Deref<Target = T> + Clone;
+
+    fn new<T>(value: T) -> Self::Ptr<T>;
+
+    fn strong_count<T>(ptr: &Self::Ptr<T>) -> usize;
+}
+
+/// 使用 `Rc`，只能在单个线程中使用
+#[derive(Debug, Clone, Copy)]
+pub struct RcKind;
+
+/// 使用 `Arc`，元素满足 `Send + Sync` 时整个结构也满足
+#[derive(Debug, Clone, Copy)]
+pub struct ArcKind;
+
+impl PointerKind for RcKind {
+    type Ptr<T> = Rc<T>;
+
+    fn new<T>(value: T) -> Rc<T> {
+        Rc::new(value)
+    }
+
+    fn strong_count<T>(ptr: &Rc<T>) -> usize {
+        Rc::strong_count(ptr)
+    }
+}
+
+impl PointerKind for ArcKind {
+    type Ptr<T> = Arc<T>;
+
+    fn new<T>(value: T) -> Arc<T> {
+        Arc::new(value)
+    }
+
+    fn strong_count<T>(ptr: &Arc<T>) -> usize {
+        Arc::strong_count(ptr)
+    }
+}
+
+pub type ArcVec<T> = PersistentVec<T, ArcKind>;
+pub type ArcMap<K, V> = PersistentMap<K, V, ArcKind>;
+
+pub fn ex1_vector_versions() {
+    println!("持久化向量 示例代码\n\n");
+
+    let empty: PersistentVec<&str> = PersistentVec::new();
+    let v1 = empty.push_back("a").push_back("b").push_back("c");
+    let v2 = v1.set(1, "B");
+    let v3 = v2.pop_back().unwrap();
+
+    // 每个版本都还可以使用，互不影响
+    for (name, v) in [("empty", &empty), ("v1", &v1), ("v2", &v2), ("v3", &v3)] {
+        println!("{:<5} = {:?}", name, v.iter().collect::<Vec<_>>());
+    }
+    assert!(empty.is_empty());
+    assert_eq!(v1.iter().copied().collect::<Vec<_>>(), ["a", "b", "c"]);
+    assert_eq!(v2.iter().copied().collect::<Vec<_>>(), ["a", "B", "c"]);
+    assert_eq!(v3.iter().copied().collect::<Vec<_>>(), ["a", "B"]);
+
+    // 元素超过 32 个时树增加一层，超过 32 * 32 个时再增加一层
+    for n in [32, 33, 1024, 1025, 32 * 1024 + 1] {
+        let v: PersistentVec<usize> = (0..n).collect();
+        assert_eq!(v.get(n - 1), Some(&(n - 1)));
+        println!("{:>6} 个元素: 深度 {}", n, v.depth());
+    }
+    let depths: Vec<usize> = [32, 33, 1024, 1025].iter().map(|&n| (0..n).collect::<PersistentVec<usize>>().depth()).collect();
+    assert_eq!(depths, [1, 2, 2, 3]);
+}
+
+pub fn ex2_vector_sharing() {
+    println!("向量的结构共享 示例代码\n\n");
+
+    // 100 个元素分布在 4 个叶子节点中：0..32、32..64、64..96、96..100
+    let v1: PersistentVec<u32> = (0..100).collect();
+    assert_eq!(v1.leaf_strong_counts(), [1, 1, 1, 1]);
+    println!("v1 叶子节点的引用计数: {:?}", v1.leaf_strong_counts());
+
+    // 修改下标 40 只复制了第 2 个叶子和根，另外 3 个叶子被两个版本共享
+    let v2 = v1.set(40, 0);
+    assert_eq!(v1.leaf_strong_counts(), [2, 1, 2, 2]);
+    assert_eq!(v2.leaf_strong_counts(), [2, 1, 2, 2]);
+    println!("v2 = v1.set(40, 0) 之后: {:?}", v2.leaf_strong_counts());
+
+    // clone 只复制根指针，所有叶子的计数都不变
+    let v3 = v2.clone();
+    assert_eq!(v3.leaf_strong_counts(), [2, 1, 2, 2]);
+    assert!(v3.ptr_eq(&v2));
+    println!("v3 = v2.clone() 与 v2 共享同一个根");
+
+    // 旧版本被释放后，共享的叶子只剩一个所有者
+    drop(v1);
+    assert_eq!(v2.leaf_strong_counts(), [1, 1, 1, 1]);
+    println!("drop(v1) 之后: {:?}", v2.leaf_strong_counts());
+}
+
+pub fn ex3_map_sharing() {
+    println!("HAMT 的结构共享 示例代码\n\n");
+
+    let m1: PersistentMap<u32, u32> = (0..1000).map(|k| (k, k * k)).collect();
+    let total = m1.node_count();
+    assert!(m1.strong_counts().iter().all(|&count| count == 1));
+
+    let m2 = m1.insert(1000, 0);
+    let copied = m2.unshared_node_count();
+    let shared = m2.node_count() - copied;
+    println!("m1 有 {} 个节点", total);
+    println!("m2 = m1.insert(1000, 0) 复制了 {} 个节点，与 m1 共享 {} 个节点", copied, shared);
+    // 复制的只是从根到新键的一条路径，路径旁边的子树的根被两个版本引用，计数为 2
+    assert!(copied <= m2.depth());
+    assert!(shared >= total - copied);
+    let shared_roots = m2.strong_counts().iter().filter(|&&count| count == 2).count();
+    assert!(shared_roots > 0);
+    println!("m2 中引用计数为 2 的共享子树有 {} 棵", shared_roots);
+
+    let m3 = m2.remove(&0);
+    assert_eq!((m1.len(), m2.len(), m3.len()), (1000, 1001, 1000));
+    assert_eq!((m1.get(&1000), m2.get(&1000), m3.get(&0)), (None, Some(&0), None));
+    assert_eq!(m3.get(&999), Some(&998001));
+    println!("m3 = m2.remove(&0): 三个版本的长度 {} / {} / {}", m1.len(), m2.len(), m3.len());
+}
+
+pub fn ex4_arc_threads() {
+    println!("Arc 版本跨线程共享 示例代码\n\n");
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ArcVec<String>>();
+    assert_send_sync::<ArcMap<String, u32>>();
+
+    let base: ArcVec<u64> = (0..1000).collect();
+    let versions: Vec<ArcVec<u64>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4u64)
+            .map(|t| {
+                let base = &base;
+                // 每个线程把自己负责的四分之一元素乘以 10，只复制被修改的路径
+                scope.spawn(move || (t * 250..(t + 1) * 250).fold(base.clone(), |v, i| v.set(i as usize, i * 10)))
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    for (t, version) in versions.iter().enumerate() {
+        let sum: u64 = version.iter().sum();
+        let expected = (0..1000u64).sum::<u64>() + (t as u64 * 250..(t as u64 + 1) * 250).map(|i| i * 9).sum::<u64>();
+        assert_eq!(sum, expected);
+        println!("线程 {} 的版本: 元素和 {}", t, sum);
+    }
+    // 原来的版本没有被任何线程修改
+    assert_eq!(base.iter().sum::<u64>(), 499500);
+    println!("原来的版本: 元素和 {}", base.iter().sum::<u64>());
+}
@@ -0,0 +1,375 @@
+use std::fmt;
+
+use super::{PointerKind, RcKind};
+
+const BITS: usize = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+enum Node<T, P: PointerKind> {
+    Branch(Vec<P::Ptr<Node<T, P>>>),
+    // 除了最后一个叶子，每个叶子都正好有 WIDTH 个元素
+    Leaf(Vec<T>),
+}
+
+// 克隆节点只复制子节点的指针，叶子才会复制元素
+impl<T: Clone, P: PointerKind> Clone for Node<T, P> {
+    fn clone(&self) -> Node<T, P> {
+        match self {
+            Node::Branch(children) => Node::Branch(children.clone()),
+            Node::Leaf(items) => Node::Leaf(items.clone()),
+        }
+    }
+}
+
+// 从 level 层开始、只包含一个元素的路径
+fn new_path<T, P: PointerKind>(level: usize, value: T) -> Node<T, P> {
+    if level == 0 {
+        Node::Leaf(vec![value])
+    } else {
+        Node::Branch(vec![P::new(new_path(level - BITS, value))])
+    }
+}
+
+fn set_in<T: Clone, P: PointerKind>(node: &Node<T, P>, level: usize, index: usize, value: T) -> Node<T, P> {
+    let mut node = node.clone();
+    match &mut node {
+        Node::Leaf(items) => items[index & MASK] = value,
+        Node::Branch(children) => {
+            let i = (index >> level) & MASK;
+            children[i] = P::new(set_in(&children[i], level - BITS, index, value));
+        }
+    }
+    node
+}
+
+// 调用者保证当前的树还有空位
+fn push_in<T: Clone, P: PointerKind>(node: &Node<T, P>, level: usize, index: usize, value: T) -> Node<T, P> {
+    let mut node = node.clone();
+    match &mut node {
+        Node::Leaf(items) => items.push(value),
+        Node::Branch(children) => {
+            let i = (index >> level) & MASK;
+            match children.get(i) {
+                Some(child) => children[i] = P::new(push_in(child, level - BITS, index, value)),
+                None => children.push(P::new(new_path(level - BITS, value))),
+            }
+        }
+    }
+    node
+}
+
+// 删除下标为 index 的最后一个元素，节点变空时返回 None
+fn pop_in<T: Clone, P: PointerKind>(node: &Node<T, P>, level: usize, index: usize) -> Option<Node<T, P>> {
+    let mut node = node.clone();
+    match &mut node {
+        Node::Leaf(items) => {
+            items.pop();
+            (!items.is_empty()).then_some(node)
+        }
+        Node::Branch(children) => {
+            let i = (index >> level) & MASK;
+            match pop_in(&children[i], level - BITS, index) {
+                Some(child) => children[i] = P::new(child),
+                None => {
+                    children.pop();
+                }
+            }
+            (!children.is_empty()).then_some(node)
+        }
+    }
+}
+
+/// 32 叉前缀树实现的持久化向量
+///
+/// `set`、`push_back`、`pop_back` 都返回新版本，复制 O(log n) 个节点，`clone` 是 O(1) 的。
+pub struct PersistentVec<T, P: PointerKind = RcKind> {
+    root: P::Ptr<Node<T, P>>,
+    // 根所在的层乘以 BITS，为 0 时根就是叶子
+    shift: usize,
+    len: usize,
+}
+
+impl<T: Clone, P: PointerKind> PersistentVec<T, P> {
+    pub fn new() -> PersistentVec<T, P> {
+        PersistentVec { root: P::new(Node::Leaf(Vec::new())), shift: 0, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 树的层数，包括叶子所在的层
+    pub fn depth(&self) -> usize {
+        self.shift / BITS + 1
+    }
+
+    // 下标 index 所在的叶子
+    fn leaf(&self, index: usize) -> &[T] {
+        let mut node = &*self.root;
+        let mut level = self.shift;
+        loop {
+            match node {
+                Node::Branch(children) => {
+                    node = &children[(index >> level) & MASK];
+                    level -= BITS;
+                }
+                Node::Leaf(items) => return items,
+            }
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        (index < self.len).then(|| &self.leaf(index)[index & MASK])
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// 返回把 index 处的元素替换为 value 的新版本
+    ///
+    /// # Panics
+    ///
+    /// index 越界时 panic
+    pub fn set(&self, index: usize, value: T) -> PersistentVec<T, P> {
+        assert!(index < self.len, "下标 {} 越界，长度为 {}", index, self.len);
+        let root = P::new(set_in(&self.root, self.shift, index, value));
+        PersistentVec { root, shift: self.shift, len: self.len }
+    }
+
+    /// 返回在末尾追加 value 的新版本
+    pub fn push_back(&self, value: T) -> PersistentVec<T, P> {
+        let capacity = 1 << (self.shift + BITS);
+        if self.len == capacity {
+            // 树满了，新根的第一个子节点是原来的根，第二个子节点是只有新元素的路径
+            let right = P::new(new_path(self.shift, value));
+            let root = P::new(Node::Branch(vec![self.root.clone(), right]));
+            return PersistentVec { root, shift: self.shift + BITS, len: self.len + 1 };
+        }
+        let root = P::new(push_in(&self.root, self.shift, self.len, value));
+        PersistentVec { root, shift: self.shift, len: self.len + 1 }
+    }
+
+    /// 返回删除最后一个元素的新版本，向量为空时返回 None
+    pub fn pop_back(&self) -> Option<PersistentVec<T, P>> {
+        if self.len == 0 {
+            return None;
+        }
+        let Some(mut root) = pop_in(&self.root, self.shift, self.len - 1) else {
+            return Some(PersistentVec::new());
+        };
+        let mut shift = self.shift;
+        // 根只剩一个子节点时降低一层
+        while let Node::Branch(children) = &root {
+            if children.len() > 1 {
+                break;
+            }
+            root = (*children[0]).clone();
+            shift -= BITS;
+        }
+        Some(PersistentVec { root: P::new(root), shift, len: self.len - 1 })
+    }
+
+    pub fn iter(&self) -> Iter<'_, T, P> {
+        Iter { vec: self, index: 0, leaf: &[] }
+    }
+
+    /// 两个版本是否共享同一个根
+    pub fn ptr_eq(&self, other: &PersistentVec<T, P>) -> bool {
+        std::ptr::eq(&*self.root, &*other.root)
+    }
+
+    /// 按顺序列出每个叶子节点的引用计数，大于 1 说明这个叶子被多个版本共享
+    pub fn leaf_strong_counts(&self) -> Vec<usize> {
+        fn walk<T, P: PointerKind>(ptr: &P::Ptr<Node<T, P>>, out: &mut Vec<usize>) {
+            match &**ptr {
+                Node::Branch(children) => children.iter().for_each(|child| walk::<T, P>(child, out)),
+                Node::Leaf(_) => out.push(P::strong_count(ptr)),
+            }
+        }
+        let mut out = Vec::new();
+        if self.len > 0 {
+            walk::<T, P>(&self.root, &mut out);
+        }
+        out
+    }
+}
+
+impl<T, P: PointerKind> Clone for PersistentVec<T, P> {
+    fn clone(&self) -> PersistentVec<T, P> {
+        PersistentVec { root: self.root.clone(), shift: self.shift, len: self.len }
+    }
+}
+
+impl<T: Clone, P: PointerKind> Default for PersistentVec<T, P> {
+    fn default() -> PersistentVec<T, P> {
+        PersistentVec::new()
+    }
+}
+
+impl<T: Clone, P: PointerKind> FromIterator<T> for PersistentVec<T, P> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> PersistentVec<T, P> {
+        iter.into_iter().fold(PersistentVec::new(), |vec, value| vec.push_back(value))
+    }
+}
+
+impl<T: Clone + PartialEq, P: PointerKind> PartialEq for PersistentVec<T, P> {
+    fn eq(&self, other: &PersistentVec<T, P>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone + fmt::Debug, P: PointerKind> fmt::Debug for PersistentVec<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: Clone, P: PointerKind> IntoIterator for &'a PersistentVec<T, P> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, P>;
+
+    fn into_iter(self) -> Iter<'a, T, P> {
+        self.iter()
+    }
+}
+
+/// 按下标顺序迭代，每 32 个元素才从根向下查找一次叶子
+pub struct Iter<'a, T, P: PointerKind> {
+    vec: &'a PersistentVec<T, P>,
+    index: usize,
+    leaf: &'a [T],
+}
+
+impl<'a, T: Clone, P: PointerKind> Iterator for Iter<'a, T, P> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.index >= self.vec.len {
+            return None;
+        }
+        if self.index & MASK == 0 {
+            self.leaf = self.vec.leaf(self.index);
+        }
+        let item = &self.leaf[self.index & MASK];
+        self.index += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = self.vec.len - self.index;
+        (rest, Some(rest))
+    }
+}
+
+impl<T: Clone, P: PointerKind> ExactSizeIterator for Iter<'_, T, P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 与 Vec 逐步比较，并且每隔 25 步留下一个版本，最后检查它们都没有被之后的操作改变
+    #[test]
+    fn versions_match_vec_model() {
+        let mut rng = XorShift64::new(18);
+        let mut vec: PersistentVec<u32> = PersistentVec::new();
+        let mut model: Vec<u32> = Vec::new();
+        let mut history = Vec::new();
+
+        for step in 0..5000 {
+            match rng.below(8) {
+                // 偶尔一次追加很多元素，让树长到三层
+                0 => {
+                    for _ in 0..rng.below(1200) {
+                        let value = rng.below(1000) as u32;
+                        vec = vec.push_back(value);
+                        model.push(value);
+                    }
+                }
+                1..=3 => {
+                    let value = rng.below(1000) as u32;
+                    vec = vec.push_back(value);
+                    model.push(value);
+                }
+                4 | 5 => {
+                    let popped = vec.pop_back();
+                    assert_eq!(popped.is_some(), model.pop().is_some(), "第 {} 步 pop_back", step);
+                    if let Some(popped) = popped {
+                        vec = popped;
+                    }
+                }
+                6 if !model.is_empty() => {
+                    let index = rng.below(model.len() as u64) as usize;
+                    let value = rng.below(1000) as u32;
+                    vec = vec.set(index, value);
+                    model[index] = value;
+                }
+                _ => {
+                    let index = rng.below(model.len() as u64 + 2) as usize;
+                    assert_eq!(vec.get(index), model.get(index), "第 {} 步 get({})", step, index);
+                }
+            }
+            assert_eq!(vec.len(), model.len(), "第 {} 步之后长度不同", step);
+            assert_eq!(vec.last(), model.last(), "第 {} 步之后最后一个元素不同", step);
+            if step % 25 == 0 {
+                history.push((vec.clone(), model.clone()));
+            }
+        }
+
+        for (i, (vec, model)) in history.iter().enumerate() {
+            assert!(vec.iter().eq(model.iter()), "第 {} 个历史版本被改变", i);
+        }
+    }
+
+    #[test]
+    fn depth_changes_exactly_when_the_tree_is_full() {
+        // (长度, 层数, 弹出一个元素之后的层数)
+        for (len, depth, popped_depth) in [(1, 1, 1), (32, 1, 1), (33, 2, 1), (1024, 2, 2), (1025, 3, 2)] {
+            let vec: PersistentVec<usize> = (0..len).collect();
+            assert_eq!(vec.depth(), depth, "长度 {}", len);
+            assert_eq!((vec.first(), vec.last()), (Some(&0), Some(&(len - 1))));
+            let popped = vec.pop_back().unwrap();
+            assert_eq!(popped.depth(), popped_depth, "长度 {} 弹出之后", len);
+            assert!(popped.iter().copied().eq(0..len - 1));
+        }
+    }
+
+    #[test]
+    fn pop_to_empty_then_push_again() {
+        let mut vec: PersistentVec<u32> = (0..3).collect();
+        while let Some(popped) = vec.pop_back() {
+            vec = popped;
+        }
+        assert!(vec.is_empty() && vec.first().is_none() && vec.leaf_strong_counts().is_empty());
+        let vec = vec.push_back(7);
+        assert_eq!((vec.len(), vec.get(0), vec.get(1)), (1, Some(&7), None));
+    }
+
+    #[test]
+    fn set_copies_only_the_touched_leaf() {
+        let old: PersistentVec<usize> = (0..65).collect();
+        let new = old.set(40, 0);
+        assert_eq!((old.get(40), new.get(40)), (Some(&40), Some(&0)));
+        // 第 1 个叶子被复制，另外两个与旧版本共享
+        assert_eq!(new.leaf_strong_counts(), [2, 1, 2]);
+        let shorter = old.pop_back().unwrap();
+        assert_eq!((old.len(), old.last(), shorter.last()), (65, Some(&64), Some(&63)));
+    }
+
+    #[test]
+    #[should_panic(expected = "下标 3 越界，长度为 3")]
+    fn set_out_of_bounds_panics() {
+        let vec: PersistentVec<u32> = (0..3).collect();
+        vec.set(3, 0);
+    }
+}
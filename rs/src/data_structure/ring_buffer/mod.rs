@@ -0,0 +1,654 @@
+/*!
+定长环形缓冲区
+
+`generics_traits` 中的 `Buffer<const N: usize>` 用 const 泛型在编译期确定缓冲区的大小，
+`RingBuffer<T, N>` 在此基础上实现了一个完整的双端队列：元素直接存放在结构体内的 `[T; N]` 中，
+整个类型的大小在编译期确定，放在栈上或 `static` 中都不会在堆上分配内存。
+
+- 两端都可以 O(1) 地推入、弹出
+- 满了之后的行为由 `OnFull` 决定：拒绝新元素，或者覆盖另一端最旧的元素
+- 元素在数组中可能绕回开头，`as_slices` 返回两段连续的切片
+- `spsc::Queue` 是只依赖 `core` 的单生产者单消费者无锁队列，可以在 `no_std` 环境中使用
+
+```
+use rust_code_examples::data_structure::ring_buffer::RingBuffer;
+
+let mut log: RingBuffer<&str, 3> = RingBuffer::overwriting();
+for line in ["a", "b", "c", "d"] {
+    log.push_back(line).unwrap();
+}
+assert_eq!(log.iter().copied().collect::<Vec<_>>(), ["b", "c", "d"]);
+
+let mut queue: RingBuffer<u32, 2> = RingBuffer::new();
+assert_eq!(queue.push_back(1), Ok(None));
+assert_eq!(queue.push_front(0), Ok(None));
+assert_eq!(queue.push_back(2), Err(2));
+assert_eq!(queue.as_slices(), (&[0][..], &[1][..]));
+```
+*/
+
+pub mod spsc;
+
+use std::fmt;
+use std::iter::Chain;
+use std::mem::MaybeUninit;
+use std::slice;
+
+use crate::basics::generics_traits::compute_buffer_size;
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_both_ends, "双端推入与弹出", "两端都可以推入和弹出，容量在编译期确定"),
+    example!(ex2_full_policy, "满了之后", "拒绝新元素，或者覆盖另一端最旧的元素"),
+    example!(ex3_slices, "两段连续的切片", "元素绕回数组开头时分成两段，iter 依次遍历两段"),
+    example!(ex4_spsc, "单生产者单消费者队列", "一个线程推入、另一个线程弹出，不需要锁"),
+];
+
+/// 缓冲区满了之后推入新元素的行为
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnFull {
+    /// 拒绝新元素，原样返回
+    Reject,
+    /// 从另一端移除最旧的元素，为新元素腾出位置
+    Overwrite,
+}
+
+/// 容量为 N 的环形双端队列，不在堆上分配内存
+pub struct RingBuffer<T, const N: usize> {
+    // head 处是第一个元素，从 head 开始的 len 个槽位（绕回开头）已经初始化
+    slots: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize,
+    on_full: OnFull,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// 满了之后拒绝新元素
+    pub const fn new() -> RingBuffer<T, N> {
+        RingBuffer::with_policy(OnFull::Reject)
+    }
+
+    /// 满了之后覆盖另一端最旧的元素
+    pub const fn overwriting() -> RingBuffer<T, N> {
+        RingBuffer::with_policy(OnFull::Overwrite)
+    }
+
+    pub const fn with_policy(on_full: OnFull) -> RingBuffer<T, N> {
+        const { assert!(N > 0, "容量不能为 0") };
+        RingBuffer { slots: [const { MaybeUninit::uninit() }; N], head: 0, len: 0, on_full }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub fn on_full(&self) -> OnFull {
+        self.on_full
+    }
+
+    // 第 i 个元素所在的槽位
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) % N
+    }
+
+    /// 在末尾推入 value
+    ///
+    /// 没有满时返回 `Ok(None)`；满了时按 `OnFull::Reject` 返回 `Err(value)`，
+    /// 按 `OnFull::Overwrite` 移除第一个元素并返回 `Ok(Some(被移除的元素))`。
+    pub fn push_back(&mut self, value: T) -> Result<Option<T>, T> {
+        let evicted = match (self.is_full(), self.on_full) {
+            (false, _) => None,
+            (true, OnFull::Reject) => return Err(value),
+            (true, OnFull::Overwrite) => self.pop_front(),
+        };
+        let slot = self.slot(self.len);
+        self.slots[slot].write(value);
+        self.len += 1;
+        Ok(evicted)
+    }
+
+    /// 在开头推入 value，满了时的行为与 `push_back` 相同，覆盖的是最后一个元素
+    pub fn push_front(&mut self, value: T) -> Result<Option<T>, T> {
+        let evicted = match (self.is_full(), self.on_full) {
+            (false, _) => None,
+            (true, OnFull::Reject) => return Err(value),
+            (true, OnFull::Overwrite) => self.pop_back(),
+        };
+        self.head = (self.head + N - 1) % N;
+        self.slots[self.head].write(value);
+        self.len += 1;
+        Ok(evicted)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: head 处的槽位已经初始化，读出之后把它移出有效范围，不会再被读取或 drop
+        let value = unsafe { self.slots[self.head].assume_init_read() };
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        // SAFETY: 原来的最后一个元素已经初始化，len 减一之后它不再属于有效范围
+        Some(unsafe { self.slots[slot].assume_init_read() })
+    }
+
+    pub fn get(&self, i: usize) -> Option<&T> {
+        // SAFETY: i < len 时槽位已经初始化
+        (i < self.len).then(|| unsafe { self.slots[self.slot(i)].assume_init_ref() })
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        let slot = self.slot(i);
+        // SAFETY: 同 get
+        (i < self.len).then(|| unsafe { self.slots[slot].assume_init_mut() })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    // 两段有效元素在 slots 中的下标范围
+    fn ranges(&self) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let end = self.head + self.len;
+        if end <= N {
+            (self.head..end, 0..0)
+        } else {
+            (self.head..N, 0..end - N)
+        }
+    }
+
+    /// 按顺序排列的两段连续切片，元素没有绕回开头时第二段为空
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.ranges();
+        // SAFETY: 两个范围内的槽位都已经初始化，MaybeUninit<T> 与 T 的内存布局相同
+        unsafe { (assume_init_slice(&self.slots[first]), assume_init_slice(&self.slots[second])) }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.ranges();
+        // 第二段总是在第一段之前，从第一段的起点切开就得到两个不重叠的可变切片
+        let (before, after) = self.slots.split_at_mut(first.start);
+        // SAFETY: 同 as_slices
+        unsafe { (assume_init_slice_mut(&mut after[..first.len()]), assume_init_slice_mut(&mut before[second])) }
+    }
+
+    /// 把元素移动到数组开头，之后 `as_slices` 的第二段为空
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.slots.rotate_left(self.head);
+        self.head = 0;
+        self.as_mut_slices().0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (first, second) = self.as_slices();
+        Iter { inner: first.iter().chain(second) }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (first, second) = self.as_mut_slices();
+        IterMut { inner: first.iter_mut().chain(second) }
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+// SAFETY: 调用者保证切片中的元素都已经初始化
+unsafe fn assume_init_slice<T>(slice: &[MaybeUninit<T>]) -> &[T] {
+    unsafe { &*(slice as *const [MaybeUninit<T>] as *const [T]) }
+}
+
+unsafe fn assume_init_slice_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    unsafe { &mut *(slice as *mut [MaybeUninit<T>] as *mut [T]) }
+}
+
+impl<T, const N: usize> Drop for RingBuffer<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> RingBuffer<T, N> {
+        RingBuffer::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for RingBuffer<T, N> {
+    fn clone(&self) -> RingBuffer<T, N> {
+        let mut copy = RingBuffer::with_policy(self.on_full);
+        for value in self {
+            let _ = copy.push_back(value.clone());
+        }
+        copy
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for RingBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for RingBuffer<T, N> {
+    fn eq(&self, other: &RingBuffer<T, N>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+pub struct Iter<'a, T> {
+    inner: Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    inner: Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// 按顺序移出所有元素
+pub struct IntoIter<T, const N: usize> {
+    buffer: RingBuffer<T, N>,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buffer.len, Some(self.buffer.len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        self.buffer.pop_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> IntoIterator for RingBuffer<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter { buffer: self }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a RingBuffer<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut RingBuffer<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+pub fn ex1_both_ends() {
+    println!("双端推入与弹出 示例代码\n\n");
+
+    // 容量与 generics_traits 中的 Buffer 一样由 const fn 在编译期计算
+    const SIZE: usize = compute_buffer_size(1);
+    let mut bytes: RingBuffer<u8, SIZE> = RingBuffer::new();
+    assert_eq!(bytes.capacity(), 1024);
+    // 元素就存放在结构体里，大小等于数组加上几个字段
+    assert!(size_of::<RingBuffer<u8, SIZE>>() >= SIZE);
+    assert!(size_of::<RingBuffer<u8, SIZE>>() <= SIZE + 3 * size_of::<usize>());
+    println!("RingBuffer<u8, {}> 占 {} 个字节", SIZE, size_of::<RingBuffer<u8, SIZE>>());
+    for b in b"hello" {
+        bytes.push_back(*b).unwrap();
+    }
+    assert_eq!(bytes.len(), 5);
+
+    let mut deque: RingBuffer<i32, 4> = RingBuffer::new();
+    deque.push_back(2).unwrap();
+    deque.push_back(3).unwrap();
+    deque.push_front(1).unwrap();
+    assert_eq!((deque.front(), deque.back()), (Some(&1), Some(&3)));
+    println!("推入之后: {:?}", deque);
+
+    assert_eq!(deque.pop_back(), Some(3));
+    assert_eq!(deque.pop_front(), Some(1));
+    assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [2]);
+    println!("两端各弹出一个之后: {:?}", deque);
+}
+
+pub fn ex2_full_policy() {
+    println!("满了之后 示例代码\n\n");
+
+    // 拒绝：适合不能丢数据的队列，调用者拿回被拒绝的元素自行处理
+    let mut reject: RingBuffer<u32, 3> = RingBuffer::new();
+    for i in 1..=3 {
+        assert_eq!(reject.push_back(i), Ok(None));
+    }
+    assert_eq!(reject.push_back(4), Err(4));
+    assert_eq!(reject.push_front(0), Err(0));
+    println!("OnFull::Reject:    {:?}，推入 4 被拒绝", reject);
+
+    // 覆盖：适合只保留最近 N 条记录的日志
+    let mut recent: RingBuffer<u32, 3> = RingBuffer::overwriting();
+    for i in 1..=5 {
+        let evicted = recent.push_back(i).unwrap();
+        println!("OnFull::Overwrite: 推入 {} -> {:?}，被覆盖的元素 {:?}", i, recent, evicted);
+    }
+    assert_eq!(recent.iter().copied().collect::<Vec<_>>(), [3, 4, 5]);
+
+    // 从开头推入时覆盖的是最后一个元素
+    assert_eq!(recent.push_front(2), Ok(Some(5)));
+    assert_eq!(recent.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    println!("push_front(2) 覆盖了末尾的 5: {:?}", recent);
+}
+
+pub fn ex3_slices() {
+    println!("两段连续的切片 示例代码\n\n");
+
+    let mut buffer: RingBuffer<char, 5> = RingBuffer::overwriting();
+    "abcdefg".chars().for_each(|c| {
+        buffer.push_back(c).unwrap();
+    });
+    // 槽位中的内容是 [f, g, c, d, e]，第一个元素 c 在下标 2
+    let (first, second) = buffer.as_slices();
+    assert_eq!((first, second), (&['c', 'd', 'e'][..], &['f', 'g'][..]));
+    println!("as_slices: {:?} + {:?}", first, second);
+
+    for c in buffer.iter_mut() {
+        *c = c.to_ascii_uppercase();
+    }
+    assert_eq!(buffer.iter().rev().collect::<String>(), "GFEDC");
+    println!("反向迭代: {}", buffer.iter().rev().collect::<String>());
+
+    let contiguous = buffer.make_contiguous();
+    assert_eq!(contiguous, ['C', 'D', 'E', 'F', 'G']);
+    assert!(buffer.as_slices().1.is_empty());
+    println!("make_contiguous 之后: {:?}", buffer.as_slices());
+
+    let owned: Vec<char> = buffer.into_iter().collect();
+    assert_eq!(owned, ['C', 'D', 'E', 'F', 'G']);
+}
+
+pub fn ex4_spsc() {
+    println!("单生产者单消费者队列 示例代码\n\n");
+
+    const COUNT: u64 = 10_000;
+    let mut queue: spsc::Queue<u64, 64> = spsc::Queue::new();
+    let (mut producer, mut consumer) = queue.split();
+
+    let (sum, max_len) = std::thread::scope(|scope| {
+        scope.spawn(move || {
+            for i in 0..COUNT {
+                // 队列满了就等消费者取走一些
+                let mut value = i;
+                while let Err(rejected) = producer.push(value) {
+                    value = rejected;
+                    std::thread::yield_now();
+                }
+            }
+        });
+        let consumer = scope.spawn(move || {
+            let (mut expected, mut sum, mut max_len) = (0, 0, 0);
+            while expected < COUNT {
+                max_len = max_len.max(consumer.len());
+                match consumer.pop() {
+                    // 先进先出，顺序不会乱
+                    Some(value) => {
+                        assert_eq!(value, expected);
+                        expected += 1;
+                        sum += value;
+                    }
+                    None => std::thread::yield_now(),
+                }
+            }
+            (sum, max_len)
+        });
+        consumer.join().unwrap()
+    });
+
+    assert_eq!(sum, COUNT * (COUNT - 1) / 2);
+    assert!(max_len <= 64);
+    println!("生产者推入 0..{}，消费者按顺序取出，总和 {}", COUNT, sum);
+    println!("队列容量 64，整个过程没有使用锁，也没有在堆上分配内存");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 按 OnFull 的规则操作 VecDeque，作为对照
+    fn model_push<T>(model: &mut VecDeque<T>, capacity: usize, on_full: OnFull, value: T, back: bool) -> Result<Option<T>, T> {
+        let evicted = match (model.len() == capacity, on_full) {
+            (false, _) => None,
+            (true, OnFull::Reject) => return Err(value),
+            (true, OnFull::Overwrite) if back => model.pop_front(),
+            (true, OnFull::Overwrite) => model.pop_back(),
+        };
+        if back {
+            model.push_back(value);
+        } else {
+            model.push_front(value);
+        }
+        Ok(evicted)
+    }
+
+    fn check_with<const N: usize>(seed: u64, steps: usize, on_full: OnFull) {
+        let mut rng = XorShift64::new(seed);
+        // 每个元素都持有 token 的一个克隆，最后检查引用计数，确认被覆盖、弹出和 drop 的元素都被释放了
+        type Item = (u32, Rc<()>);
+        let token = Rc::new(());
+        let mut buffer: RingBuffer<Item, N> = RingBuffer::with_policy(on_full);
+        let mut model: VecDeque<Item> = VecDeque::new();
+        let values = |r: &Result<Option<Item>, Item>| match r {
+            Ok(evicted) => Ok(evicted.as_ref().map(|e| e.0)),
+            Err(rejected) => Err(rejected.0),
+        };
+
+        for step in 0..steps {
+            match rng.below(6) {
+                0 | 1 => {
+                    let value = rng.below(1000) as u32;
+                    let back = rng.bool();
+                    let actual = if back {
+                        buffer.push_back((value, token.clone()))
+                    } else {
+                        buffer.push_front((value, token.clone()))
+                    };
+                    let expected = model_push(&mut model, N, on_full, (value, token.clone()), back);
+                    assert_eq!(values(&actual), values(&expected), "第 {} 步 push({}, back = {})", step, value, back);
+                }
+                2 => assert_eq!(buffer.pop_front().map(|e| e.0), model.pop_front().map(|e| e.0), "第 {} 步 pop_front", step),
+                3 => assert_eq!(buffer.pop_back().map(|e| e.0), model.pop_back().map(|e| e.0), "第 {} 步 pop_back", step),
+                4 => {
+                    let i = rng.below(N as u64 + 1) as usize;
+                    if let (Some(a), Some(b)) = (buffer.get_mut(i), model.get_mut(i)) {
+                        a.0 += 1;
+                        b.0 += 1;
+                    }
+                    assert_eq!(buffer.get(i).map(|e| e.0), model.get(i).map(|e| e.0), "第 {} 步 get({})", step, i);
+                }
+                _ if rng.below(10) == 0 => {
+                    buffer.clear();
+                    model.clear();
+                }
+                _ => {
+                    buffer.iter_mut().rev().take(2).for_each(|e| e.0 *= 2);
+                    model.iter_mut().rev().take(2).for_each(|e| e.0 *= 2);
+                }
+            }
+
+            let (first, second) = buffer.as_slices();
+            let actual: Vec<u32> = first.iter().chain(second).map(|e| e.0).collect();
+            let expected: Vec<u32> = model.iter().map(|e| e.0).collect();
+            assert_eq!(actual, expected, "第 {} 步之后元素不同", step);
+            assert!(buffer.iter().rev().map(|e| e.0).eq(expected.iter().rev().copied()), "第 {} 步之后反向迭代的结果不同", step);
+            assert_eq!(buffer.len(), model.len(), "第 {} 步之后长度不同", step);
+            assert_eq!(Rc::strong_count(&token), 1 + 2 * model.len(), "第 {} 步之后有元素泄漏或重复释放", step);
+        }
+
+        let cloned = buffer.clone();
+        assert!(cloned == buffer);
+        drop((buffer, cloned, model));
+        assert_eq!(Rc::strong_count(&token), 1, "drop 之后仍有元素没有释放");
+    }
+
+    #[test]
+    fn matches_vec_deque_on_random_ops() {
+        check_with::<1>(20, 3000, OnFull::Overwrite);
+        check_with::<7>(20, 3000, OnFull::Reject);
+        check_with::<7>(20, 3000, OnFull::Overwrite);
+    }
+
+    fn contents<T: Clone, const N: usize>(buffer: &RingBuffer<T, N>) -> (Vec<T>, Vec<T>) {
+        let (first, second) = buffer.as_slices();
+        (first.to_vec(), second.to_vec())
+    }
+
+    #[test]
+    fn capacity_one_overwrites_from_either_end() {
+        let mut buffer: RingBuffer<u32, 1> = RingBuffer::overwriting();
+        assert_eq!(buffer.push_back(1), Ok(None));
+        assert_eq!(buffer.push_back(2), Ok(Some(1)));
+        assert_eq!(buffer.push_front(3), Ok(Some(2)));
+        assert_eq!((buffer.front(), buffer.back()), (Some(&3), Some(&3)));
+        assert_eq!(contents(&buffer), (vec![3], vec![]));
+
+        let mut rejecting: RingBuffer<u32, 1> = RingBuffer::new();
+        assert_eq!((rejecting.push_front(1), rejecting.push_back(2)), (Ok(None), Err(2)));
+    }
+
+    #[test]
+    fn overwrite_evicts_the_opposite_end() {
+        let mut buffer: RingBuffer<u32, 3> = RingBuffer::overwriting();
+        for i in 0..3 {
+            buffer.push_back(i).unwrap();
+        }
+        assert_eq!(buffer.push_back(3), Ok(Some(0)));
+        assert_eq!(buffer.push_front(9), Ok(Some(3)));
+        assert!(buffer.iter().eq(&[9, 1, 2]));
+        assert!(buffer.is_full());
+    }
+
+    #[test]
+    fn push_front_into_empty_buffer_wraps_to_the_last_slot() {
+        let mut buffer: RingBuffer<u32, 4> = RingBuffer::new();
+        buffer.push_front(1).unwrap();
+        buffer.push_back(2).unwrap();
+        assert_eq!(contents(&buffer), (vec![1], vec![2]));
+        assert!(buffer.iter().rev().eq(&[2, 1]));
+        assert_eq!((buffer.pop_back(), buffer.pop_back(), buffer.pop_back()), (Some(2), Some(1), None));
+    }
+
+    #[test]
+    fn slices_split_at_the_wrap_point() {
+        let mut buffer: RingBuffer<u32, 4> = RingBuffer::new();
+        for i in 0..4 {
+            buffer.push_back(i).unwrap();
+        }
+        buffer.pop_front();
+        buffer.pop_front();
+        buffer.push_back(4).unwrap();
+        buffer.push_back(5).unwrap();
+        assert_eq!(contents(&buffer), (vec![2, 3], vec![4, 5]));
+        let (first, second) = buffer.as_mut_slices();
+        first[0] = 20;
+        second[1] = 50;
+        assert_eq!(buffer.make_contiguous(), [20, 3, 4, 50]);
+        assert_eq!(contents(&buffer), (vec![20, 3, 4, 50], vec![]));
+        assert_eq!(buffer.get(4), None);
+    }
+
+    #[test]
+    fn partially_consumed_into_iter_drops_the_rest() {
+        let token = Rc::new(());
+        let mut buffer: RingBuffer<Rc<()>, 4> = RingBuffer::new();
+        for _ in 0..4 {
+            buffer.push_front(Rc::clone(&token)).unwrap();
+        }
+        let mut iter = buffer.into_iter();
+        assert!(iter.next().is_some() && iter.next_back().is_some());
+        assert_eq!((iter.len(), Rc::strong_count(&token)), (2, 3));
+        drop(iter);
+        assert_eq!(Rc::strong_count(&token), 1);
+    }
+}
@@ -0,0 +1,245 @@
+/*!
+单生产者单消费者（SPSC）无锁环形队列
+
+只使用 `core` 中的类型，不依赖 `std` 和堆分配，可以原样用在 `no_std` 环境中，
+例如中断处理函数与主循环之间传递数据。
+
+`head` 只由消费者写，`tail` 只由生产者写。两个计数器在 `0..2N` 中循环，下标是它们对 N 取余，
+两者相差 N 表示满、相等表示空；如果让计数器一直增加到 `usize` 溢出，N 不是 2 的幂时下标会在溢出处跳变。
+生产者写入槽位后用 `Release` 发布新的 `tail`，消费者用 `Acquire` 读到它之后才读取槽位，
+反过来也一样，所以同一个槽位不会被同时读写。
+
+`Queue::split` 借出一个 `Producer` 和一个 `Consumer`，借用规则保证最多只有一对，
+两者可以分别移动到不同的线程。
+*/
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// 容量为 N 的 SPSC 队列
+pub struct Queue<T, const N: usize> {
+    slots: [UnsafeCell<MaybeUninit<T>>; N],
+    // 下一个要弹出的位置，在 0..2N 中循环
+    head: AtomicUsize,
+    // 下一个要推入的位置，在 0..2N 中循环
+    tail: AtomicUsize,
+}
+
+// SAFETY: 生产者和消费者通过 head / tail 的 Acquire / Release 同步，不会同时访问同一个槽位，
+// 元素会从生产者线程移动到消费者线程，所以要求 T: Send
+unsafe impl<T: Send, const N: usize> Sync for Queue<T, N> {}
+
+impl<T, const N: usize> Queue<T, N> {
+    pub const fn new() -> Queue<T, N> {
+        const { assert!(N > 0, "容量不能为 0") };
+        Queue {
+            slots: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// 当前的元素个数，另一端同时在操作时只是一个近似值
+    pub fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        distance::<N>(head, tail)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 分成生产者和消费者两端
+    pub fn split(&mut self) -> (Producer<'_, T, N>, Consumer<'_, T, N>) {
+        (Producer { queue: self }, Consumer { queue: self })
+    }
+}
+
+// 从 head 到 tail 的元素个数
+fn distance<const N: usize>(head: usize, tail: usize) -> usize {
+    (tail + 2 * N - head) % (2 * N)
+}
+
+fn advance<const N: usize>(counter: usize) -> usize {
+    (counter + 1) % (2 * N)
+}
+
+impl<T, const N: usize> Default for Queue<T, N> {
+    fn default() -> Queue<T, N> {
+        Queue::new()
+    }
+}
+
+impl<T, const N: usize> Drop for Queue<T, N> {
+    fn drop(&mut self) {
+        let (head, tail) = (*self.head.get_mut(), *self.tail.get_mut());
+        for i in 0..distance::<N>(head, tail) {
+            // SAFETY: head..tail 之间的槽位都已经初始化，&mut self 保证没有其他访问
+            unsafe { self.slots[(head + i) % N].get_mut().assume_init_drop() };
+        }
+    }
+}
+
+/// 推入端
+pub struct Producer<'a, T, const N: usize> {
+    queue: &'a Queue<T, N>,
+}
+
+impl<T, const N: usize> Producer<'_, T, N> {
+    /// 推入 value，队列满了时原样返回
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let head = self.queue.head.load(Ordering::Acquire);
+        if distance::<N>(head, tail) == N {
+            return Err(value);
+        }
+        // SAFETY: 队列没满，tail 处的槽位不属于消费者可以读取的范围，只有这个生产者会写它
+        unsafe { (*self.queue.slots[tail % N].get()).write(value) };
+        self.queue.tail.store(advance::<N>(tail), Ordering::Release);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.queue.len() == N
+    }
+}
+
+/// 弹出端
+pub struct Consumer<'a, T, const N: usize> {
+    queue: &'a Queue<T, N>,
+}
+
+impl<T, const N: usize> Consumer<'_, T, N> {
+    /// 弹出最早推入的元素，队列为空时返回 None
+    pub fn pop(&mut self) -> Option<T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        // SAFETY: Acquire 读到的 tail 保证 head 处的槽位已经写完，读出之后由 Release 交还给生产者
+        let value = unsafe { (*self.queue.slots[head % N].get()).assume_init_read() };
+        self.queue.head.store(advance::<N>(head), Ordering::Release);
+        Some(value)
+    }
+
+    /// 查看下一个将被弹出的元素
+    pub fn peek(&self) -> Option<&T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        // SAFETY: 同 pop，而且只有消费者能让这个槽位失效，返回的引用借用了 &self
+        (head != tail).then(|| unsafe { (*self.queue.slots[head % N].get()).assume_init_ref() })
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use std::thread;
+
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 第 i 个推入的值由 i 决定，只需要对比推入和弹出的计数；
+    // 容量取 5 这样不是 2 的幂的值，覆盖满、空和下标绕回的边界情况
+    #[test]
+    fn matches_counters_on_random_ops() {
+        let mut rng = XorShift64::new(20);
+        let mut queue: Queue<u32, 5> = Queue::new();
+        let (mut producer, mut consumer) = queue.split();
+        let value_of = |i: u32| i.wrapping_mul(2_654_435_761) % 1000;
+        let (mut pushed, mut popped) = (0, 0);
+
+        for step in 0..10_000 {
+            if rng.bool() {
+                let value = value_of(pushed);
+                let expected = if pushed - popped == 5 { Err(value) } else { Ok(()) };
+                assert_eq!(producer.push(value), expected, "第 {} 步 push({})", step, value);
+                pushed += u32::from(expected.is_ok());
+            } else {
+                let expected = (popped < pushed).then(|| value_of(popped));
+                assert_eq!(consumer.peek().copied(), expected, "第 {} 步 peek", step);
+                assert_eq!(consumer.pop(), expected, "第 {} 步 pop", step);
+                popped += u32::from(expected.is_some());
+            }
+            assert_eq!(consumer.len(), (pushed - popped) as usize, "第 {} 步之后长度不同", step);
+            assert_eq!(producer.is_full(), pushed - popped == 5, "第 {} 步之后 is_full 不同", step);
+        }
+    }
+
+    #[test]
+    fn transfers_in_order_across_threads() {
+        const COUNT: u32 = 100_000;
+        let mut queue: Queue<u32, 7> = Queue::new();
+        let (mut producer, mut consumer) = queue.split();
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                for i in 0..COUNT {
+                    let mut value = i;
+                    while let Err(rejected) = producer.push(value) {
+                        value = rejected;
+                        thread::yield_now();
+                    }
+                }
+            });
+            scope.spawn(move || {
+                let mut expected = 0;
+                while expected < COUNT {
+                    match consumer.pop() {
+                        Some(value) => {
+                            assert_eq!(value, expected);
+                            expected += 1;
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+                assert_eq!(consumer.pop(), None);
+            });
+        });
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn drop_frees_leftover_elements() {
+        let counter = Rc::new(());
+        let mut queue: Queue<Rc<()>, 5> = Queue::new();
+        {
+            let (mut producer, mut consumer) = queue.split();
+            // 先推入再弹出，让剩下的元素跨过数组末尾
+            for _ in 0..4 {
+                producer.push(Rc::clone(&counter)).unwrap();
+            }
+            for _ in 0..3 {
+                consumer.pop().unwrap();
+            }
+            for _ in 0..4 {
+                producer.push(Rc::clone(&counter)).unwrap();
+            }
+        }
+        assert_eq!(Rc::strong_count(&counter), 6);
+        drop(queue);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+}
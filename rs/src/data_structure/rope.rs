@@ -0,0 +1,803 @@
+/*!
+Rope：适合大段文本编辑的字符串
+
+`String` 是一整块连续的 UTF-8 字节，在中间插入或删除要移动后面所有的字节，而且只能使用字节下标，
+下标必须落在字符边界上（见 `compound_types::string_slice_type`）。
+Rope 把文本切成许多小块，放在一棵平衡二叉树的叶子上，每个节点记录子树的字节数、字符数和换行数：
+
+- 按字符下标插入、删除都是 O(log n)：先把树从下标处分成两棵，再把三段拼接起来
+- 字节下标、字符下标、行列号之间的转换沿着树向下查找，也是 O(log n)
+- 叶子只在字符边界处切分，所以任何时候取出的文本都是合法的 UTF-8
+
+```
+use rust_code_examples::data_structure::rope::Rope;
+
+let mut rope = Rope::from("你好，世界\n第二行");
+rope.insert(2, "Rust ");
+rope.remove(7..10);
+assert_eq!(rope.to_string(), "你好Rust \n第二行");
+assert_eq!((rope.len_chars(), rope.len_bytes(), rope.len_lines()), (11, 21, 2));
+assert_eq!(rope.char_to_line_col(8), (1, 0));
+assert_eq!(rope.char_to_byte(2), 6);
+```
+*/
+
+use std::fmt;
+use std::ops::{Add, Range};
+
+use crate::example;
+use crate::registry::Example;
+use crate::utils::XorShift64;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_editing, "按字符下标编辑", "插入和删除使用字符下标，不会切开多字节字符"),
+    example!(ex2_positions, "位置转换", "字节下标、字符下标、行列号之间互相转换"),
+    example!(ex3_lines_chars, "按行、按字符迭代", "行的划分与 str::lines 一致"),
+    example!(ex4_balance, "大文本上的编辑", "上千次随机编辑之后树仍然保持平衡"),
+];
+
+// 叶子的最大字节数，真实的编辑器通常取 1KB 左右，这里取得较小，方便在示例中观察树的形状
+const MAX_LEAF: usize = 64;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Metrics {
+    bytes: usize,
+    chars: usize,
+    line_breaks: usize,
+}
+
+impl Metrics {
+    fn of(text: &str) -> Metrics {
+        Metrics { bytes: text.len(), chars: text.chars().count(), line_breaks: text.matches('\n').count() }
+    }
+}
+
+impl Add for Metrics {
+    type Output = Metrics;
+
+    fn add(self, other: Metrics) -> Metrics {
+        Metrics {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            line_breaks: self.line_breaks + other.line_breaks,
+        }
+    }
+}
+
+enum Node {
+    // 不为空，不超过 MAX_LEAF 个字节（单个字符比 MAX_LEAF 长的情况不存在，UTF-8 字符最多 4 个字节）
+    Leaf(String),
+    Branch { left: Box<Node>, right: Box<Node>, height: u32, metrics: Metrics },
+}
+
+impl Node {
+    fn metrics(&self) -> Metrics {
+        match self {
+            Node::Leaf(text) => Metrics::of(text),
+            Node::Branch { metrics, .. } => *metrics,
+        }
+    }
+
+    fn height(&self) -> u32 {
+        match self {
+            Node::Leaf(_) => 1,
+            Node::Branch { height, .. } => *height,
+        }
+    }
+
+    fn branch(left: Node, right: Node) -> Node {
+        let height = 1 + left.height().max(right.height());
+        let metrics = left.metrics() + right.metrics();
+        Node::Branch { left: Box::new(left), right: Box::new(right), height, metrics }
+    }
+
+    fn into_children(self) -> (Node, Node) {
+        match self {
+            Node::Branch { left, right, .. } => (*left, *right),
+            Node::Leaf(_) => unreachable!("叶子没有子节点"),
+        }
+    }
+}
+
+// 把文本切成不超过 MAX_LEAF 字节的块，每块都在字符边界处结束
+fn chunks_of(text: &str) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut end = rest.len().min(MAX_LEAF);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        chunks.push(rest[..end].to_string());
+        rest = &rest[end..];
+    }
+    chunks
+}
+
+// 由叶子序列直接建出一棵完全平衡的树
+fn build(chunks: &mut Vec<String>) -> Option<Node> {
+    fn build_range(chunks: &mut std::vec::Drain<'_, String>, n: usize) -> Node {
+        if n == 1 {
+            return Node::Leaf(chunks.next().expect("块的数量足够"));
+        }
+        let left = build_range(chunks, n / 2);
+        let right = build_range(chunks, n - n / 2);
+        Node::branch(left, right)
+    }
+    let n = chunks.len();
+    (n > 0).then(|| build_range(&mut chunks.drain(..), n))
+}
+
+// 左右子树高度差不超过 2 时恢复平衡，与 AVL 树的旋转相同
+fn balance(left: Node, right: Node) -> Node {
+    let (hl, hr) = (left.height(), right.height());
+    if hl > hr + 1 {
+        let (ll, lr) = left.into_children();
+        if ll.height() >= lr.height() {
+            Node::branch(ll, Node::branch(lr, right))
+        } else {
+            let (lrl, lrr) = lr.into_children();
+            Node::branch(Node::branch(ll, lrl), Node::branch(lrr, right))
+        }
+    } else if hr > hl + 1 {
+        let (rl, rr) = right.into_children();
+        if rr.height() >= rl.height() {
+            Node::branch(Node::branch(left, rl), rr)
+        } else {
+            let (rll, rlr) = rl.into_children();
+            Node::branch(Node::branch(left, rll), Node::branch(rlr, rr))
+        }
+    } else {
+        Node::branch(left, right)
+    }
+}
+
+// 拼接两棵树，沿较高一棵的边缘向下找到高度相近的子树再合并，代价是高度差
+fn join(left: Node, right: Node) -> Node {
+    if let (Node::Leaf(a), Node::Leaf(b)) = (&left, &right) {
+        if a.len() + b.len() <= MAX_LEAF {
+            return Node::Leaf(format!("{}{}", a, b));
+        }
+    }
+    let (hl, hr) = (left.height(), right.height());
+    if hl > hr + 1 {
+        let (ll, lr) = left.into_children();
+        balance(ll, join(lr, right))
+    } else if hr > hl + 1 {
+        let (rl, rr) = right.into_children();
+        balance(join(left, rl), rr)
+    } else {
+        Node::branch(left, right)
+    }
+}
+
+fn join_opt(left: Option<Node>, right: Option<Node>) -> Option<Node> {
+    match (left, right) {
+        (Some(left), Some(right)) => Some(join(left, right)),
+        (left, right) => left.or(right),
+    }
+}
+
+// 把树分成前 at 个字符和剩下的字符两部分
+fn split(node: Node, at: usize) -> (Option<Node>, Option<Node>) {
+    match node {
+        Node::Leaf(mut text) => {
+            let byte = text.char_indices().nth(at).map_or(text.len(), |(i, _)| i);
+            let right = text.split_off(byte);
+            ((!text.is_empty()).then_some(Node::Leaf(text)), (!right.is_empty()).then_some(Node::Leaf(right)))
+        }
+        Node::Branch { left, right, .. } => {
+            let left_chars = left.metrics().chars;
+            if at == left_chars {
+                (Some(*left), Some(*right))
+            } else if at < left_chars {
+                let (a, b) = split(*left, at);
+                (a, join_opt(b, Some(*right)))
+            } else {
+                let (a, b) = split(*right, at - left_chars);
+                (join_opt(Some(*left), a), b)
+            }
+        }
+    }
+}
+
+/// 以平衡二叉树保存文本的 rope，所有位置参数默认都是字符下标
+pub struct Rope {
+    root: Option<Node>,
+}
+
+impl Rope {
+    pub fn new() -> Rope {
+        Rope { root: None }
+    }
+
+    fn metrics(&self) -> Metrics {
+        self.root.as_ref().map_or(Metrics::default(), Node::metrics)
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.metrics().bytes
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.metrics().chars
+    }
+
+    /// 行数，等于换行符个数加一，空文本也算一行
+    pub fn len_lines(&self) -> usize {
+        self.metrics().line_breaks + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 树的高度，空 rope 为 0
+    pub fn height(&self) -> u32 {
+        self.root.as_ref().map_or(0, Node::height)
+    }
+
+    /// 叶子的数量
+    pub fn leaf_count(&self) -> usize {
+        self.chunks().count()
+    }
+
+    /// 在第 at 个字符之前插入 text
+    ///
+    /// # Panics
+    ///
+    /// at 大于字符数时 panic
+    pub fn insert(&mut self, at: usize, text: &str) {
+        assert!(at <= self.len_chars(), "字符下标 {} 越界，共有 {} 个字符", at, self.len_chars());
+        let middle = build(&mut chunks_of(text));
+        let (left, right) = match self.root.take() {
+            Some(root) => split(root, at),
+            None => (None, None),
+        };
+        self.root = join_opt(join_opt(left, middle), right);
+    }
+
+    /// 删除 range 范围内的字符
+    ///
+    /// # Panics
+    ///
+    /// range 越界或起点大于终点时 panic
+    pub fn remove(&mut self, range: Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.len_chars(),
+            "字符范围 {:?} 越界，共有 {} 个字符",
+            range,
+            self.len_chars()
+        );
+        let Some(root) = self.root.take() else { return };
+        let (left, rest) = split(root, range.start);
+        let right = rest.and_then(|rest| split(rest, range.end - range.start).1);
+        self.root = join_opt(left, right);
+    }
+
+    /// 把第 at 个字符及之后的文本切下来，成为一个新的 rope
+    pub fn split_off(&mut self, at: usize) -> Rope {
+        assert!(at <= self.len_chars(), "字符下标 {} 越界，共有 {} 个字符", at, self.len_chars());
+        let Some(root) = self.root.take() else { return Rope::new() };
+        let (left, right) = split(root, at);
+        self.root = left;
+        Rope { root: right }
+    }
+
+    /// 把 other 接在末尾
+    pub fn append(&mut self, other: Rope) {
+        self.root = join_opt(self.root.take(), other.root);
+    }
+
+    // 找到第 at 个字符所在的叶子，返回叶子和它之前的统计数据，at 等于字符数时返回最后一个叶子
+    fn leaf_at_char(&self, at: usize) -> (&str, Metrics) {
+        let mut node = self.root.as_ref().expect("rope 不为空");
+        let mut before = Metrics::default();
+        loop {
+            match node {
+                Node::Leaf(text) => return (text, before),
+                Node::Branch { left, right, .. } => {
+                    let left_metrics = left.metrics();
+                    if at - before.chars < left_metrics.chars {
+                        node = left;
+                    } else {
+                        before = before + left_metrics;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // leaf_at_char 的终点情况：at 等于字符数时整棵树都在它之前
+    fn locate_char(&self, at: usize) -> Option<(&str, Metrics, usize)> {
+        assert!(at <= self.len_chars(), "字符下标 {} 越界，共有 {} 个字符", at, self.len_chars());
+        if at == self.len_chars() {
+            return None;
+        }
+        let (leaf, before) = self.leaf_at_char(at);
+        Some((leaf, before, at - before.chars))
+    }
+
+    pub fn char(&self, at: usize) -> Option<char> {
+        if at >= self.len_chars() {
+            return None;
+        }
+        let (leaf, _, offset) = self.locate_char(at)?;
+        leaf.chars().nth(offset)
+    }
+
+    /// 第 at 个字符的起始字节下标，at 等于字符数时返回字节数
+    pub fn char_to_byte(&self, at: usize) -> usize {
+        match self.locate_char(at) {
+            None => self.len_bytes(),
+            Some((leaf, before, offset)) => before.bytes + leaf.char_indices().nth(offset).expect("偏移在叶子内").0,
+        }
+    }
+
+    /// 字节下标 byte 处的字符下标
+    ///
+    /// # Panics
+    ///
+    /// byte 越界或者不在字符边界上时 panic，与对 `str` 切片的要求一致
+    pub fn byte_to_char(&self, byte: usize) -> usize {
+        assert!(byte <= self.len_bytes(), "字节下标 {} 越界，共有 {} 个字节", byte, self.len_bytes());
+        if byte == self.len_bytes() {
+            return self.len_chars();
+        }
+        let mut node = self.root.as_ref().expect("rope 不为空");
+        let mut before = Metrics::default();
+        loop {
+            match node {
+                Node::Leaf(text) => {
+                    let offset = byte - before.bytes;
+                    assert!(text.is_char_boundary(offset), "字节下标 {} 不在字符边界上", byte);
+                    return before.chars + text[..offset].chars().count();
+                }
+                Node::Branch { left, right, .. } => {
+                    let left_metrics = left.metrics();
+                    if byte - before.bytes < left_metrics.bytes {
+                        node = left;
+                    } else {
+                        before = before + left_metrics;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    /// 第 at 个字符的行号和列号，都从 0 开始，列号按字符计数
+    pub fn char_to_line_col(&self, at: usize) -> (usize, usize) {
+        let line = match self.locate_char(at) {
+            None => self.metrics().line_breaks,
+            Some((leaf, before, offset)) => before.line_breaks + leaf.chars().take(offset).filter(|&c| c == '\n').count(),
+        };
+        (line, at - self.line_to_char(line))
+    }
+
+    /// 第 line 行第一个字符的下标
+    ///
+    /// # Panics
+    ///
+    /// line 不小于行数时 panic
+    pub fn line_to_char(&self, line: usize) -> usize {
+        assert!(line < self.len_lines(), "行号 {} 越界，共有 {} 行", line, self.len_lines());
+        if line == 0 {
+            return 0;
+        }
+        // 找到第 line 个换行符，下一行从它后面开始
+        let mut node = self.root.as_ref().expect("有换行符时 rope 不为空");
+        let mut before = Metrics::default();
+        loop {
+            match node {
+                Node::Leaf(text) => {
+                    let wanted = line - before.line_breaks;
+                    let (offset, _) = text.chars().enumerate().filter(|&(_, c)| c == '\n').nth(wanted - 1).expect("换行符在这个叶子中");
+                    return before.chars + offset + 1;
+                }
+                Node::Branch { left, right, .. } => {
+                    let left_metrics = left.metrics();
+                    if line - before.line_breaks <= left_metrics.line_breaks {
+                        node = left;
+                    } else {
+                        before = before + left_metrics;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    /// 行列号对应的字符下标
+    ///
+    /// # Panics
+    ///
+    /// 行号越界，或者列号超过这一行的长度（不含换行符）时 panic
+    pub fn line_col_to_char(&self, line: usize, col: usize) -> usize {
+        let start = self.line_to_char(line);
+        let end = if line + 1 < self.len_lines() { self.line_to_char(line + 1) - 1 } else { self.len_chars() };
+        assert!(start + col <= end, "第 {} 行只有 {} 个字符，列号 {} 越界", line, end - start, col);
+        start + col
+    }
+
+    /// 字符范围内的文本
+    pub fn slice(&self, range: Range<usize>) -> String {
+        assert!(range.start <= range.end && range.end <= self.len_chars(), "字符范围 {:?} 越界", range);
+        self.chars().skip(range.start).take(range.end - range.start).collect()
+    }
+
+    /// 按顺序迭代叶子中的文本块
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks { stack: self.root.iter().collect() }
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.chunks().flat_map(str::chars)
+    }
+
+    /// 按行迭代，与 `str::lines` 一样不包含行尾的 `\n` 或 `\r\n`，末尾的换行符之后不再产生空行
+    pub fn lines(&self) -> Lines<'_> {
+        Lines { chunks: self.chunks(), current: "" }
+    }
+
+    /// 检查平衡条件、每个节点记录的统计数据以及叶子的大小
+    pub fn is_valid(&self) -> bool {
+        fn check(node: &Node) -> bool {
+            match node {
+                Node::Leaf(text) => !text.is_empty() && text.len() <= MAX_LEAF,
+                Node::Branch { left, right, height, metrics } => {
+                    check(left)
+                        && check(right)
+                        && left.height().abs_diff(right.height()) <= 1
+                        && *height == 1 + left.height().max(right.height())
+                        && *metrics == left.metrics() + right.metrics()
+                }
+            }
+        }
+        self.root.as_ref().is_none_or(check)
+    }
+}
+
+impl Default for Rope {
+    fn default() -> Rope {
+        Rope::new()
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(text: &str) -> Rope {
+        Rope { root: build(&mut chunks_of(text)) }
+    }
+}
+
+impl From<String> for Rope {
+    fn from(text: String) -> Rope {
+        Rope::from(text.as_str())
+    }
+}
+
+impl From<&Rope> for String {
+    fn from(rope: &Rope) -> String {
+        let mut text = String::with_capacity(rope.len_bytes());
+        rope.chunks().for_each(|chunk| text.push_str(chunk));
+        text
+    }
+}
+
+impl From<Rope> for String {
+    fn from(rope: Rope) -> String {
+        String::from(&rope)
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chunks().try_for_each(|chunk| f.write_str(chunk))
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&String::from(self), f)
+    }
+}
+
+impl PartialEq for Rope {
+    fn eq(&self, other: &Rope) -> bool {
+        self.len_bytes() == other.len_bytes() && self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<str> for Rope {
+    fn eq(&self, other: &str) -> bool {
+        self.len_bytes() == other.len() && self.chars().eq(other.chars())
+    }
+}
+
+/// 前序遍历，只产生叶子
+pub struct Chunks<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some(node) = self.stack.pop() {
+            match node {
+                Node::Leaf(text) => return Some(text),
+                Node::Branch { left, right, .. } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// 一行可能跨越多个叶子，所以每一行都拼成一个新的 `String`
+pub struct Lines<'a> {
+    chunks: Chunks<'a>,
+    current: &'a str,
+}
+
+impl Iterator for Lines<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let mut line = String::new();
+        loop {
+            if let Some(end) = self.current.find('\n') {
+                line.push_str(&self.current[..end]);
+                self.current = &self.current[end + 1..];
+                if line.ends_with('\r') {
+                    line.pop();
+                }
+                return Some(line);
+            }
+            line.push_str(self.current);
+            match self.chunks.next() {
+                Some(chunk) => self.current = chunk,
+                None => {
+                    self.current = "";
+                    return (!line.is_empty()).then_some(line);
+                }
+            }
+        }
+    }
+}
+
+pub fn ex1_editing() {
+    println!("按字符下标编辑 示例代码\n\n");
+
+    // String 只能按字节下标插入，下标落在 "你" 的中间会 panic
+    let mut s = String::from("你好世界");
+    assert!(!s.is_char_boundary(1));
+    s.insert(6, '，');
+    println!("String::insert 需要字节下标 6:  {}", s);
+
+    // Rope 直接使用字符下标
+    let mut rope = Rope::from("你好世界");
+    rope.insert(2, "，");
+    assert_eq!(rope, *s.as_str());
+    println!("Rope::insert 使用字符下标 2:      {}", rope);
+
+    rope.insert(rope.len_chars(), "！🦀");
+    rope.remove(0..3);
+    assert_eq!(rope.to_string(), "世界！🦀");
+    assert_eq!((rope.len_chars(), rope.len_bytes()), (4, 13));
+    println!("追加并删除前 3 个字符: {} ({} 个字符, {} 个字节)", rope, rope.len_chars(), rope.len_bytes());
+
+    // 切开再拼接
+    let mut left = Rope::from("春眠不觉晓，处处闻啼鸟。");
+    let right = left.split_off(6);
+    assert_eq!((left.to_string(), right.to_string()), ("春眠不觉晓，".to_string(), "处处闻啼鸟。".to_string()));
+    left.append(right);
+    assert_eq!(String::from(left), "春眠不觉晓，处处闻啼鸟。");
+    println!("split_off(6) 再 append 得到原文");
+}
+
+pub fn ex2_positions() {
+    println!("位置转换 示例代码\n\n");
+
+    let rope = Rope::from("fn main() {\n    println!(\"你好\");\n}\n");
+    assert_eq!(rope.len_lines(), 4);
+
+    // "你" 是第 1 行第 14 列（从 0 开始），它之前有 26 个字符，字节下标是 26，占 3 个字节
+    let at = rope.line_col_to_char(1, 14);
+    assert_eq!(rope.char(at), Some('你'));
+    assert_eq!((at, rope.char_to_byte(at), rope.char_to_byte(at + 1)), (26, 26, 29));
+    assert_eq!(rope.byte_to_char(29), 27);
+    assert_eq!(rope.char_to_line_col(27), (1, 15));
+    println!("第 1 行第 14 列是 {:?}: 字符下标 {}, 字节下标 {}", rope.char(at), at, rope.char_to_byte(at));
+
+    for line in 0..rope.len_lines() {
+        let start = rope.line_to_char(line);
+        println!("第 {} 行从字符 {:>2} / 字节 {:>2} 开始", line, start, rope.char_to_byte(start));
+    }
+    assert_eq!(rope.line_to_char(3), rope.len_chars());
+}
+
+pub fn ex3_lines_chars() {
+    println!("按行、按字符迭代 示例代码\n\n");
+
+    let text = "床前明月光，\r\n疑是地上霜。\n\n举头望明月，\n低头思故乡。\n";
+    let rope = Rope::from(text);
+    let lines: Vec<String> = rope.lines().collect();
+    assert_eq!(lines, text.lines().collect::<Vec<_>>());
+    assert_eq!(lines.len(), 5);
+    for (i, line) in lines.iter().enumerate() {
+        println!("{}: {:?}", i, line);
+    }
+
+    let hanzi = rope.chars().filter(|c| !c.is_ascii() && c.is_alphabetic()).count();
+    assert_eq!(hanzi, 20);
+    println!("\n共 {} 个字符，其中 {} 个汉字", rope.len_chars(), hanzi);
+    assert_eq!(rope.slice(8..12), "疑是地上");
+}
+
+pub fn ex4_balance() {
+    println!("大文本上的编辑 示例代码\n\n");
+
+    let line = "Rust 是一门注重安全与性能的系统编程语言。\n";
+    let mut rope = Rope::from(line.repeat(1000));
+    let mut model = line.repeat(1000);
+    println!("初始: {} 个字符, {} 行, {} 个叶子, 高度 {}", rope.len_chars(), rope.len_lines(), rope.leaf_count(), rope.height());
+
+    // 在随机位置插入和删除，树的高度仍然是 O(log n)
+    let mut rng = XorShift64::new(2024);
+    for _ in 0..1000 {
+        let at = rng.below(rope.len_chars() as u64) as usize;
+        if rng.bool() {
+            rope.insert(at, "🦀");
+            let byte = rope.char_to_byte(at);
+            model.insert(byte, '🦀');
+        } else {
+            let (start, end) = (rope.char_to_byte(at), rope.char_to_byte(at + 1));
+            rope.remove(at..at + 1);
+            model.replace_range(start..end, "");
+        }
+    }
+    assert!(rope.is_valid());
+    assert_eq!(rope.to_string(), model);
+    let leaves = rope.leaf_count() as f64;
+    assert!(f64::from(rope.height()) <= 1.45 * leaves.log2() + 2.0);
+    println!("1000 次编辑后: {} 个字符, {} 个叶子, 高度 {}", rope.len_chars(), rope.leaf_count(), rope.height());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 文本中混合了 1 到 4 个字节的字符和换行符，String 一侧先把字符下标换算成字节下标再编辑
+    #[test]
+    fn matches_string_on_random_edits() {
+        const ALPHABET: [char; 8] = ['a', 'b', 'é', 'ß', '中', '文', '😀', '\n'];
+        let mut rng = XorShift64::new(19);
+        let mut rope = Rope::new();
+        let mut model = String::new();
+        let byte_of = |s: &str, at: usize| s.char_indices().nth(at).map_or(s.len(), |(i, _)| i);
+
+        for step in 0..3000 {
+            let chars = model.chars().count();
+            match rng.below(4) {
+                0 | 1 => {
+                    let at = rng.below(chars as u64 + 1) as usize;
+                    let len = if rng.below(10) == 0 { rng.below(300) } else { rng.below(8) };
+                    let text: String = (0..len).map(|_| ALPHABET[rng.below(ALPHABET.len() as u64) as usize]).collect();
+                    rope.insert(at, &text);
+                    model.insert_str(byte_of(&model, at), &text);
+                }
+                2 => {
+                    let start = rng.below(chars as u64 + 1) as usize;
+                    let end = start + rng.below((chars - start) as u64 + 1).min(20) as usize;
+                    rope.remove(start..end);
+                    model.replace_range(byte_of(&model, start)..byte_of(&model, end), "");
+                }
+                _ => {
+                    let other = rope.split_off(rng.below(chars as u64 + 1) as usize);
+                    rope.append(other);
+                }
+            }
+            assert!(rope.is_valid(), "第 {} 步之后 rope 不满足不变量", step);
+            assert_eq!(rope.to_string(), model, "第 {} 步之后文本不同", step);
+            assert_eq!(rope.len_lines(), model.matches('\n').count() + 1, "第 {} 步之后行数不同", step);
+            assert!(rope.lines().eq(model.lines().map(String::from)), "第 {} 步之后按行迭代的结果不同", step);
+
+            let chars = model.chars().count();
+            let at = rng.below(chars as u64 + 1) as usize;
+            let byte = byte_of(&model, at);
+            assert_eq!(rope.char_to_byte(at), byte, "第 {} 步 char_to_byte({})", step, at);
+            assert_eq!(rope.byte_to_char(byte), at, "第 {} 步 byte_to_char({})", step, byte);
+            assert_eq!(rope.char(at), model[byte..].chars().next(), "第 {} 步 char({})", step, at);
+
+            let before = &model[..byte];
+            let line = before.matches('\n').count();
+            let col = before.rsplit('\n').next().expect("rsplit 至少产生一段").chars().count();
+            assert_eq!(rope.char_to_line_col(at), (line, col), "第 {} 步 char_to_line_col({})", step, at);
+            assert_eq!(rope.line_col_to_char(line, col), at, "第 {} 步 line_col_to_char({}, {})", step, line, col);
+        }
+    }
+
+    #[test]
+    fn empty_rope() {
+        let mut rope = Rope::new();
+        rope.insert(0, "");
+        rope.remove(0..0);
+        assert!(rope.is_empty() && rope.split_off(0).is_empty());
+        assert_eq!((rope.len_chars(), rope.len_lines(), rope.height()), (0, 1, 0));
+        assert_eq!((rope.char(0), rope.char_to_byte(0), rope.byte_to_char(0)), (None, 0, 0));
+        assert_eq!((rope.char_to_line_col(0), rope.line_col_to_char(0, 0)), ((0, 0), 0));
+        assert_eq!(rope.lines().count(), 0);
+        rope.append(Rope::new());
+        assert!(rope.is_empty() && rope.is_valid());
+    }
+
+    #[test]
+    fn insert_at_both_ends() {
+        let mut rope = Rope::from("bc");
+        rope.insert(0, "a");
+        rope.insert(3, "d");
+        assert_eq!(rope.to_string(), "abcd");
+        let tail = rope.split_off(4);
+        let head = Rope::from("").split_off(0);
+        assert!(tail.is_empty() && head.is_empty());
+        rope.remove(0..4);
+        assert!(rope.is_empty());
+    }
+
+    #[test]
+    fn leaves_never_split_a_character() {
+        let text = "😀".repeat(100);
+        let mut rope = Rope::from(text.as_str());
+        assert!(rope.is_valid() && rope.leaf_count() >= text.len() / MAX_LEAF);
+        assert_eq!((rope.char_to_byte(17), rope.byte_to_char(68)), (68, 17));
+        // 在叶子中间切开再拼回去
+        let tail = rope.split_off(17);
+        rope.append(tail);
+        rope.remove(15..17);
+        assert!(rope.is_valid());
+        assert_eq!((rope.len_chars(), rope.len_bytes()), (98, 392));
+        assert!(rope.chunks().all(|chunk| chunk.chars().all(|c| c == '😀')));
+    }
+
+    #[test]
+    #[should_panic(expected = "字节下标 1 不在字符边界上")]
+    fn byte_inside_a_character_panics() {
+        Rope::from("é").byte_to_char(1);
+    }
+
+    #[test]
+    fn stays_balanced_when_growing_at_one_end() {
+        let mut appended = Rope::new();
+        let mut prepended = Rope::new();
+        for i in 0..2000 {
+            appended.append(Rope::from("x".repeat(MAX_LEAF).as_str()));
+            prepended.insert(0, &"y".repeat(MAX_LEAF));
+            assert!(appended.is_valid() && prepended.is_valid(), "第 {} 次之后不平衡", i);
+        }
+        // AVL 树的高度不超过 1.44 log2(n + 2)
+        for rope in [&appended, &prepended] {
+            assert_eq!(rope.leaf_count(), 2000);
+            assert!(rope.height() <= 16);
+        }
+    }
+
+    #[test]
+    fn trailing_line_breaks() {
+        let rope = Rope::from("a\r\nb\n");
+        assert_eq!(rope.len_lines(), 3);
+        assert_eq!(rope.lines().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!((rope.char_to_line_col(5), rope.line_col_to_char(2, 0)), ((2, 0), 5));
+        assert_eq!(rope.char_to_line_col(1), (0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "第 0 行只有 1 个字符，列号 2 越界")]
+    fn column_past_the_line_panics() {
+        Rope::from("a\nbc").line_col_to_char(0, 2);
+    }
+}
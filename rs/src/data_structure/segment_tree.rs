@@ -0,0 +1,654 @@
+/*!
+线段树：任意幺半群上的区间查询与区间更新
+
+`Monoid` 描述可以按顺序合并的值（区间和、最小值、最大值……），`Action` 描述作用在整个区间上的更新（区间加、区间赋值……）。
+区间更新时只在覆盖整个子区间的节点上记下待执行的更新（懒标记），等到以后访问子节点时再下推，
+所以区间查询和区间更新都是 O(log n)。
+
+只需要单点修改时，`Action` 可以用 `()`，此时 `SegmentTree<M>` 就是普通的线段树。
+
+```
+use rust_code_examples::data_structure::segment_tree::{Add, Max, SegmentTree, Sum};
+
+let mut tree: SegmentTree<Sum, Add> = [1, 2, 3, 4, 5].into_iter().map(Sum::of).collect();
+assert_eq!(tree.query(1..4).sum, 9);
+tree.update(0..3, Add(10));
+assert_eq!(tree.query(..).sum, 45);
+
+let mut max: SegmentTree<Max> = [3, 1, 4].into_iter().map(Max::of).collect();
+max.set(1, Max::of(9));
+assert_eq!(max.query(..2), Max::of(9));
+```
+*/
+
+use std::ops::{Bound, RangeBounds};
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_monoids, "幺半群", "同一棵线段树配合不同的幺半群，得到区间和、最小值、最大值"),
+    example!(ex2_lazy_update, "懒标记", "区间加与区间赋值，只在需要时下推"),
+    example!(ex3_custom_monoid, "自定义幺半群", "不满足交换律的幺半群：最大子段和"),
+];
+
+/// 幺半群：有单位元、满足结合律的二元运算
+///
+/// 不要求交换律，`combine` 的左边是区间左半部分的结果。
+pub trait Monoid: Clone {
+    /// 单位元，与任何值合并都得到那个值本身
+    fn identity() -> Self;
+
+    fn combine(&self, other: &Self) -> Self;
+}
+
+/// 作用在区间汇总值上的更新
+///
+/// `apply` 必须能直接算出更新后的汇总值，而不需要知道区间里的每个元素，
+/// 例如区间加 x 之后，区间和增加 x 乘以区间长度，所以 `Sum` 同时记录了长度。
+pub trait Action<M: Monoid>: Clone {
+    /// 什么也不做的更新
+    fn identity() -> Self;
+
+    fn apply(&self, value: &M) -> M;
+
+    /// 先执行 `earlier` 再执行 `self` 的效果
+    fn compose(&self, earlier: &Self) -> Self;
+}
+
+/// 没有区间更新
+impl<M: Monoid> Action<M> for () {
+    fn identity() {}
+
+    fn apply(&self, value: &M) -> M {
+        value.clone()
+    }
+
+    fn compose(&self, _earlier: &()) {}
+}
+
+// 运算结果超出 i64 时在 debug 和 release 下都 panic，而不是悄悄回绕
+fn checked(result: Option<i64>, what: &str) -> i64 {
+    result.unwrap_or_else(|| panic!("{}超出了 i64 的范围", what))
+}
+
+/// 区间和，同时记录元素个数
+///
+/// 每个区间的和都要在 i64 的范围内，否则合并或者更新时 panic。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sum {
+    pub sum: i64,
+    pub len: i64,
+}
+
+impl Sum {
+    pub fn of(value: i64) -> Sum {
+        Sum { sum: value, len: 1 }
+    }
+}
+
+impl Monoid for Sum {
+    fn identity() -> Sum {
+        Sum { sum: 0, len: 0 }
+    }
+
+    fn combine(&self, other: &Sum) -> Sum {
+        Sum { sum: checked(self.sum.checked_add(other.sum), "区间和"), len: self.len + other.len }
+    }
+}
+
+/// 区间最小值，单位元 `Min(None)` 表示空区间
+///
+/// 不用 `i64::MAX` 当单位元，否则真正等于 `i64::MAX` 的元素与空区间无法区分。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Min(pub Option<i64>);
+
+impl Min {
+    pub fn of(value: i64) -> Min {
+        Min(Some(value))
+    }
+}
+
+impl Monoid for Min {
+    fn identity() -> Min {
+        Min(None)
+    }
+
+    fn combine(&self, other: &Min) -> Min {
+        match (self.0, other.0) {
+            (Some(a), Some(b)) => Min(Some(a.min(b))),
+            (a, b) => Min(a.or(b)),
+        }
+    }
+}
+
+/// 区间最大值，单位元 `Max(None)` 表示空区间
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Max(pub Option<i64>);
+
+impl Max {
+    pub fn of(value: i64) -> Max {
+        Max(Some(value))
+    }
+}
+
+impl Monoid for Max {
+    fn identity() -> Max {
+        Max(None)
+    }
+
+    fn combine(&self, other: &Max) -> Max {
+        // None 小于任何 Some，正好是最大值的单位元
+        Max(self.0.max(other.0))
+    }
+}
+
+/// 区间内每个元素加上一个数，结果或者累计的增量超出 i64 时 panic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Add(pub i64);
+
+impl Action<Sum> for Add {
+    fn identity() -> Add {
+        Add(0)
+    }
+
+    fn apply(&self, value: &Sum) -> Sum {
+        let delta = checked(self.0.checked_mul(value.len), "区间和的增量");
+        Sum { sum: checked(value.sum.checked_add(delta), "区间和"), len: value.len }
+    }
+
+    fn compose(&self, earlier: &Add) -> Add {
+        Add(checked(self.0.checked_add(earlier.0), "累计的增量"))
+    }
+}
+
+impl Action<Min> for Add {
+    fn identity() -> Add {
+        Add(0)
+    }
+
+    // 空区间保持不变
+    fn apply(&self, value: &Min) -> Min {
+        Min(value.0.map(|v| checked(v.checked_add(self.0), "区间最小值")))
+    }
+
+    fn compose(&self, earlier: &Add) -> Add {
+        Add(checked(self.0.checked_add(earlier.0), "累计的增量"))
+    }
+}
+
+impl Action<Max> for Add {
+    fn identity() -> Add {
+        Add(0)
+    }
+
+    fn apply(&self, value: &Max) -> Max {
+        Max(value.0.map(|v| checked(v.checked_add(self.0), "区间最大值")))
+    }
+
+    fn compose(&self, earlier: &Add) -> Add {
+        Add(checked(self.0.checked_add(earlier.0), "累计的增量"))
+    }
+}
+
+/// 把区间内每个元素赋值为同一个数，`Assign(None)` 表示不赋值
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assign(pub Option<i64>);
+
+impl Action<Sum> for Assign {
+    fn identity() -> Assign {
+        Assign(None)
+    }
+
+    fn apply(&self, value: &Sum) -> Sum {
+        match self.0 {
+            Some(x) => Sum { sum: checked(x.checked_mul(value.len), "区间和"), len: value.len },
+            None => *value,
+        }
+    }
+
+    // 后一次赋值覆盖前一次
+    fn compose(&self, earlier: &Assign) -> Assign {
+        Assign(self.0.or(earlier.0))
+    }
+}
+
+impl Action<Min> for Assign {
+    fn identity() -> Assign {
+        Assign(None)
+    }
+
+    fn apply(&self, value: &Min) -> Min {
+        Min(value.0.map(|v| self.0.unwrap_or(v)))
+    }
+
+    fn compose(&self, earlier: &Assign) -> Assign {
+        Assign(self.0.or(earlier.0))
+    }
+}
+
+impl Action<Max> for Assign {
+    fn identity() -> Assign {
+        Assign(None)
+    }
+
+    fn apply(&self, value: &Max) -> Max {
+        Max(value.0.map(|v| self.0.unwrap_or(v)))
+    }
+
+    fn compose(&self, earlier: &Assign) -> Assign {
+        Assign(self.0.or(earlier.0))
+    }
+}
+
+/// 带懒标记的线段树
+///
+/// 节点按堆的方式存放：节点 i 的子节点是 2i 和 2i + 1，根是 1。
+pub struct SegmentTree<M: Monoid, A: Action<M> = ()> {
+    len: usize,
+    values: Vec<M>,
+    // lazy[i] 已经作用在 values[i] 上，但还没有下推到子节点
+    lazy: Vec<A>,
+}
+
+impl<M: Monoid, A: Action<M>> SegmentTree<M, A> {
+    /// n 个单位元
+    pub fn new(len: usize) -> SegmentTree<M, A> {
+        std::iter::repeat_with(M::identity).take(len).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn build(&mut self, node: usize, lo: usize, hi: usize, items: &[M]) {
+        if hi - lo == 1 {
+            self.values[node] = items[lo].clone();
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.build(2 * node, lo, mid, items);
+        self.build(2 * node + 1, mid, hi, items);
+        self.values[node] = self.values[2 * node].combine(&self.values[2 * node + 1]);
+    }
+
+    fn apply_to(&mut self, node: usize, action: &A) {
+        self.values[node] = action.apply(&self.values[node]);
+        self.lazy[node] = action.compose(&self.lazy[node]);
+    }
+
+    // 把懒标记下推到两个子节点
+    fn push_down(&mut self, node: usize) {
+        let action = std::mem::replace(&mut self.lazy[node], A::identity());
+        self.apply_to(2 * node, &action);
+        self.apply_to(2 * node + 1, &action);
+    }
+
+    fn bounds(&self, range: impl RangeBounds<usize>) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e + 1,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end && end <= self.len, "区间 {}..{} 超出长度 {}", start, end, self.len);
+        (start, end)
+    }
+
+    /// 按顺序合并区间中的所有元素，空区间返回单位元
+    ///
+    /// 查询不下推懒标记，而是把沿途的标记合并起来作用在结果上，所以只需要 `&self`。
+    pub fn query(&self, range: impl RangeBounds<usize>) -> M {
+        let (start, end) = self.bounds(range);
+        if start == end {
+            return M::identity();
+        }
+        self.query_node(1, 0, self.len, start, end, &A::identity())
+    }
+
+    // pending 是祖先节点上还没有下推到 node 的标记
+    fn query_node(&self, node: usize, lo: usize, hi: usize, start: usize, end: usize, pending: &A) -> M {
+        if end <= lo || hi <= start {
+            return M::identity();
+        }
+        if start <= lo && hi <= end {
+            return pending.apply(&self.values[node]);
+        }
+        // 子节点先执行 node 自己的标记，再执行祖先的
+        let pending = pending.compose(&self.lazy[node]);
+        let mid = (lo + hi) / 2;
+        let left = self.query_node(2 * node, lo, mid, start, end, &pending);
+        let right = self.query_node(2 * node + 1, mid, hi, start, end, &pending);
+        left.combine(&right)
+    }
+
+    /// 对区间中的每个元素执行 action
+    pub fn update(&mut self, range: impl RangeBounds<usize>, action: A) {
+        let (start, end) = self.bounds(range);
+        if start < end {
+            self.update_node(1, 0, self.len, start, end, &action);
+        }
+    }
+
+    fn update_node(&mut self, node: usize, lo: usize, hi: usize, start: usize, end: usize, action: &A) {
+        if end <= lo || hi <= start {
+            return;
+        }
+        if start <= lo && hi <= end {
+            self.apply_to(node, action);
+            return;
+        }
+        self.push_down(node);
+        let mid = (lo + hi) / 2;
+        self.update_node(2 * node, lo, mid, start, end, action);
+        self.update_node(2 * node + 1, mid, hi, start, end, action);
+        self.values[node] = self.values[2 * node].combine(&self.values[2 * node + 1]);
+    }
+
+    /// 单个元素的当前值
+    pub fn get(&self, index: usize) -> M {
+        self.query(index..=index)
+    }
+
+    /// 修改单个元素
+    pub fn set(&mut self, index: usize, value: M) {
+        assert!(index < self.len, "下标 {} 超出长度 {}", index, self.len);
+        let (mut node, mut lo, mut hi) = (1, 0, self.len);
+        let mut path = Vec::new();
+        while hi - lo > 1 {
+            self.push_down(node);
+            path.push(node);
+            let mid = (lo + hi) / 2;
+            if index < mid {
+                (node, hi) = (2 * node, mid);
+            } else {
+                (node, lo) = (2 * node + 1, mid);
+            }
+        }
+        self.values[node] = value;
+        for &node in path.iter().rev() {
+            self.values[node] = self.values[2 * node].combine(&self.values[2 * node + 1]);
+        }
+    }
+}
+
+impl<M: Monoid, A: Action<M>> FromIterator<M> for SegmentTree<M, A> {
+    fn from_iter<I: IntoIterator<Item = M>>(iter: I) -> SegmentTree<M, A> {
+        let items: Vec<M> = iter.into_iter().collect();
+        let len = items.len();
+        // 4n 个节点足够容纳任何长度的递归划分
+        let size = 4 * len.max(1);
+        let mut tree = SegmentTree {
+            len,
+            values: vec![M::identity(); size],
+            lazy: vec![A::identity(); size],
+        };
+        if len > 0 {
+            tree.build(1, 0, len, &items);
+        }
+        tree
+    }
+}
+
+pub fn ex1_monoids() {
+    println!("幺半群 示例代码\n\n");
+
+    let data = [5, -2, 7, 3, -8, 6, 1, 4];
+    println!("数据: {:?}\n", data);
+    let mut sum: SegmentTree<Sum> = data.iter().map(|&x| Sum::of(x)).collect();
+    let mut min: SegmentTree<Min> = data.iter().map(|&x| Min::of(x)).collect();
+    let max: SegmentTree<Max> = data.iter().map(|&x| Max::of(x)).collect();
+
+    for (lo, hi) in [(0, 8), (2, 5), (4, 7)] {
+        let (s, mn, mx) = (sum.query(lo..hi).sum, min.query(lo..hi).0.unwrap(), max.query(lo..hi).0.unwrap());
+        assert_eq!(s, data[lo..hi].iter().sum::<i64>());
+        assert_eq!(mn, *data[lo..hi].iter().min().unwrap());
+        assert_eq!(mx, *data[lo..hi].iter().max().unwrap());
+        println!("[{}, {}): 和 = {:>3}，最小值 = {:>2}，最大值 = {}", lo, hi, s, mn, mx);
+    }
+
+    // 单点修改，沿着到根的路径重新计算
+    sum.set(4, Sum::of(8));
+    min.set(4, Min::of(8));
+    assert_eq!(sum.query(..).sum, 32);
+    assert_eq!(min.query(..), Min::of(-2));
+    println!("\n把下标 4 改成 8 之后: 总和 = {}，最小值 = {}", sum.query(..).sum, min.query(..).0.unwrap());
+
+    // 空区间得到单位元
+    assert_eq!(max.query(3..3), Max(None));
+}
+
+pub fn ex2_lazy_update() {
+    println!("懒标记 示例代码\n\n");
+
+    let mut tree: SegmentTree<Sum, Add> = SegmentTree::new(10);
+    // new 得到 10 个单位元，Sum 的单位元长度为 0，区间加对它不起作用，所以先逐个赋值
+    for i in 0..10 {
+        tree.set(i, Sum::of(i as i64));
+    }
+    assert_eq!(tree.query(..).sum, 45);
+
+    tree.update(2..8, Add(10));
+    assert_eq!(tree.query(..).sum, 105);
+    assert_eq!(tree.query(0..3).sum, 13);
+    println!("[2, 8) 加 10 之后: 总和 = {}，[0, 3) 的和 = {}", tree.query(..).sum, tree.query(0..3).sum);
+
+    tree.update(..5, Add(-1));
+    tree.update(4..=4, Add(100));
+    let values: Vec<i64> = (0..10).map(|i| tree.get(i).sum).collect();
+    assert_eq!(values, [-1, 0, 11, 12, 113, 15, 16, 17, 8, 9]);
+    println!("再对 [0, 5) 减 1、下标 4 加 100: {:?}", values);
+
+    let mut assign: SegmentTree<Sum, Assign> = (0..10).map(Sum::of).collect();
+    assign.update(3..7, Assign(Some(1)));
+    assign.update(5.., Assign(Some(2)));
+    let values: Vec<i64> = (0..10).map(|i| assign.get(i).sum).collect();
+    assert_eq!(values, [0, 1, 2, 1, 1, 2, 2, 2, 2, 2]);
+    println!("[3, 7) 赋值为 1、[5, 10) 赋值为 2: {:?}", values);
+}
+
+/// 最大子段和：合并时需要知道左右两边的前缀和后缀，只满足结合律，不满足交换律
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MaxSubarray {
+    sum: i64,
+    best_prefix: i64,
+    best_suffix: i64,
+    best: i64,
+}
+
+impl MaxSubarray {
+    fn of(x: i64) -> MaxSubarray {
+        MaxSubarray { sum: x, best_prefix: x, best_suffix: x, best: x }
+    }
+}
+
+impl Monoid for MaxSubarray {
+    // 用足够小的数表示“没有元素”，避免溢出
+    fn identity() -> MaxSubarray {
+        let none = i64::MIN / 4;
+        MaxSubarray { sum: 0, best_prefix: none, best_suffix: none, best: none }
+    }
+
+    fn combine(&self, right: &MaxSubarray) -> MaxSubarray {
+        MaxSubarray {
+            sum: self.sum + right.sum,
+            best_prefix: self.best_prefix.max(self.sum + right.best_prefix),
+            best_suffix: right.best_suffix.max(right.sum + self.best_suffix),
+            best: self.best.max(right.best).max(self.best_suffix + right.best_prefix),
+        }
+    }
+}
+
+pub fn ex3_custom_monoid() {
+    println!("自定义幺半群 示例代码\n\n");
+
+    let data = [2, -5, 3, 4, -1, 2, -6, 5];
+    let mut tree: SegmentTree<MaxSubarray> = data.iter().map(|&x| MaxSubarray::of(x)).collect();
+
+    // 暴力枚举所有子段
+    let brute = |lo: usize, hi: usize| {
+        (lo..hi).flat_map(|i| (i + 1..=hi).map(move |j| data[i..j].iter().sum::<i64>())).max().unwrap()
+    };
+    for (lo, hi) in [(0, 8), (0, 2), (2, 6), (5, 8)] {
+        let best = tree.query(lo..hi).best;
+        assert_eq!(best, brute(lo, hi));
+        println!("{:?} 的最大子段和 = {}", &data[lo..hi], best);
+    }
+
+    tree.set(6, MaxSubarray::of(6));
+    assert_eq!(tree.query(..).best, 19);
+    println!("把 -6 改成 6 之后整体的最大子段和 = {}", tree.query(..).best);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 覆盖 `Sum` 配合 `Add`、`Min` 配合 `Add`、`Max` 配合 `Assign` 三种组合；
+    // 长度取 37，树的左右两半深度不同
+    #[test]
+    fn segment_trees_match_brute_force() {
+        let mut rng = XorShift64::new(17);
+        let n = 37;
+        let mut brute: Vec<i64> = (0..n).map(|_| rng.range(-100..100)).collect();
+        let mut sum: SegmentTree<Sum, Add> = brute.iter().map(|&x| Sum::of(x)).collect();
+        let mut min: SegmentTree<Min, Add> = brute.iter().map(|&x| Min::of(x)).collect();
+        // Max 的树上执行赋值而不是加法，单独维护一份暴力数组
+        let mut brute_max = brute.clone();
+        let mut max: SegmentTree<Max, Assign> = brute.iter().map(|&x| Max::of(x)).collect();
+
+        for step in 0..20_000 {
+            let a = rng.below(n as u64 + 1) as usize;
+            let b = rng.below(n as u64 + 1) as usize;
+            let (lo, hi) = (a.min(b), a.max(b));
+            match rng.below(4) {
+                0 => {
+                    let x = rng.range(-50..50);
+                    sum.update(lo..hi, Add(x));
+                    min.update(lo..hi, Add(x));
+                    brute[lo..hi].iter_mut().for_each(|v| *v += x);
+                }
+                1 => {
+                    let x = rng.range(-100..100);
+                    max.update(lo..hi, Assign(Some(x)));
+                    brute_max[lo..hi].iter_mut().for_each(|v| *v = x);
+                }
+                2 if lo < n => {
+                    let x = rng.range(-100..100);
+                    sum.set(lo, Sum::of(x));
+                    min.set(lo, Min::of(x));
+                    max.set(lo, Max::of(x));
+                    brute[lo] = x;
+                    brute_max[lo] = x;
+                }
+                _ => {
+                    let expected_sum = brute[lo..hi].iter().sum::<i64>();
+                    let expected_min = brute[lo..hi].iter().copied().min();
+                    let expected_max = brute_max[lo..hi].iter().copied().max();
+                    assert_eq!(sum.query(lo..hi), Sum { sum: expected_sum, len: (hi - lo) as i64 }, "第 {} 步 sum({}..{})", step, lo, hi);
+                    assert_eq!(min.query(lo..hi), Min(expected_min), "第 {} 步 min({}..{})", step, lo, hi);
+                    assert_eq!(max.query(lo..hi), Max(expected_max), "第 {} 步 max({}..{})", step, lo, hi);
+                }
+            }
+        }
+        let values: Vec<i64> = (0..n).map(|i| sum.get(i).sum).collect();
+        assert_eq!(values, brute, "最终内容不同");
+    }
+
+    #[test]
+    fn single_element_tree() {
+        let mut tree: SegmentTree<Sum, Add> = [4].into_iter().map(Sum::of).collect();
+        tree.update(.., Add(3));
+        tree.update(0..0, Add(100));
+        assert_eq!(tree.query(..), Sum::of(7));
+        tree.set(0, Sum::of(-1));
+        tree.update(..=0, Add(1));
+        assert_eq!((tree.get(0), tree.query(1..)), (Sum::of(0), Sum::identity()));
+    }
+
+    #[test]
+    fn tags_reach_the_first_and_last_elements() {
+        let mut tree: SegmentTree<Sum, Add> = [0; 13].into_iter().map(Sum::of).collect();
+        tree.update(.., Add(2));
+        tree.update(..1, Add(10));
+        tree.update(12.., Add(20));
+        // set 沿路径下推标记，兄弟节点上的增量不能丢
+        tree.set(6, Sum::of(0));
+        let values: Vec<i64> = (0..13).map(|i| tree.get(i).sum).collect();
+        assert_eq!(values, [12, 2, 2, 2, 2, 2, 0, 2, 2, 2, 2, 2, 22]);
+        assert_eq!((tree.query(..1).sum, tree.query(12..).sum, tree.query(..).sum), (12, 22, 54));
+    }
+
+    #[test]
+    fn later_assign_overrides_pending_assign() {
+        let mut tree: SegmentTree<Max, Assign> = (0..10).map(Max::of).collect();
+        tree.update(.., Assign(Some(1)));
+        tree.update(3..7, Assign(Some(9)));
+        tree.update(..5, Assign(None));
+        tree.update(.., Assign(Some(2)));
+        tree.update(9.., Assign(Some(5)));
+        let values: Vec<Max> = (0..10).map(|i| tree.get(i)).collect();
+        assert_eq!(values, [2, 2, 2, 2, 2, 2, 2, 2, 2, 5].map(Max::of));
+    }
+
+    #[test]
+    #[should_panic(expected = "区间 0..14 超出长度 13")]
+    fn range_past_the_end_panics() {
+        let mut tree: SegmentTree<Sum, Add> = SegmentTree::new(13);
+        tree.update(..14, Add(1));
+    }
+
+    #[test]
+    fn query_through_shared_reference_leaves_tags_in_place() {
+        let mut tree: SegmentTree<Sum, Assign> = (0..8).map(Sum::of).collect();
+        tree.update(.., Assign(Some(5)));
+        tree.update(2..3, Assign(Some(7)));
+        let shared = &tree;
+        let lazy = shared.lazy.clone();
+        let values: Vec<i64> = (0..8).map(|i| shared.get(i).sum).collect();
+        assert_eq!(values, [5, 5, 7, 5, 5, 5, 5, 5]);
+        assert_eq!((shared.query(..2).sum, shared.query(3..).sum), (10, 25));
+        assert_eq!(shared.lazy, lazy);
+    }
+
+    #[test]
+    fn extreme_values_still_receive_updates() {
+        let mut min: SegmentTree<Min, Add> = [i64::MAX, 5].into_iter().map(Min::of).collect();
+        min.update(.., Add(-1));
+        assert_eq!(min.get(0), Min::of(i64::MAX - 1));
+        assert_eq!(min.query(..), Min::of(4));
+        assert_eq!(min.query(1..1), Min(None));
+
+        let mut max: SegmentTree<Max, Add> = [i64::MIN, -5].into_iter().map(Max::of).collect();
+        max.update(..1, Add(1));
+        assert_eq!(max.get(0), Max::of(i64::MIN + 1));
+        assert_eq!(max.query(..), Max::of(-5));
+
+        let mut assign: SegmentTree<Min, Assign> = SegmentTree::new(0);
+        assign.update(.., Assign(Some(3)));
+        assert_eq!(assign.query(..), Min(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "区间和超出了 i64 的范围")]
+    fn sum_overflow_panics() {
+        let mut sum: SegmentTree<Sum, Add> = [i64::MAX - 1, 0].into_iter().map(Sum::of).collect();
+        sum.update(..1, Add(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "区间最小值超出了 i64 的范围")]
+    fn min_overflow_panics() {
+        let mut min: SegmentTree<Min, Add> = [i64::MAX].into_iter().map(Min::of).collect();
+        min.update(.., Add(1));
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+struct Node<V> {
+    value: Option<V>,
+    // BTreeMap 让子节点按字符排序，前缀迭代自然是字典序
+    children: BTreeMap<char, Node<V>>,
+    // 以这个节点为根的子树中有多少个键，用于 O(前缀长度) 的 count_prefix
+    count: usize,
+}
+
+impl<V> Node<V> {
+    fn new() -> Node<V> {
+        Node { value: None, children: BTreeMap::new(), count: 0 }
+    }
+}
+
+/// 以 `char` 为边的字典树
+///
+/// 每条边是一个完整的 Unicode 标量值，`'国'`（UTF-8 中 3 个字节）和 `'😻'`（4 个字节）都只占一层，
+/// 所以前缀总是落在字符边界上。
+pub struct Trie<V> {
+    root: Node<V>,
+}
+
+impl<V> Trie<V> {
+    pub fn new() -> Trie<V> {
+        Trie { root: Node::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.root.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.count == 0
+    }
+
+    /// 包括根在内的节点数
+    pub fn node_count(&self) -> usize {
+        fn count<V>(node: &Node<V>) -> usize {
+            1 + node.children.values().map(count).sum::<usize>()
+        }
+        count(&self.root)
+    }
+
+    fn node(&self, key: &str) -> Option<&Node<V>> {
+        key.chars().try_fold(&self.root, |node, c| node.children.get(&c))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.node(key)?.value.as_ref()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 插入键，已存在时替换值并返回旧值
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let is_new = !self.contains_key(key);
+        let mut node = &mut self.root;
+        node.count += usize::from(is_new);
+        for c in key.chars() {
+            node = node.children.entry(c).or_insert_with(Node::new);
+            node.count += usize::from(is_new);
+        }
+        node.value.replace(value)
+    }
+
+    /// 删除键，并删除不再通向任何键的节点
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        fn remove<V>(node: &mut Node<V>, mut chars: std::str::Chars) -> Option<V> {
+            let removed = match chars.next() {
+                None => node.value.take(),
+                Some(c) => {
+                    let child = node.children.get_mut(&c)?;
+                    let removed = remove(child, chars)?;
+                    if child.count == 0 {
+                        node.children.remove(&c);
+                    }
+                    Some(removed)
+                }
+            };
+            node.count -= usize::from(removed.is_some());
+            removed
+        }
+        remove(&mut self.root, key.chars())
+    }
+
+    /// 以 prefix 开头的键的数量
+    pub fn count_prefix(&self, prefix: &str) -> usize {
+        self.node(prefix).map_or(0, |node| node.count)
+    }
+
+    /// query 的前缀中最长的键
+    pub fn longest_prefix<'q>(&self, query: &'q str) -> Option<(&'q str, &V)> {
+        let mut node = &self.root;
+        let mut best = node.value.as_ref().map(|v| (0, v));
+        for (i, c) in query.char_indices() {
+            match node.children.get(&c) {
+                Some(child) => node = child,
+                None => break,
+            }
+            if let Some(value) = &node.value {
+                best = Some((i + c.len_utf8(), value));
+            }
+        }
+        best.map(|(end, value)| (&query[..end], value))
+    }
+
+    /// 按字典序迭代以 prefix 开头的键
+    pub fn iter_prefix(&self, prefix: &str) -> Iter<'_, V> {
+        let stack = self.node(prefix).map(|node| (prefix.to_string(), node)).into_iter().collect();
+        Iter { stack }
+    }
+
+    pub fn iter(&self) -> Iter<'_, V> {
+        self.iter_prefix("")
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Trie<V> {
+        Trie::new()
+    }
+}
+
+impl<'a, V> FromIterator<(&'a str, V)> for Trie<V> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, V)>>(iter: I) -> Trie<V> {
+        let mut trie = Trie::new();
+        for (key, value) in iter {
+            trie.insert(key, value);
+        }
+        trie
+    }
+}
+
+/// 前序遍历：父节点的键是子节点的键的前缀，先于子节点输出
+pub struct Iter<'a, V> {
+    stack: Vec<(String, &'a Node<V>)>,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<(String, &'a V)> {
+        while let Some((key, node)) = self.stack.pop() {
+            // 逆序入栈，最小的字符先出栈
+            for (&c, child) in node.children.iter().rev() {
+                let mut child_key = key.clone();
+                child_key.push(c);
+                self.stack.push((child_key, child));
+            }
+            if let Some(value) = &node.value {
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,287 @@
+/*!
+前缀树：按字符的 `Trie` 与按字节压缩的 `RadixTree`
+
+两者都支持插入、删除、精确查找、按字典序迭代某个前缀下的所有键、最长前缀匹配和按前缀计数，
+适合做自动补全和路由表。
+
+- `Trie` 每条边是一个 `char`，前缀总是完整的字符
+- `RadixTree` 每条边是一段字节，只有一个子节点的路径会被压缩成一条边，节点更少
+
+```
+use rust_code_examples::data_structure::trie::{RadixTree, Trie};
+
+let trie: Trie<u32> = [("中国", 1), ("中国人", 2), ("中文", 3), ("😻", 4)].into_iter().collect();
+let words: Vec<String> = trie.iter_prefix("中国").map(|(k, _)| k).collect();
+assert_eq!(words, ["中国", "中国人"]);
+assert_eq!(trie.count_prefix("中"), 3);
+assert_eq!(trie.longest_prefix("中国人民"), Some(("中国人", &2)));
+
+let radix: RadixTree<u32> = [("中国", 1), ("中文", 3)].into_iter().collect();
+// "中" 的 UTF-8 编码是 e4 b8 ad，前缀可以停在字符中间
+assert_eq!(radix.count_prefix([0xe4, 0xb8]), 2);
+```
+*/
+
+mod char_trie;
+mod radix;
+
+pub use char_trie::Trie;
+pub use radix::RadixTree;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_autocomplete, "自动补全", "按字典序列出前缀下的候选词，并统计数量"),
+    example!(ex2_multibyte, "多字节字符", "'国' 占 3 个字节、'😻' 占 4 个字节，Trie 按字符、RadixTree 按字节处理"),
+    example!(ex3_radix_compression, "路径压缩", "RadixTree 把单链路径合并成一条边，节点数远少于 Trie"),
+    example!(ex4_longest_prefix, "最长前缀匹配", "用前缀树实现简单的路由表"),
+];
+
+pub fn ex1_autocomplete() {
+    println!("自动补全 示例代码\n\n");
+
+    let words = ["rust", "rustc", "rustup", "rustdoc", "ruby", "run", "cargo", "crate", "clippy"];
+    let mut trie = Trie::new();
+    for (rank, word) in words.iter().enumerate() {
+        trie.insert(word, rank);
+    }
+
+    let suggestions: Vec<String> = trie.iter_prefix("rust").map(|(word, _)| word).collect();
+    assert_eq!(suggestions, ["rust", "rustc", "rustdoc", "rustup"]);
+    println!("rust 开头: {:?}", suggestions);
+
+    let suggestions: Vec<String> = trie.iter_prefix("c").map(|(word, _)| word).collect();
+    assert_eq!(suggestions, ["cargo", "clippy", "crate"]);
+    println!("c 开头: {:?}", suggestions);
+
+    assert_eq!(trie.count_prefix("ru"), 6);
+    assert_eq!(trie.count_prefix("go"), 0);
+    println!("ru 开头的有 {} 个，go 开头的有 {} 个", trie.count_prefix("ru"), trie.count_prefix("go"));
+
+    assert_eq!(trie.remove("rustc"), Some(1));
+    assert_eq!(trie.remove("rustc"), None);
+    assert_eq!(trie.get("rust"), Some(&0));
+    assert_eq!(trie.count_prefix("rust"), 3);
+    println!("删除 rustc 之后 rust 开头的有 {} 个", trie.count_prefix("rust"));
+}
+
+pub fn ex2_multibyte() {
+    println!("多字节字符 示例代码\n\n");
+
+    // 与 basic_types::char_type 中的字符相同
+    let (g, heart_eyed_cat) = ('国', '😻');
+    assert_eq!((g.len_utf8(), heart_eyed_cat.len_utf8()), (3, 4));
+    println!("'{}' 占 {} 个字节，'{}' 占 {} 个字节", g, g.len_utf8(), heart_eyed_cat, heart_eyed_cat.len_utf8());
+
+    let keys = ["国", "国家", "国王", "中国", "😻", "😻😻", "z"];
+    let trie: Trie<usize> = keys.iter().copied().zip(0..).collect();
+    let radix: RadixTree<usize> = keys.iter().copied().zip(0..).collect();
+
+    // Trie 中 "国家" 只有两层，与字节数无关
+    let under_guo: Vec<String> = trie.iter_prefix("国").map(|(k, _)| k).collect();
+    assert_eq!(under_guo, ["国", "国家", "国王"]);
+    println!("Trie 中以 '国' 开头: {:?}", under_guo);
+
+    // 所有键按字典序（也就是 UTF-8 字节序）输出，两者一致
+    let from_trie: Vec<String> = trie.iter().map(|(k, _)| k).collect();
+    let from_radix: Vec<String> = radix.iter().map(|(k, _)| String::from_utf8(k).unwrap()).collect();
+    assert_eq!(from_trie, from_radix);
+    assert_eq!(from_trie, ["z", "中国", "国", "国家", "国王", "😻", "😻😻"]);
+    println!("字典序: {:?}", from_trie);
+
+    // '😻' 的编码是 f0 9f 98 bb，RadixTree 可以按字节前缀查询
+    assert_eq!(radix.count_prefix([0xf0, 0x9f]), 2);
+    assert_eq!(radix.count_prefix("😻".as_bytes()), 2);
+    println!("RadixTree 中以字节 f0 9f 开头的键: {} 个", radix.count_prefix([0xf0, 0x9f]));
+
+    assert_eq!(trie.longest_prefix("国家队"), Some(("国家", &1)));
+    assert_eq!(trie.longest_prefix("😻😻😻"), Some(("😻😻", &5)));
+    println!("longest_prefix(\"😻😻😻\") = {:?}", trie.longest_prefix("😻😻😻"));
+}
+
+pub fn ex3_radix_compression() {
+    println!("路径压缩 示例代码\n\n");
+
+    let words = ["internationalization", "internationally", "international", "internet", "interval", "into"];
+    let trie: Trie<()> = words.iter().map(|w| (*w, ())).collect();
+    let mut radix: RadixTree<()> = words.iter().map(|w| (*w, ())).collect();
+    assert_eq!((trie.node_count(), radix.node_count()), (29, 10));
+    println!("{} 个单词: Trie 有 {} 个节点，RadixTree 有 {} 个节点", words.len(), trie.node_count(), radix.node_count());
+
+    // 删除之后只剩一个子节点的节点会被合并
+    let before = radix.node_count();
+    radix.remove("internationally");
+    radix.remove("internationalization");
+    assert_eq!(radix.node_count(), before - 2);
+    assert_eq!(radix.count_prefix("intern"), 2);
+    println!("删除两个单词之后 RadixTree 有 {} 个节点", radix.node_count());
+
+    let rest: Vec<String> = radix.iter().map(|(k, _)| String::from_utf8(k).unwrap()).collect();
+    assert_eq!(rest, ["international", "internet", "interval", "into"]);
+    println!("剩余: {:?}", rest);
+}
+
+pub fn ex4_longest_prefix() {
+    println!("最长前缀匹配 示例代码\n\n");
+
+    let mut routes = RadixTree::new();
+    routes.insert("/", "首页");
+    routes.insert("/api", "API 入口");
+    routes.insert("/api/users", "用户列表");
+    routes.insert("/api/users/admin", "管理员");
+    routes.insert("/static", "静态文件");
+
+    let cases = [
+        ("/api/users/42", "用户列表"),
+        ("/api/orders", "API 入口"),
+        ("/static/logo.png", "静态文件"),
+        ("/about", "首页"),
+        ("/api/users/admin/settings", "管理员"),
+    ];
+    for (path, expected) in cases {
+        let (matched, handler) = routes.longest_prefix(path.as_bytes()).expect("至少匹配 /");
+        assert_eq!(*handler, expected);
+        println!("{:<28} -> {:<18} {}", path, std::str::from_utf8(matched).unwrap(), handler);
+    }
+    assert_eq!(routes.longest_prefix(b"api"), None);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 随机键使用的字符，包括 2、3、4 个字节的字符
+    const ALPHABET: [char; 6] = ['a', 'b', 'é', '国', '中', '😻'];
+
+    fn random_key(rng: &mut XorShift64, max_len: u64) -> String {
+        (0..rng.below(max_len + 1)).map(|_| ALPHABET[rng.below(ALPHABET.len() as u64) as usize]).collect()
+    }
+
+    // 键很短、字符集很小，插入、删除和前缀查询会频繁落在同一条路径上
+    #[test]
+    fn tries_match_btree_map() {
+        let mut rng = XorShift64::new(16);
+        let mut trie = Trie::new();
+        let mut radix = RadixTree::new();
+        let mut model: BTreeMap<String, u32> = BTreeMap::new();
+
+        for step in 0..20_000 {
+            let key = random_key(&mut rng, 4);
+            match rng.below(6) {
+                0..=2 => {
+                    let value = rng.below(1000) as u32;
+                    let expected = model.insert(key.clone(), value);
+                    assert_eq!(trie.insert(&key, value), expected, "第 {} 步 Trie insert({:?})", step, key);
+                    assert_eq!(radix.insert(&key, value), expected, "第 {} 步 RadixTree insert({:?})", step, key);
+                }
+                3 => {
+                    let expected = model.remove(&key);
+                    assert_eq!(trie.remove(&key), expected, "第 {} 步 Trie remove({:?})", step, key);
+                    assert_eq!(radix.remove(&key), expected, "第 {} 步 RadixTree remove({:?})", step, key);
+                }
+                4 => {
+                    let prefix = random_key(&mut rng, 2);
+                    let expected: Vec<(String, u32)> =
+                        model.iter().filter(|(k, _)| k.starts_with(&prefix)).map(|(k, &v)| (k.clone(), v)).collect();
+                    let from_trie: Vec<(String, u32)> = trie.iter_prefix(&prefix).map(|(k, &v)| (k, v)).collect();
+                    let from_radix: Vec<(String, u32)> =
+                        radix.iter_prefix(&prefix).map(|(k, &v)| (String::from_utf8(k).expect("完整的键"), v)).collect();
+                    assert_eq!(from_trie, expected, "第 {} 步 Trie iter_prefix({:?})", step, prefix);
+                    assert_eq!(from_radix, expected, "第 {} 步 RadixTree iter_prefix({:?})", step, prefix);
+                    assert_eq!(trie.count_prefix(&prefix), expected.len());
+                    assert_eq!(radix.count_prefix(&prefix), expected.len());
+
+                    // 在任意字节处截断的前缀，可能停在多字节字符的中间
+                    let bytes = key.as_bytes();
+                    let cut = &bytes[..rng.below(bytes.len() as u64 + 1) as usize];
+                    let expected = model.keys().filter(|k| k.as_bytes().starts_with(cut)).count();
+                    assert_eq!(radix.count_prefix(cut), expected, "第 {} 步 RadixTree count_prefix({:?})", step, cut);
+                    assert_eq!(radix.iter_prefix(cut).count(), expected);
+                }
+                _ => {
+                    let expected = model.iter().filter(|(k, _)| key.starts_with(k.as_str())).max_by_key(|(k, _)| k.len());
+                    let expected = expected.map(|(k, v)| (k.as_str(), v));
+                    assert_eq!(trie.longest_prefix(&key), expected, "第 {} 步 Trie longest_prefix({:?})", step, key);
+                    let from_radix = radix.longest_prefix(key.as_bytes()).map(|(k, v)| (std::str::from_utf8(k).expect("完整的键"), v));
+                    assert_eq!(from_radix, expected, "第 {} 步 RadixTree longest_prefix({:?})", step, key);
+                }
+            }
+
+            assert_eq!(trie.len(), model.len(), "第 {} 步之后 Trie 长度不同", step);
+            assert_eq!(radix.len(), model.len(), "第 {} 步之后 RadixTree 长度不同", step);
+            assert_eq!(trie.get(&key), model.get(&key));
+            assert_eq!(radix.get(&key), model.get(&key));
+            // 压缩之后每个非根节点要么有值，要么至少有两个子节点
+            assert!(radix.node_count() <= 2 * model.len() + 1, "第 {} 步之后 RadixTree 没有压缩", step);
+        }
+    }
+
+    fn build(keys: &[&str]) -> (Trie<usize>, RadixTree<usize>) {
+        let (mut trie, mut radix) = (Trie::new(), RadixTree::new());
+        for (i, key) in keys.iter().enumerate() {
+            trie.insert(key, i);
+            radix.insert(key, i);
+        }
+        (trie, radix)
+    }
+
+    #[test]
+    fn removing_a_prefix_key_keeps_longer_keys() {
+        let (mut trie, mut radix) = build(&["car", "cart", "carton"]);
+        assert_eq!((trie.remove("cart"), radix.remove("cart")), (Some(1), Some(1)));
+        assert_eq!((trie.get("carton"), radix.get("carton")), (Some(&2), Some(&2)));
+        assert_eq!((trie.count_prefix("cart"), radix.count_prefix("cart")), (1, 1));
+        assert_eq!(trie.longest_prefix("cartoon"), Some(("car", &0)));
+        assert_eq!(radix.longest_prefix(b"cartoon"), Some((&b"car"[..], &0)));
+        // 没有值的中间节点被重新压缩，结构与直接插入剩下的键相同
+        let (fresh_trie, fresh_radix) = build(&["car", "carton"]);
+        assert_eq!(trie.node_count(), fresh_trie.node_count());
+        assert_eq!(radix.node_count(), fresh_radix.node_count());
+    }
+
+    #[test]
+    fn removing_the_longest_key_prunes_its_branch() {
+        let (mut trie, mut radix) = build(&["car", "carton"]);
+        trie.remove("carton");
+        radix.remove("carton");
+        let (fresh_trie, fresh_radix) = build(&["car"]);
+        assert_eq!((trie.node_count(), radix.node_count()), (fresh_trie.node_count(), fresh_radix.node_count()));
+        assert_eq!((trie.count_prefix("cart"), radix.count_prefix("cart")), (0, 0));
+    }
+
+    #[test]
+    fn removing_a_path_without_a_value_changes_nothing() {
+        let (mut trie, mut radix) = build(&["car", "cat"]);
+        let nodes = (trie.node_count(), radix.node_count());
+        assert_eq!((trie.remove("ca"), radix.remove("ca")), (None, None));
+        assert_eq!((trie.remove("cart"), radix.remove("cart")), (None, None));
+        assert_eq!((trie.node_count(), radix.node_count()), nodes);
+        assert_eq!((trie.len(), radix.len()), (2, 2));
+    }
+
+    #[test]
+    fn empty_key_is_a_prefix_of_everything() {
+        let (mut trie, mut radix) = build(&["", "ab"]);
+        assert_eq!(trie.longest_prefix("xyz"), Some(("", &0)));
+        assert_eq!(radix.longest_prefix(b"xyz"), Some((&b""[..], &0)));
+        assert_eq!((trie.count_prefix(""), radix.count_prefix("")), (2, 2));
+        trie.remove("");
+        radix.remove("");
+        assert_eq!((trie.longest_prefix("xyz"), radix.longest_prefix(b"xyz")), (None, None));
+        assert_eq!((trie.get("ab"), radix.get("ab")), (Some(&1), Some(&1)));
+    }
+
+    #[test]
+    fn radix_prefix_may_end_inside_a_character() {
+        let (trie, radix) = build(&["中国", "中文", "日本"]);
+        let half = &"中".as_bytes()[..2];
+        assert_eq!(radix.count_prefix(half), 2);
+        assert_eq!(radix.iter_prefix(half).count(), 2);
+        assert_eq!(trie.count_prefix("中"), 2);
+        assert_eq!(radix.longest_prefix(half), None);
+    }
+}
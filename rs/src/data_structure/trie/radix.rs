@@ -0,0 +1,224 @@
+struct Edge<V> {
+    // 不为空，同一个节点的各条边首字节互不相同
+    label: Vec<u8>,
+    node: Node<V>,
+}
+
+struct Node<V> {
+    value: Option<V>,
+    // 按首字节排序
+    edges: Vec<Edge<V>>,
+    count: usize,
+}
+
+impl<V> Node<V> {
+    fn new() -> Node<V> {
+        Node { value: None, edges: Vec::new(), count: 0 }
+    }
+
+    fn edge(&self, byte: u8) -> Result<usize, usize> {
+        self.edges.binary_search_by_key(&byte, |edge| edge.label[0])
+    }
+}
+
+fn common_prefix(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+/// 以字节串为边的压缩前缀树（radix tree）
+///
+/// 只有一个子节点、自身又没有值的节点会和子节点合并，边上保存一段字节，节点数不超过键数的两倍。
+/// 边按字节切分，一个多字节字符可能被拆到两条边上，但键总是完整地存入和取出，
+/// 字节序与 `str` 的比较顺序一致，所以前缀迭代的结果仍然是字典序。
+pub struct RadixTree<V> {
+    root: Node<V>,
+}
+
+impl<V> RadixTree<V> {
+    pub fn new() -> RadixTree<V> {
+        RadixTree { root: Node::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.root.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.count == 0
+    }
+
+    /// 包括根在内的节点数
+    pub fn node_count(&self) -> usize {
+        fn count<V>(node: &Node<V>) -> usize {
+            1 + node.edges.iter().map(|edge| count(&edge.node)).sum::<usize>()
+        }
+        count(&self.root)
+    }
+
+    pub fn get(&self, key: impl AsRef<[u8]>) -> Option<&V> {
+        let mut node = &self.root;
+        let mut rest = key.as_ref();
+        while let Some(&first) = rest.first() {
+            let edge = &node.edges[node.edge(first).ok()?];
+            rest = rest.strip_prefix(edge.label.as_slice())?;
+            node = &edge.node;
+        }
+        node.value.as_ref()
+    }
+
+    pub fn contains_key(&self, key: impl AsRef<[u8]>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 插入键，已存在时替换值并返回旧值
+    pub fn insert(&mut self, key: impl AsRef<[u8]>, value: V) -> Option<V> {
+        fn insert<V>(node: &mut Node<V>, key: &[u8], value: V) -> Option<V> {
+            let Some(&first) = key.first() else {
+                let old = node.value.replace(value);
+                node.count += usize::from(old.is_none());
+                return old;
+            };
+            let i = match node.edge(first) {
+                Ok(i) => i,
+                Err(i) => {
+                    let mut leaf = Node::new();
+                    leaf.value = Some(value);
+                    leaf.count = 1;
+                    node.edges.insert(i, Edge { label: key.to_vec(), node: leaf });
+                    node.count += 1;
+                    return None;
+                }
+            };
+            let edge = &mut node.edges[i];
+            let shared = common_prefix(&edge.label, key);
+            if shared < edge.label.len() {
+                // 在边的中间插入一个节点，原来的子节点挂在它下面
+                let tail = edge.label.split_off(shared);
+                let old = std::mem::replace(&mut edge.node, Node::new());
+                edge.node.count = old.count;
+                edge.node.edges.push(Edge { label: tail, node: old });
+            }
+            let old = insert(&mut edge.node, &key[shared..], value);
+            node.count += usize::from(old.is_none());
+            old
+        }
+        insert(&mut self.root, key.as_ref(), value)
+    }
+
+    /// 删除键，并把只剩一个子节点的节点与子节点合并
+    pub fn remove(&mut self, key: impl AsRef<[u8]>) -> Option<V> {
+        fn remove<V>(node: &mut Node<V>, key: &[u8]) -> Option<V> {
+            let removed = match key.first() {
+                None => node.value.take(),
+                Some(&first) => {
+                    let i = node.edge(first).ok()?;
+                    let edge = &mut node.edges[i];
+                    let rest = key.strip_prefix(edge.label.as_slice())?;
+                    let removed = remove(&mut edge.node, rest)?;
+                    if edge.node.count == 0 {
+                        node.edges.remove(i);
+                    } else if edge.node.value.is_none() && edge.node.edges.len() == 1 {
+                        let child = edge.node.edges.pop().expect("只有一条边");
+                        edge.label.extend(child.label);
+                        edge.node = child.node;
+                    }
+                    Some(removed)
+                }
+            };
+            node.count -= usize::from(removed.is_some());
+            removed
+        }
+        remove(&mut self.root, key.as_ref())
+    }
+
+    // 找到覆盖 prefix 的节点，prefix 可能结束在一条边的中间，返回这个节点完整的键
+    fn descend(&self, prefix: &[u8]) -> Option<(Vec<u8>, &Node<V>)> {
+        let mut node = &self.root;
+        let mut key = Vec::with_capacity(prefix.len());
+        let mut rest = prefix;
+        while let Some(&first) = rest.first() {
+            let edge = &node.edges[node.edge(first).ok()?];
+            let shared = common_prefix(&edge.label, rest);
+            if shared < rest.len() && shared < edge.label.len() {
+                return None;
+            }
+            key.extend_from_slice(&edge.label);
+            node = &edge.node;
+            rest = &rest[shared..];
+        }
+        Some((key, node))
+    }
+
+    /// 以 prefix 开头的键的数量，prefix 可以结束在多字节字符的中间
+    pub fn count_prefix(&self, prefix: impl AsRef<[u8]>) -> usize {
+        self.descend(prefix.as_ref()).map_or(0, |(_, node)| node.count)
+    }
+
+    /// query 的前缀中最长的键
+    pub fn longest_prefix<'q>(&self, query: &'q [u8]) -> Option<(&'q [u8], &V)> {
+        let mut node = &self.root;
+        let mut best = node.value.as_ref().map(|v| (0, v));
+        let mut consumed = 0;
+        while let Some(&first) = query.get(consumed) {
+            let Ok(i) = node.edge(first) else { break };
+            let edge = &node.edges[i];
+            if !query[consumed..].starts_with(&edge.label) {
+                break;
+            }
+            consumed += edge.label.len();
+            node = &edge.node;
+            if let Some(value) = &node.value {
+                best = Some((consumed, value));
+            }
+        }
+        best.map(|(end, value)| (&query[..end], value))
+    }
+
+    /// 按字节序迭代以 prefix 开头的键
+    pub fn iter_prefix(&self, prefix: impl AsRef<[u8]>) -> Iter<'_, V> {
+        Iter { stack: self.descend(prefix.as_ref()).into_iter().collect() }
+    }
+
+    pub fn iter(&self) -> Iter<'_, V> {
+        self.iter_prefix(b"")
+    }
+}
+
+impl<V> Default for RadixTree<V> {
+    fn default() -> RadixTree<V> {
+        RadixTree::new()
+    }
+}
+
+impl<K: AsRef<[u8]>, V> FromIterator<(K, V)> for RadixTree<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> RadixTree<V> {
+        let mut tree = RadixTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+/// 前序遍历，产生完整的键和值
+pub struct Iter<'a, V> {
+    stack: Vec<(Vec<u8>, &'a Node<V>)>,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (Vec<u8>, &'a V);
+
+    fn next(&mut self) -> Option<(Vec<u8>, &'a V)> {
+        while let Some((key, node)) = self.stack.pop() {
+            for edge in node.edges.iter().rev() {
+                let mut child_key = key.clone();
+                child_key.extend_from_slice(&edge.label);
+                self.stack.push((child_key, &edge.node));
+            }
+            if let Some(value) = &node.value {
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,20 @@
+#[test]
+fn from_slice_builds_nested_boxes() {
+    assert_eq!(List::from_slice(&[]), List::Nil);
+    assert_eq!(
+        List::from_slice(&[1, 2]),
+        List::Cons(1, Box::new(List::Cons(2, Box::new(List::Nil))))
+    );
+}
+
+#[test]
+fn sum_walks_the_list() {
+    assert_eq!(List::Nil.sum(), 0);
+    assert_eq!(List::from_slice(&[1, 2, 3, 4]).sum(), 10);
+}
+
+#[test]
+fn leaked_string_is_static() {
+    let s: &'static str = leak_string(String::from("hello, world"));
+    assert_eq!(s, "hello, world");
+}
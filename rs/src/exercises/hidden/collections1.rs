@@ -0,0 +1,28 @@
+#[test]
+fn evens_keeps_order() {
+    assert_eq!(evens(&[1, 2, 3, 4, 6, 7]), vec![2, 4, 6]);
+    assert_eq!(evens(&[-2, -1, 0]), vec![-2, 0]);
+    assert!(evens(&[]).is_empty());
+}
+
+#[test]
+fn word_count_counts() {
+    let counts = word_count("the quick the  lazy\nthe");
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts["the"], 3);
+    assert_eq!(counts["quick"], 1);
+    assert_eq!(counts.get("dog"), None);
+}
+
+#[test]
+fn sort_people_by_age_then_name() {
+    let mut people = vec![
+        ("Zoe".to_string(), 25),
+        ("Al".to_string(), 60),
+        ("Bob".to_string(), 25),
+        ("John".to_string(), 1),
+    ];
+    sort_people(&mut people);
+    let names: Vec<&str> = people.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["Al", "Bob", "Zoe", "John"]);
+}
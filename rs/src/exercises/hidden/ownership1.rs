@@ -0,0 +1,25 @@
+#[test]
+fn append_keeps_ownership_with_caller() {
+    let mut s = String::from("hello");
+    append_world(&mut s);
+    assert_eq!(s, "hello, world");
+    append_world(&mut s);
+    assert_eq!(s, "hello, world, world");
+}
+
+#[test]
+fn calculate_length_borrows() {
+    let s = String::from("hello");
+    assert_eq!(calculate_length(&s), 5);
+    assert_eq!(calculate_length("中国"), 6);
+    assert_eq!(s, "hello");
+}
+
+#[test]
+fn duplicate_is_independent() {
+    let s = String::from("hello");
+    let mut copy = duplicate(&s);
+    copy.push('!');
+    assert_eq!(s, "hello");
+    assert_eq!(copy, "hello!");
+}
@@ -0,0 +1,21 @@
+#[test]
+fn first_word_is_a_slice() {
+    let s = String::from("hello world");
+    assert_eq!(first_word(&s), "hello");
+    assert_eq!(first_word("single"), "single");
+    assert_eq!(first_word("中国 人"), "中国");
+    assert_eq!(first_word(&s).as_ptr(), s.as_ptr());
+}
+
+#[test]
+fn char_count_counts_chars_not_bytes() {
+    assert_eq!(char_count("hello"), 5);
+    assert_eq!(char_count("中国人"), 3);
+    assert_eq!(char_count("😻"), 1);
+}
+
+#[test]
+fn join_with_dash() {
+    assert_eq!(join("hello", "world"), "hello-world");
+    assert_eq!(join("", ""), "-");
+}
@@ -0,0 +1,24 @@
+#[test]
+fn square_and_area() {
+    let square = Rectangle::square(3);
+    assert_eq!((square.width, square.height), (3, 3));
+    assert_eq!(square.area(), 9);
+    assert_eq!(Rectangle { width: 30, height: 50 }.area(), 1500);
+}
+
+#[test]
+fn can_hold_needs_both_sides() {
+    let big = Rectangle { width: 8, height: 7 };
+    let small = Rectangle { width: 5, height: 1 };
+    let tall = Rectangle { width: 1, height: 9 };
+    assert!(big.can_hold(&small));
+    assert!(!small.can_hold(&big));
+    assert!(!big.can_hold(&tall));
+}
+
+#[test]
+fn describe_messages() {
+    assert_eq!(describe(&Message::Quit), "quit");
+    assert_eq!(describe(&Message::Move { x: 1, y: -2 }), "move to (1, -2)");
+    assert_eq!(describe(&Message::Write("hi".to_string())), "hi");
+}
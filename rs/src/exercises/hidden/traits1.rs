@@ -0,0 +1,13 @@
+#[test]
+fn default_summary_uses_author() {
+    let weibo = Weibo { username: "sunface".to_string(), content: "Rust 棒极了".to_string() };
+    assert_eq!(weibo.summarize_author(), "@sunface");
+    assert_eq!(weibo.summarize(), "(Read more from @sunface...)");
+}
+
+#[test]
+fn largest_works_for_any_comparable_type() {
+    assert_eq!(largest(&[34, 50, 25, 100, 65]), 100);
+    assert_eq!(largest(&['y', 'm', 'a', 'q']), 'y');
+    assert_eq!(largest(&[1.5, -2.0]), 1.5);
+}
@@ -0,0 +1,13 @@
+#[test]
+fn count_up_sums_from_one() {
+    assert_eq!(count_up(0), 0);
+    assert_eq!(count_up(1), 1);
+    assert_eq!(count_up(4), 10);
+    assert_eq!(count_up(100), 5050);
+}
+
+#[test]
+fn parse_and_double_trims_and_doubles() {
+    assert_eq!(parse_and_double("21"), 42);
+    assert_eq!(parse_and_double("  -5 "), -10);
+}
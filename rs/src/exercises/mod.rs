@@ -0,0 +1,282 @@
+/*!
+练习模式
+
+`exercises/<主题>/<练习>.rs` 是留有 `todo!()` 的练习文件，学习者直接修改它们。
+每个练习的测试放在 `src/exercises/hidden/` 中，编译进可执行文件，检查时才拼接到练习代码后面，
+再用 `rustc --test` 编译并运行。`src/exercises/solutions/` 中是参考答案，单元测试用它们确认隐藏测试本身是对的。
+
+完成情况和已经看过的提示数保存在 `exercises/.progress` 中，`next` 从第一个未完成的练习继续。
+*/
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::utils::TempDir;
+
+/// 一个练习
+#[derive(Debug)]
+pub struct Exercise {
+    pub name: &'static str,
+    /// 对应 `basics` 中的主题，也是 `exercises/` 下的目录名
+    pub topic: &'static str,
+    /// 由浅入深的提示，`hint` 每次多显示一条
+    pub hints: &'static [&'static str],
+    tests: &'static str,
+}
+
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "variables1",
+        topic: "variables",
+        hints: &[
+            "`let` 声明的变量默认不可变，需要累加的变量要用 `let mut`。",
+            "`for i in 1..=n { total += i; }`，循环结束后把 total 作为表达式返回。",
+            "变量遮蔽：`let value: i32 = value.parse().unwrap();`，新的 value 可以是不同的类型。",
+        ],
+        tests: include_str!("hidden/variables1.rs"),
+    },
+    Exercise {
+        name: "ownership1",
+        topic: "variables",
+        hints: &[
+            "通过 `&mut String` 可以修改调用者的字符串，例如 `push_str`。",
+            "`&str` 的 `len()` 返回字节数，`&String` 会自动解引用成 `&str`。",
+            "Rust 永远不会自动深拷贝，需要显式调用 `clone()`。",
+        ],
+        tests: include_str!("hidden/ownership1.rs"),
+    },
+    Exercise {
+        name: "strings1",
+        topic: "compound_types",
+        hints: &[
+            "`s.find(' ')` 返回第一个空格的字节索引，切片 `&s[..i]` 不会分配内存。",
+            "`len()` 是字节数，字符个数要用 `chars().count()`。",
+            "`format!(\"{}-{}\", a, b)` 或者 `String::from(a) + \"-\" + b`。",
+        ],
+        tests: include_str!("hidden/strings1.rs"),
+    },
+    Exercise {
+        name: "structs1",
+        topic: "compound_types",
+        hints: &[
+            "关联函数没有 self 参数，返回 `Rectangle { width: size, height: size }`。",
+            "`can_hold` 需要宽和高都严格大于对方。",
+            "`Message::Move { x, y } => format!(\"move to ({}, {})\", x, y)`，Write 分支中用 `clone()` 得到 String。",
+        ],
+        tests: include_str!("hidden/structs1.rs"),
+    },
+    Exercise {
+        name: "collections1",
+        topic: "set_types",
+        hints: &[
+            "`numbers.iter().filter(|n| *n % 2 == 0).copied().collect()`。",
+            "`split_whitespace()` 可以处理连续的空白和换行，计数用 `*map.entry(word.to_string()).or_insert(0) += 1`。",
+            "`sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)))`。",
+        ],
+        tests: include_str!("hidden/collections1.rs"),
+    },
+    Exercise {
+        name: "traits1",
+        topic: "generics_traits",
+        hints: &[
+            "默认实现中可以直接调用 `self.summarize_author()`。",
+            "`format!(\"@{}\", self.username)`。",
+            "比较需要 `PartialOrd`，从切片中按值取出元素需要 `Copy`：`fn largest<T: PartialOrd + Copy>`。",
+        ],
+        tests: include_str!("hidden/traits1.rs"),
+    },
+    Exercise {
+        name: "box1",
+        topic: "pointer",
+        hints: &[
+            "from_slice 可以递归：`match values { [] => List::Nil, [first, rest @ ..] => ... }`。",
+            "`List::Cons(value, next) => value + next.sum()`，Box 会自动解引用。",
+            "`Box::leak(s.into_boxed_str())`。",
+        ],
+        tests: include_str!("hidden/box1.rs"),
+    },
+];
+
+impl Exercise {
+    /// 学习者要修改的练习文件
+    pub fn path(&self) -> PathBuf {
+        exercises_dir().join(self.topic).join(format!("{}.rs", self.name))
+    }
+}
+
+pub fn find(name: &str) -> Option<&'static Exercise> {
+    EXERCISES.iter().find(|e| e.name == name)
+}
+
+pub fn exercises_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("exercises")
+}
+
+/// 检查的结果
+#[derive(Debug)]
+pub enum CheckResult {
+    /// 练习代码（或它与测试拼接后）无法编译，带 rustc 的报错
+    CompileError(String),
+    /// 编译通过但测试失败，带测试输出
+    TestFailed(String),
+    Passed(String),
+}
+
+/// 把练习文件和隐藏测试拼接后用 `rustc --test` 编译并运行
+pub fn check(exercise: &Exercise) -> io::Result<CheckResult> {
+    let source = fs::read_to_string(exercise.path())?;
+    check_source(exercise, &source)
+}
+
+/// 检查给定的练习代码，每次检查都在单独的临时目录中编译，同时运行的多个检查互不影响
+fn check_source(exercise: &Exercise, source: &str) -> io::Result<CheckResult> {
+    let dir = TempDir::new(&format!("exercise_{}", exercise.name))?;
+    let work_dir = dir.path();
+
+    // 测试放在练习代码之后，报错中的行号与练习文件一致
+    let combined = work_dir.join(format!("{}.rs", exercise.name));
+    fs::write(
+        &combined,
+        format!("{}\n\n#[cfg(test)]\nmod hidden_tests {{\n    use super::*;\n\n{}\n}}\n", source, exercise.tests),
+    )?;
+
+    let binary = work_dir.join(format!("{}{}", exercise.name, std::env::consts::EXE_SUFFIX));
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let output = Command::new(rustc)
+        .args(["--edition=2021", "--test", "-A", "warnings", "--color=never"])
+        .arg(format!("--remap-path-prefix={}={}", work_dir.display(), Path::new("exercises").join(exercise.topic).display()))
+        .arg("-o")
+        .arg(&binary)
+        .arg(&combined)
+        .output()?;
+    if !output.status.success() {
+        return Ok(CheckResult::CompileError(String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+
+    let output = Command::new(&binary).arg("--test-threads=1").env("RUST_BACKTRACE", "0").output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    Ok(if output.status.success() { CheckResult::Passed(stdout) } else { CheckResult::TestFailed(stdout) })
+}
+
+/// 练习进度，每行一个练习：`<练习名> <done|pending> <已显示的提示数>`
+#[derive(Debug, Default)]
+pub struct Progress {
+    entries: Vec<(String, bool, usize)>,
+}
+
+impl Progress {
+    pub fn path() -> PathBuf {
+        exercises_dir().join(".progress")
+    }
+
+    /// 读取进度文件，文件不存在时为空进度
+    pub fn load() -> io::Result<Progress> {
+        let text = match fs::read_to_string(Progress::path()) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Progress::default()),
+            Err(e) => return Err(e),
+        };
+        let entries = text
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let name = fields.next()?.to_string();
+                let done = fields.next()? == "done";
+                let hints = fields.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                Some((name, done, hints))
+            })
+            .collect();
+        Ok(Progress { entries })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let text: String = self
+            .entries
+            .iter()
+            .map(|(name, done, hints)| format!("{} {} {}\n", name, if *done { "done" } else { "pending" }, hints))
+            .collect();
+        fs::write(Progress::path(), text)
+    }
+
+    fn entry(&mut self, name: &str) -> &mut (String, bool, usize) {
+        match self.entries.iter().position(|(n, _, _)| n == name) {
+            Some(i) => &mut self.entries[i],
+            None => {
+                self.entries.push((name.to_string(), false, 0));
+                self.entries.last_mut().unwrap()
+            }
+        }
+    }
+
+    pub fn is_done(&self, name: &str) -> bool {
+        self.entries.iter().any(|(n, done, _)| n == name && *done)
+    }
+
+    pub fn mark_done(&mut self, name: &str) {
+        self.entry(name).1 = true;
+    }
+
+    /// 多显示一条提示，返回此时应当显示的提示数，不超过提示总数
+    pub fn reveal_hint(&mut self, exercise: &Exercise) -> usize {
+        let entry = self.entry(exercise.name);
+        entry.2 = (entry.2 + 1).min(exercise.hints.len());
+        entry.2
+    }
+
+    /// 第一个没有完成的练习，全部完成时为 None
+    pub fn current(&self) -> Option<&'static Exercise> {
+        EXERCISES.iter().find(|e| !self.is_done(e.name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solution(name: &str) -> &'static str {
+        match name {
+            "variables1" => include_str!("solutions/variables1.rs"),
+            "ownership1" => include_str!("solutions/ownership1.rs"),
+            "strings1" => include_str!("solutions/strings1.rs"),
+            "structs1" => include_str!("solutions/structs1.rs"),
+            "collections1" => include_str!("solutions/collections1.rs"),
+            "traits1" => include_str!("solutions/traits1.rs"),
+            "box1" => include_str!("solutions/box1.rs"),
+            _ => panic!("练习 {} 没有参考答案", name),
+        }
+    }
+
+    #[test]
+    fn hidden_tests_pass_against_solutions() {
+        for exercise in EXERCISES {
+            match check_source(exercise, solution(exercise.name)).unwrap() {
+                CheckResult::Passed(_) => {}
+                other => panic!("{} 的参考答案没有通过隐藏测试：{:?}", exercise.name, other),
+            }
+        }
+    }
+
+    #[test]
+    fn unfinished_exercises_fail() {
+        for exercise in EXERCISES {
+            let source = fs::read_to_string(exercise.path()).unwrap();
+            match check_source(exercise, &source).unwrap() {
+                CheckResult::TestFailed(_) => {}
+                other => panic!("未修改的 {} 应当编译通过但测试失败：{:?}", exercise.name, other),
+            }
+        }
+    }
+
+    #[test]
+    fn checks_can_run_concurrently() {
+        let exercise = find("strings1").unwrap();
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4).map(|_| scope.spawn(|| check_source(exercise, solution(exercise.name)))).collect();
+            for handle in handles {
+                assert!(matches!(handle.join().unwrap().unwrap(), CheckResult::Passed(_)));
+            }
+        });
+    }
+}
@@ -0,0 +1,27 @@
+// 参考答案：Box 与递归类型
+
+#[derive(Debug, PartialEq)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+impl List {
+    pub fn from_slice(values: &[i32]) -> List {
+        match values {
+            [] => List::Nil,
+            [first, rest @ ..] => List::Cons(*first, Box::new(List::from_slice(rest))),
+        }
+    }
+
+    pub fn sum(&self) -> i32 {
+        match self {
+            List::Cons(value, next) => value + next.sum(),
+            List::Nil => 0,
+        }
+    }
+}
+
+pub fn leak_string(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
@@ -0,0 +1,18 @@
+// 参考答案：Vec 与 HashMap
+use std::collections::HashMap;
+
+pub fn evens(numbers: &[i32]) -> Vec<i32> {
+    numbers.iter().filter(|n| *n % 2 == 0).copied().collect()
+}
+
+pub fn word_count(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn sort_people(people: &mut Vec<(String, u32)>) {
+    people.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+}
@@ -0,0 +1,13 @@
+// 参考答案：所有权与借用
+
+pub fn append_world(s: &mut String) {
+    s.push_str(", world");
+}
+
+pub fn calculate_length(s: &str) -> usize {
+    s.len()
+}
+
+pub fn duplicate(s: &String) -> String {
+    s.clone()
+}
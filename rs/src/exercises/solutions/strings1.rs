@@ -0,0 +1,16 @@
+// 参考答案：String 与字符串切片
+
+pub fn first_word(s: &str) -> &str {
+    match s.find(' ') {
+        Some(i) => &s[..i],
+        None => s,
+    }
+}
+
+pub fn char_count(s: &str) -> usize {
+    s.chars().count()
+}
+
+pub fn join(a: &str, b: &str) -> String {
+    format!("{}-{}", a, b)
+}
@@ -0,0 +1,34 @@
+// 参考答案：结构体、方法与枚举
+
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rectangle {
+    pub fn square(size: u32) -> Rectangle {
+        Rectangle { width: size, height: size }
+    }
+
+    pub fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+}
+
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+}
+
+pub fn describe(message: &Message) -> String {
+    match message {
+        Message::Quit => "quit".to_string(),
+        Message::Move { x, y } => format!("move to ({}, {})", x, y),
+        Message::Write(text) => text.clone(),
+    }
+}
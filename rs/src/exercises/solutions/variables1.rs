@@ -0,0 +1,15 @@
+// 参考答案：变量绑定、可变性与变量遮蔽
+
+pub fn count_up(n: u32) -> u32 {
+    let mut total = 0;
+    for i in 1..=n {
+        total += i;
+    }
+    total
+}
+
+pub fn parse_and_double(input: &str) -> i32 {
+    let value = input.trim();
+    let value: i32 = value.parse().unwrap();
+    value * 2
+}
@@ -1,3 +1,5 @@
-pub fn run_all() {
-    println!("运行 templates 模块所有示例:");
-}
\ No newline at end of file
+use crate::registry::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.module("generics", "泛型");
+}
@@ -1 +1,60 @@
-pub mod basics;
\ No newline at end of file
+/*!
+Rust 示例代码库
+
+示例按主题分布在各个模块中，并登记到 [`registry`]，`rust_code_examples` 可执行文件只是它的命令行前端。
+*/
+
+// 示例模块
+pub mod advanced;
+pub mod algo;
+pub mod async_await;
+pub mod basics;
+pub mod data_structure;
+pub mod design_pattern;
+pub mod functional;
+pub mod generics;
+pub mod macros;
+pub mod pointers;
+pub mod threadings;
+pub mod traits;
+pub mod unsafe_rs;
+
+// 运行示例的基础设施
+pub mod benchmark;
+pub mod cli;
+pub mod exercises;
+pub mod registry;
+pub mod runner;
+pub mod snapshot;
+pub mod source;
+pub mod utils;
+
+use registry::Registry;
+
+/// 登记了所有顶层模块及其示例的注册表，模块内部的示例由各模块的 `register` 自行登记
+///
+/// # Examples
+///
+/// ```
+/// let registry = rust_code_examples::registry();
+/// let example = registry.find("basics::set_types::vec_test").unwrap();
+/// assert_eq!(example.title(), "Vec");
+/// assert!(registry.modules().iter().any(|m| m.name == "algo"));
+/// ```
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    basics::register(&mut registry);
+    advanced::register(&mut registry);
+    async_await::register(&mut registry);
+    generics::register(&mut registry);
+    traits::register(&mut registry);
+    pointers::register(&mut registry);
+    functional::register(&mut registry);
+    macros::register(&mut registry);
+    threadings::register(&mut registry);
+    unsafe_rs::register(&mut registry);
+    data_structure::register(&mut registry);
+    algo::register(&mut registry);
+    design_pattern::register(&mut registry);
+    registry
+}
@@ -0,0 +1,5 @@
+use crate::registry::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.module("macros", "宏");
+}
@@ -0,0 +1,200 @@
+/*!
+示例注册表
+
+每个示例通过 [`example!`](crate::example) 宏登记自己所在的模块、id、标题和说明，
+各模块的 `register` 函数把本模块的示例交给 [`Registry`]，菜单、列表和运行器都从注册表构建，
+新增示例只需要在所在文件的 `EXAMPLES` 中加一行，不需要修改 `main.rs` 或者 `run_all`。
+*/
+
+use crate::runner::Report;
+
+/// 一个可运行的示例
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    module_path: &'static str,
+    id: &'static str,
+    title: &'static str,
+    description: &'static str,
+    run: fn(),
+    should_panic: bool,
+    file: &'static str,
+    source: &'static str,
+}
+
+impl Example {
+    /// 一般通过 [`example!`](crate::example) 宏创建，`module_path` 直接传入 `module_path!()`
+    pub const fn new(
+        module_path: &'static str,
+        id: &'static str,
+        title: &'static str,
+        description: &'static str,
+        run: fn(),
+    ) -> Example {
+        Example { module_path, id, title, description, run, should_panic: false, file: "", source: "" }
+    }
+
+    /// 附上示例所在文件的路径和内容，用于展示不在 `src` 下的源码
+    pub const fn with_source(self, file: &'static str, source: &'static str) -> Example {
+        Example { file, source, ..self }
+    }
+
+    /// 记录示例所在的文件，通常是调用处的 `file!()`，内容从构建脚本生成的源码表中查找
+    pub const fn with_file(self, file: &'static str) -> Example {
+        Example { file, ..self }
+    }
+
+    /// 标记为预期会 panic，运行器在它 panic 时记为通过
+    pub const fn should_panic(self) -> Example {
+        Example { should_panic: true, ..self }
+    }
+
+    /// 示例所在的模块，不含 crate 名，例如 `basics::set_types`
+    pub fn module(&self) -> &'static str {
+        match self.module_path.split_once("::") {
+            Some((_, rest)) => rest,
+            None => "",
+        }
+    }
+
+    /// 顶层模块名，例如 `basics`
+    pub fn top_module(&self) -> &'static str {
+        self.module().split("::").next().unwrap_or("")
+    }
+
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// 完整路径，例如 `basics::set_types::vec_test`
+    pub fn path(&self) -> String {
+        format!("{}::{}", self.module(), self.id)
+    }
+
+    /// 示例所在的源文件，相对于 crate 根目录
+    pub fn file(&self) -> &'static str {
+        match source_file(self.file) {
+            Some((path, _)) if self.source.is_empty() => path,
+            _ => self.file,
+        }
+    }
+
+    /// 示例所在源文件的全部内容，没有附上源码时为空
+    pub fn file_source(&self) -> &'static str {
+        match source_file(self.file) {
+            Some((_, source)) if self.source.is_empty() => source,
+            _ => self.source,
+        }
+    }
+
+    pub fn expects_panic(&self) -> bool {
+        self.should_panic
+    }
+
+    pub fn run(&self) {
+        (self.run)()
+    }
+}
+
+/// 顶层模块的说明，用于在菜单中展示没有示例的模块
+#[derive(Debug, Clone, Copy)]
+pub struct Module {
+    pub name: &'static str,
+    pub title: &'static str,
+}
+
+/// 注册表，按登记顺序保存模块和示例
+#[derive(Debug, Default)]
+pub struct Registry {
+    modules: Vec<Module>,
+    examples: Vec<&'static Example>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// 登记一个顶层模块
+    pub fn module(&mut self, name: &'static str, title: &'static str) {
+        if !self.modules.iter().any(|m| m.name == name) {
+            self.modules.push(Module { name, title });
+        }
+    }
+
+    /// 登记一组示例，通常是某个文件中的 `EXAMPLES`
+    pub fn register(&mut self, examples: &'static [Example]) {
+        self.examples.extend(examples.iter());
+    }
+
+    pub fn modules(&self) -> &[Module] {
+        &self.modules
+    }
+
+    pub fn examples(&self) -> &[&'static Example] {
+        &self.examples
+    }
+
+    /// 按路径前缀筛选示例，`basics` 与 `basics::set_types` 都可以，按 `::` 分段匹配
+    pub fn in_module(&self, module: &str) -> Vec<&'static Example> {
+        self.examples
+            .iter()
+            .copied()
+            .filter(|e| {
+                let path = e.path();
+                path == module || path.starts_with(&format!("{}::", module))
+            })
+            .collect()
+    }
+
+    /// 按完整路径查找一个示例
+    pub fn find(&self, path: &str) -> Option<&'static Example> {
+        self.examples.iter().copied().find(|e| e.path() == path)
+    }
+
+    /// 依次运行某个模块下的所有示例，单个示例 panic 不会中断后续示例
+    pub fn run_module(&self, module: &str) -> Report {
+        crate::runner::run(&self.in_module(module))
+    }
+}
+
+/// `src` 下所有源文件的 (相对于 crate 根目录的路径, 内容)，由 `build.rs` 生成
+static SOURCES: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/sources.rs"));
+
+/// 按 `file!()` 查找源文件
+///
+/// `file!()` 可能相对于 crate 根目录、workspace 根目录，作为依赖构建时还可能是绝对路径，所以按路径后缀匹配。
+fn source_file(file: &str) -> Option<(&'static str, &'static str)> {
+    if file.is_empty() {
+        return None;
+    }
+    let file = file.replace('\\', "/");
+    SOURCES
+        .iter()
+        .copied()
+        .find(|(path, _)| file == *path || file.strip_suffix(path).is_some_and(|prefix| prefix.ends_with('/')))
+}
+
+/// 生成一个 [`Example`]，模块路径取自调用处的 `module_path!()`，源码取自调用处所在的文件
+///
+/// - `example!(vec_test, "标题", "说明")` 直接登记同名函数
+/// - `example!(doc_test => || { doc_test(1); }, "标题", "说明")` 用于需要参数或有返回值的函数
+/// - 预期会 panic 的示例在后面调用 `.should_panic()`
+#[macro_export]
+macro_rules! example {
+    ($func:ident, $title:expr, $description:expr $(,)?) => {
+        $crate::registry::Example::new(module_path!(), stringify!($func), $title, $description, $func)
+            .with_file(file!())
+    };
+    ($id:ident => $run:expr, $title:expr, $description:expr $(,)?) => {
+        $crate::registry::Example::new(module_path!(), stringify!($id), $title, $description, $run)
+            .with_file(file!())
+    };
+}
@@ -0,0 +1,375 @@
+/*!
+示例运行器
+
+每个示例都在 `catch_unwind` 的边界内运行，一个示例 panic 不会中断后面的示例。
+panic 的信息和位置由自定义的 panic hook 记录下来，全部运行结束后打印通过/失败汇总表。
+标记为 `should_panic` 的示例发生 panic 时算作通过，没有 panic 反而算失败。
+
+需要捕获示例的标准输出时（快照、源码展示、运行报告），用 [`run_in_child`] 通过 `exec` 子命令在子进程中运行，
+子进程在标准错误的末尾写出运行结果，由父进程解析。
+*/
+
+pub mod report;
+
+use std::cell::{Cell, RefCell};
+use std::io;
+use std::panic::{self, PanicHookInfo};
+use std::process::Command;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use console::Style;
+
+use crate::registry::Example;
+
+/// 一次 panic 的信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanicRecord {
+    pub message: String,
+    /// `文件:行:列`，取不到位置时为 None
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    /// 正常返回
+    Passed,
+    /// 标记为 should_panic，并且确实 panic 了
+    ExpectedPanic(PanicRecord),
+    /// 意外的 panic
+    Panicked(PanicRecord),
+    /// 标记为 should_panic，却正常返回了
+    DidNotPanic,
+    /// 按要求跳过，没有运行
+    Skipped,
+}
+
+impl Status {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Status::Passed | Status::ExpectedPanic(_))
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Passed => "passed",
+            Status::ExpectedPanic(_) => "expected-panic",
+            Status::Panicked(_) => "panicked",
+            Status::DidNotPanic => "did-not-panic",
+            Status::Skipped => "skipped",
+        }
+    }
+
+    fn from_label(label: &str, record: Option<PanicRecord>) -> Option<Status> {
+        Some(match (label, record) {
+            ("passed", _) => Status::Passed,
+            ("did-not-panic", _) => Status::DidNotPanic,
+            ("skipped", _) => Status::Skipped,
+            ("expected-panic", Some(record)) => Status::ExpectedPanic(record),
+            ("panicked", Some(record)) => Status::Panicked(record),
+            _ => return None,
+        })
+    }
+
+    pub fn panic(&self) -> Option<&PanicRecord> {
+        match self {
+            Status::ExpectedPanic(record) | Status::Panicked(record) => Some(record),
+            _ => None,
+        }
+    }
+}
+
+/// 单个示例的运行结果
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub example: &'static Example,
+    pub status: Status,
+    pub duration: Duration,
+    /// 在子进程中运行时捕获到的标准输出
+    pub stdout: Option<String>,
+}
+
+/// 一次运行的全部结果
+#[derive(Debug, Default)]
+pub struct Report {
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Report {
+    pub fn passed(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.status.is_success()).count()
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.status == Status::Skipped).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.outcomes.len() - self.passed() - self.skipped()
+    }
+
+    /// 所有示例的运行时间之和
+    pub fn duration(&self) -> Duration {
+        self.outcomes.iter().map(|o| o.duration).sum()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failed() == 0
+    }
+
+    /// 打印汇总表
+    pub fn print_summary(&self) {
+        let ok = Style::new().green();
+        let bad = Style::new().red().bold();
+        let skip = Style::new().yellow();
+        let width = self.outcomes.iter().map(|o| o.example.path().len()).max().unwrap_or(0);
+
+        println!("\n==== ==== ==== ==== 运行结果 ==== ==== ==== ====");
+        for outcome in &self.outcomes {
+            let style = match outcome.status {
+                Status::Skipped => &skip,
+                ref status if status.is_success() => &ok,
+                _ => &bad,
+            };
+            let detail = match &outcome.status {
+                Status::DidNotPanic => "期望 panic，但正常返回".to_string(),
+                status => status.panic().map(describe).unwrap_or_default(),
+            };
+            println!(
+                "{:<16} {:<width$} {:>8.1?}  {}",
+                style.apply_to(outcome.status.label()),
+                outcome.example.path(),
+                outcome.duration,
+                detail,
+                width = width,
+            );
+        }
+        println!(
+            "共 {} 个示例：{} 通过，{} 失败，{} 跳过",
+            self.outcomes.len(),
+            ok.apply_to(self.passed()),
+            if self.is_success() { ok.apply_to(self.failed()) } else { bad.apply_to(self.failed()) },
+            skip.apply_to(self.skipped()),
+        );
+    }
+}
+
+fn describe(record: &PanicRecord) -> String {
+    match &record.location {
+        Some(location) => format!("{} ({})", record.message, location),
+        None => record.message.clone(),
+    }
+}
+
+thread_local! {
+    // 当前线程是否处于运行器的 panic 边界内
+    static IN_BOUNDARY: Cell<bool> = const { Cell::new(false) };
+    // 边界内最近一次 panic 的信息
+    static LAST_PANIC: RefCell<Option<PanicRecord>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+// 边界内的 panic 只记录不打印，边界外的 panic 交给原来的 hook
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if IN_BOUNDARY.with(Cell::get) {
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(record(info)));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+fn record(info: &PanicHookInfo) -> PanicRecord {
+    let payload = info.payload();
+    let message = if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    };
+    PanicRecord {
+        message,
+        location: info.location().map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+    }
+}
+
+/// 在 panic 边界内运行一个示例
+pub fn run_isolated(example: &'static Example) -> Outcome {
+    install_hook();
+    IN_BOUNDARY.with(|b| b.set(true));
+    LAST_PANIC.with(|last| last.borrow_mut().take());
+
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| example.run());
+    let duration = start.elapsed();
+
+    IN_BOUNDARY.with(|b| b.set(false));
+    let panicked = LAST_PANIC.with(|last| last.borrow_mut().take());
+
+    let status = match (result, example.expects_panic()) {
+        (Ok(()), false) => Status::Passed,
+        (Ok(()), true) => Status::DidNotPanic,
+        (Err(_), expected) => {
+            let record = panicked.unwrap_or(PanicRecord { message: "unknown panic".to_string(), location: None });
+            if expected { Status::ExpectedPanic(record) } else { Status::Panicked(record) }
+        }
+    };
+    Outcome { example, status, duration, stdout: None }
+}
+
+/// 依次运行一组示例，每个示例前打印标题
+pub fn run(examples: &[&'static Example]) -> Report {
+    run_with(examples, &[], false)
+}
+
+/// 运行一组示例，`skip` 中列出的目标（完整路径或模块前缀）记为跳过；`capture` 为真时在子进程中运行并捕获输出
+pub fn run_with(examples: &[&'static Example], skip: &[String], capture: bool) -> Report {
+    let header = Style::new().bold().green();
+    let mut report = Report::default();
+    for &example in examples {
+        let path = example.path();
+        if skip.iter().any(|s| path == *s || path.starts_with(&format!("{}::", s))) {
+            report.outcomes.push(Outcome { example, status: Status::Skipped, duration: Duration::ZERO, stdout: None });
+            continue;
+        }
+
+        println!("{}", header.apply_to(format!("==== {} {} ====", path, example.title())));
+        if !capture {
+            report.outcomes.push(run_isolated(example));
+            continue;
+        }
+        let outcome = run_in_child(example).unwrap_or_else(|e| Outcome {
+            example,
+            status: Status::Panicked(PanicRecord { message: format!("无法启动子进程: {}", e), location: None }),
+            duration: Duration::ZERO,
+            stdout: None,
+        });
+        print!("{}", outcome.stdout.as_deref().unwrap_or_default());
+        report.outcomes.push(outcome);
+    }
+    report
+}
+
+// 子进程写在标准错误末尾的运行结果，示例自己也可能写标准错误（例如 dbg!），所以以最后一个标记为准
+const CHILD_RESULT_MARKER: &str = "==== exec-result ====";
+
+/// 供 `exec` 子命令使用：把运行结果写到标准错误，格式为标记行之后的 `键: 值`，message 放在最后并可以跨行
+pub fn print_child_result(outcome: &Outcome) {
+    eprintln!("\n{}", CHILD_RESULT_MARKER);
+    eprintln!("status: {}", outcome.status.label());
+    eprintln!("duration_ns: {}", outcome.duration.as_nanos());
+    if let Some(record) = outcome.status.panic() {
+        if let Some(location) = &record.location {
+            eprintln!("location: {}", location);
+        }
+        eprintln!("message: {}", record.message);
+    }
+}
+
+fn parse_child_result(stderr: &str) -> Option<(Status, Duration)> {
+    let (_, block) = stderr.rsplit_once(CHILD_RESULT_MARKER)?;
+    let block = block.trim_start_matches(['\r', '\n']);
+    let (head, message) = match block.split_once("message: ") {
+        Some((head, message)) => (head, Some(message.trim_end().to_string())),
+        None => (block, None),
+    };
+
+    let mut label = None;
+    let mut duration = Duration::ZERO;
+    let mut location = None;
+    for line in head.lines() {
+        match line.split_once(": ") {
+            Some(("status", value)) => label = Some(value.trim()),
+            Some(("duration_ns", value)) => duration = Duration::from_nanos(value.trim().parse().unwrap_or(0)),
+            Some(("location", value)) => location = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+    let record = message.map(|message| PanicRecord { message, location });
+    Some((Status::from_label(label?, record)?, duration))
+}
+
+/// 通过 `exec` 子命令在子进程中运行示例，捕获标准输出，必须在示例程序本身中调用
+pub fn run_in_child(example: &'static Example) -> io::Result<Outcome> {
+    let start = Instant::now();
+    let output = Command::new(std::env::current_exe()?).arg("exec").arg(example.path()).output()?;
+    let elapsed = start.elapsed();
+
+    let stdout = String::from_utf8_lossy(&output.stdout).replace("\r\n", "\n");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let (status, duration) = parse_child_result(&stderr).unwrap_or_else(|| {
+        // 子进程没有写出结果，例如栈溢出被系统终止
+        let record = PanicRecord { message: format!("子进程异常退出: {}", output.status), location: None };
+        (Status::Panicked(record), elapsed)
+    });
+    Ok(Outcome { example, status, duration, stdout: Some(stdout) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    fn fine() {}
+
+    fn boom() {
+        panic!("第 {} 个示例失败", 2);
+    }
+
+    static EXAMPLES: &[Example] = &[
+        example!(fine, "通过", "正常返回"),
+        example!(boom, "失败", "直接 panic"),
+        example!(expected => boom, "预期的 panic", "标记为 should_panic").should_panic(),
+        example!(quiet => fine, "没有 panic", "标记为 should_panic 却正常返回").should_panic(),
+    ];
+
+    #[test]
+    fn isolates_panics() {
+        let statuses: Vec<Status> = EXAMPLES.iter().map(|e| run_isolated(e).status).collect();
+        assert_eq!(statuses[0], Status::Passed);
+        assert_eq!(statuses[3], Status::DidNotPanic);
+        let record = statuses[1].panic().unwrap();
+        assert_eq!(record.message, "第 2 个示例失败");
+        assert!(record.location.as_deref().is_some_and(|l| l.contains("runner")), "{:?}", record.location);
+        assert!(matches!(&statuses[2], Status::ExpectedPanic(r) if r == record));
+        assert_eq!(statuses.iter().map(Status::is_success).collect::<Vec<_>>(), [true, false, true, false]);
+    }
+
+    #[test]
+    fn skips_by_path_prefix() {
+        let examples: Vec<&'static Example> = EXAMPLES.iter().collect();
+        let prefix = EXAMPLES[0].module().to_string();
+        let report = run_with(&examples, &["runner::tests::boom".to_string()], false);
+        assert_eq!((report.passed(), report.failed(), report.skipped()), (2, 1, 1));
+        assert!(!report.is_success());
+
+        let report = run_with(&examples, &[prefix], false);
+        assert_eq!((report.passed(), report.failed(), report.skipped()), (0, 0, 4));
+        assert!(report.is_success());
+        // 只按 :: 分段匹配前缀
+        let report = run_with(&examples, &["runner::tests::fi".to_string()], false);
+        assert_eq!(report.skipped(), 0);
+    }
+
+    #[test]
+    fn parses_child_result() {
+        // 示例自己写出的标记不影响解析，以最后一个为准
+        let stderr = format!(
+            "{m}\nstatus: passed\n示例的输出\n\n{m}\nstatus: panicked\nduration_ns: 1500\nlocation: src/a.rs:1:2\nmessage: 第一行\n第二行\n",
+            m = CHILD_RESULT_MARKER
+        );
+        let (status, duration) = parse_child_result(&stderr).unwrap();
+        let record = PanicRecord { message: "第一行\n第二行".to_string(), location: Some("src/a.rs:1:2".to_string()) };
+        assert_eq!((status, duration), (Status::Panicked(record), Duration::from_nanos(1500)));
+
+        let stderr = format!("{}\nstatus: did-not-panic\nduration_ns: 7\n", CHILD_RESULT_MARKER);
+        assert_eq!(parse_child_result(&stderr), Some((Status::DidNotPanic, Duration::from_nanos(7))));
+        assert_eq!(parse_child_result("thread 'main' has overflowed its stack"), None);
+        assert_eq!(parse_child_result(&format!("{}\nstatus: panicked\n", CHILD_RESULT_MARKER)), None);
+    }
+}
@@ -0,0 +1,187 @@
+/*!
+机器可读的运行报告
+
+- [`to_json`]：汇总数字加上每个示例的模块、名称、耗时、状态、捕获的输出和 panic 信息
+- [`to_junit`]：JUnit XML，每个模块一个 `<testsuite>`，CI 可以直接展示
+
+只在子进程中运行时才有捕获的输出，进程内运行时 `stdout` 为 `null`，JUnit 中省略 `<system-out>`。
+
+```
+use rust_code_examples::runner::{self, report};
+
+let registry = rust_code_examples::registry();
+let example = registry.find("basics::set_types::vec_test").unwrap();
+let report = runner::run_with(&[example], &["basics::set_types".to_string()], false);
+
+let json = report::to_json(&report);
+assert!(json.contains(r#""module": "basics::set_types""#));
+assert!(json.contains(r#""status": "skipped""#));
+
+let xml = report::to_junit(&report);
+assert!(xml.contains(r#"<testcase classname="basics::set_types" name="vec_test""#));
+assert!(xml.contains("<skipped/>"));
+```
+*/
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use super::{Outcome, Report, Status};
+
+/// 转义为 JSON 字符串（带引号）
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_option(text: Option<&str>) -> String {
+    text.map_or_else(|| "null".to_string(), json_string)
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn json_outcome(outcome: &Outcome) -> String {
+    let panic = match outcome.status.panic() {
+        Some(record) => format!(
+            "{{\"message\": {}, \"location\": {}}}",
+            json_string(&record.message),
+            json_option(record.location.as_deref())
+        ),
+        None => "null".to_string(),
+    };
+    format!(
+        "    {{\"module\": {}, \"name\": {}, \"title\": {}, \"status\": {}, \"duration_ms\": {:.3}, \"stdout\": {}, \"panic\": {}}}",
+        json_string(outcome.example.module()),
+        json_string(outcome.example.id()),
+        json_string(outcome.example.title()),
+        json_string(outcome.status.label()),
+        millis(outcome.duration),
+        json_option(outcome.stdout.as_deref()),
+        panic
+    )
+}
+
+/// 生成 JSON 报告
+pub fn to_json(report: &Report) -> String {
+    let examples: Vec<String> = report.outcomes.iter().map(json_outcome).collect();
+    format!(
+        "{{\n  \"total\": {},\n  \"passed\": {},\n  \"failed\": {},\n  \"skipped\": {},\n  \"duration_ms\": {:.3},\n  \"examples\": [\n{}\n  ]\n}}\n",
+        report.outcomes.len(),
+        report.passed(),
+        report.failed(),
+        report.skipped(),
+        millis(report.duration()),
+        examples.join(",\n")
+    )
+}
+
+/// 转义为 XML 文本或属性值，XML 1.0 不允许的控制字符直接丢弃
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' | '\r' | '\t' => out.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn junit_testcase(out: &mut String, outcome: &Outcome) {
+    let _ = write!(
+        out,
+        "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.6}\"",
+        xml_escape(outcome.example.module()),
+        xml_escape(outcome.example.id()),
+        outcome.duration.as_secs_f64()
+    );
+
+    let mut body = String::new();
+    match &outcome.status {
+        Status::Passed | Status::ExpectedPanic(_) => {}
+        Status::Skipped => body.push_str("      <skipped/>\n"),
+        Status::DidNotPanic => body.push_str("      <failure type=\"did-not-panic\" message=\"预期 panic，但正常返回了\"/>\n"),
+        Status::Panicked(record) => {
+            let location = record.location.as_deref().unwrap_or("未知位置");
+            let _ = writeln!(
+                body,
+                "      <failure type=\"panicked\" message=\"{}\">{}\n{}</failure>",
+                xml_escape(&record.message),
+                xml_escape(location),
+                xml_escape(&record.message)
+            );
+        }
+    }
+    if let Some(stdout) = &outcome.stdout {
+        let _ = writeln!(body, "      <system-out>{}</system-out>", xml_escape(stdout));
+    }
+
+    if body.is_empty() {
+        out.push_str("/>\n");
+    } else {
+        let _ = write!(out, ">\n{}    </testcase>\n", body);
+    }
+}
+
+/// 生成 JUnit XML 报告，按模块分组，模块的顺序与示例的运行顺序一致
+pub fn to_junit(report: &Report) -> String {
+    let mut modules: Vec<(&str, Vec<&Outcome>)> = Vec::new();
+    for outcome in &report.outcomes {
+        let module = outcome.example.module();
+        match modules.iter_mut().find(|(name, _)| *name == module) {
+            Some((_, outcomes)) => outcomes.push(outcome),
+            None => modules.push((module, vec![outcome])),
+        }
+    }
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<testsuites name=\"rust_code_examples\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+        report.outcomes.len(),
+        report.failed(),
+        report.skipped(),
+        report.duration().as_secs_f64()
+    );
+    for (module, outcomes) in modules {
+        let failures = outcomes.iter().filter(|o| !o.status.is_success() && o.status != Status::Skipped).count();
+        let skipped = outcomes.iter().filter(|o| o.status == Status::Skipped).count();
+        let time: Duration = outcomes.iter().map(|o| o.duration).sum();
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+            xml_escape(module),
+            outcomes.len(),
+            failures,
+            skipped,
+            time.as_secs_f64()
+        );
+        for outcome in outcomes {
+            junit_testcase(&mut out, outcome);
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>\n");
+    out
+}
@@ -0,0 +1,207 @@
+/*!
+示例输出的快照（golden output）
+
+每个示例的标准输出保存在 `snapshots/<模块路径>/<示例>.txt`，`verify` 会重新运行示例并与快照逐行比较，
+`verify --bless` 用当前输出覆盖快照。
+
+`println!` 直接写进程的标准输出，在进程内无法截获，
+所以示例输出由 [`runner::run_in_child`](crate::runner::run_in_child) 在子进程中单独运行示例得到。
+*/
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use console::Style;
+
+use crate::registry::Example;
+
+/// 快照目录，位于 crate 根目录下
+pub fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots")
+}
+
+/// 示例对应的快照文件，`basics::set_types::vec_test` 对应 `snapshots/basics/set_types/vec_test.txt`
+pub fn snapshot_path(example: &Example) -> PathBuf {
+    let mut path = snapshot_dir();
+    path.extend(example.module().split("::"));
+    path.push(format!("{}.txt", example.id()));
+    path
+}
+
+// 统一换行符，避免 Windows 上检出的快照带 \r\n
+fn normalize(text: &str) -> String {
+    text.replace("\r\n", "\n")
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Matched,
+    Missing,
+    Changed(String),
+    Blessed,
+}
+
+/// 比较（或在 `bless` 时更新）一个示例的快照，`Changed` 中带有可读的差异
+pub fn verify(example: &Example, actual: &str, bless: bool) -> io::Result<Verdict> {
+    let path = snapshot_path(example);
+    let expected = match fs::read_to_string(&path) {
+        Ok(text) => Some(normalize(&text)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+
+    if expected.as_deref() == Some(actual) {
+        return Ok(Verdict::Matched);
+    }
+    if bless {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, actual)?;
+        return Ok(Verdict::Blessed);
+    }
+    Ok(match expected {
+        None => Verdict::Missing,
+        Some(expected) => Verdict::Changed(diff(&expected, actual)),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+// 基于最长公共子序列的逐行差异，示例输出都很短，O(n*m) 足够
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Line<'a>> {
+    let (n, m) = (old.len(), new.len());
+    // lcs[i][j] 为 old[i..] 与 new[j..] 的最长公共子序列长度
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::with_capacity(n.max(m));
+    while i < n && j < m {
+        if old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|l| Line::Removed(l)));
+    lines.extend(new[j..].iter().map(|l| Line::Added(l)));
+    lines
+}
+
+/// 生成带行号和上下文的差异文本，`-` 为快照中的行，`+` 为当前输出的行
+pub fn diff(expected: &str, actual: &str) -> String {
+    const CONTEXT: usize = 2;
+
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+    let lines = diff_lines(&old, &new);
+
+    // 只保留改动行及其前后 CONTEXT 行
+    let changed: Vec<usize> = (0..lines.len()).filter(|&i| !matches!(lines[i], Line::Same(_))).collect();
+    let keep = |i: usize| changed.iter().any(|&c| c.abs_diff(i) <= CONTEXT);
+
+    let removed = Style::new().red();
+    let added = Style::new().green();
+    let mut out = String::new();
+    let (mut old_no, mut new_no) = (0, 0);
+    let mut skipped = false;
+    for (i, line) in lines.iter().enumerate() {
+        match line {
+            Line::Same(_) => {
+                old_no += 1;
+                new_no += 1;
+            }
+            Line::Removed(_) => old_no += 1,
+            Line::Added(_) => new_no += 1,
+        }
+        if !keep(i) {
+            skipped = true;
+            continue;
+        }
+        if skipped {
+            out.push_str("   ...\n");
+            skipped = false;
+        }
+        let text = match line {
+            Line::Same(text) => format!("  {:>4} {:>4}  {}", old_no, new_no, text),
+            Line::Removed(text) => removed.apply_to(format!("- {:>4}       {}", old_no, text)).to_string(),
+            Line::Added(text) => added.apply_to(format!("+      {:>4}  {}", new_no, text)).to_string(),
+        };
+        out.push_str(&text);
+        out.push('\n');
+    }
+    if expected.ends_with('\n') != actual.ends_with('\n') {
+        out.push_str("   (结尾换行不同)\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 终端中运行测试时 diff 带有颜色，比较之前去掉
+    fn plain_diff(expected: &str, actual: &str) -> String {
+        console::strip_ansi_codes(&diff(expected, actual)).into_owned()
+    }
+
+    #[test]
+    fn diff_lines_follows_longest_common_subsequence() {
+        use Line::*;
+        assert_eq!(
+            diff_lines(&["a", "b", "c", "d"], &["a", "c", "x", "d", "e"]),
+            [Same("a"), Removed("b"), Same("c"), Added("x"), Same("d"), Added("e")]
+        );
+        assert_eq!(diff_lines(&[], &["a"]), [Added("a")]);
+        assert_eq!(diff_lines(&["a"], &[]), [Removed("a")]);
+        assert_eq!(diff_lines(&["a", "b"], &["b", "a"]), [Removed("a"), Same("b"), Added("a")]);
+    }
+
+    #[test]
+    fn diff_shows_line_numbers_and_context() {
+        let expected: String = (1..=12).map(|i| format!("{}\n", i)).collect();
+        let actual = expected.replacen("3\n", "三\n", 1).replace("12\n", "");
+        assert_eq!(
+            plain_diff(&expected, &actual),
+            "     1    1  1
+     2    2  2
+-    3       3
++         3  三
+     4    4  4
+     5    5  5
+   ...
+    10   10  10
+    11   11  11
+-   12       12
+"
+        );
+        assert_eq!(plain_diff("a\nb\n", "b\n"), "-    1       a\n     2    1  b\n");
+    }
+
+    #[test]
+    fn diff_reports_trailing_newline() {
+        assert_eq!(plain_diff("a\n", "a\n"), "");
+        assert_eq!(plain_diff("a\n", "a"), "   (结尾换行不同)\n");
+    }
+}
@@ -0,0 +1,149 @@
+// 简单的 Rust 语法高亮：只区分注释、字符串、字符、生命周期、数字、关键字、类型和宏，不做完整的词法分析
+use console::Style;
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+];
+
+struct Theme {
+    comment: Style,
+    string: Style,
+    lifetime: Style,
+    number: Style,
+    keyword: Style,
+    type_name: Style,
+    macro_name: Style,
+}
+
+impl Theme {
+    fn new() -> Theme {
+        Theme {
+            comment: Style::new().green(),
+            string: Style::new().yellow(),
+            lifetime: Style::new().magenta(),
+            number: Style::new().magenta(),
+            keyword: Style::new().blue().bold(),
+            type_name: Style::new().cyan(),
+            macro_name: Style::new().cyan().bold(),
+        }
+    }
+}
+
+// 跨行的字符串或注释按行分别着色，按行切分后每一行的颜色仍然完整
+fn push_styled(out: &mut String, style: &Style, text: &str) {
+    for (i, part) in text.split('\n').enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        if !part.is_empty() {
+            out.push_str(&style.apply_to(part).to_string());
+        }
+    }
+}
+
+/// `start` 处是 `"`，返回字符串结束后的位置
+pub(super) fn skip_string(source: &str, start: usize) -> usize {
+    let bytes = source.as_bytes();
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// `start` 处是 `r`，返回原始字符串 `r#"..."#` 结束后的位置；不是原始字符串（例如 `r#ident`）时只跳过 `r`
+pub(super) fn skip_raw_string(source: &str, start: usize) -> usize {
+    let rest = &source[start + 1..];
+    let hashes = rest.len() - rest.trim_start_matches('#').len();
+    if !rest[hashes..].starts_with('"') {
+        return start + 1;
+    }
+    let closing = format!("\"{}", "#".repeat(hashes));
+    let body = start + 1 + hashes + 1;
+    source[body..].find(&closing).map_or(source.len(), |end| body + end + closing.len())
+}
+
+/// `start` 处是 `'`，字符字面量返回它结束后的位置，生命周期只跳过 `'`
+pub(super) fn skip_char_or_lifetime(source: &str, start: usize) -> usize {
+    let rest = &source[start + 1..];
+    if rest.starts_with('\\') {
+        return rest[2.min(rest.len())..].find('\'').map_or(source.len(), |end| start + 1 + 2 + end + 1);
+    }
+    match rest.chars().next() {
+        Some(c) if rest[c.len_utf8()..].starts_with('\'') => start + 1 + c.len_utf8() + 1,
+        _ => start + 1,
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// 返回带 ANSI 颜色的源码，终端不支持颜色时 console 会输出原文
+pub fn highlight(source: &str) -> String {
+    let theme = Theme::new();
+    let mut out = String::with_capacity(source.len() * 2);
+    let mut i = 0;
+    while i < source.len() {
+        let rest = &source[i..];
+        let c = rest.chars().next().unwrap();
+
+        let (end, style) = if rest.starts_with("//") {
+            (i + rest.find('\n').unwrap_or(rest.len()), Some(&theme.comment))
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            (body.find("*/").map_or(source.len(), |e| i + 2 + e + 2), Some(&theme.comment))
+        } else if c == '"' {
+            (skip_string(source, i), Some(&theme.string))
+        } else if (rest.starts_with("r\"") || rest.starts_with("r#")) && skip_raw_string(source, i) > i + 1 {
+            (skip_raw_string(source, i), Some(&theme.string))
+        } else if rest.starts_with("b\"") {
+            (skip_string(source, i + 1), Some(&theme.string))
+        } else if c == '\'' {
+            let end = skip_char_or_lifetime(source, i);
+            if end > i + 1 {
+                (end, Some(&theme.string))
+            } else {
+                let name = rest[1..].find(|c: char| !is_ident_char(c)).map_or(rest.len(), |e| e + 1);
+                (i + name, Some(&theme.lifetime))
+            }
+        } else if c.is_ascii_digit() {
+            let len = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.')).unwrap_or(rest.len());
+            // `0..10` 中的 `..` 不属于数字
+            let len = rest[..len].find("..").unwrap_or(len);
+            (i + len, Some(&theme.number))
+        } else if is_ident_start(c) {
+            let len = rest.find(|c: char| !is_ident_char(c)).unwrap_or(rest.len());
+            let word = &rest[..len];
+            let style = if rest[len..].starts_with('!') && !rest[len..].starts_with("!=") {
+                Some(&theme.macro_name)
+            } else if KEYWORDS.contains(&word) {
+                Some(&theme.keyword)
+            } else if word.starts_with(|c: char| c.is_ascii_uppercase()) {
+                Some(&theme.type_name)
+            } else {
+                None
+            };
+            let len = if style.is_some_and(|s| std::ptr::eq(s, &theme.macro_name)) { len + 1 } else { len };
+            (i + len, style)
+        } else {
+            (i + c.len_utf8(), None)
+        };
+
+        match style {
+            Some(style) => push_styled(&mut out, style, &source[i..end]),
+            None => out.push_str(&source[i..end]),
+        }
+        i = end;
+    }
+    out
+}
@@ -0,0 +1,219 @@
+/*!
+展示示例的源码
+
+示例的源文件在编译期由 `example!` 宏嵌入，这里从中找出示例函数（连同它前面的注释和属性），
+高亮后与示例的输出一起展示，支持“源码在上、输出在下”和左右并排两种布局，较长的内容可以分页。
+*/
+
+mod highlight;
+
+pub use highlight::highlight;
+
+use std::io::{self, Write};
+
+use console::{measure_text_width, pad_str, truncate_str, Alignment, Term};
+
+use crate::registry::Example;
+
+/// 一段源码及其在文件中的起始行号（从 1 开始）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snippet<'a> {
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+/// 在源码中找到名为 `name` 的函数，包括紧挨在它前面的注释和属性
+///
+/// # Examples
+///
+/// ```
+/// use rust_code_examples::source::extract_fn;
+///
+/// let source = "fn a() {}\n\n/// 说明\npub fn b() {\n    println!(\"{{}\", '}');\n}\nfn c() {}\n";
+/// let snippet = extract_fn(source, "b").unwrap();
+/// assert_eq!(snippet.first_line, 3);
+/// assert_eq!(snippet.text, "/// 说明\npub fn b() {\n    println!(\"{{}\", '}');\n}");
+/// ```
+pub fn extract_fn<'a>(source: &'a str, name: &str) -> Option<Snippet<'a>> {
+    let needle = format!("fn {}", name);
+    let fn_start = source.match_indices(&needle).map(|(i, _)| i).find(|&i| {
+        let before_ok = i == 0 || !is_ident_char(source[..i].chars().next_back().unwrap());
+        let after = source[i + needle.len()..].trim_start();
+        before_ok && (after.starts_with('(') || after.starts_with('<'))
+    })?;
+
+    let body_start = fn_start + source[fn_start..].find('{')?;
+    let body_end = matching_brace(source, body_start)?;
+
+    // 向上包含紧挨着的注释和属性
+    let mut start = source[..fn_start].rfind('\n').map_or(0, |i| i + 1);
+    while start > 0 {
+        let prev_start = source[..start - 1].rfind('\n').map_or(0, |i| i + 1);
+        let prev = source[prev_start..start - 1].trim();
+        if prev.starts_with("//") || prev.starts_with("#[") {
+            start = prev_start;
+        } else {
+            break;
+        }
+    }
+
+    Some(Snippet {
+        first_line: source[..start].matches('\n').count() + 1,
+        text: &source[start..=body_end],
+    })
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// 返回与 `open` 处的 `{` 匹配的 `}` 的位置，跳过字符串、字符字面量和注释中的括号
+fn matching_brace(source: &str, open: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i += source[i..].find('\n').unwrap_or(source.len() - i);
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += source[i + 2..].find("*/").map_or(source.len() - i, |end| end + 4);
+                continue;
+            }
+            b'r' if matches!(bytes.get(i + 1), Some(b'"' | b'#')) && (i == 0 || !is_ident_char(bytes[i - 1] as char)) => {
+                i = highlight::skip_raw_string(source, i);
+                continue;
+            }
+            b'"' => {
+                i = highlight::skip_string(source, i);
+                continue;
+            }
+            b'\'' => {
+                i = highlight::skip_char_or_lifetime(source, i);
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// 展示布局
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Layout {
+    /// 源码在上，输出在下
+    Stacked,
+    /// 源码在左，输出在右
+    SideBySide,
+}
+
+impl Layout {
+    pub fn parse(name: &str) -> Option<Layout> {
+        match name {
+            "stacked" => Some(Layout::Stacked),
+            "side" | "side-by-side" => Some(Layout::SideBySide),
+            _ => None,
+        }
+    }
+}
+
+/// 示例函数的源码，带行号并高亮，源文件中没有同名函数时展示整个文件
+pub fn source_lines(example: &Example) -> Vec<String> {
+    if example.file_source().is_empty() {
+        return vec![format!("// {} 没有附带源码", example.path())];
+    }
+    let snippet = extract_fn(example.file_source(), example.id())
+        .unwrap_or(Snippet { first_line: 1, text: example.file_source().trim_end() });
+    let gutter = console::Style::new().dim();
+    let mut lines = vec![gutter.apply_to(format!("// {}:{}", example.file(), snippet.first_line)).to_string()];
+    lines.extend(
+        highlight(snippet.text)
+            .lines()
+            .enumerate()
+            .map(|(i, line)| format!("{} {}", gutter.apply_to(format!("{:>4}", snippet.first_line + i)), line)),
+    );
+    lines
+}
+
+/// 按布局排版源码和输出，返回要打印的行
+pub fn render(example: &Example, output: &str, layout: Layout, width: usize) -> Vec<String> {
+    let source = source_lines(example);
+    let title = console::Style::new().bold().cyan();
+    match layout {
+        Layout::Stacked => {
+            let mut lines = vec![title.apply_to(format!("==== {} 源码 ====", example.path())).to_string()];
+            lines.extend(source);
+            lines.push(title.apply_to("==== 输出 ====").to_string());
+            lines.extend(output.lines().map(str::to_string));
+            lines
+        }
+        Layout::SideBySide => {
+            let left_width = (width.saturating_sub(3) / 2).max(20);
+            let right_width = width.saturating_sub(left_width + 3).max(20);
+            let output: Vec<&str> = output.lines().collect();
+            let mut lines = vec![format!(
+                "{} │ {}",
+                pad_str(&title.apply_to("源码").to_string(), left_width, Alignment::Left, None),
+                title.apply_to("输出")
+            )];
+            for i in 0..source.len().max(output.len()) {
+                let left = source.get(i).map_or("", String::as_str);
+                let right = output.get(i).copied().unwrap_or("");
+                let left = truncate_str(left, left_width, "…");
+                lines.push(format!(
+                    "{}{} │ {}",
+                    left,
+                    " ".repeat(left_width.saturating_sub(measure_text_width(&left))),
+                    truncate_str(right, right_width, "…")
+                ));
+            }
+            lines
+        }
+    }
+}
+
+/// 打印若干行，终端中每满一屏等待按键，按 q 结束
+///
+/// 写入标准输出失败时返回错误，包括输出接在管道后面、读的一端已经关闭时的 `BrokenPipe`。
+pub fn page(lines: &[String], paging: bool) -> io::Result<()> {
+    let term = Term::stdout();
+    let height = if paging && term.is_term() { term.size().0 as usize } else { 0 };
+    let mut out = io::stdout().lock();
+    if height < 3 {
+        return write_lines(&mut out, lines);
+    }
+
+    let hint = console::Style::new().reverse();
+    for chunk in lines.chunks(height - 1) {
+        write_lines(&mut out, chunk)?;
+        if chunk.as_ptr_range().end == lines.as_ptr_range().end {
+            break;
+        }
+        write!(out, "{}", hint.apply_to("-- 按任意键继续，q 退出 --"))?;
+        out.flush()?;
+        let key = term.read_char().unwrap_or('q');
+        let _ = term.clear_line();
+        if key == 'q' {
+            break;
+        }
+    }
+    Ok(())
+}
+
+// 逐行写入，遇到错误立即返回
+fn write_lines(out: &mut impl Write, lines: &[String]) -> io::Result<()> {
+    for line in lines {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,8 @@
+use crate::registry::Registry;
+
+pub mod thread_pool;
+
+pub fn register(registry: &mut Registry) {
+    registry.module("threadings", "多线程");
+    registry.register(thread_pool::EXAMPLES);
+}
@@ -0,0 +1,106 @@
+//! 取回任务结果的句柄
+
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+
+/// [`ThreadPool::spawn`](super::ThreadPool::spawn) 返回的句柄，`join` 等待任务结束并取回结果
+///
+/// 丢掉句柄不影响任务的运行，只是不再关心结果。
+pub struct JoinHandle<T> {
+    result: mpsc::Receiver<thread::Result<T>>,
+}
+
+/// 没有取到结果的原因
+pub enum JoinError {
+    /// 任务 panic 了，带着 panic 的载荷
+    Panicked(Box<dyn Any + Send + 'static>),
+    /// 线程池以 [`Shutdown::Cancel`](super::Shutdown::Cancel) 关闭，任务还没有开始就被丢弃了
+    Cancelled,
+}
+
+impl<T> JoinHandle<T> {
+    /// 阻塞直到任务结束
+    ///
+    /// 在线程池的任务中等待同一个线程池中的其他任务时，所有工作线程都在等待就会死锁。
+    pub fn join(self) -> Result<T, JoinError> {
+        match self.result.recv() {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(payload)) => Err(JoinError::Panicked(payload)),
+            // 任务没有运行就被 drop 了，发送端随之关闭
+            Err(mpsc::RecvError) => Err(JoinError::Cancelled),
+        }
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("JoinHandle { .. }")
+    }
+}
+
+impl JoinError {
+    /// `panic!` 的信息，取消或者载荷不是字符串时为 None
+    pub fn panic_message(&self) -> Option<&str> {
+        match self {
+            JoinError::Panicked(payload) => {
+                payload.downcast_ref::<&str>().copied().or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            }
+            JoinError::Cancelled => None,
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, JoinError::Cancelled)
+    }
+
+    /// 在当前线程继续任务中的 panic，`handle.join().unwrap_or_else(|e| e.resume())` 就像直接调用了任务一样
+    pub fn resume(self) -> ! {
+        match self {
+            JoinError::Panicked(payload) => panic::resume_unwind(payload),
+            JoinError::Cancelled => panic!("任务在线程池关闭时被取消"),
+        }
+    }
+}
+
+impl fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JoinError::Panicked(_) => f.debug_tuple("Panicked").field(&self.panic_message().unwrap_or("..")).finish(),
+            JoinError::Cancelled => f.write_str("Cancelled"),
+        }
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self, self.panic_message()) {
+            (JoinError::Cancelled, _) => write!(f, "任务被取消"),
+            (JoinError::Panicked(_), Some(message)) => write!(f, "任务 panic: {}", message),
+            (JoinError::Panicked(_), None) => write!(f, "任务 panic"),
+        }
+    }
+}
+
+impl Error for JoinError {}
+
+/// 把 f 包装成返回“是否 panic”的任务，结果通过通道交给句柄
+pub(super) fn package<'a, T, F>(f: F) -> (impl FnOnce() -> bool + Send + 'a, JoinHandle<T>)
+where
+    T: Send + 'a,
+    F: FnOnce() -> T + Send + 'a,
+{
+    let (sender, result) = mpsc::channel();
+    let job = move || {
+        // f 的状态在 panic 之后不会再被使用，跟 thread::spawn 一样不要求 UnwindSafe
+        let outcome = panic::catch_unwind(AssertUnwindSafe(f));
+        let panicked = outcome.is_err();
+        // 句柄已经被丢掉时没有人接收，忽略即可
+        let _ = sender.send(outcome);
+        panicked
+    };
+    (job, JoinHandle { result })
+}
@@ -0,0 +1,670 @@
+/*!
+固定大小的线程池
+
+启动时创建 n 个工作线程，任务通过 `mpsc` 通道排队，空闲的工作线程依次取出来运行。
+
+- [`ThreadPool::execute`] 提交不需要结果的任务，[`ThreadPool::spawn`] 返回 [`JoinHandle`]，`join` 取回结果
+- [`ThreadPool::scope`] 中的任务可以借用调用方栈上的数据，`scope` 返回前等待它们全部结束
+- 任务 panic 时只结束这个任务，工作线程继续运行后面的任务；panic 的载荷交给句柄，`join` 得到 [`JoinError::Panicked`]
+- 线程池被 drop 时按 [`Shutdown`] 关闭：`Drain` 运行完排队的任务，`Cancel` 丢弃还没有开始的任务；两种方式都会等正在运行的任务结束
+
+```
+use rust_code_examples::threadings::thread_pool::{Shutdown, ThreadPool};
+
+let pool = ThreadPool::new(4);
+let handles: Vec<_> = (1..=10u64).map(|n| pool.spawn(move || n * n)).collect();
+let squares: u64 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+assert_eq!(squares, 385);
+
+// 任务 panic 不影响工作线程
+let failed = pool.spawn(|| -> u32 { std::panic::resume_unwind(Box::new("坏任务")) });
+assert_eq!(failed.join().unwrap_err().panic_message(), Some("坏任务"));
+assert_eq!(pool.spawn(|| 1 + 1).join().unwrap(), 2);
+
+// 借用栈上的数据
+let mut data = vec![1, 2, 3, 4, 5, 6];
+pool.scope(|s| {
+    for chunk in data.chunks_mut(2) {
+        s.execute(move || chunk.iter_mut().for_each(|x| *x *= 10));
+    }
+});
+assert_eq!(data, [10, 20, 30, 40, 50, 60]);
+
+let stats = pool.shutdown(Shutdown::Drain);
+assert_eq!((stats.completed, stats.panicked, stats.cancelled), (14, 1, 0));
+```
+
+`scope` 闭包内部的局部变量在闭包返回时就释放了，任务不能借用它：
+
+```compile_fail
+use rust_code_examples::threadings::thread_pool::ThreadPool;
+
+let pool = ThreadPool::new(1);
+pool.scope(|s| {
+    let local = vec![1, 2, 3];
+    s.execute(|| println!("{:?}", local));
+});
+```
+*/
+
+mod handle;
+mod scope;
+
+pub use handle::{JoinError, JoinHandle};
+pub use scope::Scope;
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::example;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    example!(ex1_execute_and_spawn, "提交任务", "execute 提交不需要结果的任务，spawn 返回句柄，join 取回结果"),
+    example!(ex2_scope, "借用栈上的数据", "scope 中的任务可以借用局部变量，scope 返回前等待它们全部结束"),
+    example!(ex3_panic_recovery, "任务 panic", "panic 交给句柄，工作线程继续运行后面的任务"),
+    example!(ex4_shutdown, "关闭线程池", "Drain 运行完排队的任务，Cancel 丢弃还没有开始的任务，两者都等正在运行的任务结束"),
+];
+
+// 工作线程运行的任务，返回是否 panic
+type Job = Box<dyn FnOnce() -> bool + Send + 'static>;
+
+/// 关闭线程池时如何处理还在排队的任务
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shutdown {
+    /// 运行完所有已经提交的任务
+    Drain,
+    /// 丢弃还没有开始的任务，它们的句柄得到 [`JoinError::Cancelled`]
+    Cancel,
+}
+
+/// 任务的计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub completed: usize,
+    pub panicked: usize,
+    /// 关闭时被丢弃、没有运行的任务
+    pub cancelled: usize,
+}
+
+pub struct ThreadPool {
+    // 关闭时先 drop 发送端，工作线程取完队列后 recv 返回错误并退出
+    sender: Option<mpsc::Sender<Job>>,
+    workers: Vec<thread::JoinHandle<()>>,
+    shared: Arc<Shared>,
+    on_drop: Shutdown,
+}
+
+// 所有工作线程共享的状态
+struct Shared {
+    receiver: Mutex<mpsc::Receiver<Job>>,
+    cancelled: AtomicBool,
+    queued: AtomicUsize,
+    completed: AtomicUsize,
+    panicked: AtomicUsize,
+    discarded: AtomicUsize,
+}
+
+impl ThreadPool {
+    /// 启动 size 个工作线程，drop 时按 [`Shutdown::Drain`] 关闭
+    ///
+    /// # Panics
+    ///
+    /// size 为 0 或者无法创建线程时 panic。
+    pub fn new(size: usize) -> ThreadPool {
+        assert!(size > 0, "线程池至少需要一个工作线程");
+        let (sender, receiver) = mpsc::channel();
+        let shared = Arc::new(Shared {
+            receiver: Mutex::new(receiver),
+            cancelled: AtomicBool::new(false),
+            queued: AtomicUsize::new(0),
+            completed: AtomicUsize::new(0),
+            panicked: AtomicUsize::new(0),
+            discarded: AtomicUsize::new(0),
+        });
+        let workers = (0..size)
+            .map(|id| {
+                let shared = Arc::clone(&shared);
+                thread::Builder::new()
+                    .name(format!("pool-worker-{}", id))
+                    .spawn(move || shared.work())
+                    .expect("无法创建工作线程")
+            })
+            .collect();
+        ThreadPool { sender: Some(sender), workers, shared, on_drop: Shutdown::Drain }
+    }
+
+    /// drop 时的关闭方式
+    pub fn with_shutdown(mut self, on_drop: Shutdown) -> ThreadPool {
+        self.on_drop = on_drop;
+        self
+    }
+
+    /// 工作线程的个数
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// 已经提交、还没有被工作线程取走的任务数
+    pub fn queued(&self) -> usize {
+        self.shared.queued.load(Ordering::SeqCst)
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            completed: self.shared.completed.load(Ordering::SeqCst),
+            panicked: self.shared.panicked.load(Ordering::SeqCst),
+            cancelled: self.shared.discarded.load(Ordering::SeqCst),
+        }
+    }
+
+    /// 提交一个不需要结果的任务，panic 只计入 [`Stats::panicked`]
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.submit(Box::new(move || panic::catch_unwind(AssertUnwindSafe(f)).is_err()));
+    }
+
+    /// 提交一个任务，返回取回结果的句柄
+    pub fn spawn<T, F>(&self, f: F) -> JoinHandle<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (job, handle) = handle::package(f);
+        self.submit(Box::new(job));
+        handle
+    }
+
+    /// 按 policy 关闭，等所有工作线程退出后返回最终的计数
+    pub fn shutdown(mut self, policy: Shutdown) -> Stats {
+        self.stop(policy);
+        self.stats()
+    }
+
+    fn submit(&self, job: Job) {
+        self.shared.queued.fetch_add(1, Ordering::SeqCst);
+        let sender = self.sender.as_ref().expect("线程池关闭之后不能再提交任务");
+        sender.send(job).expect("接收端由线程池持有，关闭之前一直存在");
+    }
+
+    fn stop(&mut self, policy: Shutdown) {
+        if self.sender.is_none() {
+            return;
+        }
+        if policy == Shutdown::Cancel {
+            self.shared.cancelled.store(true, Ordering::SeqCst);
+        }
+        // 发送端关闭后 recv 不会再一直阻塞，工作线程不会拿着锁不放
+        drop(self.sender.take());
+        if policy == Shutdown::Cancel {
+            // 工作线程可能都在忙，不等它们来取，直接丢弃排队的任务
+            let receiver = self.shared.receiver.lock().unwrap();
+            while let Ok(job) = receiver.try_recv() {
+                self.shared.discard(job);
+            }
+        }
+        for worker in self.workers.drain(..) {
+            worker.join().expect("任务的 panic 都已经被捕获，工作线程不会 panic");
+        }
+    }
+}
+
+impl Shared {
+    fn work(&self) {
+        loop {
+            // 只在取任务时持有锁，运行任务时其他工作线程可以继续取
+            let job = self.receiver.lock().unwrap().recv();
+            let Ok(job) = job else {
+                return;
+            };
+            if self.cancelled.load(Ordering::SeqCst) {
+                self.discard(job);
+                continue;
+            }
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+            let counter = if job() { &self.panicked } else { &self.completed };
+            counter.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn discard(&self, job: Job) {
+        self.queued.fetch_sub(1, Ordering::SeqCst);
+        self.discarded.fetch_add(1, Ordering::SeqCst);
+        drop(job);
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.stop(self.on_drop);
+    }
+}
+
+impl fmt::Debug for ThreadPool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ThreadPool")
+            .field("size", &self.size())
+            .field("queued", &self.queued())
+            .field("stats", &self.stats())
+            .field("on_drop", &self.on_drop)
+            .finish()
+    }
+}
+
+pub fn ex1_execute_and_spawn() {
+    println!("提交任务 示例代码\n\n");
+
+    let pool = ThreadPool::new(4);
+    println!("{:?}", pool);
+
+    // execute 不返回结果，通过共享的状态收集
+    let total = Arc::new(AtomicUsize::new(0));
+    for n in 1..=100 {
+        let total = Arc::clone(&total);
+        pool.execute(move || {
+            total.fetch_add(n, Ordering::SeqCst);
+        });
+    }
+
+    // spawn 返回句柄，join 按提交的顺序取回结果，与任务实际完成的顺序无关
+    let handles: Vec<JoinHandle<(u64, usize)>> = (20..25).map(|n| pool.spawn(move || (n, collatz_steps(n)))).collect();
+    for handle in handles {
+        let (n, steps) = handle.join().unwrap();
+        println!("{} 经过 {} 步到达 1", n, steps);
+    }
+
+    // 关闭时会等排队的 execute 任务全部运行完
+    let stats = pool.shutdown(Shutdown::Drain);
+    println!("1 + 2 + ... + 100 = {}，{:?}", total.load(Ordering::SeqCst), stats);
+    assert_eq!(total.load(Ordering::SeqCst), 5050);
+    assert_eq!(stats, Stats { completed: 105, panicked: 0, cancelled: 0 });
+}
+
+fn collatz_steps(mut n: u64) -> usize {
+    let mut steps = 0;
+    while n != 1 {
+        n = if n.is_multiple_of(2) { n / 2 } else { 3 * n + 1 };
+        steps += 1;
+    }
+    steps
+}
+
+pub fn ex2_scope() {
+    println!("借用栈上的数据 示例代码\n\n");
+
+    let pool = ThreadPool::new(3);
+    let words = ["线程池", "scope", "借用", "栈上的数据", "不需要", "Arc"];
+
+    // 任务直接借用 words 和 lengths，不需要 Arc 也不需要 'static
+    let mut lengths = vec![0; words.len()];
+    pool.scope(|s| {
+        for (word, length) in words.iter().zip(lengths.iter_mut()) {
+            s.execute(move || *length = word.chars().count());
+        }
+    });
+    println!("{:?}\n{:?}", words, lengths);
+    assert_eq!(lengths, [3, 5, 2, 5, 3, 3]);
+
+    // spawn 返回的结果也可以借用 'scope 的数据
+    let longest = pool.scope(|s| {
+        let handles: Vec<JoinHandle<&str>> = words.chunks(2).map(|pair| s.spawn(move || *pair.iter().max_by_key(|w| w.len()).unwrap())).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<&str>>()
+    });
+    println!("每两个词中字节最多的: {:?}", longest);
+    assert_eq!(longest, ["线程池", "栈上的数据", "不需要"]);
+
+    // execute 任务的 panic 在 scope 返回时传到调用方
+    let caught = panic::catch_unwind(AssertUnwindSafe(|| {
+        pool.scope(|s| {
+            s.execute(|| panic::resume_unwind(Box::new("scope 中的任务失败了")));
+            s.execute(|| thread::sleep(Duration::from_millis(10)));
+        })
+    }));
+    let payload = caught.unwrap_err();
+    println!("scope 继续了任务的 panic: {}", payload.downcast_ref::<&str>().unwrap());
+}
+
+pub fn ex3_panic_recovery() {
+    println!("任务 panic 示例代码\n\n");
+
+    // 只有一个工作线程，panic 之后后面的任务仍然由它运行
+    let pool = ThreadPool::new(1);
+    let name = || thread::current().name().unwrap_or_default().to_string();
+    let before = pool.spawn(name).join().unwrap();
+
+    // panic 信息照常由 panic hook 打印到标准错误，载荷交给句柄
+    let failed = pool.spawn(|| -> i32 { panic!("除数为零") });
+    let error = failed.join().unwrap_err();
+    println!("join 的结果: {}", error);
+    assert_eq!(error.panic_message(), Some("除数为零"));
+    assert!(!error.is_cancelled());
+
+    pool.execute(|| panic::resume_unwind(Box::new("没有句柄的任务")));
+    let after = pool.spawn(name).join().unwrap();
+    println!("panic 前后运行任务的线程: {} / {}", before, after);
+    assert_eq!(before, after);
+    assert_eq!(pool.size(), 1);
+
+    // 调用方想要把 panic 继续传下去时用 resume
+    let answer = pool.spawn(|| -> i32 { panic::resume_unwind(Box::new(42)) });
+    let caught = panic::catch_unwind(AssertUnwindSafe(|| answer.join().unwrap_or_else(|e| e.resume())));
+    assert_eq!(caught.unwrap_err().downcast_ref::<i32>(), Some(&42));
+
+    let stats = pool.shutdown(Shutdown::Drain);
+    println!("{:?}", stats);
+    assert_eq!(stats, Stats { completed: 2, panicked: 3, cancelled: 0 });
+}
+
+pub fn ex4_shutdown() {
+    println!("关闭线程池 示例代码\n\n");
+
+    // Drain：drop 时运行完排队的任务；只有一个工作线程，任务按提交的顺序运行
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let pool = ThreadPool::new(1);
+    for i in 0..5 {
+        let log = Arc::clone(&log);
+        pool.execute(move || {
+            thread::sleep(Duration::from_millis(2));
+            log.lock().unwrap().push(i);
+        });
+    }
+    drop(pool);
+    println!("Drain: drop 返回时运行过的任务 {:?}", log.lock().unwrap());
+    assert_eq!(*log.lock().unwrap(), [0, 1, 2, 3, 4]);
+
+    // Cancel：正在运行的任务会运行完，排队的任务被丢弃
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let pool = ThreadPool::new(1).with_shutdown(Shutdown::Cancel);
+    let (started, wait_started) = mpsc::channel();
+    let (release, released) = mpsc::channel::<()>();
+    let running = {
+        let log = Arc::clone(&log);
+        pool.spawn(move || {
+            started.send(()).unwrap();
+            // 发送端都在排队的任务中，它们被丢弃之后 recv 返回错误，这个任务才结束
+            let _ = released.recv();
+            log.lock().unwrap().push("正在运行的任务结束".to_string());
+        })
+    };
+    let queued: Vec<JoinHandle<()>> = (1..5)
+        .map(|i| {
+            let (log, release) = (Arc::clone(&log), release.clone());
+            pool.spawn(move || {
+                let _ = release.send(());
+                log.lock().unwrap().push(format!("排队的任务 {} 运行了", i));
+            })
+        })
+        .collect();
+    drop(release);
+    wait_started.recv().unwrap();
+    assert_eq!(pool.queued(), 4);
+
+    let stats = pool.shutdown(Shutdown::Cancel);
+    println!("Cancel: {:?}，{:?}", log.lock().unwrap(), stats);
+    assert_eq!(*log.lock().unwrap(), ["正在运行的任务结束"]);
+    assert_eq!(stats, Stats { completed: 1, panicked: 0, cancelled: 4 });
+    assert!(running.join().is_ok());
+    assert!(queued.into_iter().all(|h| h.join().is_err_and(|e| e.is_cancelled())));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::Barrier;
+
+    use super::*;
+    use crate::utils::XorShift64;
+
+    // 只有一个工作线程，先提交一个等待放行的任务占住它，之后提交的任务都会排队
+    fn blocked_pool(on_drop: Shutdown) -> (ThreadPool, mpsc::Sender<()>) {
+        let pool = ThreadPool::new(1).with_shutdown(on_drop);
+        let (started, wait_started) = mpsc::channel();
+        let (release, released) = mpsc::channel::<()>();
+        pool.execute(move || {
+            started.send(()).unwrap();
+            let _ = released.recv();
+        });
+        wait_started.recv().unwrap();
+        (pool, release)
+    }
+
+    #[test]
+    fn drain_runs_queued_jobs_in_order() {
+        let (pool, release) = blocked_pool(Shutdown::Drain);
+        let log = Arc::new(Mutex::new(Vec::new()));
+        for i in 0..10 {
+            let log = Arc::clone(&log);
+            pool.execute(move || log.lock().unwrap().push(i));
+        }
+        assert_eq!(pool.queued(), 10);
+        drop(release);
+
+        let stats = pool.shutdown(Shutdown::Drain);
+        assert_eq!(*log.lock().unwrap(), (0..10).collect::<Vec<_>>());
+        assert_eq!(stats, Stats { completed: 11, panicked: 0, cancelled: 0 });
+    }
+
+    #[test]
+    fn cancel_drops_queued_jobs_and_finishes_running_job() {
+        let pool = ThreadPool::new(1);
+        let (started, wait_started) = mpsc::channel();
+        let (release, released) = mpsc::channel::<()>();
+        let finished = Arc::new(AtomicBool::new(false));
+        let running = {
+            let finished = Arc::clone(&finished);
+            pool.spawn(move || {
+                started.send(()).unwrap();
+                // 排队的任务持有全部发送端，它们被丢弃之后才会返回
+                let _ = released.recv();
+                thread::sleep(Duration::from_millis(20));
+                finished.store(true, Ordering::SeqCst);
+            })
+        };
+        let ran = Arc::new(AtomicUsize::new(0));
+        for _ in 0..5 {
+            let (ran, release) = (Arc::clone(&ran), release.clone());
+            pool.execute(move || {
+                let _ = release.send(());
+                ran.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        drop(release);
+        wait_started.recv().unwrap();
+
+        let stats = pool.shutdown(Shutdown::Cancel);
+        assert!(finished.load(Ordering::SeqCst), "shutdown 返回之前正在运行的任务已经结束");
+        assert_eq!(ran.load(Ordering::SeqCst), 0);
+        assert_eq!(stats, Stats { completed: 1, panicked: 0, cancelled: 5 });
+        assert!(running.join().is_ok());
+    }
+
+    #[test]
+    fn dropped_jobs_report_cancelled() {
+        let (pool, release) = blocked_pool(Shutdown::Cancel);
+        // 排队的任务持有全部发送端，它们被丢弃之后占住工作线程的任务才会结束
+        let handles: Vec<JoinHandle<u32>> = (0..3)
+            .map(|i| {
+                let release = release.clone();
+                pool.spawn(move || {
+                    let _ = release.send(());
+                    i
+                })
+            })
+            .collect();
+        drop(release);
+        // drop 时按 with_shutdown 设置的 Cancel 关闭
+        drop(pool);
+
+        for handle in handles {
+            let error = handle.join().unwrap_err();
+            assert!(error.is_cancelled());
+            assert_eq!(error.panic_message(), None);
+            assert_eq!(error.to_string(), "任务被取消");
+        }
+    }
+
+    #[test]
+    fn worker_survives_panicking_job() {
+        let pool = ThreadPool::new(1);
+        let name = || thread::current().name().unwrap_or_default().to_string();
+        let before = pool.spawn(name).join().unwrap();
+
+        pool.execute(|| panic::resume_unwind(Box::new("execute 中的 panic")));
+        let error = pool.spawn(|| -> u32 { panic::resume_unwind(Box::new("spawn 中的 panic")) }).join().unwrap_err();
+        assert_eq!(error.panic_message(), Some("spawn 中的 panic"));
+
+        let after = pool.spawn(name).join().unwrap();
+        assert_eq!(before, after);
+        assert_eq!(pool.spawn(|| 6 * 7).join().unwrap(), 42);
+        assert_eq!(pool.shutdown(Shutdown::Drain), Stats { completed: 3, panicked: 2, cancelled: 0 });
+    }
+
+    #[test]
+    fn scope_resumes_first_panic_after_all_jobs_finish() {
+        let pool = ThreadPool::new(3);
+        let finished = AtomicUsize::new(0);
+        let caught = panic::catch_unwind(AssertUnwindSafe(|| {
+            pool.scope(|s| {
+                s.execute(|| panic::resume_unwind(Box::new("第一个")));
+                s.execute(|| {
+                    thread::sleep(Duration::from_millis(30));
+                    panic::resume_unwind(Box::new("第二个"));
+                });
+                for _ in 0..4 {
+                    s.execute(|| {
+                        thread::sleep(Duration::from_millis(20));
+                        finished.fetch_add(1, Ordering::SeqCst);
+                    });
+                }
+            })
+        }));
+        // scope 在所有任务结束之后才继续 panic，所以这里看得到全部的计数
+        assert_eq!(finished.load(Ordering::SeqCst), 4);
+        assert_eq!(caught.unwrap_err().downcast_ref::<&str>(), Some(&"第一个"));
+    }
+
+    // 每个任务恰好被运行或者被丢弃一次；`Drain` 时所有任务都会运行，句柄取回的结果与任务的结局一致。
+    // 任务用 `resume_unwind` 触发 panic，不经过 panic hook，不会在标准错误上留下大量输出。
+    #[test]
+    fn random_jobs_run_or_cancel_exactly_once() {
+        let mut rng = XorShift64::new(25);
+        for round in 0..200 {
+            let size = 1 + rng.below(4) as usize;
+            let policy = if rng.bool() { Shutdown::Drain } else { Shutdown::Cancel };
+            let count = rng.below(40) as usize;
+            let ran: Arc<Vec<AtomicBool>> = Arc::new((0..count).map(|_| AtomicBool::new(false)).collect());
+            let context = format!("第 {} 轮 {} 个工作线程 {:?}", round, size, policy);
+
+            let pool = ThreadPool::new(size);
+            let mut handles = Vec::new();
+            let mut panics = HashSet::new();
+            for i in 0..count {
+                let ran = Arc::clone(&ran);
+                let panics_here = rng.below(5) == 0;
+                if panics_here {
+                    panics.insert(i);
+                }
+                let job = move || {
+                    ran[i].store(true, Ordering::SeqCst);
+                    if panics_here {
+                        panic::resume_unwind(Box::new(i));
+                    }
+                    i
+                };
+                if rng.bool() {
+                    pool.execute(move || {
+                        job();
+                    });
+                } else {
+                    handles.push((i, pool.spawn(job)));
+                }
+            }
+            let stats = pool.shutdown(policy);
+
+            let ran: Vec<usize> = (0..count).filter(|&i| ran[i].load(Ordering::SeqCst)).collect();
+            assert_eq!(stats.completed + stats.panicked + stats.cancelled, count, "{} {:?}", context, stats);
+            assert_eq!(stats.completed + stats.panicked, ran.len(), "{} {:?}", context, stats);
+            assert_eq!(stats.panicked, ran.iter().filter(|i| panics.contains(i)).count(), "{} {:?}", context, stats);
+            if policy == Shutdown::Drain {
+                assert_eq!(stats.cancelled, 0, "{}", context);
+            }
+            for (i, handle) in handles {
+                match handle.join() {
+                    Ok(value) => assert!(value == i && ran.contains(&i) && !panics.contains(&i), "{} 任务 {}", context, i),
+                    Err(JoinError::Panicked(payload)) => {
+                        assert_eq!(payload.downcast_ref::<usize>(), Some(&i), "{} 任务 {}", context, i);
+                        assert!(ran.contains(&i) && panics.contains(&i), "{} 任务 {}", context, i);
+                    }
+                    Err(JoinError::Cancelled) => {
+                        assert!(policy == Shutdown::Cancel && !ran.contains(&i), "{} 任务 {}", context, i)
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shutdown_without_jobs() {
+        for policy in [Shutdown::Drain, Shutdown::Cancel] {
+            let pool = ThreadPool::new(3);
+            assert_eq!((pool.size(), pool.queued()), (3, 0));
+            assert_eq!(pool.shutdown(policy), Stats::default());
+        }
+        drop(ThreadPool::new(1).with_shutdown(Shutdown::Cancel));
+    }
+
+    #[test]
+    #[should_panic(expected = "线程池至少需要一个工作线程")]
+    fn zero_workers_panics() {
+        ThreadPool::new(0);
+    }
+
+    #[test]
+    fn all_workers_run_at_the_same_time() {
+        // 四个任务都到达屏障才能继续，只有四个工作线程同时运行时才不会卡住
+        let pool = ThreadPool::new(4);
+        let barrier = Arc::new(Barrier::new(4));
+        let handles: Vec<JoinHandle<()>> = (0..4)
+            .map(|_| {
+                let barrier = Arc::clone(&barrier);
+                pool.spawn(move || {
+                    barrier.wait();
+                })
+            })
+            .collect();
+        assert!(handles.into_iter().all(|h| h.join().is_ok()));
+        assert_eq!(pool.shutdown(Shutdown::Drain).completed, 4);
+    }
+
+    #[test]
+    fn empty_scope_and_handles_joined_after_scope() {
+        let pool = ThreadPool::new(2);
+        assert_eq!(pool.scope(|_| 5), 5);
+        let words = ["a", "bb", "ccc"];
+        let handles = pool.scope(|s| words.iter().map(|w| s.spawn(move || w.len())).collect::<Vec<_>>());
+        assert_eq!(handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn scope_waits_for_jobs_when_its_closure_panics() {
+        let pool = ThreadPool::new(1);
+        let finished = AtomicBool::new(false);
+        let caught = panic::catch_unwind(AssertUnwindSafe(|| {
+            pool.scope(|s| {
+                s.execute(|| {
+                    thread::sleep(Duration::from_millis(20));
+                    finished.store(true, Ordering::SeqCst);
+                });
+                panic::resume_unwind(Box::new("scope 中的 panic"));
+            })
+        }));
+        assert!(finished.load(Ordering::SeqCst));
+        assert_eq!(caught.unwrap_err().downcast_ref::<&str>(), Some(&"scope 中的 panic"));
+    }
+}
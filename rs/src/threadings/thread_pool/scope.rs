@@ -0,0 +1,123 @@
+//! 可以借用调用方栈上数据的任务
+
+use std::any::Any;
+use std::marker::PhantomData;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex};
+
+use super::handle::{self, JoinHandle};
+use super::{Job, ThreadPool};
+
+/// [`ThreadPool::scope`] 中提交任务的入口
+///
+/// 任务只需要活得比 `'scope` 长，可以借用 `scope` 调用之前就存在的局部变量；`scope` 返回之前会等待所有任务结束。
+pub struct Scope<'pool, 'scope> {
+    pool: &'pool ThreadPool,
+    state: Arc<State>,
+    // 'scope 必须是不变的，否则可以把它缩短到 scope 闭包内部的某个局部变量上
+    _invariant: PhantomData<&'scope mut &'scope ()>,
+}
+
+#[derive(Default)]
+struct State {
+    progress: Mutex<Progress>,
+    idle: Condvar,
+}
+
+#[derive(Default)]
+struct Progress {
+    // 提交了还没有结束的任务数
+    pending: usize,
+    // 第一个 panic 的 execute 任务的载荷
+    panic: Option<Box<dyn Any + Send + 'static>>,
+}
+
+// 任务提交时加一，任务运行结束或者被丢弃时减一
+struct Pending(Arc<State>);
+
+impl Pending {
+    fn new(state: &Arc<State>) -> Pending {
+        state.progress.lock().unwrap().pending += 1;
+        Pending(Arc::clone(state))
+    }
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        let mut progress = self.0.progress.lock().unwrap();
+        progress.pending -= 1;
+        if progress.pending == 0 {
+            self.0.idle.notify_all();
+        }
+    }
+}
+
+impl State {
+    fn wait(&self) {
+        let mut progress = self.progress.lock().unwrap();
+        while progress.pending > 0 {
+            progress = self.idle.wait(progress).unwrap();
+        }
+    }
+}
+
+impl ThreadPool {
+    /// 在 f 中提交的任务可以借用当前栈上的数据，所有任务结束之后才返回
+    ///
+    /// 用 [`Scope::execute`] 提交的任务 panic 时，等所有任务结束后在调用方继续第一个 panic；
+    /// [`Scope::spawn`] 的 panic 只交给它的句柄。在线程池自己的任务中调用 `scope` 可能因为没有空闲的工作线程而死锁。
+    pub fn scope<'scope, R>(&self, f: impl FnOnce(&Scope<'_, 'scope>) -> R) -> R {
+        let scope = Scope { pool: self, state: Arc::default(), _invariant: PhantomData };
+        // f 本身 panic 时也要等任务结束，已经提交的任务可能还借用着栈上的数据
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+        scope.state.wait();
+        let job_panic = scope.state.progress.lock().unwrap().panic.take();
+        match (result, job_panic) {
+            (Err(payload), _) | (Ok(_), Some(payload)) => panic::resume_unwind(payload),
+            (Ok(value), None) => value,
+        }
+    }
+}
+
+impl<'scope> Scope<'_, 'scope> {
+    /// 提交一个不需要结果的任务
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'scope,
+    {
+        let state = Arc::clone(&self.state);
+        self.submit(move || match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(()) => false,
+            Err(payload) => {
+                state.progress.lock().unwrap().panic.get_or_insert(payload);
+                true
+            }
+        });
+    }
+
+    /// 提交一个任务，返回取回结果的句柄，句柄也可以在 `scope` 结束之后再 join
+    pub fn spawn<T, F>(&self, f: F) -> JoinHandle<T>
+    where
+        T: Send + 'scope,
+        F: FnOnce() -> T + Send + 'scope,
+    {
+        let (job, handle) = handle::package(f);
+        self.submit(job);
+        handle
+    }
+
+    fn submit(&self, job: impl FnOnce() -> bool + Send + 'scope) {
+        let pending = Pending::new(&self.state);
+        let job: Box<dyn FnOnce() -> bool + Send + 'scope> = Box::new(move || {
+            let panicked = job();
+            drop(pending);
+            panicked
+        });
+        // SAFETY: 只是抹掉了生命周期。'scope 比 ThreadPool::scope 的调用活得长，
+        // 而 scope 在 pending 归零之前不会返回；pending 在任务运行结束、捕获的数据都被 drop 之后才减一。
+        // 线程池在 scope 期间被借用着，不会关闭，所以任务不会在运行之前被丢弃。
+        let job = unsafe { mem::transmute::<Box<dyn FnOnce() -> bool + Send + 'scope>, Job>(job) };
+        self.pool.submit(job);
+    }
+}
@@ -0,0 +1,106 @@
+/*!
+示例和基准测试共用的小工具
+
+只依赖标准库，可以离线使用。
+*/
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// xorshift64* 伪随机数生成器，用固定种子得到可重复的随机序列
+///
+/// 只用于生成测试数据，不适合密码学用途。
+///
+/// ```
+/// use rust_code_examples::utils::XorShift64;
+///
+/// let mut a = XorShift64::new(42);
+/// let mut b = XorShift64::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// assert!((0..1000).all(|_| a.below(10) < 10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// 状态不能为 0，种子为 0 时换成一个固定的非零值
+    pub fn new(seed: u64) -> XorShift64 {
+        XorShift64 { state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed } }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// `[0, bound)` 中的随机数，`bound` 不能为 0
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound 不能为 0");
+        // 128 位乘法取高位，比取模更均匀
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// `range` 中的随机数
+    pub fn range(&mut self, range: std::ops::Range<i64>) -> i64 {
+        assert!(range.start < range.end, "范围不能为空");
+        range.start + self.below(range.end.abs_diff(range.start)) as i64
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// Fisher-Yates 洗牌
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// 系统临时目录下的一个新目录，drop 时连同内容一起删除
+///
+/// 目录名带有进程号和进程内的计数，同时运行的多个进程或线程不会写到同一个目录。
+///
+/// ```
+/// use rust_code_examples::utils::TempDir;
+///
+/// let (a, b) = (TempDir::new("doc").unwrap(), TempDir::new("doc").unwrap());
+/// assert_ne!(a.path(), b.path());
+/// let path = a.path().to_path_buf();
+/// std::fs::write(path.join("file"), "hello").unwrap();
+/// drop(a);
+/// assert!(!path.exists());
+/// ```
+#[derive(Debug)]
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(prefix: &str) -> io::Result<TempDir> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let n = NEXT.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("rust_code_examples_{}_{}_{}", prefix, process::id(), n));
+        fs::create_dir_all(&path)?;
+        Ok(TempDir(path))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}